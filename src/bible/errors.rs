@@ -1,25 +1,80 @@
 //! This module contains several error types which might occur during the process of creating and manipulating Bible references.
 
-use std::fmt::{Display, Formatter};
-use std::error::Error;
+use alloc::format;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
 
-/// The [BibleReferenceValidationError] will be thrown in case of an error during a validation of a BibleReference, which means that the Bible reference does not exist because the chapter or verse of the reference are not in the Bible. 
+/// The [BibleReferenceValidationError] will be thrown in case of an error during a validation of a BibleReference, which means that the Bible reference does not exist because the chapter or verse of the reference are not in the Bible.
 /// The field `problem` contains more information about the problem which caused the error.
 #[derive(PartialEq, Debug)]
 pub struct BibleReferenceValidationError {
     /// The actual problem which caused the [BibleReferenceValidationError]
-    pub problem: BibleReferenceProblem
+    pub problem: BibleReferenceProblem,
 }
+impl BibleReferenceValidationError {
+    /// Returns the error message in the language with the given language code. German ("de"), Spanish ("es") and French ("fr") are supported, English is used for all other language codes.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::errors::{BibleReferenceProblem, BibleReferenceValidationError};
+    /// let error = BibleReferenceValidationError { problem: BibleReferenceProblem::ChapterDoesNotExist };
+    /// assert_eq!(error.message_in_language("de"), "Die Bibelstelle ist ungültig: Das Kapitel existiert nicht");
+    /// assert_eq!(error.message_in_language("xx"), error.to_string());
+    /// ```
+    pub fn message_in_language(&self, language_code: &str) -> String {
+        let (prefix, problem) = match language_code.trim().to_lowercase().as_str() {
+            "de" => (
+                "Die Bibelstelle ist ungültig",
+                match self.problem {
+                    BibleReferenceProblem::ChapterDoesNotExist => "Das Kapitel existiert nicht",
+                    BibleReferenceProblem::VerseDoesNotExist => "Der Vers existiert nicht",
+                    BibleReferenceProblem::StartReferenceAfterEndReference => {
+                        "Die Anfangsstelle liegt nach der Endstelle"
+                    }
+                },
+            ),
+            "es" => (
+                "La referencia bíblica no es válida",
+                match self.problem {
+                    BibleReferenceProblem::ChapterDoesNotExist => "El capítulo no existe",
+                    BibleReferenceProblem::VerseDoesNotExist => "El versículo no existe",
+                    BibleReferenceProblem::StartReferenceAfterEndReference => {
+                        "La referencia inicial está después de la referencia final"
+                    }
+                },
+            ),
+            "fr" => (
+                "La référence biblique n'est pas valide",
+                match self.problem {
+                    BibleReferenceProblem::ChapterDoesNotExist => "Le chapitre n'existe pas",
+                    BibleReferenceProblem::VerseDoesNotExist => "Le verset n'existe pas",
+                    BibleReferenceProblem::StartReferenceAfterEndReference => {
+                        "La référence de début se trouve après la référence de fin"
+                    }
+                },
+            ),
+            _ => (
+                "The Bible reference is invalid",
+                match self.problem {
+                    BibleReferenceProblem::ChapterDoesNotExist => "The chapter does not exist",
+                    BibleReferenceProblem::VerseDoesNotExist => "The verse does not exist",
+                    BibleReferenceProblem::StartReferenceAfterEndReference => {
+                        "The start reference is after the end reference"
+                    }
+                },
+            ),
+        };
+        // French puts a space in front of the colon
+        match language_code.trim().to_lowercase().as_str() {
+            "fr" => format!("{} : {}", prefix, problem),
+            _ => format!("{}: {}", prefix, problem),
+        }
+    }
+}
+
 impl Display for BibleReferenceValidationError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, 
-            "The Bible reference is invalid: {}",
-            match self.problem {
-                BibleReferenceProblem::ChapterDoesNotExist => "The chapter does not exist",
-                BibleReferenceProblem::VerseDoesNotExist => "The verse does not exist",
-                BibleReferenceProblem::StartReferenceAfterEndReference => "The start reference is after the end reference",
-            }
-        )
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.message_in_language("en"))
     }
 }
 impl Error for BibleReferenceValidationError {}
@@ -33,4 +88,68 @@ pub enum BibleReferenceProblem {
     VerseDoesNotExist,
     /// Indicates that a given start reference is after the end reference.
     StartReferenceAfterEndReference,
-}
\ No newline at end of file
+}
+
+/// The [BookNameNotRecognizedError] will be returned if a string could not be converted into a [BibleBook](crate::bible::BibleBook) because it is no known English book name.
+#[derive(PartialEq, Debug)]
+pub struct BookNameNotRecognizedError {
+    /// The provided string which could not be recognized as a Bible book
+    pub provided_book_name: String,
+}
+impl Display for BookNameNotRecognizedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "The Bible book name '{}' could not be recognized.",
+            self.provided_book_name
+        )
+    }
+}
+impl Error for BookNameNotRecognizedError {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localized_messages() {
+        let problems = [
+            BibleReferenceProblem::ChapterDoesNotExist,
+            BibleReferenceProblem::VerseDoesNotExist,
+            BibleReferenceProblem::StartReferenceAfterEndReference,
+        ];
+        for problem in problems {
+            let error = BibleReferenceValidationError { problem };
+            assert_eq!(error.message_in_language("en"), error.to_string());
+            assert_eq!(error.message_in_language("unknown"), error.to_string());
+            assert_eq!(
+                error.message_in_language(" DE "),
+                error.message_in_language("de")
+            );
+            for language_code in ["de", "es", "fr"] {
+                assert_ne!(error.message_in_language(language_code), error.to_string());
+            }
+        }
+        assert_eq!(
+            BibleReferenceValidationError {
+                problem: BibleReferenceProblem::VerseDoesNotExist
+            }
+            .message_in_language("es"),
+            "La referencia bíblica no es válida: El versículo no existe"
+        );
+        assert_eq!(
+            BibleReferenceValidationError {
+                problem: BibleReferenceProblem::VerseDoesNotExist
+            }
+            .message_in_language("fr"),
+            "La référence biblique n'est pas valide : Le verset n'existe pas"
+        );
+        assert_eq!(
+            BibleReferenceValidationError {
+                problem: BibleReferenceProblem::ChapterDoesNotExist
+            }
+            .to_string(),
+            "The Bible reference is invalid: The chapter does not exist"
+        );
+    }
+}
@@ -15,10 +15,19 @@ pub mod lists;
 /// Includes helper functions for the validation of Bible references.
 pub mod validate;
 
+/// Includes the versification schemes which define the chapters and verses of the Bible books.
+pub mod versification;
+
 /// Includes errors which might occur during validation, creation or manipulation of Bible references
 pub mod errors;
 
-use std::cmp::Ordering;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use core::fmt::{Display, Formatter};
+#[cfg(feature = "std")]
+use core::str::FromStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -28,9 +37,13 @@ use lists::{BibleBookList, BibleChapterList, BibleReferenceList, BibleVerseList}
 use validate::*;
 
 use self::errors::BibleReferenceValidationError;
+#[cfg(feature = "std")]
+use self::errors::BookNameNotRecognizedError;
+#[cfg(feature = "std")]
+use crate::referencing::language::{BookReferenceType, ENGLISH_REFERENCE_LANGUAGE};
 
 /// This struct represents a valid Bible reference which consists of a book.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BibleBookReference {
     book: BibleBook,
@@ -44,10 +57,59 @@ impl BibleBookReference {
     pub fn book(&self) -> BibleBook {
         self.book
     }
+
+    /// Returns the first chapter of the book, e.g. Psalm 1.
+    pub fn first_chapter(&self) -> BibleChapterReference {
+        BibleChapterReference::new(self.book, 1).unwrap()
+    }
+
+    /// Returns the first verse of the book, e.g. Psalm 1:1.
+    pub fn first_verse(&self) -> BibleVerseReference {
+        self.first_chapter().first_verse()
+    }
+
+    /// Returns the last chapter of the book, e.g. Psalm 150.
+    pub fn last_chapter(&self) -> BibleChapterReference {
+        BibleChapterReference::new(self.book, get_number_of_chapters(&self.book)).unwrap()
+    }
+
+    /// Returns the last verse of the book, e.g. Psalm 150:6.
+    pub fn last_verse(&self) -> BibleVerseReference {
+        self.last_chapter().last_verse()
+    }
+
+    /// Encodes the reference as a [u32] in the same layout as [BibleVerseReference::to_u32], with chapter and verse set to 0.
+    pub fn to_u32(&self) -> u32 {
+        u32::from_be_bytes([0, self.book.number(), 0, 0])
+    }
+
+    /// Decodes a reference which has been encoded with [BibleBookReference::to_u32].
+    /// # Returns
+    /// - [None] if the value does not encode an existing book.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value.to_be_bytes() {
+            [0, book, 0, 0] => get_bible_book_by_number(book).map(Self::new),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for BibleBookReference {
+    /// Formats the reference in English with the long book name, e.g. "Genesis".
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        BibleReference::BibleBook(self.clone()).fmt(f)
+    }
+}
+
+impl From<BibleBook> for BibleBookReference {
+    fn from(book: BibleBook) -> Self {
+        BibleBookReference::new(book)
+    }
 }
 
 /// This struct represents a Bible reference which is valid (can be found in a real Bible), consisting of a book and a chapter.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BibleChapterReference {
     book: BibleBook,
@@ -66,6 +128,19 @@ impl BibleChapterReference {
         }
     }
 
+    /// Creates a chapter reference like [BibleChapterReference::new], but clamps a chapter which does not exist into the valid chapters of the book instead of returning an error.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleChapterReference};
+    /// assert_eq!(BibleChapterReference::new_clamped(BibleBook::Jude, 3).chapter(), 1);
+    /// assert_eq!(BibleChapterReference::new_clamped(BibleBook::John, 3).chapter(), 3);
+    /// assert_eq!(BibleChapterReference::new_clamped(BibleBook::John, 0).chapter(), 1);
+    /// ```
+    pub fn new_clamped(book: BibleBook, chapter: BibleChapter) -> Self {
+        let chapter = chapter.clamp(1, get_number_of_chapters(&book));
+        BibleChapterReference { book, chapter }
+    }
+
     /// Returns the book of the BibleChapterReference
     pub fn book(&self) -> BibleBook {
         self.book
@@ -75,14 +150,79 @@ impl BibleChapterReference {
     pub fn chapter(&self) -> BibleChapter {
         self.chapter
     }
+
+    /// Returns the first verse of the chapter, e.g. John 3:1 for John 3. The superscription of a Psalm (verse 0) is not regarded as the first verse.
+    pub fn first_verse(&self) -> BibleVerseReference {
+        BibleVerseReference::new(self.book, self.chapter, 1).unwrap()
+    }
+
+    /// Returns the last verse of the chapter, e.g. John 3:36 for John 3.
+    pub fn last_verse(&self) -> BibleVerseReference {
+        let last_verse = get_number_of_verses(&self.book, &self.chapter).unwrap();
+        BibleVerseReference::new(self.book, self.chapter, last_verse).unwrap()
+    }
+
+    /// Returns all verses of the chapter in their order, e.g. John 3:1 to John 3:36. The superscription of a Psalm (verse 0) is not included.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleChapterReference};
+    /// let verses = BibleChapterReference::new(BibleBook::John, 3).unwrap().verses();
+    /// assert_eq!(verses.len(), 36);
+    /// assert_eq!(verses[15].verse(), 16);
+    /// ```
+    pub fn verses(&self) -> BibleVerseList {
+        (1..=get_number_of_verses(&self.book, &self.chapter).unwrap())
+            .map(|verse| BibleVerseReference::new(self.book, self.chapter, verse).unwrap())
+            .collect()
+    }
+
+    /// Encodes the reference as a [u32] in the same layout as [BibleVerseReference::to_u32], with the verse set to 0.
+    pub fn to_u32(&self) -> u32 {
+        u32::from_be_bytes([0, self.book.number(), self.chapter, 0])
+    }
+
+    /// Decodes a reference which has been encoded with [BibleChapterReference::to_u32].
+    /// # Returns
+    /// - [None] if the value does not encode an existing chapter.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value.to_be_bytes() {
+            [0, book, chapter, 0] => Self::new(get_bible_book_by_number(book)?, chapter).ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for BibleChapterReference {
+    /// Formats the reference in English with the long book name, e.g. "Genesis 1".
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        BibleReference::BibleChapter(self.clone()).fmt(f)
+    }
+}
+
+impl TryFrom<(BibleBook, BibleChapter)> for BibleChapterReference {
+    type Error = BibleReferenceValidationError;
+
+    /// Creates a chapter reference from a `(book, chapter)` tuple, see [BibleChapterReference::new].
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleChapterReference};
+    /// let chapter: BibleChapterReference = (BibleBook::John, 3).try_into().unwrap();
+    /// assert_eq!(chapter.chapter(), 3);
+    /// assert!(BibleChapterReference::try_from((BibleBook::John, 22)).is_err());
+    /// ```
+    fn try_from((book, chapter): (BibleBook, BibleChapter)) -> Result<Self, Self::Error> {
+        BibleChapterReference::new(book, chapter)
+    }
 }
 
 /// This struct contains a Bible reference which is valid (can be found in a real Bible), consisting of a book, a chapter and a verse.
 ///
 /// Please note the following: There are some differences concerning the number of verses of certain chapters depending on some Bible versions, e.g. in English Bible translations, Psalms may have one verse more as in most German translations–because the introduction words at the beginning of some Psalms are counted as a separate verse, while other translations might render them as the preface (or a verse 0). In this crate, we are always assuming the **maximum amount** of verses, so that all translations and versions can be used.
+/// The superscription of a Psalm itself can be referenced as verse 0 (e.g. Psalm 3:0), see [BibleVerseReference::is_superscription].
 /// In the new testament, the Textus Receptus is used as template for determining the numbers of chapters and verses.
-/// Some books (like the book of Jude) may only have one Chapter. Normally, in human languages people would only quote the verse and leave the chapter out (e.g. Jude 13)–however, this will be parsed as Jude 1:13 technically.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+/// Some books (Obadiah, Philemon, 2 John, 3 John and Jude) only have one chapter. Normally, in human languages people would only quote the verse and leave the chapter out (e.g. Jude 13)–the parser therefore interprets a single number after these books as a verse, so that "Jude 13" is parsed as Jude 1:13. Accordingly, the chapter of such a book is written as the range of all its verses, e.g. "Jude 1:1-25".
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BibleVerseReference {
     book: BibleBook,
@@ -107,6 +247,25 @@ impl BibleVerseReference {
         }
     }
 
+    /// Creates a verse reference like [BibleVerseReference::new], but clamps a chapter or verse which does not exist into the valid range instead of returning an error.
+    /// The chapter is clamped first (see [BibleChapterReference::new_clamped]), then the verse is clamped to the verses of this chapter. Verse 0 is kept for the superscription of a Psalm.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleVerseReference};
+    /// let verse = BibleVerseReference::new_clamped(BibleBook::John, 3, 40);
+    /// assert_eq!((verse.chapter(), verse.verse()), (3, 36));
+    /// let verse = BibleVerseReference::new_clamped(BibleBook::John, 30, 1);
+    /// assert_eq!((verse.chapter(), verse.verse()), (21, 1));
+    /// ```
+    pub fn new_clamped(book: BibleBook, chapter: BibleChapter, verse: BibleVerse) -> Self {
+        let chapter = BibleChapterReference::new_clamped(book, chapter);
+        match Self::new(book, chapter.chapter(), verse) {
+            Ok(verse) => verse,
+            Err(_) if verse == 0 => chapter.first_verse(),
+            Err(_) => chapter.last_verse(),
+        }
+    }
+
     /// Returns the book of the BibleVerseReference
     pub fn book(&self) -> BibleBook {
         self.book
@@ -121,11 +280,90 @@ impl BibleVerseReference {
     pub fn verse(&self) -> BibleVerse {
         self.verse
     }
+
+    /// Returns true if the reference points to the superscription of a Psalm, which is referenced as verse 0 (e.g. Psalm 3:0).
+    /// See [has_superscription] for the Psalms which have a superscription.
+    pub fn is_superscription(&self) -> bool {
+        self.verse == 0
+    }
+
+    /// Encodes the reference as a compact [u32], e.g. for storing it in a database.
+    /// The book number (see [BibleBook::number]), the chapter and the verse are packed into the lower three bytes (8 bits each), the highest byte is 0.
+    /// Therefore, the encoded values sort in the canonical order of the verses.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleVerseReference};
+    /// let verse = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+    /// assert_eq!(verse.to_u32(), 0x002B0310);
+    /// assert_eq!(BibleVerseReference::from_u32(verse.to_u32()), Some(verse));
+    /// ```
+    pub fn to_u32(&self) -> u32 {
+        u32::from_be_bytes([0, self.book.number(), self.chapter, self.verse])
+    }
+
+    /// Decodes a reference which has been encoded with [BibleVerseReference::to_u32].
+    /// # Returns
+    /// - [None] if the value does not encode an existing verse.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value.to_be_bytes() {
+            [0, book, chapter, verse] => {
+                Self::new(get_bible_book_by_number(book)?, chapter, verse).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the zero-based index of the verse in the whole Bible, e.g. 0 for Genesis 1:1 and 31101 for Revelation 22:21.
+    /// The superscription of a Psalm (verse 0) has the same index as the first verse of the Psalm. See [verse_at_index] for the inverse.
+    pub fn global_index(&self) -> u32 {
+        (verse_position(self) - 1) as u32
+    }
+
+    /// Returns the fraction of the Bible's verses up to and including this verse, e.g. for showing the reading progress.
+    /// The superscription of a Psalm (verse 0) is not counted as a verse of its own, see [total_number_of_verses].
+    /// # Returns
+    /// - A value greater than 0.0 which is 1.0 for the last verse of the Bible
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleVerseReference};
+    /// let verse = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+    /// # #[cfg(not(feature = "deuterocanon"))]
+    /// assert_eq!(format!("{:.1}%", verse.position_fraction() * 100.0), "84.0%");
+    /// ```
+    pub fn position_fraction(&self) -> f64 {
+        verse_position(self) as f64 / total_number_of_verses() as f64
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for BibleVerseReference {
+    /// Formats the reference in English with the long book name, e.g. "John 3:16".
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        BibleReference::BibleVerse(self.clone()).fmt(f)
+    }
+}
+
+impl TryFrom<(BibleBook, BibleChapter, BibleVerse)> for BibleVerseReference {
+    type Error = BibleReferenceValidationError;
+
+    /// Creates a verse reference from a `(book, chapter, verse)` tuple, see [BibleVerseReference::new].
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleVerseReference};
+    /// let verse: BibleVerseReference = (BibleBook::John, 3, 16).try_into().unwrap();
+    /// assert_eq!(verse.verse(), 16);
+    /// assert!(BibleVerseReference::try_from((BibleBook::John, 3, 37)).is_err());
+    /// ```
+    fn try_from(
+        (book, chapter, verse): (BibleBook, BibleChapter, BibleVerse),
+    ) -> Result<Self, Self::Error> {
+        BibleVerseReference::new(book, chapter, verse)
+    }
 }
 
 /// This enum represents all possible representations of one or multiple Bible references.
 /// It can be a reference to a book, a chapter or a verse. It can also be a range of books, chapters or verses or to a list of books, chapters or verses.
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BibleReferenceRepresentation {
     /// A single Bible reference
@@ -146,6 +384,45 @@ impl BibleReferenceRepresentation {
         matches!(self, BibleReferenceRepresentation::Range(_))
     }
 
+    /// Returns the reference where the representation begins.
+    fn start(&self) -> BibleReference {
+        match self {
+            BibleReferenceRepresentation::Single(reference) => reference.clone(),
+            BibleReferenceRepresentation::Range(range) => range.start(),
+        }
+    }
+
+    /// Returns the last verse which is covered by the representation.
+    fn last_verse(&self) -> BibleVerseReference {
+        match self {
+            BibleReferenceRepresentation::Single(reference) => reference.verse_span().1,
+            BibleReferenceRepresentation::Range(range) => range.end().verse_span().1,
+        }
+    }
+
+    /// Returns every single verse which is covered by the representation in canonical order. Books and chapters are expanded to all of their verses, ranges may span over several chapters and books.
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use bibleref::bible::BibleBook;
+    /// use bibleref::bible::validate::total_verses_in_book;
+    /// let verses = bibleref::parse("Jude").unwrap().as_verse_list();
+    /// assert_eq!(verses.len(), total_verses_in_book(&BibleBook::Jude) as usize);
+    /// assert_eq!(bibleref::parse("John 3:35-4:2").unwrap().as_verse_list().len(), 4);
+    /// # }
+    /// ```
+    pub fn as_verse_list(&self) -> BibleVerseList {
+        let (first, last) = match self {
+            BibleReferenceRepresentation::Single(reference) => reference.verse_span(),
+            BibleReferenceRepresentation::Range(range) => {
+                (range.start().verse_span().0, range.end().verse_span().1)
+            }
+        };
+        BibleVerseRange::new(first, last)
+            .map(|range| range.as_list())
+            .unwrap_or_default()
+    }
+
     pub fn try_upcast(&self) -> BibleReferenceRepresentation {
         match self {
             BibleReferenceRepresentation::Single(_) => self.clone(),
@@ -190,59 +467,103 @@ impl BibleReferenceRepresentation {
 }
 
 impl Ord for BibleReferenceRepresentation {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match (self, other) {
-            (BibleReferenceRepresentation::Single(a), BibleReferenceRepresentation::Single(b)) => {
-                a.cmp(b)
-            }
-            (BibleReferenceRepresentation::Range(a), BibleReferenceRepresentation::Range(b)) => {
-                a.cmp(b)
-            }
-
-            (BibleReferenceRepresentation::Single(a), BibleReferenceRepresentation::Range(b)) => {
-                match a {
-                    BibleReference::BibleBook(a) => {
-                        let a_ref = BibleReference::BibleBook(a.clone());
-                        a_ref.cmp(&b.end())
-                    }
-                    BibleReference::BibleChapter(a) => {
-                        let a_ref = BibleReference::BibleChapter(a.clone());
-                        a_ref.cmp(&b.end())
-                    }
-                    BibleReference::BibleVerse(a) => {
-                        let a_ref = BibleReference::BibleVerse(a.clone());
-                        a_ref.cmp(&b.end())
-                    }
-                }
-            }
-            (BibleReferenceRepresentation::Range(a), BibleReferenceRepresentation::Single(b)) => {
-                match b {
-                    BibleReference::BibleBook(b) => {
-                        let b_ref = BibleReference::BibleBook(b.clone());
-                        a.end().cmp(&b_ref)
-                    }
-                    BibleReference::BibleChapter(b) => {
-                        let b_ref = BibleReference::BibleChapter(b.clone());
-                        a.end().cmp(&b_ref)
-                    }
-                    BibleReference::BibleVerse(b) => {
-                        let b_ref = BibleReference::BibleVerse(b.clone());
-                        a.end().cmp(&b_ref)
-                    }
-                }
-            }
-        }
+    /// Representations are sorted by the position where they begin (see the [Ord] implementation of [BibleReference]), so that a reference inside a range sorts after the beginning of the range.
+    /// If two representations begin at the same position, the one which ends first comes first, and a single reference comes before a range covering the same verses.
+    /// This order is total and consistent with [PartialEq]; for single references, it is the same as the order of [BibleReference].
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use bibleref::bible::BibleReferenceRepresentation;
+    /// let mut representations: Vec<BibleReferenceRepresentation> = ["Gen 1:5", "Gen 1:1-10", "Gen 1:1-3", "Gen 1", "Gen 1:1"]
+    ///     .iter()
+    ///     .map(|reference| bibleref::parse(reference).unwrap())
+    ///     .collect();
+    /// representations.sort();
+    /// let sorted: Vec<String> = representations.iter().map(|representation| representation.to_string()).collect();
+    /// assert_eq!(sorted, vec!["Genesis 1", "Genesis 1:1", "Genesis 1:1-3", "Genesis 1:1-10", "Genesis 1:5"]);
+    /// # }
+    /// ```
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.start()
+            .cmp(&other.start())
+            .then_with(|| self.last_verse().cmp(&other.last_verse()))
+            .then_with(|| match (self, other) {
+                (
+                    BibleReferenceRepresentation::Single(_),
+                    BibleReferenceRepresentation::Single(_),
+                ) => Ordering::Equal,
+                (
+                    BibleReferenceRepresentation::Single(_),
+                    BibleReferenceRepresentation::Range(_),
+                ) => Ordering::Less,
+                (
+                    BibleReferenceRepresentation::Range(_),
+                    BibleReferenceRepresentation::Single(_),
+                ) => Ordering::Greater,
+                (
+                    BibleReferenceRepresentation::Range(a),
+                    BibleReferenceRepresentation::Range(b),
+                ) => a.cmp(b),
+            })
     }
 }
 
 impl PartialOrd for BibleReferenceRepresentation {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
+impl From<BibleReference> for BibleReferenceRepresentation {
+    fn from(reference: BibleReference) -> Self {
+        BibleReferenceRepresentation::Single(reference)
+    }
+}
+
+impl PartialEq<BibleReference> for BibleReferenceRepresentation {
+    /// A representation is equal to a reference if it is a single representation of this reference. Ranges are never equal to a reference.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleReference, BibleReferenceRepresentation, BibleVerseReference};
+    /// let verse = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+    /// # #[cfg(feature = "std")] {
+    /// assert!(bibleref::parse("John 3:16").unwrap() == verse);
+    /// assert!(verse != bibleref::parse("John 3:16-17").unwrap());
+    /// # }
+    /// ```
+    fn eq(&self, other: &BibleReference) -> bool {
+        match self {
+            BibleReferenceRepresentation::Single(reference) => reference == other,
+            BibleReferenceRepresentation::Range(_) => false,
+        }
+    }
+}
+
+impl PartialEq<BibleReferenceRepresentation> for BibleReference {
+    fn eq(&self, other: &BibleReferenceRepresentation) -> bool {
+        other == self
+    }
+}
+
+impl From<BibleRange> for BibleReferenceRepresentation {
+    fn from(range: BibleRange) -> Self {
+        BibleReferenceRepresentation::Range(range)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for BibleReferenceRepresentation {
+    /// Formats the single reference or range in English with the long book name, e.g. "John 3:16" or "Joshua 3-7".
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BibleReferenceRepresentation::Single(reference) => reference.fmt(f),
+            BibleReferenceRepresentation::Range(range) => range.fmt(f),
+        }
+    }
+}
+
 /// This enum represents *any* single Bible reference (one book, one chapter or one verse)
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BibleReference {
     BibleBook(BibleBookReference),
@@ -257,16 +578,18 @@ impl BibleReference {
     pub fn next(&self) -> Option<BibleReference> {
         match self {
             BibleReference::BibleBook(book) => {
-                let next_book = get_bible_book_by_number(book.book.number() + 1);
+                let next_book = Self::following_book(book.book());
                 next_book
                     .map(|next_book| BibleReference::BibleBook(BibleBookReference::new(next_book)))
             }
             BibleReference::BibleChapter(chapter) => {
-                let next_chapter = chapter.chapter + 1;
-                match BibleChapterReference::new(chapter.book(), next_chapter) {
-                    Ok(next_chapter) => Some(BibleReference::BibleChapter(next_chapter)),
-                    Err(_) => {
-                        let next_book = get_bible_book_by_number(chapter.book().number() + 1);
+                let next_chapter = chapter.chapter.checked_add(1).and_then(|next_chapter| {
+                    BibleChapterReference::new(chapter.book(), next_chapter).ok()
+                });
+                match next_chapter {
+                    Some(next_chapter) => Some(BibleReference::BibleChapter(next_chapter)),
+                    None => {
+                        let next_book = Self::following_book(chapter.book());
                         next_book.map(|next_book| {
                             BibleReference::BibleBook(BibleBookReference::new(next_book))
                         })
@@ -274,15 +597,19 @@ impl BibleReference {
                 }
             }
             BibleReference::BibleVerse(verse) => {
-                let next_verse = verse.verse + 1;
-                match BibleVerseReference::new(verse.book(), verse.chapter(), next_verse) {
-                    Ok(next_verse) => Some(BibleReference::BibleVerse(next_verse)),
-                    Err(_) => {
-                        let next_chapter = verse.chapter + 1;
-                        match BibleChapterReference::new(verse.book(), next_chapter) {
-                            Ok(next_chapter) => Some(BibleReference::BibleChapter(next_chapter)),
-                            Err(_) => {
-                                let next_book = get_bible_book_by_number(verse.book().number() + 1);
+                let next_verse = verse.verse.checked_add(1).and_then(|next_verse| {
+                    BibleVerseReference::new(verse.book(), verse.chapter(), next_verse).ok()
+                });
+                match next_verse {
+                    Some(next_verse) => Some(BibleReference::BibleVerse(next_verse)),
+                    None => {
+                        let next_chapter = verse.chapter.checked_add(1).and_then(|next_chapter| {
+                            BibleChapterReference::new(verse.book(), next_chapter).ok()
+                        });
+                        match next_chapter {
+                            Some(next_chapter) => Some(BibleReference::BibleChapter(next_chapter)),
+                            None => {
+                                let next_book = Self::following_book(verse.book());
                                 next_book.map(|next_book| {
                                     BibleReference::BibleBook(BibleBookReference::new(next_book))
                                 })
@@ -300,17 +627,20 @@ impl BibleReference {
     pub fn previous(&self) -> Option<BibleReference> {
         match self {
             BibleReference::BibleBook(book) => {
-                let previous_book = get_bible_book_by_number(book.book.number() - 1);
+                let previous_book = Self::preceding_book(book.book());
                 previous_book.map(|previous_book| {
                     BibleReference::BibleBook(BibleBookReference::new(previous_book))
                 })
             }
             BibleReference::BibleChapter(chapter) => {
-                let previous_chapter = chapter.chapter - 1;
-                match BibleChapterReference::new(chapter.book(), previous_chapter) {
-                    Ok(previous_chapter) => Some(BibleReference::BibleChapter(previous_chapter)),
-                    Err(_) => {
-                        let previous_book = get_bible_book_by_number(chapter.book().number() - 1);
+                let previous_chapter =
+                    chapter.chapter.checked_sub(1).and_then(|previous_chapter| {
+                        BibleChapterReference::new(chapter.book(), previous_chapter).ok()
+                    });
+                match previous_chapter {
+                    Some(previous_chapter) => Some(BibleReference::BibleChapter(previous_chapter)),
+                    None => {
+                        let previous_book = Self::preceding_book(chapter.book());
                         previous_book.map(|previous_book| {
                             BibleReference::BibleBook(BibleBookReference::new(previous_book))
                         })
@@ -318,18 +648,23 @@ impl BibleReference {
                 }
             }
             BibleReference::BibleVerse(verse) => {
-                let previous_verse = verse.verse - 1;
+                // The superscription (verse 0) is skipped, the previous reference of verse 1 is the previous chapter
+                let previous_verse = verse.verse.saturating_sub(1);
                 match BibleVerseReference::new(verse.book(), verse.chapter(), previous_verse) {
-                    Ok(previous_verse) => Some(BibleReference::BibleVerse(previous_verse)),
-                    Err(_) => {
-                        let previous_chapter = verse.chapter - 1;
-                        match BibleChapterReference::new(verse.book(), previous_chapter) {
-                            Ok(previous_chapter) => {
+                    Ok(previous_verse) if !previous_verse.is_superscription() => {
+                        Some(BibleReference::BibleVerse(previous_verse))
+                    }
+                    _ => {
+                        let previous_chapter =
+                            verse.chapter.checked_sub(1).and_then(|previous_chapter| {
+                                BibleChapterReference::new(verse.book(), previous_chapter).ok()
+                            });
+                        match previous_chapter {
+                            Some(previous_chapter) => {
                                 Some(BibleReference::BibleChapter(previous_chapter))
                             }
-                            Err(_) => {
-                                let previous_book =
-                                    get_bible_book_by_number(verse.book().number() - 1);
+                            None => {
+                                let previous_book = Self::preceding_book(verse.book());
                                 previous_book.map(|previous_book| {
                                     BibleReference::BibleBook(BibleBookReference::new(
                                         previous_book,
@@ -342,82 +677,534 @@ impl BibleReference {
             }
         }
     }
-}
 
-impl Ord for BibleReference {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match (self, other) {
-            (BibleReference::BibleBook(a), BibleReference::BibleBook(b)) => a.cmp(b),
-            (BibleReference::BibleChapter(a), BibleReference::BibleChapter(b)) => a.cmp(b),
-            (BibleReference::BibleVerse(a), BibleReference::BibleVerse(b)) => a.cmp(b),
-            (BibleReference::BibleBook(a), BibleReference::BibleChapter(b)) => {
-                if a.book == b.book {
-                    Ordering::Less
-                } else {
-                    a.book().number().cmp(&b.book.number())
-                }
+    /// Returns the next Bible reference like [BibleReference::next], but saturates at the end of the Bible instead of returning [None].
+    /// # Returns
+    /// - The next Bible reference, or the current reference if it is the last one of the Bible (e.g. Revelation 22:21).
+    pub fn next_or_last(&self) -> BibleReference {
+        self.next().unwrap_or_else(|| self.clone())
+    }
+
+    /// Returns the previous Bible reference like [BibleReference::previous], but saturates at the beginning of the Bible instead of returning [None].
+    /// # Returns
+    /// - The previous Bible reference, or the current reference if it is the first one of the Bible (e.g. Genesis 1:1).
+    pub fn previous_or_first(&self) -> BibleReference {
+        self.previous().unwrap_or_else(|| self.clone())
+    }
+
+    /// Returns the next Bible reference with the same granularity as the current reference (see [BibleReference::advance_by]).
+    /// After the end of the Bible, it wraps around to its beginning, e.g. Revelation 22:21 (or 2 Maccabees 15:39 with the `deuterocanon` feature) is followed by Genesis 1:1 and Revelation 22 by Genesis 1.
+    /// This is useful for reading the whole Bible in a loop.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
+    /// let verse = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 31).unwrap());
+    /// assert_eq!(
+    ///     verse.next_wrapping(),
+    ///     BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 2, 1).unwrap())
+    /// );
+    /// ```
+    pub fn next_wrapping(&self) -> BibleReference {
+        self.advance_by(1)
+            .unwrap_or_else(|| self.start_of_book(BibleBook::Genesis))
+    }
+
+    /// Returns the previous Bible reference with the same granularity as the current reference.
+    /// Before the beginning of the Bible, it wraps around to its end, e.g. Genesis 1:1 is preceded by Revelation 22:21 (or 2 Maccabees 15:39 with the `deuterocanon` feature) and Genesis 1 by Revelation 22.
+    /// Like [BibleReference::previous], the superscription of a Psalm (verse 0) is skipped.
+    pub fn previous_wrapping(&self) -> BibleReference {
+        self.preceding()
+            .unwrap_or_else(|| self.end_of_book(*BibleBook::all().last().unwrap()))
+    }
+
+    /// Returns the reference to the following chapter, regardless of the granularity of the current reference.
+    /// A verse reference moves to the first verse of the following chapter, a chapter reference to the following chapter and a book reference to the first chapter of the next book.
+    /// At the end of a book, the first chapter of the next book is used.
+    /// # Returns
+    /// - An Option with the reference to the following chapter, or None if there is no following chapter (at the end of the Bible).
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
+    /// let verse = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 50, 10).unwrap());
+    /// assert_eq!(
+    ///     verse.next_chapter(),
+    ///     Some(BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Exodus, 1, 1).unwrap()))
+    /// );
+    /// ```
+    pub fn next_chapter(&self) -> Option<BibleReference> {
+        match self {
+            BibleReference::BibleBook(book) => {
+                let next_book = Self::following_book(book.book())?;
+                Some(BibleReference::BibleChapter(
+                    BibleChapterReference::new(next_book, 1).unwrap(),
+                ))
             }
-            (BibleReference::BibleChapter(a), BibleReference::BibleBook(b)) => {
-                if a.book == b.book {
-                    Ordering::Greater
-                } else {
-                    a.book().number().cmp(&b.book.number())
-                }
+            BibleReference::BibleChapter(chapter) => {
+                Self::following_chapter(chapter).map(BibleReference::BibleChapter)
             }
-            (BibleReference::BibleBook(a), BibleReference::BibleVerse(b)) => {
-                if a.book == b.book {
-                    Ordering::Less
-                } else {
-                    a.book().number().cmp(&b.book.number())
-                }
+            BibleReference::BibleVerse(verse) => {
+                let chapter = BibleChapterReference::new(verse.book(), verse.chapter()).unwrap();
+                Self::following_chapter(&chapter).map(Self::first_verse_of_chapter)
             }
-            (BibleReference::BibleVerse(a), BibleReference::BibleBook(b)) => {
-                if a.book == b.book {
-                    Ordering::Greater
-                } else {
-                    a.book().number().cmp(&b.book.number())
-                }
+        }
+    }
+
+    /// Returns the reference to the preceding chapter, regardless of the granularity of the current reference.
+    /// A verse reference moves to the first verse of the preceding chapter, a chapter reference to the preceding chapter and a book reference to the last chapter of the previous book.
+    /// At the beginning of a book, the last chapter of the previous book is used.
+    /// # Returns
+    /// - An Option with the reference to the preceding chapter, or None if there is no preceding chapter (at the beginning of the Bible).
+    pub fn previous_chapter(&self) -> Option<BibleReference> {
+        match self {
+            BibleReference::BibleBook(book) => {
+                let previous_book = Self::preceding_book(book.book())?;
+                Some(BibleReference::BibleChapter(
+                    BibleChapterReference::new(
+                        previous_book,
+                        get_number_of_chapters(&previous_book),
+                    )
+                    .unwrap(),
+                ))
             }
-            (BibleReference::BibleChapter(a), BibleReference::BibleVerse(b)) => {
-                if a.book == b.book && a.chapter == b.chapter {
-                    Ordering::Less
-                } else {
-                    match a.book().number().cmp(&b.book.number()) {
-                        Ordering::Equal => a.chapter.cmp(&b.chapter),
-                        other => other,
+            BibleReference::BibleChapter(chapter) => {
+                Self::preceding_chapter(chapter).map(BibleReference::BibleChapter)
+            }
+            BibleReference::BibleVerse(verse) => {
+                let chapter = BibleChapterReference::new(verse.book(), verse.chapter()).unwrap();
+                Self::preceding_chapter(&chapter).map(Self::first_verse_of_chapter)
+            }
+        }
+    }
+
+    /// Returns the reference to the next book, regardless of the granularity of the current reference.
+    /// A verse reference moves to the first verse of the next book, a chapter reference to the first chapter of the next book and a book reference to the next book.
+    /// # Returns
+    /// - An Option with the reference to the next book, or None if there is no next book.
+    pub fn next_book(&self) -> Option<BibleReference> {
+        let next_book = Self::following_book(self.book())?;
+        Some(self.start_of_book(next_book))
+    }
+
+    /// Returns the reference to the previous book, regardless of the granularity of the current reference.
+    /// A verse reference moves to the first verse of the previous book, a chapter reference to the first chapter of the previous book and a book reference to the previous book.
+    /// # Returns
+    /// - An Option with the reference to the previous book, or None if there is no previous book.
+    pub fn previous_book(&self) -> Option<BibleReference> {
+        let previous_book = Self::preceding_book(self.book())?;
+        Some(self.start_of_book(previous_book))
+    }
+
+    /// Advances the reference by `n` steps of its own granularity: a verse reference by `n` verses, a chapter reference by `n` chapters and a book reference by `n` books.
+    /// Chapter and book boundaries are crossed as needed. In contrast to calling [BibleReference::next] repeatedly, the type of the reference is kept,
+    /// and the new position is calculated with the known numbers of chapters and verses instead of moving one step at a time.
+    /// # Params
+    /// - `n`: The number of steps
+    /// # Returns
+    /// - An Option with the reference `n` steps after the current one, or None if it would be after the end of the Bible.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
+    /// let verse = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 30).unwrap());
+    /// assert_eq!(
+    ///     verse.advance_by(3),
+    ///     Some(BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 2, 2).unwrap()))
+    /// );
+    /// ```
+    pub fn advance_by(&self, n: usize) -> Option<BibleReference> {
+        match self {
+            BibleReference::BibleBook(book) => {
+                let number = (book.book().number() as usize).checked_add(n)?;
+                let book = get_bible_book_by_number(u8::try_from(number).ok()?)?;
+                Some(BibleReference::BibleBook(BibleBookReference::new(book)))
+            }
+            BibleReference::BibleChapter(chapter) => {
+                let mut book = chapter.book();
+                let mut chapter = chapter.chapter() as usize;
+                let mut remaining = n;
+                loop {
+                    let chapters_left = (get_number_of_chapters(&book) as usize) - chapter;
+                    if remaining <= chapters_left {
+                        return Some(BibleReference::BibleChapter(
+                            BibleChapterReference::new(book, (chapter + remaining) as BibleChapter)
+                                .unwrap(),
+                        ));
                     }
+                    remaining -= chapters_left + 1;
+                    book = Self::following_book(book)?;
+                    chapter = 1;
                 }
             }
-            (BibleReference::BibleVerse(a), BibleReference::BibleChapter(b)) => {
-                if a.book == b.book && a.chapter == b.chapter {
-                    Ordering::Greater
-                } else {
-                    match a.book().number().cmp(&b.book.number()) {
-                        Ordering::Equal => a.chapter.cmp(&b.chapter),
-                        other => other,
+            BibleReference::BibleVerse(verse) => {
+                let mut chapter =
+                    BibleChapterReference::new(verse.book(), verse.chapter()).unwrap();
+                let mut verse = verse.verse() as usize;
+                let mut remaining = n;
+                loop {
+                    let verses_left = (get_number_of_verses(&chapter.book(), &chapter.chapter())
+                        .unwrap() as usize)
+                        - verse;
+                    if remaining <= verses_left {
+                        return Some(BibleReference::BibleVerse(
+                            BibleVerseReference::new(
+                                chapter.book(),
+                                chapter.chapter(),
+                                (verse + remaining) as BibleVerse,
+                            )
+                            .unwrap(),
+                        ));
                     }
+                    remaining -= verses_left + 1;
+                    chapter = Self::following_chapter(&chapter)?;
+                    verse = 1;
                 }
             }
         }
     }
-}
 
-impl PartialOrd for BibleReference {
-    fn partial_cmp(&self, other: &BibleReference) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// Returns the number of verses from the current reference to `other`, which is negative if `other` is before the current reference.
+    /// Chapters and books are measured from their first verse, e.g. the distance from John 3 to John 3:16 is 15.
+    /// See also [verses_between].
+    pub fn distance_to(&self, other: &BibleReference) -> i64 {
+        verses_between(&self.verse_span().0, &other.verse_span().0)
     }
-}
 
-/// The struct BibleBook contains all books of the Bible in their correct order. As it derives from `PartialOrd` and `PartialEq`, you can make comparisons like `<` or `>` to determine whether a book is before or after an other.
-#[derive(PartialEq, PartialOrd, Ord, Eq, Debug, Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum BibleBook {
-    Genesis,
-    Exodus,
-    Leviticus,
-    Numbers,
-    Deuteronomy,
-    Joshua,
+    /// Returns the fraction of the Bible's verses up to and including the reference (see [BibleVerseReference::position_fraction]).
+    /// Chapters and books are measured up to their last verse, e.g. Revelation has the fraction 1.0.
+    pub fn position_fraction(&self) -> f64 {
+        self.verse_span().1.position_fraction()
+    }
+
+    /// Returns the book of the reference
+    pub fn book(&self) -> BibleBook {
+        match self {
+            BibleReference::BibleBook(book) => book.book(),
+            BibleReference::BibleChapter(chapter) => chapter.book(),
+            BibleReference::BibleVerse(verse) => verse.book(),
+        }
+    }
+
+    /// Returns the chapter of the reference or [None] if the reference is a book.
+    pub fn chapter(&self) -> Option<BibleChapter> {
+        match self {
+            BibleReference::BibleBook(_) => None,
+            BibleReference::BibleChapter(chapter) => Some(chapter.chapter()),
+            BibleReference::BibleVerse(verse) => Some(verse.chapter()),
+        }
+    }
+
+    /// Returns the verse of the reference or [None] if the reference is a book or a chapter.
+    pub fn verse(&self) -> Option<BibleVerse> {
+        match self {
+            BibleReference::BibleVerse(verse) => Some(verse.verse()),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the reference is a book, a chapter or a verse.
+    pub fn granularity(&self) -> ReferenceGranularity {
+        match self {
+            BibleReference::BibleBook(_) => ReferenceGranularity::Book,
+            BibleReference::BibleChapter(_) => ReferenceGranularity::Chapter,
+            BibleReference::BibleVerse(_) => ReferenceGranularity::Verse,
+        }
+    }
+
+    /// Returns the book which contains the reference, e.g. John for John 3:16.
+    pub fn to_book(&self) -> BibleBookReference {
+        BibleBookReference::new(self.book())
+    }
+
+    /// Returns the chapter which contains the reference, e.g. John 3 for John 3:16.
+    /// # Returns
+    /// - [None] if the reference is a book, as a book has no specific chapter.
+    pub fn to_chapter(&self) -> Option<BibleChapterReference> {
+        match self {
+            BibleReference::BibleBook(_) => None,
+            BibleReference::BibleChapter(chapter) => Some(chapter.clone()),
+            BibleReference::BibleVerse(verse) => {
+                Some(BibleChapterReference::new(verse.book(), verse.chapter()).unwrap())
+            }
+        }
+    }
+
+    /// Returns the first verse which is covered by the reference, e.g. John 1:1 for John and John 3:1 for John 3.
+    pub fn to_first_verse(&self) -> BibleVerseReference {
+        self.verse_span().0
+    }
+
+    /// Encodes the reference as a compact [u32]. The lower three bytes are the same as in [BibleVerseReference::to_u32], [BibleChapterReference::to_u32] and [BibleBookReference::to_u32],
+    /// the highest byte marks the type of the reference: 0 for a verse (so that the encoding of a verse is the same as with [BibleVerseReference::to_u32]), 1 for a chapter and 2 for a book.
+    /// References of the same type sort in their canonical order.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleChapterReference, BibleReference};
+    /// let chapter = BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap());
+    /// assert_eq!(chapter.to_u32(), 0x012B0300);
+    /// assert_eq!(BibleReference::from_u32(chapter.to_u32()), Some(chapter));
+    /// ```
+    pub fn to_u32(&self) -> u32 {
+        match self {
+            BibleReference::BibleVerse(verse) => verse.to_u32(),
+            BibleReference::BibleChapter(chapter) => 1 << 24 | chapter.to_u32(),
+            BibleReference::BibleBook(book) => 2 << 24 | book.to_u32(),
+        }
+    }
+
+    /// Decodes a reference which has been encoded with [BibleReference::to_u32].
+    /// # Returns
+    /// - [None] if the value does not encode an existing reference.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        let reference = value & 0x00FF_FFFF;
+        match value >> 24 {
+            0 => BibleVerseReference::from_u32(reference).map(BibleReference::BibleVerse),
+            1 => BibleChapterReference::from_u32(reference).map(BibleReference::BibleChapter),
+            2 => BibleBookReference::from_u32(reference).map(BibleReference::BibleBook),
+            _ => None,
+        }
+    }
+
+    /// Returns the beginning of `book` with the same granularity as the current reference.
+    fn start_of_book(&self, book: BibleBook) -> BibleReference {
+        let book = BibleBookReference::new(book);
+        match self {
+            BibleReference::BibleBook(_) => BibleReference::BibleBook(book),
+            BibleReference::BibleChapter(_) => BibleReference::BibleChapter(book.first_chapter()),
+            BibleReference::BibleVerse(_) => BibleReference::BibleVerse(book.first_verse()),
+        }
+    }
+
+    /// Returns the end of `book` with the same granularity as the current reference.
+    fn end_of_book(&self, book: BibleBook) -> BibleReference {
+        let book = BibleBookReference::new(book);
+        match self {
+            BibleReference::BibleBook(_) => BibleReference::BibleBook(book),
+            BibleReference::BibleChapter(_) => BibleReference::BibleChapter(book.last_chapter()),
+            BibleReference::BibleVerse(_) => BibleReference::BibleVerse(book.last_verse()),
+        }
+    }
+
+    /// Returns the previous reference with the same granularity as the current reference, or None at the beginning of the Bible.
+    fn preceding(&self) -> Option<BibleReference> {
+        match self {
+            BibleReference::BibleBook(book) => {
+                let previous_book = Self::preceding_book(book.book())?;
+                Some(BibleReference::BibleBook(BibleBookReference::new(
+                    previous_book,
+                )))
+            }
+            BibleReference::BibleChapter(chapter) => {
+                Self::preceding_chapter(chapter).map(BibleReference::BibleChapter)
+            }
+            BibleReference::BibleVerse(verse) if verse.verse() > 1 => {
+                Some(BibleReference::BibleVerse(
+                    BibleVerseReference::new(verse.book(), verse.chapter(), verse.verse() - 1)
+                        .unwrap(),
+                ))
+            }
+            BibleReference::BibleVerse(verse) => {
+                let chapter = BibleChapterReference::new(verse.book(), verse.chapter()).unwrap();
+                let previous_chapter = Self::preceding_chapter(&chapter)?;
+                let last_verse =
+                    get_number_of_verses(&previous_chapter.book(), &previous_chapter.chapter())
+                        .unwrap();
+                Some(BibleReference::BibleVerse(
+                    BibleVerseReference::new(
+                        previous_chapter.book(),
+                        previous_chapter.chapter(),
+                        last_verse,
+                    )
+                    .unwrap(),
+                ))
+            }
+        }
+    }
+
+    fn first_verse_of_chapter(chapter: BibleChapterReference) -> BibleReference {
+        BibleReference::BibleVerse(chapter.first_verse())
+    }
+
+    /// Returns the book after `book` in canonical order, or None after the last book.
+    fn following_book(book: BibleBook) -> Option<BibleBook> {
+        get_bible_book_by_number(book.number().checked_add(1)?)
+    }
+
+    /// Returns the book before `book` in canonical order, or None before the first book.
+    fn preceding_book(book: BibleBook) -> Option<BibleBook> {
+        get_bible_book_by_number(book.number().checked_sub(1)?)
+    }
+
+    fn following_chapter(chapter: &BibleChapterReference) -> Option<BibleChapterReference> {
+        let next_chapter = chapter
+            .chapter()
+            .checked_add(1)
+            .and_then(|next_chapter| BibleChapterReference::new(chapter.book(), next_chapter).ok());
+        match next_chapter {
+            Some(next_chapter) => Some(next_chapter),
+            None => {
+                let next_book = Self::following_book(chapter.book())?;
+                BibleChapterReference::new(next_book, 1).ok()
+            }
+        }
+    }
+
+    fn preceding_chapter(chapter: &BibleChapterReference) -> Option<BibleChapterReference> {
+        let previous_chapter = chapter
+            .chapter()
+            .checked_sub(1)
+            .and_then(|previous_chapter| {
+                BibleChapterReference::new(chapter.book(), previous_chapter).ok()
+            });
+        match previous_chapter {
+            Some(previous_chapter) => Some(previous_chapter),
+            None => {
+                let previous_book = Self::preceding_book(chapter.book())?;
+                BibleChapterReference::new(previous_book, get_number_of_chapters(&previous_book))
+                    .ok()
+            }
+        }
+    }
+
+    /// Returns the first and the last verse which are covered by the reference, e.g. John 3:1 and John 3:36 for the chapter John 3.
+    fn verse_span(&self) -> (BibleVerseReference, BibleVerseReference) {
+        match self {
+            BibleReference::BibleBook(book) => {
+                let chapter_range = BibleBookRange::new(book.clone(), book.clone())
+                    .unwrap()
+                    .as_chapter_range();
+                let verse_range = chapter_range.as_verse_range();
+                (verse_range.start(), verse_range.end())
+            }
+            BibleReference::BibleChapter(chapter) => {
+                let verse_range = BibleChapterRange::new(chapter.clone(), chapter.clone())
+                    .unwrap()
+                    .as_verse_range();
+                (verse_range.start(), verse_range.end())
+            }
+            BibleReference::BibleVerse(verse) => (verse.clone(), verse.clone()),
+        }
+    }
+}
+
+impl Ord for BibleReference {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self, other) {
+            (BibleReference::BibleBook(a), BibleReference::BibleBook(b)) => a.cmp(b),
+            (BibleReference::BibleChapter(a), BibleReference::BibleChapter(b)) => a.cmp(b),
+            (BibleReference::BibleVerse(a), BibleReference::BibleVerse(b)) => a.cmp(b),
+            (BibleReference::BibleBook(a), BibleReference::BibleChapter(b)) => {
+                if a.book == b.book {
+                    Ordering::Less
+                } else {
+                    a.book().number().cmp(&b.book.number())
+                }
+            }
+            (BibleReference::BibleChapter(a), BibleReference::BibleBook(b)) => {
+                if a.book == b.book {
+                    Ordering::Greater
+                } else {
+                    a.book().number().cmp(&b.book.number())
+                }
+            }
+            (BibleReference::BibleBook(a), BibleReference::BibleVerse(b)) => {
+                if a.book == b.book {
+                    Ordering::Less
+                } else {
+                    a.book().number().cmp(&b.book.number())
+                }
+            }
+            (BibleReference::BibleVerse(a), BibleReference::BibleBook(b)) => {
+                if a.book == b.book {
+                    Ordering::Greater
+                } else {
+                    a.book().number().cmp(&b.book.number())
+                }
+            }
+            (BibleReference::BibleChapter(a), BibleReference::BibleVerse(b)) => {
+                if a.book == b.book && a.chapter == b.chapter {
+                    Ordering::Less
+                } else {
+                    match a.book().number().cmp(&b.book.number()) {
+                        Ordering::Equal => a.chapter.cmp(&b.chapter),
+                        other => other,
+                    }
+                }
+            }
+            (BibleReference::BibleVerse(a), BibleReference::BibleChapter(b)) => {
+                if a.book == b.book && a.chapter == b.chapter {
+                    Ordering::Greater
+                } else {
+                    match a.book().number().cmp(&b.book.number()) {
+                        Ordering::Equal => a.chapter.cmp(&b.chapter),
+                        other => other,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for BibleReference {
+    fn partial_cmp(&self, other: &BibleReference) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<BibleBookReference> for BibleReference {
+    fn from(book: BibleBookReference) -> Self {
+        BibleReference::BibleBook(book)
+    }
+}
+
+impl From<BibleChapterReference> for BibleReference {
+    fn from(chapter: BibleChapterReference) -> Self {
+        BibleReference::BibleChapter(chapter)
+    }
+}
+
+impl From<BibleVerseReference> for BibleReference {
+    /// Wraps a verse reference into a [BibleReference].
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleReference, BibleReferenceRepresentation, BibleVerseReference};
+    /// let verse = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+    /// let reference: BibleReference = verse.clone().into();
+    /// assert_eq!(reference, BibleReference::BibleVerse(verse));
+    /// let representation: BibleReferenceRepresentation = reference.into();
+    /// assert!(representation.is_single());
+    /// ```
+    fn from(verse: BibleVerseReference) -> Self {
+        BibleReference::BibleVerse(verse)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for BibleReference {
+    /// Formats the reference in English with the long book name, e.g. "John 3:16".
+    ///
+    /// Use [get_reference_in_language](crate::referencing::language::get_reference_in_language) for other languages or abbreviated book names.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
+    /// let reference = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+    /// assert_eq!(reference.to_string(), "John 3:16");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        ENGLISH_REFERENCE_LANGUAGE.write_reference(f, self, BookReferenceType::Long)
+    }
+}
+
+/// The struct BibleBook contains all books of the Bible in their correct order.
+/// If the `deuterocanon` feature is enabled, the deuterocanonical books of the Catholic and Orthodox canons (Tobit, Judith, the Additions to Esther, Wisdom, Sirach, Baruch including the Letter of Jeremiah as chapter 6,
+/// the Additions to Daniel and 1-2 Maccabees) are available as well. They are placed after Revelation. As it derives from `PartialOrd` and `PartialEq`, you can make comparisons like `<` or `>` to determine whether a book is before or after an other.
+#[derive(PartialEq, PartialOrd, Ord, Eq, Debug, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BibleBook {
+    Genesis,
+    Exodus,
+    Leviticus,
+    Numbers,
+    Deuteronomy,
+    Joshua,
     Judges,
     Ruth,
     ISamuel,
@@ -478,12 +1265,174 @@ pub enum BibleBook {
     IIIJohn,
     Jude,
     Revelation,
+    // The deuterocanonical books follow after the Protestant canon so that the numbers of the other books stay the same.
+    #[cfg(feature = "deuterocanon")]
+    Tobit,
+    #[cfg(feature = "deuterocanon")]
+    Judith,
+    #[cfg(feature = "deuterocanon")]
+    AdditionsToEsther,
+    #[cfg(feature = "deuterocanon")]
+    Wisdom,
+    #[cfg(feature = "deuterocanon")]
+    Sirach,
+    #[cfg(feature = "deuterocanon")]
+    Baruch,
+    #[cfg(feature = "deuterocanon")]
+    PrayerOfAzariah,
+    #[cfg(feature = "deuterocanon")]
+    Susanna,
+    #[cfg(feature = "deuterocanon")]
+    BelAndTheDragon,
+    #[cfg(feature = "deuterocanon")]
+    IMaccabees,
+    #[cfg(feature = "deuterocanon")]
+    IIMaccabees,
 }
 
+/// The deuterocanonical books in their order (empty if the `deuterocanon` feature is disabled)
+const DEUTEROCANONICAL_BOOKS: &[BibleBook] = &[
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::Tobit,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::Judith,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::AdditionsToEsther,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::Wisdom,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::Sirach,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::Baruch,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::PrayerOfAzariah,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::Susanna,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::BelAndTheDragon,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::IMaccabees,
+    #[cfg(feature = "deuterocanon")]
+    BibleBook::IIMaccabees,
+];
+
+/// The books of the Bible (without the deuterocanonical books) in chronological order, see [BibleBook::chronological_number].
+const CHRONOLOGICAL_BOOKS: &[BibleBook] = &[
+    BibleBook::Genesis,
+    BibleBook::Job,
+    BibleBook::Exodus,
+    BibleBook::Leviticus,
+    BibleBook::Numbers,
+    BibleBook::Deuteronomy,
+    BibleBook::Joshua,
+    BibleBook::Judges,
+    BibleBook::Ruth,
+    BibleBook::ISamuel,
+    BibleBook::IISamuel,
+    BibleBook::IChronicles,
+    BibleBook::Psalm,
+    BibleBook::Proverbs,
+    BibleBook::Ecclesiastes,
+    BibleBook::SongofSolomon,
+    BibleBook::IKings,
+    BibleBook::IIKings,
+    BibleBook::IIChronicles,
+    BibleBook::Obadiah,
+    BibleBook::Joel,
+    BibleBook::Jonah,
+    BibleBook::Amos,
+    BibleBook::Hosea,
+    BibleBook::Isaiah,
+    BibleBook::Micah,
+    BibleBook::Nahum,
+    BibleBook::Zephaniah,
+    BibleBook::Jeremiah,
+    BibleBook::Habakkuk,
+    BibleBook::Lamentations,
+    BibleBook::Ezekiel,
+    BibleBook::Daniel,
+    BibleBook::Ezra,
+    BibleBook::Haggai,
+    BibleBook::Zechariah,
+    BibleBook::Esther,
+    BibleBook::Nehemiah,
+    BibleBook::Malachi,
+    BibleBook::Matthew,
+    BibleBook::Mark,
+    BibleBook::Luke,
+    BibleBook::John,
+    BibleBook::Acts,
+    BibleBook::James,
+    BibleBook::Galatians,
+    BibleBook::IThessalonians,
+    BibleBook::IIThessalonians,
+    BibleBook::ICorinthians,
+    BibleBook::IICorinthians,
+    BibleBook::Romans,
+    BibleBook::Ephesians,
+    BibleBook::Colossians,
+    BibleBook::Philemon,
+    BibleBook::Philippians,
+    BibleBook::ITimothy,
+    BibleBook::Titus,
+    BibleBook::IPeter,
+    BibleBook::Hebrews,
+    BibleBook::IITimothy,
+    BibleBook::IIPeter,
+    BibleBook::Jude,
+    BibleBook::IJohn,
+    BibleBook::IIJohn,
+    BibleBook::IIIJohn,
+    BibleBook::Revelation,
+];
+
+/// The books of the Old Testament in the order of the Hebrew Bible (Tanakh), see [BibleBook::tanakh_order].
+const TANAKH_BOOKS: &[BibleBook] = &[
+    BibleBook::Genesis,
+    BibleBook::Exodus,
+    BibleBook::Leviticus,
+    BibleBook::Numbers,
+    BibleBook::Deuteronomy,
+    BibleBook::Joshua,
+    BibleBook::Judges,
+    BibleBook::ISamuel,
+    BibleBook::IISamuel,
+    BibleBook::IKings,
+    BibleBook::IIKings,
+    BibleBook::Isaiah,
+    BibleBook::Jeremiah,
+    BibleBook::Ezekiel,
+    BibleBook::Hosea,
+    BibleBook::Joel,
+    BibleBook::Amos,
+    BibleBook::Obadiah,
+    BibleBook::Jonah,
+    BibleBook::Micah,
+    BibleBook::Nahum,
+    BibleBook::Habakkuk,
+    BibleBook::Zephaniah,
+    BibleBook::Haggai,
+    BibleBook::Zechariah,
+    BibleBook::Malachi,
+    BibleBook::Psalm,
+    BibleBook::Proverbs,
+    BibleBook::Job,
+    BibleBook::SongofSolomon,
+    BibleBook::Ruth,
+    BibleBook::Lamentations,
+    BibleBook::Ecclesiastes,
+    BibleBook::Esther,
+    BibleBook::Daniel,
+    BibleBook::Ezra,
+    BibleBook::Nehemiah,
+    BibleBook::IChronicles,
+    BibleBook::IIChronicles,
+];
+
 impl BibleBook {
-    /// Gets a [Vec] with all books of the Bible
+    /// Gets a [Vec] with all books of the Bible (including the deuterocanonical books if the `deuterocanon` feature is enabled)
     pub fn all() -> Vec<Self> {
-        vec![
+        let mut books = vec![
             Self::Genesis,
             Self::Exodus,
             Self::Leviticus,
@@ -550,7 +1499,25 @@ impl BibleBook {
             Self::IIIJohn,
             Self::Jude,
             Self::Revelation,
-        ]
+        ];
+        books.extend_from_slice(DEUTEROCANONICAL_BOOKS);
+        books
+    }
+
+    /// Gets a [Vec] with all books of the Bible in chronological order (see [BibleBook::chronological_number]).
+    /// The deuterocanonical books are placed after Revelation if the `deuterocanon` feature is enabled, as in [BibleBook::all].
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// let books = BibleBook::all_chronological();
+    /// assert_eq!(books[0], BibleBook::Genesis);
+    /// assert_eq!(books[1], BibleBook::Job);
+    /// assert_eq!(books.len(), BibleBook::all().len());
+    /// ```
+    pub fn all_chronological() -> Vec<Self> {
+        let mut books = CHRONOLOGICAL_BOOKS.to_vec();
+        books.extend_from_slice(DEUTEROCANONICAL_BOOKS);
+        books
     }
 
     /// This function determines whether the current Bible book is part of the Old Testament.
@@ -561,7 +1528,7 @@ impl BibleBook {
     /// # Note
     /// This function is per definition the inverse of `is_new_testament`.
     pub fn is_old_testament(&self) -> bool {
-        self < &BibleBook::Matthew
+        !self.is_new_testament()
     }
 
     /// This function determines whether the current Bible book is part of the New Testament.
@@ -572,7 +1539,305 @@ impl BibleBook {
     /// # Note
     /// This function is per definition the inverse of `is_old_testament`.
     pub fn is_new_testament(&self) -> bool {
-        self >= &BibleBook::Matthew
+        (BibleBook::Matthew..=BibleBook::Revelation).contains(self)
+    }
+
+    /// This function determines whether the current Bible book is one of the deuterocanonical books, which are only available with the `deuterocanon` feature.
+    /// Deuterocanonical books belong to the Old Testament.
+    pub fn is_deuterocanonical(&self) -> bool {
+        DEUTEROCANONICAL_BOOKS.contains(self)
+    }
+
+    /// Returns an [Iterator] over the books from `start` to `end` (both inclusive) in canonical order. The iterator is empty if `start` comes after `end`.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// let gospels: Vec<BibleBook> = BibleBook::range(BibleBook::Matthew, BibleBook::John).collect();
+    /// assert_eq!(gospels, vec![BibleBook::Matthew, BibleBook::Mark, BibleBook::Luke, BibleBook::John]);
+    /// ```
+    pub fn range(start: BibleBook, end: BibleBook) -> impl Iterator<Item = BibleBook> {
+        (start.number()..=end.number()).filter_map(get_bible_book_by_number)
+    }
+
+    /// Returns an [Iterator] over all books of the Old Testament (Genesis to Malachi, followed by the deuterocanonical books if the `deuterocanon` feature is enabled).
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::books_of_old_testament().next(), Some(BibleBook::Genesis));
+    /// assert!(BibleBook::books_of_old_testament().all(|book| book.is_old_testament()));
+    /// ```
+    pub fn books_of_old_testament() -> impl Iterator<Item = BibleBook> {
+        Self::range(BibleBook::Genesis, BibleBook::Malachi)
+            .chain(DEUTEROCANONICAL_BOOKS.iter().copied())
+    }
+
+    /// Returns an [Iterator] over all books of the New Testament (Matthew to Revelation).
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::books_of_new_testament().count(), 27);
+    /// ```
+    pub fn books_of_new_testament() -> impl Iterator<Item = BibleBook> {
+        Self::range(BibleBook::Matthew, BibleBook::Revelation)
+    }
+
+    /// Returns an [Iterator] over all books of the Hebrew Bible (Tanakh) in their Jewish order: the Torah, the Nevi'im (prophets) and the Ketuvim (writings).
+    /// See [BibleBook::tanakh_order] for details.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::tanakh_books().count(), 39);
+    /// assert_eq!(BibleBook::tanakh_books().last(), Some(BibleBook::IIChronicles));
+    /// ```
+    pub fn tanakh_books() -> impl Iterator<Item = BibleBook> {
+        TANAKH_BOOKS.iter().copied()
+    }
+
+    /// Returns the [BibleSection] (genre) to which the book belongs.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleSection};
+    /// assert_eq!(BibleBook::Exodus.section(), BibleSection::Pentateuch);
+    /// assert_eq!(BibleBook::Philemon.section(), BibleSection::PaulineEpistles);
+    /// assert_eq!(BibleBook::Hebrews.section(), BibleSection::GeneralEpistles);
+    /// ```
+    pub fn section(&self) -> BibleSection {
+        match self {
+            BibleBook::Genesis => BibleSection::Pentateuch,
+            BibleBook::Exodus => BibleSection::Pentateuch,
+            BibleBook::Leviticus => BibleSection::Pentateuch,
+            BibleBook::Numbers => BibleSection::Pentateuch,
+            BibleBook::Deuteronomy => BibleSection::Pentateuch,
+            BibleBook::Joshua => BibleSection::HistoricalOT,
+            BibleBook::Judges => BibleSection::HistoricalOT,
+            BibleBook::Ruth => BibleSection::HistoricalOT,
+            BibleBook::ISamuel => BibleSection::HistoricalOT,
+            BibleBook::IISamuel => BibleSection::HistoricalOT,
+            BibleBook::IKings => BibleSection::HistoricalOT,
+            BibleBook::IIKings => BibleSection::HistoricalOT,
+            BibleBook::IChronicles => BibleSection::HistoricalOT,
+            BibleBook::IIChronicles => BibleSection::HistoricalOT,
+            BibleBook::Ezra => BibleSection::HistoricalOT,
+            BibleBook::Nehemiah => BibleSection::HistoricalOT,
+            BibleBook::Esther => BibleSection::HistoricalOT,
+            BibleBook::Job => BibleSection::Wisdom,
+            BibleBook::Psalm => BibleSection::Wisdom,
+            BibleBook::Proverbs => BibleSection::Wisdom,
+            BibleBook::Ecclesiastes => BibleSection::Wisdom,
+            BibleBook::SongofSolomon => BibleSection::Wisdom,
+            BibleBook::Isaiah => BibleSection::MajorProphets,
+            BibleBook::Jeremiah => BibleSection::MajorProphets,
+            BibleBook::Lamentations => BibleSection::MajorProphets,
+            BibleBook::Ezekiel => BibleSection::MajorProphets,
+            BibleBook::Daniel => BibleSection::MajorProphets,
+            BibleBook::Hosea => BibleSection::MinorProphets,
+            BibleBook::Joel => BibleSection::MinorProphets,
+            BibleBook::Amos => BibleSection::MinorProphets,
+            BibleBook::Obadiah => BibleSection::MinorProphets,
+            BibleBook::Jonah => BibleSection::MinorProphets,
+            BibleBook::Micah => BibleSection::MinorProphets,
+            BibleBook::Nahum => BibleSection::MinorProphets,
+            BibleBook::Habakkuk => BibleSection::MinorProphets,
+            BibleBook::Zephaniah => BibleSection::MinorProphets,
+            BibleBook::Haggai => BibleSection::MinorProphets,
+            BibleBook::Zechariah => BibleSection::MinorProphets,
+            BibleBook::Malachi => BibleSection::MinorProphets,
+            BibleBook::Matthew => BibleSection::Gospels,
+            BibleBook::Mark => BibleSection::Gospels,
+            BibleBook::Luke => BibleSection::Gospels,
+            BibleBook::John => BibleSection::Gospels,
+            BibleBook::Acts => BibleSection::ActsBook,
+            BibleBook::Romans => BibleSection::PaulineEpistles,
+            BibleBook::ICorinthians => BibleSection::PaulineEpistles,
+            BibleBook::IICorinthians => BibleSection::PaulineEpistles,
+            BibleBook::Galatians => BibleSection::PaulineEpistles,
+            BibleBook::Ephesians => BibleSection::PaulineEpistles,
+            BibleBook::Philippians => BibleSection::PaulineEpistles,
+            BibleBook::Colossians => BibleSection::PaulineEpistles,
+            BibleBook::IThessalonians => BibleSection::PaulineEpistles,
+            BibleBook::IIThessalonians => BibleSection::PaulineEpistles,
+            BibleBook::ITimothy => BibleSection::PaulineEpistles,
+            BibleBook::IITimothy => BibleSection::PaulineEpistles,
+            BibleBook::Titus => BibleSection::PaulineEpistles,
+            BibleBook::Philemon => BibleSection::PaulineEpistles,
+            BibleBook::Hebrews => BibleSection::GeneralEpistles,
+            BibleBook::James => BibleSection::GeneralEpistles,
+            BibleBook::IPeter => BibleSection::GeneralEpistles,
+            BibleBook::IIPeter => BibleSection::GeneralEpistles,
+            BibleBook::IJohn => BibleSection::GeneralEpistles,
+            BibleBook::IIJohn => BibleSection::GeneralEpistles,
+            BibleBook::IIIJohn => BibleSection::GeneralEpistles,
+            BibleBook::Jude => BibleSection::GeneralEpistles,
+            BibleBook::Revelation => BibleSection::Apocalypse,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Tobit => BibleSection::HistoricalOT,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Judith => BibleSection::HistoricalOT,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::AdditionsToEsther => BibleSection::HistoricalOT,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Wisdom => BibleSection::Wisdom,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Sirach => BibleSection::Wisdom,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Baruch => BibleSection::MajorProphets,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::PrayerOfAzariah => BibleSection::MajorProphets,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Susanna => BibleSection::MajorProphets,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::BelAndTheDragon => BibleSection::MajorProphets,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IMaccabees => BibleSection::HistoricalOT,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IIMaccabees => BibleSection::HistoricalOT,
+        }
+    }
+
+    /// Returns the author to whom the book is attributed by (Jewish and Christian) tradition, e.g. "Moses" for the Pentateuch or "Paul" for Romans to Philemon.
+    /// This is the traditional attribution, not a statement about historical authorship. Books without a widely agreed traditional author (e.g. Esther, Job or Hebrews) return [None].
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::Romans.traditional_author(), Some("Paul"));
+    /// assert_eq!(BibleBook::Revelation.traditional_author(), Some("John"));
+    /// assert_eq!(BibleBook::Hebrews.traditional_author(), None);
+    /// ```
+    pub fn traditional_author(&self) -> Option<&'static str> {
+        match self {
+            BibleBook::Genesis => Some("Moses"),
+            BibleBook::Exodus => Some("Moses"),
+            BibleBook::Leviticus => Some("Moses"),
+            BibleBook::Numbers => Some("Moses"),
+            BibleBook::Deuteronomy => Some("Moses"),
+            BibleBook::Joshua => Some("Joshua"),
+            BibleBook::Judges => Some("Samuel"),
+            BibleBook::Ruth => Some("Samuel"),
+            BibleBook::ISamuel => Some("Samuel"),
+            BibleBook::IISamuel => Some("Samuel"),
+            BibleBook::IKings => Some("Jeremiah"),
+            BibleBook::IIKings => Some("Jeremiah"),
+            BibleBook::IChronicles => Some("Ezra"),
+            BibleBook::IIChronicles => Some("Ezra"),
+            BibleBook::Ezra => Some("Ezra"),
+            BibleBook::Nehemiah => Some("Nehemiah"),
+            BibleBook::Esther => None,
+            BibleBook::Job => None,
+            BibleBook::Psalm => Some("David"),
+            BibleBook::Proverbs => Some("Solomon"),
+            BibleBook::Ecclesiastes => Some("Solomon"),
+            BibleBook::SongofSolomon => Some("Solomon"),
+            BibleBook::Isaiah => Some("Isaiah"),
+            BibleBook::Jeremiah => Some("Jeremiah"),
+            BibleBook::Lamentations => Some("Jeremiah"),
+            BibleBook::Ezekiel => Some("Ezekiel"),
+            BibleBook::Daniel => Some("Daniel"),
+            BibleBook::Hosea => Some("Hosea"),
+            BibleBook::Joel => Some("Joel"),
+            BibleBook::Amos => Some("Amos"),
+            BibleBook::Obadiah => Some("Obadiah"),
+            BibleBook::Jonah => Some("Jonah"),
+            BibleBook::Micah => Some("Micah"),
+            BibleBook::Nahum => Some("Nahum"),
+            BibleBook::Habakkuk => Some("Habakkuk"),
+            BibleBook::Zephaniah => Some("Zephaniah"),
+            BibleBook::Haggai => Some("Haggai"),
+            BibleBook::Zechariah => Some("Zechariah"),
+            BibleBook::Malachi => Some("Malachi"),
+            BibleBook::Matthew => Some("Matthew"),
+            BibleBook::Mark => Some("Mark"),
+            BibleBook::Luke => Some("Luke"),
+            BibleBook::John => Some("John"),
+            BibleBook::Acts => Some("Luke"),
+            BibleBook::Romans => Some("Paul"),
+            BibleBook::ICorinthians => Some("Paul"),
+            BibleBook::IICorinthians => Some("Paul"),
+            BibleBook::Galatians => Some("Paul"),
+            BibleBook::Ephesians => Some("Paul"),
+            BibleBook::Philippians => Some("Paul"),
+            BibleBook::Colossians => Some("Paul"),
+            BibleBook::IThessalonians => Some("Paul"),
+            BibleBook::IIThessalonians => Some("Paul"),
+            BibleBook::ITimothy => Some("Paul"),
+            BibleBook::IITimothy => Some("Paul"),
+            BibleBook::Titus => Some("Paul"),
+            BibleBook::Philemon => Some("Paul"),
+            BibleBook::Hebrews => None,
+            BibleBook::James => Some("James"),
+            BibleBook::IPeter => Some("Peter"),
+            BibleBook::IIPeter => Some("Peter"),
+            BibleBook::IJohn => Some("John"),
+            BibleBook::IIJohn => Some("John"),
+            BibleBook::IIIJohn => Some("John"),
+            BibleBook::Jude => Some("Jude"),
+            BibleBook::Revelation => Some("John"),
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Tobit => None,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Judith => None,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::AdditionsToEsther => None,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Wisdom => Some("Solomon"),
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Sirach => Some("Jesus ben Sirach"),
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Baruch => Some("Baruch"),
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::PrayerOfAzariah => None,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Susanna => None,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::BelAndTheDragon => None,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IMaccabees => None,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IIMaccabees => None,
+        }
+    }
+
+    /// Returns all books which are traditionally attributed to an author (see [BibleBook::traditional_author]) in canonical order, e.g. the books of Moses or the letters of Paul.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::all_by_traditional_author("Moses").len(), 5);
+    /// assert_eq!(BibleBook::all_by_traditional_author("Paul").len(), 13);
+    /// assert!(BibleBook::all_by_traditional_author("Nobody").is_empty());
+    /// ```
+    pub fn all_by_traditional_author(author: &str) -> Vec<Self> {
+        Self::all()
+            .into_iter()
+            .filter(|book| book.traditional_author() == Some(author))
+            .collect()
+    }
+
+    /// Returns all books of a [BibleSection] in canonical order.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleSection};
+    /// assert_eq!(
+    ///     BibleBook::all_in_section(BibleSection::Gospels),
+    ///     vec![BibleBook::Matthew, BibleBook::Mark, BibleBook::Luke, BibleBook::John]
+    /// );
+    /// ```
+    pub fn all_in_section(section: BibleSection) -> Vec<Self> {
+        Self::all()
+            .into_iter()
+            .filter(|book| book.section() == section)
+            .collect()
+    }
+
+    /// Returns the [BibleBook] with the given canonical English long or short name (e.g. "Genesis" or "Gen"), ignoring case and surrounding whitespace.
+    /// This is a shorthand for the [FromStr] implementation. To look up names in all supported languages, use [find_book_by_name](crate::referencing::language::find_book_by_name).
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::from_english_name("song of solomon"), Some(BibleBook::SongofSolomon));
+    /// assert_eq!(BibleBook::from_english_name("Johannes"), None);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_english_name(name: &str) -> Option<Self> {
+        name.parse().ok()
     }
 
     /// This function returns the number of the book which it has in the Bible
@@ -654,23 +1919,650 @@ impl BibleBook {
             BibleBook::IIIJohn => 64,
             BibleBook::Jude => 65,
             BibleBook::Revelation => 66,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Tobit => 67,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Judith => 68,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::AdditionsToEsther => 69,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Wisdom => 70,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Sirach => 71,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Baruch => 72,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::PrayerOfAzariah => 73,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Susanna => 74,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::BelAndTheDragon => 75,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IMaccabees => 76,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IIMaccabees => 77,
         }
     }
-}
 
-/// This function returns a Bible book by its number. The number is the number of the book in the Bible (1-66).
-/// # Parameters
-/// - `number`: The number of the book in the Bible (1-66)
-/// # Returns
-/// - an `Option<BibleBook>` which contains the book if it exists, or `None` if it does not exist.
-/// # Note
-/// This function is the inverse of `BibleBook::number()`.
-/// # Example
-/// ```
-/// use bibleref::bible::get_bible_book_by_number;
-/// use bibleref::bible::BibleBook;
-/// assert_eq!(get_bible_book_by_number(1), Some(BibleBook::Genesis));
-/// assert_eq!(get_bible_book_by_number(2), Some(BibleBook::Exodus));
+    /// Returns all chapters of the book in their order, e.g. Ruth 1 to Ruth 4.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// let chapters = BibleBook::Ruth.chapters();
+    /// assert_eq!(chapters.len(), 4);
+    /// assert_eq!(chapters[3].chapter(), 4);
+    /// ```
+    pub fn chapters(&self) -> BibleChapterList {
+        (1..=get_number_of_chapters(self))
+            .map(|chapter| BibleChapterReference::new(*self, chapter).unwrap())
+            .collect()
+    }
+
+    /// Returns the number of the book in a chronological order of the Bible, as used by some study Bibles. The canonical [BibleBook::number] is not affected.
+    ///
+    /// The books are ordered by the period of the events they describe or, for the prophets and letters, the period in which they were written:
+    /// - Old Testament: Job is placed in the time of the patriarchs after Genesis. The Psalms and the writings of Solomon (Proverbs, Ecclesiastes and Song of Solomon) follow 1 Chronicles, as they belong to the time of David and Solomon.
+    ///   After the history of the kings (1-2 Kings and 2 Chronicles), the prophets follow in the order of their ministry (Obadiah, Joel, Jonah, Amos, Hosea, Isaiah, Micah, Nahum, Zephaniah, Jeremiah, Habakkuk, Lamentations, Ezekiel and Daniel),
+    ///   then the books of the time after the exile (Ezra, Haggai, Zechariah, Esther, Nehemiah and Malachi).
+    /// - New Testament: The Gospels and Acts keep their order, followed by the letters in the order in which they were probably written
+    ///   (James, Galatians, 1-2 Thessalonians, 1-2 Corinthians, Romans, Ephesians, Colossians, Philemon, Philippians, 1 Timothy, Titus, 1 Peter, Hebrews, 2 Timothy, 2 Peter, Jude and 1-3 John) and Revelation.
+    ///
+    /// The dating of some books is disputed, so this is only one of several possible chronological orders.
+    /// The deuterocanonical books (`deuterocanon` feature) keep their canonical numbers after Revelation.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::Genesis.chronological_number(), 1);
+    /// assert_eq!(BibleBook::Job.chronological_number(), 2);
+    /// assert_eq!(BibleBook::Exodus.chronological_number(), 3);
+    /// assert_eq!(BibleBook::Revelation.chronological_number(), 66);
+    /// ```
+    pub fn chronological_number(&self) -> u8 {
+        match CHRONOLOGICAL_BOOKS.iter().position(|book| book == self) {
+            Some(position) => position as u8 + 1,
+            None => self.number(),
+        }
+    }
+
+    /// Returns the position of the book in the Hebrew Bible (Tanakh), which orders the books of the Old Testament differently from Christian Bibles:
+    /// - Torah (1-5): Genesis to Deuteronomy
+    /// - Nevi'im (6-26): Joshua, Judges, 1-2 Samuel, 1-2 Kings, Isaiah, Jeremiah, Ezekiel and the twelve minor prophets (Hosea to Malachi)
+    /// - Ketuvim (27-39): Psalms, Proverbs, Job, Song of Solomon, Ruth, Lamentations, Ecclesiastes, Esther, Daniel, Ezra, Nehemiah and 1-2 Chronicles
+    ///
+    /// The Tanakh counts books like Samuel, Kings and the twelve minor prophets as one book each, but they are numbered separately here, so that every book of the Old Testament has its own position.
+    /// # Returns
+    /// - The position of the book in the Tanakh (1-39), or [None] for books which are not part of it (the New Testament and the deuterocanonical books).
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::Genesis.tanakh_order(), Some(1));
+    /// assert_eq!(BibleBook::Psalm.tanakh_order(), Some(27));
+    /// assert_eq!(BibleBook::IIChronicles.tanakh_order(), Some(39));
+    /// assert_eq!(BibleBook::Matthew.tanakh_order(), None);
+    /// ```
+    pub fn tanakh_order(&self) -> Option<u8> {
+        TANAKH_BOOKS
+            .iter()
+            .position(|book| book == self)
+            .map(|position| position as u8 + 1)
+    }
+
+    /// Returns the three-letter USFM/Paratext code of the book (e.g. "GEN", "JHN" or "REV").
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::Genesis.usfm_code(), "GEN");
+    /// assert_eq!(BibleBook::IJohn.usfm_code(), "1JN");
+    /// ```
+    pub fn usfm_code(&self) -> &'static str {
+        match self {
+            BibleBook::Genesis => "GEN",
+            BibleBook::Exodus => "EXO",
+            BibleBook::Leviticus => "LEV",
+            BibleBook::Numbers => "NUM",
+            BibleBook::Deuteronomy => "DEU",
+            BibleBook::Joshua => "JOS",
+            BibleBook::Judges => "JDG",
+            BibleBook::Ruth => "RUT",
+            BibleBook::ISamuel => "1SA",
+            BibleBook::IISamuel => "2SA",
+            BibleBook::IKings => "1KI",
+            BibleBook::IIKings => "2KI",
+            BibleBook::IChronicles => "1CH",
+            BibleBook::IIChronicles => "2CH",
+            BibleBook::Ezra => "EZR",
+            BibleBook::Nehemiah => "NEH",
+            BibleBook::Esther => "EST",
+            BibleBook::Job => "JOB",
+            BibleBook::Psalm => "PSA",
+            BibleBook::Proverbs => "PRO",
+            BibleBook::Ecclesiastes => "ECC",
+            BibleBook::SongofSolomon => "SNG",
+            BibleBook::Isaiah => "ISA",
+            BibleBook::Jeremiah => "JER",
+            BibleBook::Lamentations => "LAM",
+            BibleBook::Ezekiel => "EZK",
+            BibleBook::Daniel => "DAN",
+            BibleBook::Hosea => "HOS",
+            BibleBook::Joel => "JOL",
+            BibleBook::Amos => "AMO",
+            BibleBook::Obadiah => "OBA",
+            BibleBook::Jonah => "JON",
+            BibleBook::Micah => "MIC",
+            BibleBook::Nahum => "NAM",
+            BibleBook::Habakkuk => "HAB",
+            BibleBook::Zephaniah => "ZEP",
+            BibleBook::Haggai => "HAG",
+            BibleBook::Zechariah => "ZEC",
+            BibleBook::Malachi => "MAL",
+            BibleBook::Matthew => "MAT",
+            BibleBook::Mark => "MRK",
+            BibleBook::Luke => "LUK",
+            BibleBook::John => "JHN",
+            BibleBook::Acts => "ACT",
+            BibleBook::Romans => "ROM",
+            BibleBook::ICorinthians => "1CO",
+            BibleBook::IICorinthians => "2CO",
+            BibleBook::Galatians => "GAL",
+            BibleBook::Ephesians => "EPH",
+            BibleBook::Philippians => "PHP",
+            BibleBook::Colossians => "COL",
+            BibleBook::IThessalonians => "1TH",
+            BibleBook::IIThessalonians => "2TH",
+            BibleBook::ITimothy => "1TI",
+            BibleBook::IITimothy => "2TI",
+            BibleBook::Titus => "TIT",
+            BibleBook::Philemon => "PHM",
+            BibleBook::Hebrews => "HEB",
+            BibleBook::James => "JAS",
+            BibleBook::IPeter => "1PE",
+            BibleBook::IIPeter => "2PE",
+            BibleBook::IJohn => "1JN",
+            BibleBook::IIJohn => "2JN",
+            BibleBook::IIIJohn => "3JN",
+            BibleBook::Jude => "JUD",
+            BibleBook::Revelation => "REV",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Tobit => "TOB",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Judith => "JDT",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::AdditionsToEsther => "ESG",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Wisdom => "WIS",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Sirach => "SIR",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Baruch => "BAR",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::PrayerOfAzariah => "S3Y",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Susanna => "SUS",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::BelAndTheDragon => "BEL",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IMaccabees => "1MA",
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IIMaccabees => "2MA",
+        }
+    }
+
+    /// Returns the [BibleBook] with the given three-letter USFM/Paratext code (e.g. "GEN"). The comparison is case-insensitive.
+    /// # Returns
+    /// - [Some] with the [BibleBook] if the code is known
+    /// - [None] if the code is unknown
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::from_usfm_code("jhn"), Some(BibleBook::John));
+    /// assert_eq!(BibleBook::from_usfm_code("JOH"), None);
+    /// ```
+    pub fn from_usfm_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::all()
+            .into_iter()
+            .find(|book| book.usfm_code().eq_ignore_ascii_case(code))
+    }
+
+    /// Returns the number which USFM assigns to the book (e.g. 1 for "01-GEN"). This number is used for the file names of Paratext projects.
+    /// It equals [BibleBook::number] for the Old Testament, but as USFM reserves the number 40, the New Testament starts with 41 for Matthew.
+    /// The deuterocanonical books start with 68 for Tobit. As USFM counts the Letter of Jeremiah (74) as a book of its own, the books after Baruch are shifted by one more.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!(BibleBook::Malachi.usfm_number(), 39);
+    /// assert_eq!(BibleBook::Matthew.usfm_number(), 41);
+    /// assert_eq!(BibleBook::Revelation.usfm_number(), 67);
+    /// ```
+    pub fn usfm_number(&self) -> u8 {
+        match self {
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::PrayerOfAzariah => self.number() + 2,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::Susanna => self.number() + 2,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::BelAndTheDragon => self.number() + 2,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IMaccabees => self.number() + 2,
+            #[cfg(feature = "deuterocanon")]
+            BibleBook::IIMaccabees => self.number() + 2,
+            _ if self.is_new_testament() || self.is_deuterocanonical() => self.number() + 1,
+            _ => self.number(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromStr for BibleBook {
+    type Err = BookNameNotRecognizedError;
+
+    /// Converts a canonical English long or short book name (e.g. "Genesis", "1 Samuel" or "Rev") into a [BibleBook].
+    /// The comparison is case-insensitive and surrounding whitespace is ignored.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// assert_eq!("Genesis".parse::<BibleBook>(), Ok(BibleBook::Genesis));
+    /// assert_eq!(" 1 samuel ".parse::<BibleBook>(), Ok(BibleBook::ISamuel));
+    /// assert_eq!("Rev".parse::<BibleBook>(), Ok(BibleBook::Revelation));
+    /// assert!("Foo".parse::<BibleBook>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let book_name = s.trim().to_lowercase();
+        let english = &*ENGLISH_REFERENCE_LANGUAGE;
+
+        BibleBook::all()
+            .into_iter()
+            .find(|book| {
+                english.long_names[book]
+                    .iter()
+                    .chain(english.short_names[book].iter())
+                    .any(|name| name.to_lowercase() == book_name)
+            })
+            .ok_or(BookNameNotRecognizedError {
+                provided_book_name: s.to_string(),
+            })
+    }
+}
+
+/// The traditional sections (genres) into which the books of the Bible are grouped, e.g. for showing the books by category.
+/// The deuterocanonical books are assigned to the sections in which they appear in Catholic Bibles (e.g. Tobit to the historical books and Baruch to the major prophets).
+#[derive(PartialEq, PartialOrd, Ord, Eq, Debug, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BibleSection {
+    /// The five books of Moses (Genesis to Deuteronomy)
+    Pentateuch,
+    /// The historical books of the Old Testament (Joshua to Esther)
+    HistoricalOT,
+    /// The poetic and wisdom books (Job to Song of Solomon)
+    Wisdom,
+    /// The major prophets (Isaiah to Daniel, including Lamentations)
+    MajorProphets,
+    /// The twelve minor prophets (Hosea to Malachi)
+    MinorProphets,
+    /// The four Gospels (Matthew to John)
+    Gospels,
+    /// The book of Acts
+    ActsBook,
+    /// The thirteen letters of Paul (Romans to Philemon)
+    PaulineEpistles,
+    /// The general (catholic) letters including Hebrews (Hebrews to Jude)
+    GeneralEpistles,
+    /// The book of Revelation
+    Apocalypse,
+}
+
+/// The granularity of a [BibleReference] or [BibleRange], i.e. whether it refers to whole books, whole chapters or single verses.
+/// The variants are ordered from the coarsest to the finest granularity.
+#[derive(PartialEq, PartialOrd, Ord, Eq, Debug, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ReferenceGranularity {
+    /// A whole book
+    Book,
+    /// A whole chapter
+    Chapter,
+    /// A single verse
+    Verse,
+}
+
+/// An [Iterator] which lazily yields [BibleVerseReference]s in canonical order, starting at a given verse and ending with the last verse of the Bible (Revelation 22:21, or 2 Maccabees 15:39 with the `deuterocanon` feature).
+/// The verses are determined one by one with [BibleReference::next], so no list of verses is allocated up front.
+#[derive(Debug, Clone)]
+pub struct BibleVerseIterator {
+    next_verse: Option<BibleVerseReference>,
+}
+
+impl BibleVerseIterator {
+    /// Creates a new [BibleVerseIterator] which starts with (and includes) the verse `start`.
+    pub fn new(start: BibleVerseReference) -> Self {
+        BibleVerseIterator {
+            next_verse: Some(start),
+        }
+    }
+}
+
+impl Iterator for BibleVerseIterator {
+    type Item = BibleVerseReference;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current_verse = self.next_verse.take()?;
+        // The next reference of the last verse of a chapter (or book) is the following chapter (or book), so we go down to its first verse.
+        self.next_verse = match BibleReference::BibleVerse(current_verse.clone()).next() {
+            Some(BibleReference::BibleVerse(verse)) => Some(verse),
+            Some(BibleReference::BibleChapter(chapter)) => {
+                BibleVerseReference::new(chapter.book(), chapter.chapter(), 1).ok()
+            }
+            Some(BibleReference::BibleBook(book)) => {
+                BibleVerseReference::new(book.book(), 1, 1).ok()
+            }
+            None => None,
+        };
+        Some(current_verse)
+    }
+}
+
+/// Returns a [BibleVerseIterator] over all verses of the Bible, from Genesis 1:1 to Revelation 22:21 (or 2 Maccabees 15:39 if the `deuterocanon` feature is enabled).
+/// # Example
+/// ```
+/// use bibleref::bible::{all_verses, BibleBook, BibleVerseReference};
+/// let mut verses = all_verses();
+/// assert_eq!(verses.next(), Some(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap()));
+/// # #[cfg(not(feature = "deuterocanon"))]
+/// assert_eq!(verses.last(), Some(BibleVerseReference::new(BibleBook::Revelation, 22, 21).unwrap()));
+/// ```
+pub fn all_verses() -> BibleVerseIterator {
+    BibleVerseIterator::new(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap())
+}
+
+/// Returns the verse with the given zero-based index in the whole Bible, which allows to treat the Bible as a flat array of verses (e.g. to select a random verse).
+/// This is the inverse of [BibleVerseReference::global_index].
+/// # Params
+/// - `index`: The index of the verse, where 0 is Genesis 1:1
+/// # Returns
+/// - The verse or [None] if the index is not smaller than [total_number_of_verses]
+/// # Example
+/// ```
+/// use bibleref::bible::{verse_at_index, BibleBook, BibleVerseReference};
+/// assert_eq!(verse_at_index(0), Some(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap()));
+/// assert_eq!(verse_at_index(31), Some(BibleVerseReference::new(BibleBook::Genesis, 2, 1).unwrap()));
+/// # #[cfg(not(feature = "deuterocanon"))]
+/// assert_eq!(verse_at_index(31101), Some(BibleVerseReference::new(BibleBook::Revelation, 22, 21).unwrap()));
+/// # #[cfg(not(feature = "deuterocanon"))]
+/// assert_eq!(verse_at_index(31102), None);
+/// ```
+pub fn verse_at_index(index: u32) -> Option<BibleVerseReference> {
+    let mut index = index;
+    for book in BibleBook::all() {
+        let verses_of_book = total_verses_in_book(&book) as u32;
+        if index >= verses_of_book {
+            index -= verses_of_book;
+            continue;
+        }
+        for chapter in 1..=get_number_of_chapters(&book) {
+            let verses = get_number_of_verses(&book, &chapter).unwrap() as u32;
+            if index < verses {
+                return BibleVerseReference::new(book, chapter, index as BibleVerse + 1).ok();
+            }
+            index -= verses;
+        }
+    }
+    None
+}
+
+/// Returns the position of a verse in the whole Bible, counting from 1 for Genesis 1:1.
+/// The superscription of a Psalm (verse 0) has the same position as verse 1 of the Psalm.
+fn verse_position(verse: &BibleVerseReference) -> i64 {
+    let verses_of_previous_books: i64 = BibleBook::all()
+        .iter()
+        .take_while(|book| **book < verse.book())
+        .flat_map(|book| (1..=get_number_of_chapters(book)).map(move |chapter| (*book, chapter)))
+        .map(|(book, chapter)| get_number_of_verses(&book, &chapter).unwrap() as i64)
+        .sum();
+    let verses_of_previous_chapters: i64 = (1..verse.chapter())
+        .map(|chapter| get_number_of_verses(&verse.book(), &chapter).unwrap() as i64)
+        .sum();
+    verses_of_previous_books + verses_of_previous_chapters + verse.verse().max(1) as i64
+}
+
+/// Returns the number of verses from `a` to `b`, e.g. 2 from John 3:16 to John 3:18.
+/// The result is negative if `a` is after `b`. The superscription of a Psalm (verse 0) is counted like verse 1.
+/// # Example
+/// ```
+/// use bibleref::bible::{verses_between, BibleBook, BibleVerseReference};
+/// let a = BibleVerseReference::new(BibleBook::Genesis, 50, 26).unwrap();
+/// let b = BibleVerseReference::new(BibleBook::Exodus, 1, 1).unwrap();
+/// assert_eq!(verses_between(&a, &b), 1);
+/// assert_eq!(verses_between(&b, &a), -1);
+/// ```
+pub fn verses_between(a: &BibleVerseReference, b: &BibleVerseReference) -> i64 {
+    verse_position(b) - verse_position(a)
+}
+
+/// Returns the smallest range which covers both references, e.g. John 3:16-4:2 for John 4:2 and John 3:16.
+/// The order of the references does not matter. The range has the finest granularity of both references, e.g. a book and a verse are covered by a [BibleRange::VerseRange] from the first to the last verse.
+/// # Example
+/// ```
+/// use bibleref::bible::{span, BibleBook, BibleBookReference, BibleReference, BibleVerseReference};
+/// let john_3_16 = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+/// let acts = BibleReference::BibleBook(BibleBookReference::new(BibleBook::Acts));
+/// # #[cfg(feature = "std")]
+/// assert_eq!(span(&acts, &john_3_16).to_string(), "John 3:16-Acts 28:31");
+/// ```
+pub fn span(a: &BibleReference, b: &BibleReference) -> BibleRange {
+    let start = a.verse_span().0.min(b.verse_span().0);
+    let end = a.verse_span().1.max(b.verse_span().1);
+    let granularity = a.granularity().max(b.granularity());
+    BibleRange::new(
+        reference_at_granularity(start, granularity),
+        reference_at_granularity(end, granularity),
+    )
+    .unwrap()
+}
+
+/// Iterates over all references of the given granularity from `start` to `end` (both inclusive), e.g. over every chapter between two verses.
+/// In contrast to the range types, the granularity of the result does not depend on the types of the endpoints:
+/// - A finer endpoint is replaced by the book or chapter which contains it, e.g. John 3:16 by John 3 when iterating chapters.
+/// - A coarser endpoint is replaced by its first (for `start`) or last (for `end`) chapter or verse, e.g. Jude by Jude 1:25 when iterating verses up to it.
+/// # Params
+/// - `start`: The first reference
+/// - `end`: The last reference
+/// - `granularity`: The granularity of the returned references
+/// # Returns
+/// - An [Iterator] over the references in canonical order. It is empty if `start` is after `end`.
+/// # Example
+/// ```
+/// use bibleref::bible::{references_between, BibleBook, BibleReference, BibleVerseReference, ReferenceGranularity};
+/// let start = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 20, 31).unwrap());
+/// let end = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Acts, 2, 1).unwrap());
+/// # #[cfg(feature = "std")] {
+/// let chapters: Vec<String> = references_between(&start, &end, ReferenceGranularity::Chapter)
+///     .map(|chapter| chapter.to_string())
+///     .collect();
+/// assert_eq!(chapters, vec!["John 20", "John 21", "Acts 1", "Acts 2"]);
+/// # }
+/// ```
+pub fn references_between(
+    start: &BibleReference,
+    end: &BibleReference,
+    granularity: ReferenceGranularity,
+) -> impl Iterator<Item = BibleReference> + use<> {
+    let first = reference_at_granularity(start.verse_span().0, granularity);
+    let last = reference_at_granularity(end.verse_span().1, granularity);
+    core::iter::successors(Some(first), move |reference| {
+        // After the last chapter (or verse) of a book, the next reference is a coarser one, so we go down to its first chapter (or verse)
+        let next = reference.next()?;
+        match next.granularity() == granularity {
+            true => Some(next),
+            false => Some(reference_at_granularity(next.verse_span().0, granularity)),
+        }
+    })
+    .take_while(move |reference| *reference <= last)
+}
+
+/// Returns the book, chapter or verse (depending on `granularity`) which contains the verse.
+fn reference_at_granularity(
+    verse: BibleVerseReference,
+    granularity: ReferenceGranularity,
+) -> BibleReference {
+    match granularity {
+        ReferenceGranularity::Book => {
+            BibleReference::BibleBook(BibleBookReference::new(verse.book()))
+        }
+        ReferenceGranularity::Chapter => BibleReference::BibleChapter(
+            BibleChapterReference::new(verse.book(), verse.chapter()).unwrap(),
+        ),
+        ReferenceGranularity::Verse => BibleReference::BibleVerse(verse),
+    }
+}
+
+/// Splits the whole Bible into `n` contiguous portions with (almost) the same number of verses, e.g. for a plan to read the Bible in a year.
+/// The sizes of the portions differ by at most one verse, and together they cover every verse of the Bible exactly once.
+/// Portions which cover whole chapters or books are returned as a [BibleRange::ChapterRange] or a [BibleRange::BookRange], all others as a [BibleRange::VerseRange].
+/// # Params
+/// - `n`: The number of portions
+/// # Returns
+/// - The portions in canonical order. If `n` is 0, the list is empty; if `n` is greater than the number of verses, every verse is a portion of its own.
+/// # Example
+/// ```
+/// use bibleref::bible::split_into_portions;
+/// let plan = split_into_portions(365);
+/// assert_eq!(plan.len(), 365);
+/// # #[cfg(feature = "std")]
+/// assert_eq!(plan[0].start().to_string(), "Genesis 1:1");
+/// ```
+pub fn split_into_portions(n: usize) -> Vec<BibleRange> {
+    let chapters: Vec<(BibleBook, BibleChapter, usize)> = BibleBook::all()
+        .into_iter()
+        .flat_map(|book| (1..=get_number_of_chapters(&book)).map(move |chapter| (book, chapter)))
+        .map(|(book, chapter)| {
+            let verses = get_number_of_verses(&book, &chapter).unwrap() as usize;
+            (book, chapter, verses)
+        })
+        .collect();
+    let total: usize = chapters.iter().map(|(_, _, verses)| verses).sum();
+    let n = n.min(total);
+
+    // The verse with the zero-based position `index` in the whole Bible
+    let verse_at = |mut index: usize| {
+        for (book, chapter, verses) in &chapters {
+            if index < *verses {
+                return BibleVerseReference::new(*book, *chapter, index as BibleVerse + 1).unwrap();
+            }
+            index -= verses;
+        }
+        unreachable!("The verse index is out of range")
+    };
+
+    (0..n)
+        .map(|portion| {
+            let start = verse_at(portion * total / n);
+            let end = verse_at((portion + 1) * total / n - 1);
+            let verse_range = BibleVerseRange::new(start, end).unwrap();
+            match verse_range.as_chapter_range() {
+                Some(chapter_range) => match chapter_range.as_book_range() {
+                    Some(book_range) => BibleRange::BookRange(book_range),
+                    None => BibleRange::ChapterRange(chapter_range),
+                },
+                None => BibleRange::VerseRange(verse_range),
+            }
+        })
+        .collect()
+}
+
+/// Returns the verse at the zero-based position `index` within `book`, ignoring Psalm superscriptions.
+#[cfg(feature = "rand")]
+fn nth_verse_in_book(book: BibleBook, mut index: usize) -> BibleVerseReference {
+    for chapter in 1..=get_number_of_chapters(&book) {
+        let verses = get_number_of_verses(&book, &chapter).unwrap() as usize;
+        if index < verses {
+            return BibleVerseReference::new(book, chapter, index as BibleVerse + 1).unwrap();
+        }
+        index -= verses;
+    }
+    panic!("The verse index is out of range for {:?}", book);
+}
+
+/// Returns a random verse of the Bible (`rand` feature). Every verse has the same probability, so books and chapters with more verses are picked more often.
+/// # Params
+/// - `rng`: The random number generator to use
+/// # Returns
+/// - a random [BibleVerseReference]
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use bibleref::bible::random_verse;
+/// let verse = random_verse(&mut rand::rng());
+/// assert!(verse.verse() >= 1);
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn random_verse<R: rand::Rng + ?Sized>(rng: &mut R) -> BibleVerseReference {
+    let mut index = rng.random_range(0..total_number_of_verses() as usize);
+    for book in BibleBook::all() {
+        let verses = total_verses_in_book(&book) as usize;
+        if index < verses {
+            return nth_verse_in_book(book, index);
+        }
+        index -= verses;
+    }
+    unreachable!()
+}
+
+/// Returns a random verse of the given book (`rand` feature). Every verse of the book has the same probability.
+/// # Params
+/// - `book`: The book from which the verse is picked
+/// - `rng`: The random number generator to use
+/// # Returns
+/// - a random [BibleVerseReference] within `book`
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use bibleref::bible::{random_verse_in, BibleBook};
+/// let verse = random_verse_in(BibleBook::John, &mut rand::rng());
+/// assert_eq!(verse.book(), BibleBook::John);
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn random_verse_in<R: rand::Rng + ?Sized>(book: BibleBook, rng: &mut R) -> BibleVerseReference {
+    let index = rng.random_range(0..total_verses_in_book(&book) as usize);
+    nth_verse_in_book(book, index)
+}
+
+/// Returns a random chapter of the Bible (`rand` feature). Every chapter has the same probability.
+/// # Params
+/// - `rng`: The random number generator to use
+/// # Returns
+/// - a random [BibleChapterReference]
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use bibleref::bible::random_chapter;
+/// let chapter = random_chapter(&mut rand::rng());
+/// assert!(chapter.chapter() >= 1);
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn random_chapter<R: rand::Rng + ?Sized>(rng: &mut R) -> BibleChapterReference {
+    let chapters: Vec<(BibleBook, BibleChapter)> = BibleBook::all()
+        .into_iter()
+        .flat_map(|book| (1..=get_number_of_chapters(&book)).map(move |chapter| (book, chapter)))
+        .collect();
+    let (book, chapter) = chapters[rng.random_range(0..chapters.len())];
+    BibleChapterReference::new(book, chapter).unwrap()
+}
+
+/// This function returns a Bible book by its number. The number is the number of the book in the Bible (1-66, or 1-77 with the `deuterocanon` feature).
+/// # Parameters
+/// - `number`: The number of the book in the Bible (1-66)
+/// # Returns
+/// - an `Option<BibleBook>` which contains the book if it exists, or `None` if it does not exist.
+/// # Note
+/// This function is the inverse of `BibleBook::number()`.
+/// # Example
+/// ```
+/// use bibleref::bible::get_bible_book_by_number;
+/// use bibleref::bible::BibleBook;
+/// assert_eq!(get_bible_book_by_number(1), Some(BibleBook::Genesis));
+/// assert_eq!(get_bible_book_by_number(2), Some(BibleBook::Exodus));
 /// assert_eq!(get_bible_book_by_number(3), Some(BibleBook::Leviticus));
 /// assert_eq!(get_bible_book_by_number(4), Some(BibleBook::Numbers));
 /// assert_eq!(get_bible_book_by_number(5), Some(BibleBook::Deuteronomy));
@@ -745,18 +2637,72 @@ pub fn get_bible_book_by_number(number: u8) -> Option<BibleBook> {
         64 => Some(BibleBook::IIIJohn),
         65 => Some(BibleBook::Jude),
         66 => Some(BibleBook::Revelation),
+        #[cfg(feature = "deuterocanon")]
+        67 => Some(BibleBook::Tobit),
+        #[cfg(feature = "deuterocanon")]
+        68 => Some(BibleBook::Judith),
+        #[cfg(feature = "deuterocanon")]
+        69 => Some(BibleBook::AdditionsToEsther),
+        #[cfg(feature = "deuterocanon")]
+        70 => Some(BibleBook::Wisdom),
+        #[cfg(feature = "deuterocanon")]
+        71 => Some(BibleBook::Sirach),
+        #[cfg(feature = "deuterocanon")]
+        72 => Some(BibleBook::Baruch),
+        #[cfg(feature = "deuterocanon")]
+        73 => Some(BibleBook::PrayerOfAzariah),
+        #[cfg(feature = "deuterocanon")]
+        74 => Some(BibleBook::Susanna),
+        #[cfg(feature = "deuterocanon")]
+        75 => Some(BibleBook::BelAndTheDragon),
+        #[cfg(feature = "deuterocanon")]
+        76 => Some(BibleBook::IMaccabees),
+        #[cfg(feature = "deuterocanon")]
+        77 => Some(BibleBook::IIMaccabees),
         _ => None,
     }
 }
 
+/// This function returns the book of the Bible which has the given number in the chronological order.
+/// # Params
+/// - `number`: The chronological number of the book, starting with 1.
+/// # Returns
+/// - an `Option<BibleBook>` which contains the book if it exists, or `None` if it does not exist.
+/// # Note
+/// This function is the inverse of `BibleBook::chronological_number()`.
+/// # Example
+/// ```
+/// use bibleref::bible::get_bible_book_by_chronological_number;
+/// use bibleref::bible::BibleBook;
+/// assert_eq!(get_bible_book_by_chronological_number(1), Some(BibleBook::Genesis));
+/// assert_eq!(get_bible_book_by_chronological_number(2), Some(BibleBook::Job));
+/// assert_eq!(get_bible_book_by_chronological_number(0), None);
+/// ```
+pub fn get_bible_book_by_chronological_number(number: u8) -> Option<BibleBook> {
+    match (number as usize).checked_sub(1)? {
+        index if index < CHRONOLOGICAL_BOOKS.len() => Some(CHRONOLOGICAL_BOOKS[index]),
+        _ => get_bible_book_by_number(number),
+    }
+}
+
 /// An unsigned positive number which represents the chapter of a Bible reference
+///
+/// As it is a [u8], 255 is the highest possible chapter number. The book with the most chapters has 151 (the Psalms in the Septuagint versification), so there is enough room for all supported versifications.
+/// Navigation methods like [BibleReference::next] use checked arithmetic and never overflow.
 pub type BibleChapter = u8;
 
 /// An unsigned positive number which represents the verse of a Bible reference
+///
+/// As it is a [u8], 255 is the highest possible verse number. The chapter with the most verses is Psalm 119 with 176 verses.
+/// A versification scheme with more than 255 verses in one chapter can't be represented and would require to widen this type (and to change the compact encoding of [BibleVerseReference::to_u32]).
 pub type BibleVerse = u8;
 
+// The compact encoding of [BibleVerseReference::to_u32] stores the book, the chapter and the verse in one byte each.
+const _: () =
+    assert!(core::mem::size_of::<BibleChapter>() == 1 && core::mem::size_of::<BibleVerse>() == 1);
+
 /// A Bible Book range is a range of Bible books, e.g. Genesis to Exodus. It is represented by two [BibleBook]s. The first book is the start of the range and the second book is the end of the range.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BibleBookRange {
     start: BibleBookReference,
@@ -819,10 +2765,21 @@ impl BibleBookRange {
             None
         }
     }
+
+    /// Returns the number of books in the range (including the start and end book) without creating a list.
+    pub fn count(&self) -> usize {
+        (self.end.book().number() - self.start.book().number()) as usize + 1
+    }
+
+    /// Checks whether the given reference lies completely inside the range.
+    /// Chapters and verses are contained if their book is part of the range.
+    pub fn contains(&self, reference: &BibleReference) -> bool {
+        self.as_chapter_range().contains(reference)
+    }
 }
 
 /// A Bible Chapter range is a range of Bible chapters, e.g. Genesis 1 to Genesis 2. It is represented by two [BibleChapterReference]s. The first chapter is the start of the range and the second chapter is the end of the range.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BibleChapterRange {
     start: BibleChapterReference,
@@ -896,10 +2853,50 @@ impl BibleChapterRange {
             None
         }
     }
+
+    /// Returns the number of chapters in the range (including the start and end chapter) without creating a list.
+    /// The number is computed from the number of chapters of each book in the range.
+    pub fn count(&self) -> usize {
+        let chapters_of_books: usize = (self.start.book().number()..=self.end.book().number())
+            .map(|number| {
+                get_number_of_chapters(&get_bible_book_by_number(number).unwrap()) as usize
+            })
+            .sum();
+        let chapters_before_start = self.start.chapter() as usize - 1;
+        let chapters_after_end =
+            (get_number_of_chapters(&self.end.book()) - self.end.chapter()) as usize;
+        chapters_of_books - chapters_before_start - chapters_after_end
+    }
+
+    /// Checks whether the given reference lies completely inside the range.
+    /// Verses are contained if their chapter is part of the range, books are only contained if all of their chapters are part of the range.
+    pub fn contains(&self, reference: &BibleReference) -> bool {
+        self.as_verse_range().contains(reference)
+    }
+
+    /// Returns the total number of verses of all chapters in the range.
+    fn number_of_verses(&self) -> usize {
+        let mut number_of_verses: usize = 0;
+        for number in self.start.book().number()..=self.end.book().number() {
+            let book = get_bible_book_by_number(number).unwrap();
+            let first_chapter = match book == self.start.book() {
+                true => self.start.chapter(),
+                false => 1,
+            };
+            let last_chapter = match book == self.end.book() {
+                true => self.end.chapter(),
+                false => get_number_of_chapters(&book),
+            };
+            for chapter in first_chapter..=last_chapter {
+                number_of_verses += get_number_of_verses(&book, &chapter).unwrap() as usize;
+            }
+        }
+        number_of_verses
+    }
 }
 
 /// A Bible Verse range is a range of Bible verses, e.g. Genesis 1:1 to Genesis 1:2. It is represented by two [BibleVerseReference]s. The first verse is the start of the range and the second verse is the end of the range.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BibleVerseRange {
     start: BibleVerseReference,
@@ -933,15 +2930,11 @@ impl BibleVerseRange {
 
     /// Returns the range as a [BibleVerseList]
     /// # Note
-    /// This function will return all verses in the range, including the start and end verse.
+    /// This function will return all verses in the range, including the start and end verse. Ranges spanning several chapters or books are supported.
     pub fn as_list(&self) -> BibleVerseList {
-        let mut verses: BibleVerseList = vec![];
-        for i in self.start.verse()..=self.end.verse() {
-            verses.push(
-                BibleVerseReference::new(self.start.book(), self.start.chapter(), i).unwrap(),
-            );
-        }
-        verses
+        BibleVerseIterator::new(self.start())
+            .take_while(|verse| verse <= &self.end)
+            .collect()
     }
 
     /// Tries to convert the range into a [BibleChapterRange] if the range spans completely over multiple chapters.
@@ -979,10 +2972,44 @@ impl BibleVerseRange {
             None
         }
     }
+
+    /// Returns the number of verses in the range (including the start and end verse) without creating a list.
+    /// The number is computed from the number of verses of each chapter in the range, so ranges spanning several chapters or books are supported.
+    pub fn count(&self) -> usize {
+        let verses_of_chapters: usize = BibleChapterRange::new(
+            BibleChapterReference::new(self.start.book(), self.start.chapter()).unwrap(),
+            BibleChapterReference::new(self.end.book(), self.end.chapter()).unwrap(),
+        )
+        .unwrap()
+        .number_of_verses();
+        let verses_after_end = (get_number_of_verses(&self.end.book(), &self.end.chapter())
+            .unwrap()
+            - self.end.verse()) as usize;
+        // A start at a superscription (verse 0) counts as an additional verse
+        verses_of_chapters + 1 - self.start.verse() as usize - verses_after_end
+    }
+
+    /// Checks whether the given reference lies completely inside the range.
+    /// Chapters and books are only contained if all of their verses are part of the range.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleChapterReference, BibleReference, BibleVerseRange, BibleVerseReference};
+    /// let verse_range = BibleVerseRange::new(
+    ///     BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+    ///     BibleVerseReference::new(BibleBook::John, 5, 2).unwrap(),
+    /// ).unwrap();
+    /// assert!(verse_range.contains(&BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 4, 10).unwrap())));
+    /// assert!(verse_range.contains(&BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 4).unwrap())));
+    /// assert!(!verse_range.contains(&BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 5).unwrap())));
+    /// ```
+    pub fn contains(&self, reference: &BibleReference) -> bool {
+        let (first_verse, last_verse) = reference.verse_span();
+        self.start <= first_verse && last_verse <= self.end
+    }
 }
 
 /// This enum represents a range of Bible references. It can be a range of books, chapters or verses.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BibleRange {
     /// A range of Bible books
@@ -1075,6 +3102,15 @@ impl BibleRange {
         }
     }
 
+    /// Returns whether the range is a range of books, chapters or verses.
+    pub fn granularity(&self) -> ReferenceGranularity {
+        match self {
+            BibleRange::BookRange(_) => ReferenceGranularity::Book,
+            BibleRange::ChapterRange(_) => ReferenceGranularity::Chapter,
+            BibleRange::VerseRange(_) => ReferenceGranularity::Verse,
+        }
+    }
+
     /// Returns the range as a list (vector) of Bible references ([BibleReference]). The list will contain all references in the range, including the start and end reference.
     pub fn as_list(&self) -> BibleReferenceList {
         match self {
@@ -1096,6 +3132,66 @@ impl BibleRange {
         }
     }
 
+    /// Returns the number of references in the range (books, chapters or verses depending on the type of the range), including the start and end reference.
+    /// In contrast to `as_list().len()`, the number is computed without creating a list.
+    pub fn count(&self) -> usize {
+        match self {
+            BibleRange::BookRange(range) => range.count(),
+            BibleRange::ChapterRange(range) => range.count(),
+            BibleRange::VerseRange(range) => range.count(),
+        }
+    }
+
+    /// Checks whether the given reference (book, chapter or verse) lies completely inside the range.
+    /// References with a finer granularity than the range are contained if their book or chapter is part of the range,
+    /// references with a coarser granularity only if the range covers them completely.
+    pub fn contains(&self, reference: &BibleReference) -> bool {
+        match self {
+            BibleRange::BookRange(range) => range.contains(reference),
+            BibleRange::ChapterRange(range) => range.contains(reference),
+            BibleRange::VerseRange(range) => range.contains(reference),
+        }
+    }
+
+    /// Checks whether the range covers exactly one complete book, e.g. Ruth 1:1-4:22 or Ruth 1-4. The range itself is not changed.
+    /// # Returns
+    /// - [Some] with the book if the range starts at its first verse and ends at its last verse
+    /// - [None] otherwise
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleRange, BibleReference, BibleVerseReference};
+    /// let ruth = BibleRange::new(
+    ///     BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Ruth, 1, 1).unwrap()),
+    ///     BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Ruth, 4, 22).unwrap()),
+    /// ).unwrap();
+    /// assert_eq!(ruth.is_whole_book(), Some(BibleBook::Ruth));
+    /// assert_eq!(ruth.is_whole_chapter(), None);
+    /// ```
+    pub fn is_whole_book(&self) -> Option<BibleBook> {
+        let (first, last) = (self.start().verse_span().0, self.end().verse_span().1);
+        let book = BibleBookReference::new(first.book());
+        (first == book.first_verse() && last == book.last_verse()).then(|| book.book())
+    }
+
+    /// Checks whether the range covers exactly one complete chapter, e.g. John 3:1-36. The range itself is not changed.
+    /// # Returns
+    /// - [Some] with the chapter if the range starts at its first verse and ends at its last verse
+    /// - [None] otherwise
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleChapterReference, BibleRange, BibleReference, BibleVerseReference};
+    /// let john_3 = BibleRange::new(
+    ///     BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 1).unwrap()),
+    ///     BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 36).unwrap()),
+    /// ).unwrap();
+    /// assert_eq!(john_3.is_whole_chapter(), Some(BibleChapterReference::new(BibleBook::John, 3).unwrap()));
+    /// ```
+    pub fn is_whole_chapter(&self) -> Option<BibleChapterReference> {
+        let (first, last) = (self.start().verse_span().0, self.end().verse_span().1);
+        let chapter = BibleChapterReference::new(first.book(), first.chapter()).unwrap();
+        (first == chapter.first_verse() && last == chapter.last_verse()).then_some(chapter)
+    }
+
     pub fn end(&self) -> BibleReference {
         match self {
             BibleRange::BookRange(range) => BibleReference::BibleBook(range.end()),
@@ -1110,46 +3206,231 @@ impl BibleRange {
             BibleRange::VerseRange(range) => BibleReference::BibleVerse(range.start()),
         }
     }
-}
 
-/// This function takes a vector of [BibleReferenceRepresentation]s and aggregates them,
-/// which means that it combines overlapping or adjacent ranges or references into one or multible range.
-/// It wil also remove duplicates and sort the references.
-/// # Parameters
-/// - `bible_representations`: A vector of [BibleReferenceRepresentation]s
-/// # Returns
-/// - A vector of [BibleReferenceRepresentation]s which contains the aggregated references.
-pub fn aggregate_bible_representations(
-    bible_representations: Vec<BibleReferenceRepresentation>,
-) -> Vec<BibleReferenceRepresentation> {
-    let mut representations = bible_representations.clone();
+    /// Returns the parts of the range which are not covered by `other`. The calculation is done at verse granularity,
+    /// so the range might be split into two parts if `other` lies in the middle of it.
+    /// # Returns
+    /// - A vector with zero, one or two parts, each of them upcasted as far as possible (see [BibleReferenceRepresentation::try_upcast])
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleChapterReference, BibleRange, BibleReference};
+    /// let john = BibleRange::new(
+    ///     BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 1).unwrap()),
+    ///     BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 21).unwrap()),
+    /// ).unwrap();
+    /// let john_3 = BibleRange::new(
+    ///     BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap()),
+    ///     BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap()),
+    /// ).unwrap();
+    /// let rest = john.subtract(&john_3);
+    /// assert_eq!(rest.len(), 2);
+    /// # #[cfg(feature = "std")] {
+    /// assert_eq!(rest[0].to_string(), "John 1-2");
+    /// assert_eq!(rest[1].to_string(), "John 4-21");
+    /// # }
+    /// ```
+    pub fn subtract(&self, other: &BibleRange) -> Vec<BibleReferenceRepresentation> {
+        let (first, last) = (self.start().verse_span().0, self.end().verse_span().1);
+        let (other_first, other_last) = (other.start().verse_span().0, other.end().verse_span().1);
 
-    'outer: loop {
-        if representations.len() < 2 {
-            return representations;
+        if other_last < first || other_first > last {
+            return vec![BibleReferenceRepresentation::Range(self.clone()).try_upcast()];
         }
 
-        representations.sort_unstable();
-        representations.dedup();
-
-        for i in 0..representations.len() - 1 {
-            let j = i + 1;
-            if representations.get(i).is_none() || representations.get(j).is_none() {
-                continue;
+        let mut parts = vec![];
+        if other_first > first {
+            if let Some(previous_verse) = previous_verse(&other_first) {
+                parts.push((first.clone(), previous_verse));
             }
-
-            if representations[i] == representations[j] {
-                continue;
+        }
+        if other_last < last {
+            if let Some(BibleReference::BibleVerse(next_verse)) =
+                BibleReference::BibleVerse(other_last).advance_by(1)
+            {
+                parts.push((next_verse, last.clone()));
             }
-            match (&representations[i], &representations[j]) {
-                (
-                    BibleReferenceRepresentation::Single(a),
-                    BibleReferenceRepresentation::Single(b),
-                ) => {
-                    if a == b {
-                        continue;
-                    }
-                    let a_next = a.next();
+        }
+        parts
+            .into_iter()
+            .map(|(start, end)| {
+                BibleReferenceRepresentation::Range(BibleRange::VerseRange(
+                    BibleVerseRange::new(start, end).unwrap(),
+                ))
+                .try_upcast()
+            })
+            .collect()
+    }
+
+    /// Returns the part of the range which is also covered by `other`. The calculation is done at verse granularity,
+    /// so ranges of different types (e.g. a chapter range and a verse range) can be intersected.
+    /// # Returns
+    /// - [Some] with the overlapping part, upcasted as far as possible (see [BibleReferenceRepresentation::try_upcast])
+    /// - [None] if the ranges don't overlap
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleChapterReference, BibleRange, BibleReference, BibleVerseReference};
+    /// let john_3_to_4 = BibleRange::new(
+    ///     BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap()),
+    ///     BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 4).unwrap()),
+    /// ).unwrap();
+    /// let verses = BibleRange::new(
+    ///     BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 2, 20).unwrap()),
+    ///     BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()),
+    /// ).unwrap();
+    /// # #[cfg(feature = "std")]
+    /// assert_eq!(john_3_to_4.intersect(&verses).unwrap().to_string(), "John 3:1-16");
+    /// ```
+    pub fn intersect(&self, other: &BibleRange) -> Option<BibleReferenceRepresentation> {
+        let (first, last) = (self.start().verse_span().0, self.end().verse_span().1);
+        let (other_first, other_last) = (other.start().verse_span().0, other.end().verse_span().1);
+
+        let start = first.max(other_first);
+        let end = last.min(other_last);
+        if start > end {
+            return None;
+        }
+        Some(
+            BibleReferenceRepresentation::Range(BibleRange::VerseRange(
+                BibleVerseRange::new(start, end).unwrap(),
+            ))
+            .try_upcast(),
+        )
+    }
+}
+
+/// Returns the verse before `verse`, crossing chapter and book boundaries.
+/// The verse before verse 1 of a Psalm with a superscription is the superscription (verse 0).
+fn previous_verse(verse: &BibleVerseReference) -> Option<BibleVerseReference> {
+    if verse.verse() > 1
+        || (verse.verse() == 1 && has_superscription(&verse.book(), &verse.chapter()))
+    {
+        return BibleVerseReference::new(verse.book(), verse.chapter(), verse.verse() - 1).ok();
+    }
+    let chapter = BibleChapterReference::new(verse.book(), verse.chapter()).unwrap();
+    let previous_chapter = BibleReference::preceding_chapter(&chapter)?;
+    BibleVerseReference::new(
+        previous_chapter.book(),
+        previous_chapter.chapter(),
+        get_number_of_verses(&previous_chapter.book(), &previous_chapter.chapter()).unwrap(),
+    )
+    .ok()
+}
+
+impl From<BibleBookRange> for BibleRange {
+    fn from(range: BibleBookRange) -> Self {
+        BibleRange::BookRange(range)
+    }
+}
+
+impl From<BibleChapterRange> for BibleRange {
+    fn from(range: BibleChapterRange) -> Self {
+        BibleRange::ChapterRange(range)
+    }
+}
+
+impl From<BibleVerseRange> for BibleRange {
+    fn from(range: BibleVerseRange) -> Self {
+        BibleRange::VerseRange(range)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for BibleRange {
+    /// Formats the range in English with the long book name in its shortened form, e.g. "Joshua 3-7" or "John 3:16-18".
+    ///
+    /// Use [get_range_in_language](crate::referencing::language::get_range_in_language) for other languages or abbreviated book names.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleRange, BibleReference, BibleChapterReference};
+    /// let range = BibleRange::new(
+    ///     BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Joshua, 3).unwrap()),
+    ///     BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Joshua, 7).unwrap()),
+    /// ).unwrap();
+    /// assert_eq!(range.to_string(), "Joshua 3-7");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            ENGLISH_REFERENCE_LANGUAGE.create_bible_range(self, BookReferenceType::Long, true)
+        )
+    }
+}
+
+/// Returns true if `later` is not after the verse which follows `earlier`, i.e. if a passage ending with `earlier` and a passage starting with `later` overlap or are adjacent.
+fn is_at_most_next_verse(earlier: &BibleVerseReference, later: &BibleVerseReference) -> bool {
+    match BibleReference::BibleVerse(earlier.clone()).advance_by(1) {
+        Some(BibleReference::BibleVerse(next_verse)) => later <= &next_verse,
+        // There is no verse after the last verse of the Bible
+        _ => true,
+    }
+}
+
+/// Merges two ranges into one if they overlap, if one contains the other or if they are adjacent. The comparison is done at verse granularity.
+/// # Returns
+/// - [Some] with the range covering both ranges
+/// - [None] if there is a gap between the ranges
+fn merge_ranges(a: &BibleRange, b: &BibleRange) -> Option<BibleRange> {
+    let (a_first, a_last) = (a.start().verse_span().0, a.end().verse_span().1);
+    let (b_first, b_last) = (b.start().verse_span().0, b.end().verse_span().1);
+    if !is_at_most_next_verse(&a_last, &b_first) || !is_at_most_next_verse(&b_last, &a_first) {
+        return None;
+    }
+
+    if core::mem::discriminant(a) == core::mem::discriminant(b) {
+        let start = if a_first <= b_first {
+            a.start()
+        } else {
+            b.start()
+        };
+        let end = if a_last >= b_last { a.end() } else { b.end() };
+        BibleRange::new(start, end).ok()
+    } else {
+        // Ranges of different types are merged at verse granularity
+        Some(BibleRange::VerseRange(
+            BibleVerseRange::new(a_first.min(b_first), a_last.max(b_last)).ok()?,
+        ))
+    }
+}
+
+/// This function takes a vector of [BibleReferenceRepresentation]s and aggregates them,
+/// which means that it combines overlapping or adjacent ranges or references into one or multible range.
+/// It wil also remove duplicates and sort the references.
+/// # Parameters
+/// - `bible_representations`: A vector of [BibleReferenceRepresentation]s
+/// # Returns
+/// - A vector of [BibleReferenceRepresentation]s which contains the aggregated references.
+pub fn aggregate_bible_representations(
+    bible_representations: Vec<BibleReferenceRepresentation>,
+) -> Vec<BibleReferenceRepresentation> {
+    let mut representations = bible_representations.clone();
+
+    'outer: loop {
+        if representations.len() < 2 {
+            return representations;
+        }
+
+        representations.sort_unstable();
+        representations.dedup();
+
+        for i in 0..representations.len() - 1 {
+            let j = i + 1;
+            if representations.get(i).is_none() || representations.get(j).is_none() {
+                continue;
+            }
+
+            if representations[i] == representations[j] {
+                continue;
+            }
+            match (&representations[i], &representations[j]) {
+                (
+                    BibleReferenceRepresentation::Single(a),
+                    BibleReferenceRepresentation::Single(b),
+                ) => {
+                    if a == b {
+                        continue;
+                    }
+                    let a_next = a.next();
                     if a_next.is_some() && *b == a.next().unwrap() {
                         let new_range = BibleRange::new(a.clone(), b.clone()).unwrap();
                         representations.push(BibleReferenceRepresentation::Range(new_range));
@@ -1165,8 +3446,7 @@ pub fn aggregate_bible_representations(
                     if a == b {
                         continue;
                     }
-                    if a.end() > b.start() && b.end() > a.end() {
-                        let new_range = BibleRange::new(a.start(), b.end()).unwrap();
+                    if let Some(new_range) = merge_ranges(a, b) {
                         representations.push(BibleReferenceRepresentation::Range(new_range));
                         representations.remove(j);
                         representations.remove(i);
@@ -1177,8 +3457,9 @@ pub fn aggregate_bible_representations(
                     BibleReferenceRepresentation::Single(a),
                     BibleReferenceRepresentation::Range(b),
                 ) => {
-                    if a.next().is_some() && a.next().unwrap() == b.start() {
-                        let new_range = BibleRange::new(a.clone(), b.end()).unwrap();
+                    // A single reference inside the range is dropped, an adjacent one extends it
+                    let single_range = BibleRange::new(a.clone(), a.clone()).unwrap();
+                    if let Some(new_range) = merge_ranges(&single_range, b) {
                         representations.push(BibleReferenceRepresentation::Range(new_range));
                         representations.remove(j);
                         representations.remove(i);
@@ -1189,8 +3470,8 @@ pub fn aggregate_bible_representations(
                     BibleReferenceRepresentation::Range(a),
                     BibleReferenceRepresentation::Single(b),
                 ) => {
-                    if a.end().next().is_some() && a.end().next().unwrap() == *b {
-                        let new_range = BibleRange::new(a.start(), b.clone()).unwrap();
+                    let single_range = BibleRange::new(b.clone(), b.clone()).unwrap();
+                    if let Some(new_range) = merge_ranges(a, &single_range) {
                         representations.push(BibleReferenceRepresentation::Range(new_range));
                         representations.remove(j);
                         representations.remove(i);
@@ -1208,8 +3489,21 @@ pub fn aggregate_bible_representations(
 
 #[cfg(test)]
 mod tests {
+    use super::versification::Versification;
     use super::*;
 
+    fn verse(book: BibleBook, chapter: BibleChapter, verse: BibleVerse) -> BibleReference {
+        BibleReference::BibleVerse(BibleVerseReference::new(book, chapter, verse).unwrap())
+    }
+
+    fn chapter(book: BibleBook, chapter: BibleChapter) -> BibleReference {
+        BibleReference::BibleChapter(BibleChapterReference::new(book, chapter).unwrap())
+    }
+
+    fn book(book: BibleBook) -> BibleReference {
+        BibleReference::BibleBook(BibleBookReference::new(book))
+    }
+
     #[test]
     fn test_book_ot_nt() {
         assert!(BibleBook::Malachi.is_old_testament());
@@ -1237,6 +3531,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_biblerefrepresentation_comp() {
         let bibleref1 =
             BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap());
@@ -1296,6 +3591,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     pub fn test_biblerepresentations_aggregation() {
         let bibleref1 =
             BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap());
@@ -1323,6 +3619,1832 @@ mod tests {
 
     #[test]
     pub fn test_biblebooks_length() {
+        #[cfg(not(feature = "deuterocanon"))]
         assert_eq!(BibleBook::all().len(), 66);
+        #[cfg(feature = "deuterocanon")]
+        assert_eq!(BibleBook::all().len(), 77);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_display() {
+        let verse = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+        assert_eq!(verse.to_string(), "John 3:16");
+        assert_eq!(
+            BibleChapterReference::new(BibleBook::ISamuel, 3)
+                .unwrap()
+                .to_string(),
+            "1 Samuel 3"
+        );
+        assert_eq!(BibleBookReference::new(BibleBook::Jude).to_string(), "Jude");
+
+        let range = BibleRange::new(
+            BibleReference::BibleVerse(verse),
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 4, 2).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(format!("{}", range), "John 3:16-4:2");
+
+        let range = BibleRange::new(
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis)),
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Exodus)),
+        )
+        .unwrap();
+        assert_eq!(
+            BibleReferenceRepresentation::Range(range).to_string(),
+            "Genesis-Exodus"
+        );
+    }
+
+    #[test]
+    fn test_all_verses() {
+        let expected_number_of_verses: usize = BibleBook::all()
+            .iter()
+            .map(|book| {
+                (1..=get_number_of_chapters(book))
+                    .map(|chapter| get_number_of_verses(book, &chapter).unwrap() as usize)
+                    .sum::<usize>()
+            })
+            .sum();
+        assert_eq!(all_verses().count(), expected_number_of_verses);
+
+        let mut verses = all_verses().skip(30);
+        assert_eq!(
+            verses.next(),
+            Some(BibleVerseReference::new(BibleBook::Genesis, 1, 31).unwrap())
+        );
+        assert_eq!(
+            verses.next(),
+            Some(BibleVerseReference::new(BibleBook::Genesis, 2, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_verse_range_as_list() {
+        let verse_range = |start: (BibleBook, u8, u8), end: (BibleBook, u8, u8)| {
+            BibleVerseRange::new(
+                BibleVerseReference::new(start.0, start.1, start.2).unwrap(),
+                BibleVerseReference::new(end.0, end.1, end.2).unwrap(),
+            )
+            .unwrap()
+        };
+
+        // Same chapter
+        let list = verse_range((BibleBook::John, 3, 16), (BibleBook::John, 3, 18)).as_list();
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list[2],
+            BibleVerseReference::new(BibleBook::John, 3, 18).unwrap()
+        );
+
+        // Across chapters: John 3:30-36 and John 4:1-2
+        let list = verse_range((BibleBook::John, 3, 30), (BibleBook::John, 4, 2)).as_list();
+        assert_eq!(list.len(), 9);
+        assert_eq!(
+            list[7],
+            BibleVerseReference::new(BibleBook::John, 4, 1).unwrap()
+        );
+
+        // Across books: Malachi 4:5-6 and Matthew 1:1
+        let list = verse_range((BibleBook::Malachi, 4, 5), (BibleBook::Matthew, 1, 1)).as_list();
+        assert_eq!(
+            list,
+            vec![
+                BibleVerseReference::new(BibleBook::Malachi, 4, 5).unwrap(),
+                BibleVerseReference::new(BibleBook::Malachi, 4, 6).unwrap(),
+                BibleVerseReference::new(BibleBook::Matthew, 1, 1).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_range_count() {
+        let range = BibleRange::new(
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis)),
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Revelation)),
+        )
+        .unwrap();
+        assert_eq!(range.count(), 66);
+
+        // Matthew 27-28 and Mark 1-16 and Luke 1
+        let range = BibleRange::new(
+            BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Matthew, 27).unwrap(),
+            ),
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Luke, 1).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(range.count(), 19);
+
+        // John 3:30-36 and John 4:1-2
+        let range = BibleRange::new(
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 30).unwrap()),
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 4, 2).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(range.count(), 9);
+
+        let range = BibleRange::new(
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Jude, 1, 20).unwrap()),
+            BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Revelation, 2, 3).unwrap(),
+            ),
+        )
+        .unwrap();
+        assert_eq!(range.count(), range.as_list().len());
+    }
+
+    #[test]
+    fn test_range_contains() {
+        let book_range = BibleRange::new(book(BibleBook::Matthew), book(BibleBook::John)).unwrap();
+        assert!(book_range.contains(&book(BibleBook::Mark)));
+        assert!(book_range.contains(&chapter(BibleBook::John, 21)));
+        assert!(book_range.contains(&verse(BibleBook::Matthew, 1, 1)));
+        assert!(!book_range.contains(&verse(BibleBook::Acts, 1, 1)));
+        assert!(!book_range.contains(&book(BibleBook::Malachi)));
+
+        let chapter_range =
+            BibleRange::new(chapter(BibleBook::John, 3), chapter(BibleBook::John, 5)).unwrap();
+        assert!(chapter_range.contains(&chapter(BibleBook::John, 4)));
+        assert!(chapter_range.contains(&verse(BibleBook::John, 3, 1)));
+        assert!(chapter_range.contains(&verse(BibleBook::John, 5, 47)));
+        assert!(!chapter_range.contains(&verse(BibleBook::John, 6, 1)));
+        assert!(!chapter_range.contains(&book(BibleBook::John)));
+
+        let obadiah = BibleRange::new(
+            chapter(BibleBook::Obadiah, 1),
+            chapter(BibleBook::Obadiah, 1),
+        )
+        .unwrap();
+        assert!(obadiah.contains(&book(BibleBook::Obadiah)));
+
+        let verse_range =
+            BibleRange::new(verse(BibleBook::John, 3, 16), verse(BibleBook::John, 3, 18)).unwrap();
+        assert!(verse_range.contains(&verse(BibleBook::John, 3, 16)));
+        assert!(verse_range.contains(&verse(BibleBook::John, 3, 18)));
+        assert!(!verse_range.contains(&verse(BibleBook::John, 3, 19)));
+        assert!(!verse_range.contains(&chapter(BibleBook::John, 3)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_biblebook_from_str() {
+        let english = &*ENGLISH_REFERENCE_LANGUAGE;
+        for book in BibleBook::all() {
+            let long_name = english.long_names[&book].first().unwrap();
+            assert_eq!(long_name.parse::<BibleBook>(), Ok(book));
+            assert_eq!(long_name.to_uppercase().parse::<BibleBook>(), Ok(book));
+        }
+        assert_eq!(
+            "Mose".parse::<BibleBook>(),
+            Err(BookNameNotRecognizedError {
+                provided_book_name: "Mose".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_usfm_codes() {
+        for book in BibleBook::all() {
+            assert_eq!(book.usfm_code().len(), 3);
+            assert_eq!(BibleBook::from_usfm_code(book.usfm_code()), Some(book));
+        }
+        assert_eq!(
+            BibleBook::from_usfm_code(" php "),
+            Some(BibleBook::Philippians)
+        );
+        assert_eq!(BibleBook::from_usfm_code("XXX"), None);
+        assert_eq!(BibleBook::Genesis.usfm_number(), 1);
+        assert_eq!(BibleBook::John.usfm_number(), 44);
+    }
+
+    #[test]
+    fn test_psalm_superscription() {
+        let superscription = BibleVerseReference::new(BibleBook::Psalm, 3, 0).unwrap();
+        assert!(superscription.is_superscription());
+        assert!(
+            !BibleVerseReference::new(BibleBook::Psalm, 3, 1)
+                .unwrap()
+                .is_superscription()
+        );
+        assert!(BibleVerseReference::new(BibleBook::Psalm, 1, 0).is_err());
+        assert!(BibleVerseReference::new(BibleBook::Genesis, 1, 0).is_err());
+
+        assert_eq!(
+            BibleReference::BibleVerse(superscription.clone()).next(),
+            Some(BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Psalm, 3, 1).unwrap()
+            ))
+        );
+        assert_eq!(
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Psalm, 3, 1).unwrap())
+                .previous(),
+            Some(BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Psalm, 2).unwrap()
+            ))
+        );
+
+        let range = BibleVerseRange::new(
+            superscription,
+            BibleVerseReference::new(BibleBook::Psalm, 3, 8).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(range.count(), 9);
+        assert_eq!(range.as_list().len(), 9);
+    }
+
+    #[test]
+    fn test_chapter_and_book_navigation() {
+        let genesis_50_26 = BibleReference::BibleVerse(
+            BibleVerseReference::new(BibleBook::Genesis, 50, 26).unwrap(),
+        );
+        let exodus_1_1 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Exodus, 1, 1).unwrap());
+        assert_eq!(genesis_50_26.next_chapter(), Some(exodus_1_1.clone()));
+        assert_eq!(genesis_50_26.next_book(), Some(exodus_1_1.clone()));
+        assert_eq!(
+            exodus_1_1.previous_chapter(),
+            Some(BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Genesis, 50, 1).unwrap()
+            ))
+        );
+        assert_eq!(
+            exodus_1_1.previous_book(),
+            Some(BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap()
+            ))
+        );
+
+        let genesis_50 = BibleReference::BibleChapter(
+            BibleChapterReference::new(BibleBook::Genesis, 50).unwrap(),
+        );
+        let exodus_1 =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Exodus, 1).unwrap());
+        assert_eq!(genesis_50.next_chapter(), Some(exodus_1.clone()));
+        assert_eq!(exodus_1.previous_chapter(), Some(genesis_50.clone()));
+        assert_eq!(
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis)).next_chapter(),
+            Some(exodus_1.clone())
+        );
+        assert_eq!(
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Exodus))
+                .previous_chapter(),
+            Some(genesis_50)
+        );
+        assert_eq!(
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis)).next_book(),
+            Some(BibleReference::BibleBook(BibleBookReference::new(
+                BibleBook::Exodus
+            )))
+        );
+
+        // The beginning and the end of the Bible
+        assert_eq!(exodus_1_1.previous_book().unwrap().previous_book(), None);
+        assert_eq!(
+            BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Genesis, 1).unwrap()
+            )
+            .previous_chapter(),
+            None
+        );
+        #[cfg(not(feature = "deuterocanon"))]
+        {
+            let revelation_22 = BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Revelation, 22).unwrap(),
+            );
+            assert_eq!(revelation_22.next_chapter(), None);
+            assert_eq!(revelation_22.next_book(), None);
+        }
+    }
+
+    #[test]
+    fn test_saturating_navigation() {
+        let genesis_1_1 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap());
+        let genesis_1_2 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 2).unwrap());
+        assert_eq!(genesis_1_1.next_or_last(), genesis_1_2);
+        assert_eq!(genesis_1_2.previous_or_first(), genesis_1_1);
+        assert_eq!(genesis_1_1.previous_or_first(), genesis_1_1);
+
+        let genesis = BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis));
+        assert_eq!(genesis.previous_or_first(), genesis);
+
+        #[cfg(not(feature = "deuterocanon"))]
+        {
+            let revelation_22_21 = BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Revelation, 22, 21).unwrap(),
+            );
+            assert_eq!(revelation_22_21.next_or_last(), revelation_22_21);
+            let revelation =
+                BibleReference::BibleBook(BibleBookReference::new(BibleBook::Revelation));
+            assert_eq!(revelation.next_or_last(), revelation);
+        }
+    }
+
+    #[test]
+    fn test_wrapping_navigation() {
+        let genesis_1_1 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap());
+        let last_book = *BibleBook::all().last().unwrap();
+        let last_chapter = get_number_of_chapters(&last_book);
+        let last_verse = BibleReference::BibleVerse(
+            BibleVerseReference::new(
+                last_book,
+                last_chapter,
+                get_number_of_verses(&last_book, &last_chapter).unwrap(),
+            )
+            .unwrap(),
+        );
+        assert_eq!(last_verse.next_wrapping(), genesis_1_1);
+        assert_eq!(genesis_1_1.previous_wrapping(), last_verse);
+
+        // The granularity is kept
+        let genesis_1 = BibleReference::BibleChapter(
+            BibleChapterReference::new(BibleBook::Genesis, 1).unwrap(),
+        );
+        let last_chapter = BibleReference::BibleChapter(
+            BibleChapterReference::new(last_book, last_chapter).unwrap(),
+        );
+        assert_eq!(last_chapter.next_wrapping(), genesis_1);
+        assert_eq!(genesis_1.previous_wrapping(), last_chapter);
+        let genesis = BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis));
+        let last_book = BibleReference::BibleBook(BibleBookReference::new(last_book));
+        assert_eq!(last_book.next_wrapping(), genesis);
+        assert_eq!(genesis.previous_wrapping(), last_book);
+
+        // Within the Bible, chapter boundaries are crossed without changing the granularity
+        let genesis_1_31 = BibleReference::BibleVerse(
+            BibleVerseReference::new(BibleBook::Genesis, 1, 31).unwrap(),
+        );
+        let genesis_2_1 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 2, 1).unwrap());
+        assert_eq!(genesis_1_31.next_wrapping(), genesis_2_1);
+        assert_eq!(genesis_2_1.previous_wrapping(), genesis_1_31);
+        assert_eq!(
+            genesis_1_31.previous_wrapping().next_wrapping(),
+            genesis_1_31
+        );
+        // The superscription of a Psalm is skipped
+        assert_eq!(
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Psalm, 3, 1).unwrap())
+                .previous_wrapping(),
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Psalm, 2, 12).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_split_into_portions() {
+        assert!(split_into_portions(0).is_empty());
+        for n in [1, 7, 66, 365, 1189] {
+            let portions = split_into_portions(n);
+            assert_eq!(portions.len(), n);
+
+            let sizes: Vec<usize> = portions
+                .iter()
+                .map(|portion| {
+                    BibleReferenceRepresentation::Range(portion.clone())
+                        .as_verse_list()
+                        .len()
+                })
+                .collect();
+            // The portions cover every verse exactly once
+            assert_eq!(
+                sizes.iter().sum::<usize>(),
+                total_number_of_verses() as usize
+            );
+            assert!(sizes.iter().max().unwrap() - sizes.iter().min().unwrap() <= 1);
+            assert_eq!(
+                portions[0].start().to_first_verse(),
+                BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap()
+            );
+            for pair in portions.windows(2) {
+                let end = BibleReferenceRepresentation::Range(pair[0].clone())
+                    .as_verse_list()
+                    .pop()
+                    .unwrap();
+                assert_eq!(verses_between(&end, &pair[1].start().to_first_verse()), 1);
+            }
+        }
+
+        // The whole Bible is one book range
+        assert_eq!(
+            split_into_portions(1)[0].granularity(),
+            ReferenceGranularity::Book
+        );
+    }
+
+    #[test]
+    fn test_chronological_order() {
+        let books = BibleBook::all_chronological();
+        let mut sorted_books = books.clone();
+        sorted_books.sort();
+        assert_eq!(sorted_books, BibleBook::all());
+
+        for (index, book) in books.iter().enumerate() {
+            assert_eq!(book.chronological_number() as usize, index + 1);
+            assert_eq!(
+                get_bible_book_by_chronological_number(book.chronological_number()),
+                Some(*book)
+            );
+        }
+        assert_eq!(
+            get_bible_book_by_chronological_number(books.len() as u8 + 1),
+            None
+        );
+
+        // The canonical numbers are not affected
+        assert_eq!(BibleBook::Job.number(), 18);
+        assert_eq!(BibleBook::Job.chronological_number(), 2);
+        assert!(BibleBook::Daniel.chronological_number() < BibleBook::Ezra.chronological_number());
+        assert!(
+            BibleBook::Galatians.chronological_number() < BibleBook::Romans.chronological_number()
+        );
+    }
+
+    #[test]
+    fn test_tanakh_order() {
+        let books: Vec<BibleBook> = BibleBook::tanakh_books().collect();
+        let mut sorted_books = books.clone();
+        sorted_books.sort();
+        assert_eq!(
+            sorted_books,
+            BibleBook::range(BibleBook::Genesis, BibleBook::Malachi).collect::<Vec<_>>()
+        );
+        for (index, book) in books.iter().enumerate() {
+            assert_eq!(book.tanakh_order(), Some(index as u8 + 1));
+        }
+        assert!(BibleBook::books_of_new_testament().all(|book| book.tanakh_order().is_none()));
+        assert_eq!(BibleBook::Malachi.tanakh_order(), Some(26));
+        assert_eq!(BibleBook::Job.tanakh_order(), Some(29));
+    }
+
+    #[test]
+    fn test_u32_encoding() {
+        let encoded: Vec<u32> = all_verses().map(|verse| verse.to_u32()).collect();
+        assert!(encoded.windows(2).all(|pair| pair[0] < pair[1]));
+        for (verse, value) in all_verses().zip(&encoded) {
+            assert_eq!(BibleVerseReference::from_u32(*value), Some(verse));
+        }
+        let superscription = BibleVerseReference::new(BibleBook::Psalm, 3, 0).unwrap();
+        assert_eq!(
+            BibleVerseReference::from_u32(superscription.to_u32()),
+            Some(superscription)
+        );
+
+        for book in BibleBook::all() {
+            let book_reference = BibleBookReference::new(book);
+            assert_eq!(
+                BibleBookReference::from_u32(book_reference.to_u32()),
+                Some(book_reference.clone())
+            );
+            let reference = BibleReference::BibleBook(book_reference);
+            assert_eq!(
+                BibleReference::from_u32(reference.to_u32()),
+                Some(reference)
+            );
+            for chapter in 1..=get_number_of_chapters(&book) {
+                let chapter_reference = BibleChapterReference::new(book, chapter).unwrap();
+                assert_eq!(
+                    BibleChapterReference::from_u32(chapter_reference.to_u32()),
+                    Some(chapter_reference.clone())
+                );
+                let reference = BibleReference::BibleChapter(chapter_reference);
+                assert_eq!(
+                    BibleReference::from_u32(reference.to_u32()),
+                    Some(reference)
+                );
+            }
+        }
+
+        // Invalid values are rejected
+        let john_3_16 = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+        assert_eq!(
+            BibleReference::from_u32(john_3_16.to_u32()),
+            Some(BibleReference::BibleVerse(john_3_16))
+        );
+        assert_eq!(BibleVerseReference::from_u32(0x002B_0340), None);
+        assert_eq!(BibleVerseReference::from_u32(0x0000_0101), None);
+        assert_eq!(BibleVerseReference::from_u32(0x012B_0310), None);
+        assert_eq!(BibleChapterReference::from_u32(0x002B_0310), None);
+        assert_eq!(BibleBookReference::from_u32(0x002B_0300), None);
+        assert_eq!(BibleReference::from_u32(0x032B_0000), None);
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        assert_eq!(
+            BibleBookReference::from(BibleBook::Jude),
+            BibleBookReference::new(BibleBook::Jude)
+        );
+        let chapter: Result<BibleChapterReference, _> = (BibleBook::Genesis, 50).try_into();
+        assert_eq!(
+            chapter,
+            Ok(BibleChapterReference::new(BibleBook::Genesis, 50).unwrap())
+        );
+        let verse: Result<BibleVerseReference, _> = (BibleBook::Psalm, 3, 0).try_into();
+        assert!(verse.unwrap().is_superscription());
+
+        let error = BibleChapterReference::try_from((BibleBook::Genesis, 51)).unwrap_err();
+        assert_eq!(
+            error,
+            BibleChapterReference::new(BibleBook::Genesis, 51).unwrap_err()
+        );
+        assert!(BibleVerseReference::try_from((BibleBook::Genesis, 1, 32)).is_err());
+    }
+
+    #[test]
+    fn test_reference_conversions() {
+        let book = BibleBookReference::new(BibleBook::John);
+        let chapter = BibleChapterReference::new(BibleBook::John, 3).unwrap();
+        let verse = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+        assert_eq!(
+            BibleReference::from(book.clone()),
+            BibleReference::BibleBook(book)
+        );
+        assert_eq!(
+            BibleReference::from(chapter.clone()),
+            BibleReference::BibleChapter(chapter)
+        );
+        assert_eq!(
+            BibleReference::from(verse.clone()),
+            BibleReference::BibleVerse(verse.clone())
+        );
+
+        let range = BibleVerseRange::new(
+            verse,
+            BibleVerseReference::new(BibleBook::John, 3, 18).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            BibleRange::from(range.clone()),
+            BibleRange::VerseRange(range.clone())
+        );
+        let representation: BibleReferenceRepresentation = BibleRange::from(range.clone()).into();
+        assert_eq!(
+            representation,
+            BibleReferenceRepresentation::Range(BibleRange::VerseRange(range))
+        );
+        let book_range = BibleBookRange::new(
+            BibleBookReference::new(BibleBook::Matthew),
+            BibleBookReference::new(BibleBook::John),
+        )
+        .unwrap();
+        assert_eq!(
+            BibleRange::from(book_range.clone()),
+            BibleRange::BookRange(book_range)
+        );
+        let chapter_range = BibleChapterRange::new(
+            BibleChapterReference::new(BibleBook::John, 3).unwrap(),
+            BibleChapterReference::new(BibleBook::John, 4).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            BibleRange::from(chapter_range.clone()),
+            BibleRange::ChapterRange(chapter_range)
+        );
+    }
+
+    #[test]
+    fn test_advance_by() {
+        let genesis_1_1 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap());
+        assert_eq!(genesis_1_1.advance_by(0), Some(genesis_1_1.clone()));
+        // The result must be the same as walking through the verses one by one
+        for n in [1, 30, 31, 55, 1532, 1533, 5000] {
+            assert_eq!(
+                genesis_1_1.advance_by(n),
+                all_verses().nth(n).map(BibleReference::BibleVerse),
+                "advance_by({})",
+                n
+            );
+        }
+        // Across a book boundary
+        assert_eq!(
+            BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Genesis, 50, 25).unwrap()
+            )
+            .advance_by(3),
+            Some(BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Exodus, 1, 2).unwrap()
+            ))
+        );
+        assert_eq!(
+            BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Genesis, 49).unwrap()
+            )
+            .advance_by(3),
+            Some(BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Exodus, 2).unwrap()
+            ))
+        );
+        assert_eq!(
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis)).advance_by(42),
+            Some(BibleReference::BibleBook(BibleBookReference::new(
+                BibleBook::John
+            )))
+        );
+
+        // Running off the end of the Bible
+        let verse_count = all_verses().count();
+        assert!(genesis_1_1.advance_by(verse_count - 1).is_some());
+        assert_eq!(genesis_1_1.advance_by(verse_count), None);
+        assert_eq!(
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis)).advance_by(1000),
+            None
+        );
+        // Huge steps must not overflow
+        for reference in [
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis)),
+            BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Genesis, 1).unwrap(),
+            ),
+            genesis_1_1.clone(),
+        ] {
+            assert_eq!(reference.advance_by(usize::MAX), None);
+        }
+    }
+
+    #[test]
+    fn test_verses_between() {
+        let john_3_16 = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+        let john_3_18 = BibleVerseReference::new(BibleBook::John, 3, 18).unwrap();
+        assert_eq!(verses_between(&john_3_16, &john_3_18), 2);
+        assert_eq!(verses_between(&john_3_18, &john_3_16), -2);
+        assert_eq!(verses_between(&john_3_16, &john_3_16), 0);
+
+        // Across chapters and books
+        let genesis_1_1 = BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap();
+        let exodus_1_1 = BibleVerseReference::new(BibleBook::Exodus, 1, 1).unwrap();
+        assert_eq!(verses_between(&genesis_1_1, &exodus_1_1), 1533);
+        let revelation_22_21 = BibleVerseReference::new(BibleBook::Revelation, 22, 21).unwrap();
+        assert_eq!(
+            verses_between(&genesis_1_1, &revelation_22_21),
+            all_verses()
+                .take_while(|verse| verse < &revelation_22_21)
+                .count() as i64
+        );
+
+        // The superscription of a Psalm is positioned like its first verse
+        let psalm_2_12 = BibleVerseReference::new(BibleBook::Psalm, 2, 12).unwrap();
+        let psalm_3_0 = BibleVerseReference::new(BibleBook::Psalm, 3, 0).unwrap();
+        let psalm_3_1 = BibleVerseReference::new(BibleBook::Psalm, 3, 1).unwrap();
+        assert_eq!(verses_between(&psalm_2_12, &psalm_3_0), 1);
+        assert_eq!(verses_between(&psalm_3_0, &psalm_3_1), 0);
+        assert_eq!(psalm_3_0.global_index(), psalm_3_1.global_index());
+
+        let john_3 =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap());
+        assert_eq!(
+            john_3.distance_to(&BibleReference::BibleVerse(john_3_16.clone())),
+            15
+        );
+        assert_eq!(
+            BibleReference::BibleVerse(john_3_16).distance_to(&john_3),
+            -15
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_references_as_hash_keys() {
+        use std::collections::{HashMap, HashSet};
+
+        let john_3_16 = BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+            BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+        ));
+        let mut texts = HashMap::new();
+        texts.insert(john_3_16.clone(), "For God so loved the world");
+        assert_eq!(
+            texts.get(&crate::parse("Johannes 3,16").unwrap()),
+            Some(&"For God so loved the world")
+        );
+
+        let verses: HashSet<BibleVerseReference> = BibleVerseRange::new(
+            BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+            BibleVerseReference::new(BibleBook::John, 3, 18).unwrap(),
+        )
+        .unwrap()
+        .as_list()
+        .into_iter()
+        .chain([BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()])
+        .collect();
+        assert_eq!(verses.len(), 3);
+    }
+
+    #[test]
+    fn test_range_aggregation() {
+        let john = |chapter, start, end| {
+            BibleReferenceRepresentation::Range(
+                BibleRange::new(
+                    BibleReference::BibleVerse(
+                        BibleVerseReference::new(BibleBook::John, chapter, start).unwrap(),
+                    ),
+                    BibleReference::BibleVerse(
+                        BibleVerseReference::new(BibleBook::John, chapter, end).unwrap(),
+                    ),
+                )
+                .unwrap(),
+            )
+        };
+
+        // A range contained in an other range
+        assert_eq!(
+            aggregate_bible_representations(vec![john(3, 1, 20), john(3, 5, 8)]),
+            vec![john(3, 1, 20)]
+        );
+        // Ranges with the same end
+        assert_eq!(
+            aggregate_bible_representations(vec![john(3, 5, 20), john(3, 1, 20)]),
+            vec![john(3, 1, 20)]
+        );
+        // Ranges with the same start
+        assert_eq!(
+            aggregate_bible_representations(vec![john(3, 1, 20), john(3, 1, 8)]),
+            vec![john(3, 1, 20)]
+        );
+        // Overlapping ranges
+        assert_eq!(
+            aggregate_bible_representations(vec![john(3, 1, 10), john(3, 8, 20)]),
+            vec![john(3, 1, 20)]
+        );
+        // Adjacent ranges
+        assert_eq!(
+            aggregate_bible_representations(vec![john(3, 1, 4), john(3, 5, 8)]),
+            vec![john(3, 1, 8)]
+        );
+        // Adjacent ranges across a chapter boundary
+        let john_2_20_to_3_2 = BibleReferenceRepresentation::Range(
+            BibleRange::new(
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, 2, 20).unwrap(),
+                ),
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, 3, 2).unwrap(),
+                ),
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            aggregate_bible_representations(vec![john(2, 20, 25), john(3, 1, 2)]),
+            vec![john_2_20_to_3_2]
+        );
+        // Ranges with a gap stay separate
+        assert_eq!(
+            aggregate_bible_representations(vec![john(3, 6, 8), john(3, 1, 4)]),
+            vec![john(3, 1, 4), john(3, 6, 8)]
+        );
+    }
+
+    #[test]
+    fn test_single_and_range_aggregation() {
+        let genesis_1 = |verse| {
+            BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Genesis, 1, verse).unwrap(),
+            )
+        };
+        let range = |start, end| {
+            BibleReferenceRepresentation::Range(
+                BibleRange::new(genesis_1(start), genesis_1(end)).unwrap(),
+            )
+        };
+        let single = |verse| BibleReferenceRepresentation::Single(genesis_1(verse));
+
+        // A single reference inside the range
+        assert_eq!(
+            aggregate_bible_representations(vec![range(1, 10), single(5)]),
+            vec![range(1, 10)]
+        );
+        assert_eq!(
+            aggregate_bible_representations(vec![single(3), range(1, 10), single(10), single(1)]),
+            vec![range(1, 10)]
+        );
+        // Single references adjacent to the start or the end
+        assert_eq!(
+            aggregate_bible_representations(vec![range(2, 10), single(1)]),
+            vec![range(1, 10)]
+        );
+        assert_eq!(
+            aggregate_bible_representations(vec![range(2, 10), single(11)]),
+            vec![range(2, 11)]
+        );
+        // A single reference with a gap stays separate
+        assert_eq!(
+            aggregate_bible_representations(vec![range(2, 10), single(12)]),
+            vec![range(2, 10), single(12)]
+        );
+    }
+
+    #[test]
+    fn test_range_subtraction() {
+        let verses = |start: (BibleChapter, BibleVerse), end: (BibleChapter, BibleVerse)| {
+            BibleRange::new(
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, start.0, start.1).unwrap(),
+                ),
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, end.0, end.1).unwrap(),
+                ),
+            )
+            .unwrap()
+        };
+        let representation =
+            |start, end| BibleReferenceRepresentation::Range(verses(start, end)).try_upcast();
+
+        // Removing the middle splits the range
+        assert_eq!(
+            verses((3, 1), (3, 20)).subtract(&verses((3, 5), (3, 8))),
+            vec![
+                representation((3, 1), (3, 4)),
+                representation((3, 9), (3, 20))
+            ]
+        );
+        // Removing a prefix or a suffix
+        assert_eq!(
+            verses((3, 1), (3, 20)).subtract(&verses((2, 10), (3, 8))),
+            vec![representation((3, 9), (3, 20))]
+        );
+        assert_eq!(
+            verses((3, 1), (3, 20)).subtract(&verses((3, 16), (4, 2))),
+            vec![representation((3, 1), (3, 15))]
+        );
+        // Removing everything
+        assert_eq!(
+            verses((3, 1), (3, 20)).subtract(&verses((3, 1), (3, 20))),
+            Vec::<BibleReferenceRepresentation>::new()
+        );
+        assert_eq!(
+            verses((3, 5), (3, 8)).subtract(&verses((2, 1), (4, 1))),
+            Vec::<BibleReferenceRepresentation>::new()
+        );
+        // Nothing to remove
+        assert_eq!(
+            verses((3, 1), (3, 20)).subtract(&verses((4, 1), (4, 5))),
+            vec![representation((3, 1), (3, 20))]
+        );
+        // The remaining parts are upcasted, e.g. to whole chapters
+        let remaining = verses((2, 1), (4, 54)).subtract(&verses((3, 1), (3, 36)));
+        assert_eq!(
+            remaining,
+            vec![
+                BibleReferenceRepresentation::Single(BibleReference::BibleChapter(
+                    BibleChapterReference::new(BibleBook::John, 2).unwrap()
+                )),
+                BibleReferenceRepresentation::Single(BibleReference::BibleChapter(
+                    BibleChapterReference::new(BibleBook::John, 4).unwrap()
+                )),
+            ]
+        );
+
+        // The superscription of a Psalm is the verse before verse 1
+        let psalm_3 = |start: BibleVerse, end: BibleVerse| {
+            BibleRange::new(
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::Psalm, 3, start).unwrap(),
+                ),
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::Psalm, 3, end).unwrap(),
+                ),
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            psalm_3(0, 8).subtract(&psalm_3(1, 8)),
+            vec![BibleReferenceRepresentation::Single(
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::Psalm, 3, 0).unwrap()
+                )
+            )]
+        );
+        assert_eq!(
+            psalm_3(0, 8).subtract(&psalm_3(0, 8)),
+            Vec::<BibleReferenceRepresentation>::new()
+        );
+    }
+
+    #[test]
+    fn test_range_intersection() {
+        let verses = |start: (BibleChapter, BibleVerse), end: (BibleChapter, BibleVerse)| {
+            BibleRange::new(
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, start.0, start.1).unwrap(),
+                ),
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, end.0, end.1).unwrap(),
+                ),
+            )
+            .unwrap()
+        };
+        let representation =
+            |start, end| BibleReferenceRepresentation::Range(verses(start, end)).try_upcast();
+
+        // Partial overlap
+        assert_eq!(
+            verses((3, 1), (3, 20)).intersect(&verses((3, 16), (4, 2))),
+            Some(representation((3, 16), (3, 20)))
+        );
+        // Containment
+        assert_eq!(
+            verses((3, 1), (3, 20)).intersect(&verses((3, 5), (3, 8))),
+            Some(representation((3, 5), (3, 8)))
+        );
+        // Adjacent and disjoint ranges
+        assert_eq!(
+            verses((3, 1), (3, 4)).intersect(&verses((3, 5), (3, 8))),
+            None
+        );
+        assert_eq!(
+            verses((3, 1), (3, 4)).intersect(&verses((5, 1), (5, 8))),
+            None
+        );
+        // A single common verse
+        assert_eq!(
+            verses((3, 1), (3, 16)).intersect(&verses((3, 16), (3, 20))),
+            Some(BibleReferenceRepresentation::Single(
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()
+                )
+            ))
+        );
+        // Mixed granularities are upcasted
+        let chapters = BibleRange::new(
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 2).unwrap()),
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 5).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(
+            chapters.intersect(&verses((1, 10), (3, 36))),
+            Some(BibleReferenceRepresentation::Range(
+                BibleRange::new(
+                    BibleReference::BibleChapter(
+                        BibleChapterReference::new(BibleBook::John, 2).unwrap()
+                    ),
+                    BibleReference::BibleChapter(
+                        BibleChapterReference::new(BibleBook::John, 3).unwrap()
+                    ),
+                )
+                .unwrap()
+            ))
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_verse() {
+        use rand::SeedableRng;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let samples = 5000;
+        let mut genesis_verses = 0;
+        for _ in 0..samples {
+            let verse = random_verse(&mut rng);
+            assert!(BibleVerseReference::new(verse.book(), verse.chapter(), verse.verse()).is_ok());
+            assert!(verse.verse() >= 1);
+            if verse.book() == BibleBook::Genesis {
+                genesis_verses += 1;
+            }
+        }
+        // Genesis has 1533 of 31102 verses (about 4.9%), but only 50 of 1189 chapters (about 4.2%)
+        let expected =
+            total_verses_in_book(&BibleBook::Genesis) as f64 / total_number_of_verses() as f64;
+        let share = genesis_verses as f64 / samples as f64;
+        assert!(
+            (share - expected).abs() < 0.008,
+            "{} != {}",
+            share,
+            expected
+        );
+
+        for _ in 0..1000 {
+            let verse = random_verse_in(BibleBook::Jude, &mut rng);
+            assert_eq!(verse.book(), BibleBook::Jude);
+            assert!((1..=25).contains(&verse.verse()));
+
+            let chapter = random_chapter(&mut rng);
+            assert!(chapter.chapter() <= get_number_of_chapters(&chapter.book()));
+        }
+    }
+
+    #[test]
+    fn test_book_ranges() {
+        assert_eq!(
+            BibleBook::range(BibleBook::Romans, BibleBook::Philemon).count(),
+            13
+        );
+        assert_eq!(
+            BibleBook::range(BibleBook::Jude, BibleBook::Jude).collect::<Vec<_>>(),
+            vec![BibleBook::Jude]
+        );
+        assert_eq!(
+            BibleBook::range(BibleBook::John, BibleBook::Matthew).count(),
+            0
+        );
+        assert_eq!(
+            BibleBook::range(BibleBook::Genesis, BibleBook::Revelation).count(),
+            66
+        );
+
+        #[cfg(not(feature = "deuterocanon"))]
+        assert_eq!(BibleBook::books_of_old_testament().count(), 39);
+        assert_eq!(
+            BibleBook::books_of_new_testament().last(),
+            Some(BibleBook::Revelation)
+        );
+        let books: Vec<BibleBook> = BibleBook::books_of_old_testament()
+            .chain(BibleBook::books_of_new_testament())
+            .collect();
+        let mut all_books = BibleBook::all();
+        all_books.sort();
+        let mut sorted_books = books.clone();
+        sorted_books.sort();
+        assert_eq!(sorted_books, all_books);
+        assert!(BibleBook::books_of_new_testament().all(|book| book.is_new_testament()));
+    }
+
+    #[test]
+    fn test_sections() {
+        let sizes = [
+            (BibleSection::Pentateuch, 5),
+            (BibleSection::HistoricalOT, 12),
+            (BibleSection::Wisdom, 5),
+            (BibleSection::MajorProphets, 5),
+            (BibleSection::MinorProphets, 12),
+            (BibleSection::Gospels, 4),
+            (BibleSection::ActsBook, 1),
+            (BibleSection::PaulineEpistles, 13),
+            (BibleSection::GeneralEpistles, 8),
+            (BibleSection::Apocalypse, 1),
+        ];
+        for (section, size) in sizes {
+            let books = BibleBook::all_in_section(section);
+            assert!(books.iter().all(|book| book.section() == section));
+            #[cfg(not(feature = "deuterocanon"))]
+            assert_eq!(books.len(), size, "{:?}", section);
+            #[cfg(feature = "deuterocanon")]
+            assert!(books.len() >= size);
+        }
+        assert!(
+            BibleBook::all()
+                .iter()
+                .all(|book| book.is_new_testament() == (book.section() >= BibleSection::Gospels))
+        );
+        assert_eq!(
+            BibleBook::Lamentations.section(),
+            BibleSection::MajorProphets
+        );
+        #[cfg(feature = "deuterocanon")]
+        assert_eq!(BibleBook::Sirach.section(), BibleSection::Wisdom);
+    }
+
+    #[test]
+    fn test_traditional_authors() {
+        assert_eq!(
+            BibleBook::all_by_traditional_author("Moses"),
+            BibleBook::all_in_section(BibleSection::Pentateuch)
+        );
+        assert_eq!(
+            BibleBook::all_by_traditional_author("Paul"),
+            BibleBook::all_in_section(BibleSection::PaulineEpistles)
+        );
+        assert_eq!(
+            BibleBook::all_by_traditional_author("John"),
+            vec![
+                BibleBook::John,
+                BibleBook::IJohn,
+                BibleBook::IIJohn,
+                BibleBook::IIIJohn,
+                BibleBook::Revelation
+            ]
+        );
+        assert_eq!(
+            BibleBook::all_by_traditional_author("Luke"),
+            vec![BibleBook::Luke, BibleBook::Acts]
+        );
+        assert_eq!(BibleBook::Malachi.traditional_author(), Some("Malachi"));
+        assert_eq!(BibleBook::Esther.traditional_author(), None);
+        assert_eq!(BibleBook::Job.traditional_author(), None);
+    }
+
+    #[test]
+    fn test_as_verse_list() {
+        let book = |book| {
+            BibleReferenceRepresentation::Single(BibleReference::BibleBook(
+                BibleBookReference::new(book),
+            ))
+        };
+        for bible_book in [
+            BibleBook::Genesis,
+            BibleBook::Psalm,
+            BibleBook::Obadiah,
+            BibleBook::Revelation,
+        ] {
+            let verses = book(bible_book).as_verse_list();
+            assert_eq!(verses.len(), total_verses_in_book(&bible_book) as usize);
+            assert!(verses.iter().all(|verse| verse.book() == bible_book));
+        }
+
+        let chapter = BibleReferenceRepresentation::Single(BibleReference::BibleChapter(
+            BibleChapterReference::new(BibleBook::Psalm, 119).unwrap(),
+        ));
+        assert_eq!(chapter.as_verse_list().len(), 176);
+
+        let verse = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+        assert_eq!(
+            BibleReferenceRepresentation::Single(BibleReference::BibleVerse(verse.clone()))
+                .as_verse_list(),
+            vec![verse]
+        );
+
+        // A book range crosses book boundaries
+        let books = BibleReferenceRepresentation::Range(BibleRange::BookRange(
+            BibleBookRange::new(
+                BibleBookReference::new(BibleBook::IJohn),
+                BibleBookReference::new(BibleBook::Jude),
+            )
+            .unwrap(),
+        ));
+        let verses = books.as_verse_list();
+        assert_eq!(
+            verses.len(),
+            BibleBook::range(BibleBook::IJohn, BibleBook::Jude)
+                .map(|book| total_verses_in_book(&book) as usize)
+                .sum::<usize>()
+        );
+        assert_eq!(
+            verses.first(),
+            BibleVerseReference::new(BibleBook::IJohn, 1, 1)
+                .ok()
+                .as_ref()
+        );
+        assert_eq!(
+            verses.last(),
+            BibleVerseReference::new(BibleBook::Jude, 1, 25)
+                .ok()
+                .as_ref()
+        );
+        assert!(verses.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // A verse range crosses chapter boundaries
+        let range = BibleReferenceRepresentation::Range(BibleRange::VerseRange(
+            BibleVerseRange::new(
+                BibleVerseReference::new(BibleBook::Genesis, 1, 30).unwrap(),
+                BibleVerseReference::new(BibleBook::Genesis, 2, 2).unwrap(),
+            )
+            .unwrap(),
+        ));
+        assert_eq!(range.as_verse_list().len(), 4);
+    }
+
+    #[test]
+    fn test_reference_accessors() {
+        let book = BibleReference::BibleBook(BibleBookReference::new(BibleBook::John));
+        assert_eq!(book.book(), BibleBook::John);
+        assert_eq!(book.chapter(), None);
+        assert_eq!(book.verse(), None);
+
+        let chapter =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap());
+        assert_eq!(chapter.book(), BibleBook::John);
+        assert_eq!(chapter.chapter(), Some(3));
+        assert_eq!(chapter.verse(), None);
+
+        let verse =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        assert_eq!(verse.book(), BibleBook::John);
+        assert_eq!(verse.chapter(), Some(3));
+        assert_eq!(verse.verse(), Some(16));
+    }
+
+    #[test]
+    fn test_granularity() {
+        let book = BibleReference::BibleBook(BibleBookReference::new(BibleBook::John));
+        let chapter =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap());
+        let verse =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        assert_eq!(book.granularity(), ReferenceGranularity::Book);
+        assert_eq!(chapter.granularity(), ReferenceGranularity::Chapter);
+        assert_eq!(verse.granularity(), ReferenceGranularity::Verse);
+        assert!(ReferenceGranularity::Book < ReferenceGranularity::Verse);
+
+        let book_range = BibleRange::new(
+            book.clone(),
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Acts)),
+        )
+        .unwrap();
+        assert_eq!(book_range.granularity(), ReferenceGranularity::Book);
+        // Mixed ranges are converted into the finer granularity
+        assert_eq!(
+            BibleRange::new(book.clone(), chapter.clone())
+                .unwrap()
+                .granularity(),
+            ReferenceGranularity::Chapter
+        );
+        assert_eq!(
+            BibleRange::new(chapter, verse).unwrap().granularity(),
+            ReferenceGranularity::Verse
+        );
+    }
+
+    #[test]
+    fn test_downcasting() {
+        let book = BibleReference::BibleBook(BibleBookReference::new(BibleBook::John));
+        let chapter =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap());
+        let verse =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+
+        for reference in [&book, &chapter, &verse] {
+            assert_eq!(
+                reference.to_book(),
+                BibleBookReference::new(BibleBook::John)
+            );
+        }
+
+        assert_eq!(book.to_chapter(), None);
+        assert_eq!(
+            chapter.to_chapter(),
+            Some(BibleChapterReference::new(BibleBook::John, 3).unwrap())
+        );
+        assert_eq!(
+            verse.to_chapter(),
+            Some(BibleChapterReference::new(BibleBook::John, 3).unwrap())
+        );
+
+        assert_eq!(
+            book.to_first_verse(),
+            BibleVerseReference::new(BibleBook::John, 1, 1).unwrap()
+        );
+        assert_eq!(
+            chapter.to_first_verse(),
+            BibleVerseReference::new(BibleBook::John, 3, 1).unwrap()
+        );
+        assert_eq!(
+            verse.to_first_verse(),
+            BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_last_helpers() {
+        let psalm = BibleBookReference::new(BibleBook::Psalm);
+        assert_eq!(
+            psalm.last_chapter(),
+            BibleChapterReference::new(BibleBook::Psalm, 150).unwrap()
+        );
+        assert_eq!(
+            psalm.last_verse(),
+            BibleVerseReference::new(BibleBook::Psalm, 150, 6).unwrap()
+        );
+        assert_eq!(
+            BibleChapterReference::new(BibleBook::Psalm, 119)
+                .unwrap()
+                .last_verse(),
+            BibleVerseReference::new(BibleBook::Psalm, 119, 176).unwrap()
+        );
+
+        // Obadiah has a single chapter
+        let obadiah = BibleBookReference::new(BibleBook::Obadiah);
+        assert_eq!(
+            obadiah.last_chapter(),
+            BibleChapterReference::new(BibleBook::Obadiah, 1).unwrap()
+        );
+        assert_eq!(
+            obadiah.last_verse(),
+            BibleVerseReference::new(BibleBook::Obadiah, 1, 21).unwrap()
+        );
+        assert_eq!(obadiah.last_chapter().last_verse(), obadiah.last_verse());
+    }
+
+    #[test]
+    fn test_first_helpers() {
+        let psalm = BibleBookReference::new(BibleBook::Psalm);
+        assert_eq!(
+            psalm.first_chapter(),
+            BibleChapterReference::new(BibleBook::Psalm, 1).unwrap()
+        );
+        assert_eq!(
+            psalm.first_verse(),
+            BibleVerseReference::new(BibleBook::Psalm, 1, 1).unwrap()
+        );
+
+        // The superscription (verse 0) is skipped
+        let chapter = BibleChapterReference::new(BibleBook::Psalm, 3).unwrap();
+        assert_eq!(
+            chapter.first_verse(),
+            BibleVerseReference::new(BibleBook::Psalm, 3, 1).unwrap()
+        );
+        assert_eq!(psalm.first_chapter().first_verse(), psalm.first_verse());
+    }
+
+    #[test]
+    fn test_clamped_creation() {
+        assert_eq!(
+            BibleChapterReference::new_clamped(BibleBook::Psalm, 151),
+            BibleChapterReference::new(BibleBook::Psalm, 150).unwrap()
+        );
+        assert_eq!(
+            BibleChapterReference::new_clamped(BibleBook::Psalm, 23),
+            BibleChapterReference::new(BibleBook::Psalm, 23).unwrap()
+        );
+        assert_eq!(
+            BibleChapterReference::new_clamped(BibleBook::Psalm, 0),
+            BibleChapterReference::new(BibleBook::Psalm, 1).unwrap()
+        );
+
+        assert_eq!(
+            BibleVerseReference::new_clamped(BibleBook::John, 3, 16),
+            BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()
+        );
+        assert_eq!(
+            BibleVerseReference::new_clamped(BibleBook::John, 3, 99),
+            BibleVerseReference::new(BibleBook::John, 3, 36).unwrap()
+        );
+        assert_eq!(
+            BibleVerseReference::new_clamped(BibleBook::John, 25, 99),
+            BibleVerseReference::new(BibleBook::John, 21, 25).unwrap()
+        );
+        assert_eq!(
+            BibleVerseReference::new_clamped(BibleBook::John, 3, 0),
+            BibleVerseReference::new(BibleBook::John, 3, 1).unwrap()
+        );
+        // The superscription of a Psalm is kept
+        assert_eq!(
+            BibleVerseReference::new_clamped(BibleBook::Psalm, 3, 0),
+            BibleVerseReference::new(BibleBook::Psalm, 3, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_position_fraction() {
+        let genesis = BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap();
+        assert!(genesis.position_fraction() > 0.0);
+        assert!(genesis.position_fraction() < 0.0001);
+
+        let last_book = *BibleBook::all().last().unwrap();
+        let last_verse = BibleBookReference::new(last_book).last_verse();
+        assert_eq!(last_verse.position_fraction(), 1.0);
+        #[cfg(not(feature = "deuterocanon"))]
+        assert_eq!(
+            BibleVerseReference::new(BibleBook::Revelation, 22, 21)
+                .unwrap()
+                .position_fraction(),
+            1.0
+        );
+
+        // Chapters and books are measured up to their last verse
+        let chapter =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap());
+        assert_eq!(
+            chapter.position_fraction(),
+            BibleVerseReference::new(BibleBook::John, 3, 36)
+                .unwrap()
+                .position_fraction()
+        );
+        assert_eq!(
+            BibleReference::BibleBook(BibleBookReference::new(last_book)).position_fraction(),
+            1.0
+        );
+
+        // The superscription of a Psalm does not count as a verse of its own
+        assert_eq!(
+            BibleVerseReference::new(BibleBook::Psalm, 3, 0)
+                .unwrap()
+                .position_fraction(),
+            BibleVerseReference::new(BibleBook::Psalm, 3, 1)
+                .unwrap()
+                .position_fraction()
+        );
+    }
+
+    #[test]
+    fn test_global_index() {
+        let first = BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap();
+        assert_eq!(first.global_index(), 0);
+        assert_eq!(verse_at_index(0), Some(first));
+
+        // Chapter and book boundaries
+        for verse in [
+            BibleVerseReference::new(BibleBook::Genesis, 1, 31).unwrap(),
+            BibleVerseReference::new(BibleBook::Genesis, 2, 1).unwrap(),
+            BibleVerseReference::new(BibleBook::Genesis, 50, 26).unwrap(),
+            BibleVerseReference::new(BibleBook::Exodus, 1, 1).unwrap(),
+            BibleVerseReference::new(BibleBook::Malachi, 4, 6).unwrap(),
+            BibleVerseReference::new(BibleBook::Matthew, 1, 1).unwrap(),
+            BibleVerseReference::new(BibleBook::Revelation, 22, 21).unwrap(),
+        ] {
+            assert_eq!(verse_at_index(verse.global_index()), Some(verse.clone()));
+        }
+        assert_eq!(
+            BibleVerseReference::new(BibleBook::Genesis, 2, 1)
+                .unwrap()
+                .global_index(),
+            31
+        );
+
+        let last_verse = BibleBookReference::new(*BibleBook::all().last().unwrap()).last_verse();
+        assert_eq!(last_verse.global_index(), total_number_of_verses() - 1);
+        assert_eq!(verse_at_index(last_verse.global_index()), Some(last_verse));
+        assert_eq!(verse_at_index(total_number_of_verses()), None);
+        assert_eq!(verse_at_index(u32::MAX), None);
+
+        // The superscription of a Psalm has the index of the first verse
+        assert_eq!(
+            BibleVerseReference::new(BibleBook::Psalm, 3, 0)
+                .unwrap()
+                .global_index(),
+            BibleVerseReference::new(BibleBook::Psalm, 3, 1)
+                .unwrap()
+                .global_index()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_span() {
+        let john = BibleReference::BibleBook(BibleBookReference::new(BibleBook::John));
+        let john_3 =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap());
+        let john_3_16 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        let romans_8 =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Romans, 8).unwrap());
+        let romans_8_28 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Romans, 8, 28).unwrap());
+        let genesis = BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis));
+
+        for (a, b, expected) in [
+            (&john_3_16, &romans_8_28, "John 3:16-Romans 8:28"),
+            (&romans_8_28, &john_3_16, "John 3:16-Romans 8:28"),
+            (&john_3_16, &john_3_16, "John 3:16"),
+            (&john, &genesis, "Genesis-John"),
+            (&john_3, &romans_8, "John 3-Romans 8"),
+            // Mixed granularities result in the finer granularity
+            (&genesis, &john_3_16, "Genesis 1:1-John 3:16"),
+            (&john_3_16, &genesis, "Genesis 1:1-John 3:16"),
+            (&john, &romans_8_28, "John 1:1-Romans 8:28"),
+            (&john_3, &romans_8_28, "John 3:1-Romans 8:28"),
+            (&genesis, &romans_8, "Genesis 1-Romans 8"),
+            (&romans_8, &john, "John 1-Romans 8"),
+            // A reference which is contained in the other one
+            (&john, &john_3_16, "John 1:1-21:25"),
+            (&john_3_16, &john_3, "John 3:1-36"),
+        ] {
+            let range = span(a, b);
+            assert_eq!(range.to_string(), expected, "{} and {}", a, b);
+            assert!(range.contains(a) && range.contains(b));
+        }
+    }
+
+    #[test]
+    fn test_representation_ordering_with_overlaps() {
+        let verse = |chapter, verse| {
+            BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Genesis, chapter, verse).unwrap(),
+            )
+        };
+        let single = |reference: BibleReference| BibleReferenceRepresentation::Single(reference);
+        let range = |start: BibleReference, end: BibleReference| {
+            BibleReferenceRepresentation::Range(BibleRange::new(start, end).unwrap())
+        };
+        let genesis = BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis));
+        let genesis_1 = BibleReference::BibleChapter(
+            BibleChapterReference::new(BibleBook::Genesis, 1).unwrap(),
+        );
+        let genesis_2 = BibleReference::BibleChapter(
+            BibleChapterReference::new(BibleBook::Genesis, 2).unwrap(),
+        );
+
+        let sorted = vec![
+            single(genesis.clone()),
+            single(genesis_1.clone()),
+            range(genesis_1.clone(), genesis_2.clone()),
+            single(verse(1, 1)),
+            range(verse(1, 1), verse(1, 3)),
+            range(verse(1, 1), verse(1, 10)),
+            range(verse(1, 3), verse(1, 5)),
+            single(verse(1, 5)),
+            range(verse(1, 5), verse(1, 6)),
+            single(verse(1, 10)),
+            single(genesis_2.clone()),
+            single(verse(2, 1)),
+        ];
+
+        // A single reference inside a range sorts after the beginning of the range
+        assert!(single(verse(1, 5)) > range(verse(1, 1), verse(1, 10)));
+        assert!(single(verse(1, 5)) < single(verse(1, 10)));
+
+        // The order is consistent with equality
+        for (i, a) in sorted.iter().enumerate() {
+            for (j, b) in sorted.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "{:?} and {:?}", a, b);
+            }
+        }
+
+        // Sorting is independent of the original order
+        let mut reversed: Vec<BibleReferenceRepresentation> =
+            sorted.iter().rev().cloned().collect();
+        reversed.sort();
+        assert_eq!(reversed, sorted);
+        for shift in 0..sorted.len() {
+            let mut rotated = sorted.clone();
+            rotated.rotate_left(shift);
+            rotated.sort_unstable();
+            assert_eq!(rotated, sorted);
+        }
+    }
+
+    #[test]
+    fn test_navigation_at_number_boundaries() {
+        // The previous reference of verse 1 is in the previous chapter or book
+        assert_eq!(verse(BibleBook::Genesis, 1, 1).previous(), None);
+        assert_eq!(
+            verse(BibleBook::John, 3, 1).previous(),
+            Some(chapter(BibleBook::John, 2))
+        );
+        assert_eq!(
+            verse(BibleBook::Exodus, 1, 1).previous(),
+            Some(book(BibleBook::Genesis))
+        );
+        assert_eq!(chapter(BibleBook::Genesis, 1).previous(), None);
+        assert_eq!(book(BibleBook::Genesis).previous(), None);
+        assert_eq!(verse(BibleBook::Genesis, 1, 1).previous_chapter(), None);
+        assert_eq!(verse(BibleBook::Genesis, 1, 1).previous_book(), None);
+
+        // Psalm 119 has the most verses of all chapters and Psalms the most chapters of all books
+        assert_eq!(
+            verse(BibleBook::Psalm, 119, 175).next(),
+            Some(verse(BibleBook::Psalm, 119, 176))
+        );
+        assert_eq!(
+            verse(BibleBook::Psalm, 119, 176).next(),
+            Some(chapter(BibleBook::Psalm, 120))
+        );
+        assert_eq!(
+            verse(BibleBook::Psalm, 119, 1).advance_by(175),
+            Some(verse(BibleBook::Psalm, 119, 176))
+        );
+        assert_eq!(
+            verse(BibleBook::Psalm, 119, 176).previous(),
+            Some(verse(BibleBook::Psalm, 119, 175))
+        );
+        assert_eq!(
+            chapter(BibleBook::Psalm, 150).next(),
+            Some(book(BibleBook::Proverbs))
+        );
+        assert_eq!(
+            chapter(BibleBook::Psalm, 150).advance_by(1),
+            Some(chapter(BibleBook::Proverbs, 1))
+        );
+        assert_eq!(
+            verse(BibleBook::Psalm, 1, 1).previous(),
+            Some(book(BibleBook::Job))
+        );
+
+        // The end of the Bible
+        let last_book = *BibleBook::all().last().unwrap();
+        let last_book_reference = BibleBookReference::new(last_book);
+        assert_eq!(book(last_book).next(), None);
+        assert_eq!(
+            BibleReference::BibleChapter(last_book_reference.last_chapter()).next(),
+            None
+        );
+        assert_eq!(
+            BibleReference::BibleVerse(last_book_reference.last_verse()).next(),
+            None
+        );
+        assert_eq!(
+            BibleReference::BibleVerse(last_book_reference.last_verse()).advance_by(1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_number_limits() {
+        // All chapter and verse numbers of the supported versifications fit into a u8 with headroom
+        for versification in [Versification::Masoretic, Versification::Septuagint] {
+            for book in BibleBook::all() {
+                let chapters = get_number_of_chapters_with_versification(&book, &versification);
+                assert!(chapters < BibleChapter::MAX);
+                for chapter in 1..=chapters {
+                    let verses =
+                        get_number_of_verses_with_versification(&book, &chapter, &versification)
+                            .unwrap();
+                    assert!(verses < BibleVerse::MAX);
+                }
+            }
+        }
+        assert_eq!(get_number_of_verses(&BibleBook::Psalm, &119).unwrap(), 176);
+    }
+
+    #[test]
+    fn test_representation_equality_with_reference() {
+        let john_3_16 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        let john_3_17 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 17).unwrap());
+        let single = BibleReferenceRepresentation::Single(john_3_16.clone());
+        let range = BibleReferenceRepresentation::Range(
+            BibleRange::new(john_3_16.clone(), john_3_17.clone()).unwrap(),
+        );
+
+        assert_eq!(single, john_3_16);
+        assert_eq!(john_3_16, single);
+        assert_ne!(single, john_3_17);
+        assert_ne!(john_3_17, single);
+
+        // A range is never equal to a single reference, not even to its start
+        assert_ne!(range, john_3_16);
+        assert_ne!(john_3_16, range);
+
+        // A range of one verse is a range as well
+        let one_verse_range = BibleReferenceRepresentation::Range(
+            BibleRange::new(john_3_16.clone(), john_3_16.clone()).unwrap(),
+        );
+        assert_ne!(one_verse_range, john_3_16);
+    }
+
+    #[test]
+    fn test_whole_book_and_chapter_detection() {
+        // A verse range covering the entire book of Ruth
+        let ruth =
+            BibleRange::new(verse(BibleBook::Ruth, 1, 1), verse(BibleBook::Ruth, 4, 22)).unwrap();
+        assert_eq!(ruth.is_whole_book(), Some(BibleBook::Ruth));
+        assert_eq!(ruth.is_whole_chapter(), None);
+        // The range is not changed
+        assert!(matches!(ruth, BibleRange::VerseRange(_)));
+
+        // The same for chapter and book ranges
+        let ruth_chapters =
+            BibleRange::new(chapter(BibleBook::Ruth, 1), chapter(BibleBook::Ruth, 4)).unwrap();
+        assert_eq!(ruth_chapters.is_whole_book(), Some(BibleBook::Ruth));
+        let ruth_book = BibleRange::new(
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Ruth)),
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Ruth)),
+        )
+        .unwrap();
+        assert_eq!(ruth_book.is_whole_book(), Some(BibleBook::Ruth));
+
+        // Ranges missing a verse or spanning several books are not a whole book
+        let missing_last_verse =
+            BibleRange::new(verse(BibleBook::Ruth, 1, 1), verse(BibleBook::Ruth, 4, 21)).unwrap();
+        assert_eq!(missing_last_verse.is_whole_book(), None);
+        let missing_first_verse =
+            BibleRange::new(verse(BibleBook::Ruth, 1, 2), verse(BibleBook::Ruth, 4, 22)).unwrap();
+        assert_eq!(missing_first_verse.is_whole_book(), None);
+        let two_books = BibleRange::new(
+            verse(BibleBook::Judges, 1, 1),
+            verse(BibleBook::Ruth, 4, 22),
+        )
+        .unwrap();
+        assert_eq!(two_books.is_whole_book(), None);
+
+        // Whole chapters
+        let john_3 =
+            BibleRange::new(verse(BibleBook::John, 3, 1), verse(BibleBook::John, 3, 36)).unwrap();
+        assert_eq!(
+            john_3.is_whole_chapter(),
+            Some(BibleChapterReference::new(BibleBook::John, 3).unwrap())
+        );
+        assert_eq!(john_3.is_whole_book(), None);
+        let john_3_4 =
+            BibleRange::new(chapter(BibleBook::John, 3), chapter(BibleBook::John, 4)).unwrap();
+        assert_eq!(john_3_4.is_whole_chapter(), None);
+
+        // A book with a single chapter is both
+        let jude =
+            BibleRange::new(verse(BibleBook::Jude, 1, 1), verse(BibleBook::Jude, 1, 25)).unwrap();
+        assert_eq!(jude.is_whole_book(), Some(BibleBook::Jude));
+        assert_eq!(
+            jude.is_whole_chapter(),
+            Some(BibleChapterReference::new(BibleBook::Jude, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_chapters_and_verses() {
+        for book in BibleBook::all() {
+            let chapters = book.chapters();
+            assert_eq!(chapters.len(), get_number_of_chapters(&book) as usize);
+            assert_eq!(
+                chapters.first(),
+                Some(&BibleBookReference::new(book).first_chapter())
+            );
+            assert_eq!(
+                chapters.last(),
+                Some(&BibleBookReference::new(book).last_chapter())
+            );
+
+            for chapter in chapters {
+                let verses = chapter.verses();
+                assert_eq!(
+                    verses.len(),
+                    get_number_of_verses(&book, &chapter.chapter()).unwrap() as usize
+                );
+                assert_eq!(verses.first(), Some(&chapter.first_verse()));
+                assert_eq!(verses.last(), Some(&chapter.last_verse()));
+                assert!(verses.windows(2).all(|pair| pair[0] < pair[1]));
+            }
+        }
+
+        // The verses of a chapter are the same as the verses of the chapter range
+        let john_3 = BibleChapterReference::new(BibleBook::John, 3).unwrap();
+        assert_eq!(
+            john_3.verses(),
+            BibleChapterRange::new(john_3.clone(), john_3.clone())
+                .unwrap()
+                .as_verse_range()
+                .as_list()
+        );
+    }
+
+    #[test]
+    fn test_references_between() {
+        // Every chapter between two verses, across a book boundary
+        let chapters: Vec<BibleReference> = references_between(
+            &verse(BibleBook::John, 20, 31),
+            &verse(BibleBook::Acts, 2, 1),
+            ReferenceGranularity::Chapter,
+        )
+        .collect();
+        assert_eq!(
+            chapters,
+            vec![
+                chapter(BibleBook::John, 20),
+                chapter(BibleBook::John, 21),
+                chapter(BibleBook::Acts, 1),
+                chapter(BibleBook::Acts, 2),
+            ]
+        );
+
+        // Two verses of the same chapter are within one chapter
+        assert_eq!(
+            references_between(
+                &verse(BibleBook::John, 3, 16),
+                &verse(BibleBook::John, 3, 18),
+                ReferenceGranularity::Chapter,
+            )
+            .collect::<Vec<_>>(),
+            vec![chapter(BibleBook::John, 3)]
+        );
+
+        // The books between two chapters
+        assert_eq!(
+            references_between(
+                &chapter(BibleBook::IIIJohn, 1),
+                &chapter(BibleBook::Revelation, 3),
+                ReferenceGranularity::Book,
+            )
+            .collect::<Vec<_>>(),
+            vec![
+                book(BibleBook::IIIJohn),
+                book(BibleBook::Jude),
+                book(BibleBook::Revelation)
+            ]
+        );
+
+        // Coarser endpoints are extended to their first and last verse
+        let verses: Vec<BibleReference> = references_between(
+            &book(BibleBook::IIJohn),
+            &book(BibleBook::IIIJohn),
+            ReferenceGranularity::Verse,
+        )
+        .collect();
+        assert_eq!(
+            verses.len(),
+            (total_verses_in_book(&BibleBook::IIJohn) + total_verses_in_book(&BibleBook::IIIJohn))
+                as usize
+        );
+        assert_eq!(verses.first(), Some(&verse(BibleBook::IIJohn, 1, 1)));
+        assert_eq!(
+            verses.last(),
+            Some(&BibleReference::BibleVerse(
+                BibleBookReference::new(BibleBook::IIIJohn).last_verse()
+            ))
+        );
+
+        // The same granularity as the endpoints equals the list of the range
+        let start = verse(BibleBook::Ruth, 1, 20);
+        let end = verse(BibleBook::Ruth, 2, 3);
+        assert_eq!(
+            references_between(&start, &end, ReferenceGranularity::Verse).collect::<Vec<_>>(),
+            BibleRange::new(start.clone(), end.clone())
+                .unwrap()
+                .as_list()
+        );
+
+        // The iteration ends at the end of the Bible and is empty if the start is after the end
+        assert_eq!(
+            references_between(
+                &chapter(BibleBook::Revelation, 21),
+                &book(BibleBook::Revelation),
+                ReferenceGranularity::Chapter,
+            )
+            .count(),
+            2
+        );
+        assert_eq!(
+            references_between(&end, &start, ReferenceGranularity::Verse).count(),
+            0
+        );
     }
 }
@@ -1,13 +1,29 @@
 //! This submodule contains some helper function to validate Bible references
 
+#[cfg(feature = "std")]
+use crate::bible::BibleReferenceRepresentation;
 use crate::bible::errors::*;
-use crate::bible::{BibleBook, BibleChapter, BibleVerse};
+use crate::bible::versification::Versification;
+use crate::bible::{BibleBook, BibleChapter, BibleVerse, BibleVerseReference};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::error::Error;
 
+/// Validates whether a chapter exists in a book, using the default [Versification].
 pub fn validate_book_chapter(
     book: &BibleBook,
     chapter: &BibleChapter,
 ) -> Result<(), BibleReferenceValidationError> {
-    if *chapter == 0 || chapter > &get_number_of_chapters(book) {
+    validate_book_chapter_with_versification(book, chapter, &Versification::default())
+}
+
+/// Validates whether a chapter exists in a book according to the given [Versification].
+pub fn validate_book_chapter_with_versification(
+    book: &BibleBook,
+    chapter: &BibleChapter,
+    versification: &Versification,
+) -> Result<(), BibleReferenceValidationError> {
+    if *chapter == 0 || chapter > &versification.number_of_chapters(book) {
         Err(BibleReferenceValidationError {
             problem: BibleReferenceProblem::ChapterDoesNotExist,
         })
@@ -16,13 +32,31 @@ pub fn validate_book_chapter(
     }
 }
 
-/// Returns the number of chapters of a given BibleBook
+/// Returns the number of chapters of a given BibleBook, using the default [Versification]
 /// # Params
 /// - book: The [BibleBook] as a reference (`&BibleBook`)
 /// # Returns
 /// A number which can be used as a BibleChapter
-#[allow(clippy::all)]
 pub fn get_number_of_chapters(book: &BibleBook) -> BibleChapter {
+    get_number_of_chapters_with_versification(book, &Versification::default())
+}
+
+/// Returns the number of chapters of a given BibleBook according to the given [Versification]
+/// # Params
+/// - book: The [BibleBook] as a reference (`&BibleBook`)
+/// - versification: The [Versification] which defines the chapters
+/// # Returns
+/// A number which can be used as a BibleChapter
+pub fn get_number_of_chapters_with_versification(
+    book: &BibleBook,
+    versification: &Versification,
+) -> BibleChapter {
+    versification.number_of_chapters(book)
+}
+
+/// Returns the number of chapters of a given BibleBook in the Masoretic versification
+#[allow(clippy::all)]
+pub(crate) fn get_masoretic_number_of_chapters(book: &BibleBook) -> BibleChapter {
     match *book {
         BibleBook::Genesis => 50,
         BibleBook::Exodus => 40,
@@ -90,27 +124,60 @@ pub fn get_number_of_chapters(book: &BibleBook) -> BibleChapter {
         BibleBook::IIIJohn => 1,
         BibleBook::Jude => 1,
         BibleBook::Revelation => 22,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Tobit => 14,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Judith => 16,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::AdditionsToEsther => 6,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Wisdom => 19,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Sirach => 51,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Baruch => 6,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::PrayerOfAzariah => 1,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Susanna => 1,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::BelAndTheDragon => 1,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::IMaccabees => 16,
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::IIMaccabees => 15,
     }
 }
 
-/// Validates whether a reference consisting of a BibleBook, a Chapter and a Verse exists.
+/// Validates whether a reference consisting of a BibleBook, a Chapter and a Verse exists, using the default [Versification].
 pub fn validate_book_chapter_verse(
     book: &BibleBook,
     chapter: &BibleChapter,
     verse: &BibleVerse,
+) -> Result<(), BibleReferenceValidationError> {
+    validate_book_chapter_verse_with_versification(book, chapter, verse, &Versification::default())
+}
+
+/// Validates whether a reference consisting of a BibleBook, a Chapter and a Verse exists according to the given [Versification].
+pub fn validate_book_chapter_verse_with_versification(
+    book: &BibleBook,
+    chapter: &BibleChapter,
+    verse: &BibleVerse,
+    versification: &Versification,
 ) -> Result<(), BibleReferenceValidationError> {
     if *chapter == 0 {
         return Err(BibleReferenceValidationError {
             problem: (BibleReferenceProblem::ChapterDoesNotExist),
         });
     }
-    if *verse == 0 {
+    // Verse 0 is only used to reference the superscription of a Psalm
+    if *verse == 0 && !has_superscription(book, chapter) {
         return Err(BibleReferenceValidationError {
             problem: (BibleReferenceProblem::VerseDoesNotExist),
         });
     }
 
-    match get_number_of_verses(book, chapter) {
+    match versification.number_of_verses(book, chapter) {
         Ok(number) => {
             if *verse <= number {
                 Ok(())
@@ -124,2401 +191,2793 @@ pub fn validate_book_chapter_verse(
     }
 }
 
-/// Returns the number of Bible verses of a chapter (specified by book and chapter)
-#[allow(clippy::all)]
+/// Validates a list of verses at once and returns one result per item, in the same order.
+/// # Params
+/// - `refs`: The verses as tuples of book, chapter and verse
+/// # Returns
+/// - a [Vec] which contains the [BibleVerseReference] of every valid item or the [BibleReferenceValidationError] of every invalid item
+/// # Example
+/// ```
+/// use bibleref::bible::BibleBook;
+/// use bibleref::bible::validate::validate_all;
+/// let results = validate_all(&[(BibleBook::John, 3, 16), (BibleBook::John, 3, 99)]);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub fn validate_all(
+    refs: &[(BibleBook, BibleChapter, BibleVerse)],
+) -> Vec<Result<BibleVerseReference, BibleReferenceValidationError>> {
+    refs.iter()
+        .map(|(book, chapter, verse)| BibleVerseReference::new(*book, *chapter, *verse))
+        .collect()
+}
+
+/// Parses and validates a list of Bible references in any supported language and returns one result per item, in the same order.
+/// # Params
+/// - `refs`: The Bible references as strings, e.g. `"John 3:16"` or `"1. Mose 1,1-3"`
+/// # Returns
+/// - a [Vec] which contains the [BibleReferenceRepresentation] of every valid item or the parsing or validation error of every invalid item
+/// # Example
+/// ```
+/// use bibleref::bible::validate::validate_all_strings;
+/// let results = validate_all_strings(&["John 3:16", "Revelation 24", "Genesis 1-3"]);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// assert!(results[2].is_ok());
+/// ```
+#[cfg(feature = "std")]
+pub fn validate_all_strings(
+    refs: &[&str],
+) -> Vec<Result<BibleReferenceRepresentation, Box<dyn Error>>> {
+    refs.iter()
+        .map(|reference| crate::parse(reference))
+        .collect()
+}
+
+/// Returns whether a chapter has a superscription (a heading like "A Psalm of David") which can be referenced as verse 0.
+/// Only Psalms have superscriptions; the 34 Psalms without a heading in the Masoretic text (e.g. Psalm 1 and 2) have none.
+/// # Example
+/// ```
+/// use bibleref::bible::BibleBook;
+/// use bibleref::bible::validate::has_superscription;
+/// assert!(has_superscription(&BibleBook::Psalm, &3));
+/// assert!(!has_superscription(&BibleBook::Psalm, &1));
+/// assert!(!has_superscription(&BibleBook::John, &3));
+/// ```
+pub fn has_superscription(book: &BibleBook, chapter: &BibleChapter) -> bool {
+    const PSALMS_WITHOUT_SUPERSCRIPTION: [BibleChapter; 34] = [
+        1, 2, 10, 33, 43, 71, 91, 93, 94, 95, 96, 97, 99, 104, 105, 106, 107, 111, 112, 113, 114,
+        115, 116, 117, 118, 119, 135, 136, 137, 146, 147, 148, 149, 150,
+    ];
+    book == &BibleBook::Psalm
+        && (1..=150).contains(chapter)
+        && !PSALMS_WITHOUT_SUPERSCRIPTION.contains(chapter)
+}
+
+/// Returns the number of Bible verses of a chapter (specified by book and chapter), using the default [Versification]
 pub fn get_number_of_verses(
     book: &BibleBook,
     chapter: &BibleChapter,
 ) -> Result<BibleVerse, BibleReferenceValidationError> {
-    if book == &BibleBook::Genesis && *chapter == 1 {
-        Ok(31)
-    } else if book == &BibleBook::Genesis && *chapter == 2 {
-        Ok(25)
-    } else if book == &BibleBook::Genesis && *chapter == 3 {
-        Ok(24)
-    } else if book == &BibleBook::Genesis && *chapter == 4 {
-        Ok(26)
-    } else if book == &BibleBook::Genesis && *chapter == 5 {
-        Ok(32)
-    } else if book == &BibleBook::Genesis && *chapter == 6 {
-        Ok(22)
-    } else if book == &BibleBook::Genesis && *chapter == 7 {
-        Ok(24)
-    } else if book == &BibleBook::Genesis && *chapter == 8 {
-        Ok(22)
-    } else if book == &BibleBook::Genesis && *chapter == 9 {
-        Ok(29)
-    } else if book == &BibleBook::Genesis && *chapter == 10 {
-        Ok(32)
-    } else if book == &BibleBook::Genesis && *chapter == 11 {
-        Ok(32)
-    } else if book == &BibleBook::Genesis && *chapter == 12 {
-        Ok(20)
-    } else if book == &BibleBook::Genesis && *chapter == 13 {
-        Ok(18)
-    } else if book == &BibleBook::Genesis && *chapter == 14 {
-        Ok(24)
-    } else if book == &BibleBook::Genesis && *chapter == 15 {
-        Ok(21)
-    } else if book == &BibleBook::Genesis && *chapter == 16 {
-        Ok(16)
-    } else if book == &BibleBook::Genesis && *chapter == 17 {
-        Ok(27)
-    } else if book == &BibleBook::Genesis && *chapter == 18 {
-        Ok(33)
-    } else if book == &BibleBook::Genesis && *chapter == 19 {
-        Ok(38)
-    } else if book == &BibleBook::Genesis && *chapter == 20 {
-        Ok(18)
-    } else if book == &BibleBook::Genesis && *chapter == 21 {
-        Ok(34)
-    } else if book == &BibleBook::Genesis && *chapter == 22 {
-        Ok(24)
-    } else if book == &BibleBook::Genesis && *chapter == 23 {
-        Ok(20)
-    } else if book == &BibleBook::Genesis && *chapter == 24 {
-        Ok(67)
-    } else if book == &BibleBook::Genesis && *chapter == 25 {
-        Ok(34)
-    } else if book == &BibleBook::Genesis && *chapter == 26 {
-        Ok(35)
-    } else if book == &BibleBook::Genesis && *chapter == 27 {
-        Ok(46)
-    } else if book == &BibleBook::Genesis && *chapter == 28 {
-        Ok(22)
-    } else if book == &BibleBook::Genesis && *chapter == 29 {
-        Ok(35)
-    } else if book == &BibleBook::Genesis && *chapter == 30 {
-        Ok(43)
-    } else if book == &BibleBook::Genesis && *chapter == 31 {
-        Ok(55)
-    } else if book == &BibleBook::Genesis && *chapter == 32 {
-        Ok(32)
-    } else if book == &BibleBook::Genesis && *chapter == 33 {
-        Ok(20)
-    } else if book == &BibleBook::Genesis && *chapter == 34 {
-        Ok(31)
-    } else if book == &BibleBook::Genesis && *chapter == 35 {
-        Ok(29)
-    } else if book == &BibleBook::Genesis && *chapter == 36 {
-        Ok(43)
-    } else if book == &BibleBook::Genesis && *chapter == 37 {
-        Ok(36)
-    } else if book == &BibleBook::Genesis && *chapter == 38 {
-        Ok(30)
-    } else if book == &BibleBook::Genesis && *chapter == 39 {
-        Ok(23)
-    } else if book == &BibleBook::Genesis && *chapter == 40 {
-        Ok(23)
-    } else if book == &BibleBook::Genesis && *chapter == 41 {
-        Ok(57)
-    } else if book == &BibleBook::Genesis && *chapter == 42 {
-        Ok(38)
-    } else if book == &BibleBook::Genesis && *chapter == 43 {
-        Ok(34)
-    } else if book == &BibleBook::Genesis && *chapter == 44 {
-        Ok(34)
-    } else if book == &BibleBook::Genesis && *chapter == 45 {
-        Ok(28)
-    } else if book == &BibleBook::Genesis && *chapter == 46 {
-        Ok(34)
-    } else if book == &BibleBook::Genesis && *chapter == 47 {
-        Ok(31)
-    } else if book == &BibleBook::Genesis && *chapter == 48 {
-        Ok(22)
-    } else if book == &BibleBook::Genesis && *chapter == 49 {
-        Ok(33)
-    } else if book == &BibleBook::Genesis && *chapter == 50 {
-        Ok(26)
-    } else if book == &BibleBook::Exodus && *chapter == 1 {
-        Ok(22)
-    } else if book == &BibleBook::Exodus && *chapter == 2 {
-        Ok(25)
-    } else if book == &BibleBook::Exodus && *chapter == 3 {
-        Ok(22)
-    } else if book == &BibleBook::Exodus && *chapter == 4 {
-        Ok(31)
-    } else if book == &BibleBook::Exodus && *chapter == 5 {
-        Ok(23)
-    } else if book == &BibleBook::Exodus && *chapter == 6 {
-        Ok(30)
-    } else if book == &BibleBook::Exodus && *chapter == 7 {
-        Ok(25)
-    } else if book == &BibleBook::Exodus && *chapter == 8 {
-        Ok(32)
-    } else if book == &BibleBook::Exodus && *chapter == 9 {
-        Ok(35)
-    } else if book == &BibleBook::Exodus && *chapter == 10 {
-        Ok(29)
-    } else if book == &BibleBook::Exodus && *chapter == 11 {
-        Ok(10)
-    } else if book == &BibleBook::Exodus && *chapter == 12 {
-        Ok(51)
-    } else if book == &BibleBook::Exodus && *chapter == 13 {
-        Ok(22)
-    } else if book == &BibleBook::Exodus && *chapter == 14 {
-        Ok(31)
-    } else if book == &BibleBook::Exodus && *chapter == 15 {
-        Ok(27)
-    } else if book == &BibleBook::Exodus && *chapter == 16 {
-        Ok(36)
-    } else if book == &BibleBook::Exodus && *chapter == 17 {
-        Ok(16)
-    } else if book == &BibleBook::Exodus && *chapter == 18 {
-        Ok(27)
-    } else if book == &BibleBook::Exodus && *chapter == 19 {
-        Ok(25)
-    } else if book == &BibleBook::Exodus && *chapter == 20 {
-        Ok(26)
-    } else if book == &BibleBook::Exodus && *chapter == 21 {
-        Ok(36)
-    } else if book == &BibleBook::Exodus && *chapter == 22 {
-        Ok(31)
-    } else if book == &BibleBook::Exodus && *chapter == 23 {
-        Ok(33)
-    } else if book == &BibleBook::Exodus && *chapter == 24 {
-        Ok(18)
-    } else if book == &BibleBook::Exodus && *chapter == 25 {
-        Ok(40)
-    } else if book == &BibleBook::Exodus && *chapter == 26 {
-        Ok(37)
-    } else if book == &BibleBook::Exodus && *chapter == 27 {
-        Ok(21)
-    } else if book == &BibleBook::Exodus && *chapter == 28 {
-        Ok(43)
-    } else if book == &BibleBook::Exodus && *chapter == 29 {
-        Ok(46)
-    } else if book == &BibleBook::Exodus && *chapter == 30 {
-        Ok(38)
-    } else if book == &BibleBook::Exodus && *chapter == 31 {
-        Ok(18)
-    } else if book == &BibleBook::Exodus && *chapter == 32 {
-        Ok(35)
-    } else if book == &BibleBook::Exodus && *chapter == 33 {
-        Ok(23)
-    } else if book == &BibleBook::Exodus && *chapter == 34 {
-        Ok(35)
-    } else if book == &BibleBook::Exodus && *chapter == 35 {
-        Ok(35)
-    } else if book == &BibleBook::Exodus && *chapter == 36 {
-        Ok(38)
-    } else if book == &BibleBook::Exodus && *chapter == 37 {
-        Ok(29)
-    } else if book == &BibleBook::Exodus && *chapter == 38 {
-        Ok(31)
-    } else if book == &BibleBook::Exodus && *chapter == 39 {
-        Ok(43)
-    } else if book == &BibleBook::Exodus && *chapter == 40 {
-        Ok(38)
-    } else if book == &BibleBook::Leviticus && *chapter == 1 {
-        Ok(17)
-    } else if book == &BibleBook::Leviticus && *chapter == 2 {
-        Ok(16)
-    } else if book == &BibleBook::Leviticus && *chapter == 3 {
-        Ok(17)
-    } else if book == &BibleBook::Leviticus && *chapter == 4 {
-        Ok(35)
-    } else if book == &BibleBook::Leviticus && *chapter == 5 {
-        Ok(19)
-    } else if book == &BibleBook::Leviticus && *chapter == 6 {
-        Ok(30)
-    } else if book == &BibleBook::Leviticus && *chapter == 7 {
-        Ok(38)
-    } else if book == &BibleBook::Leviticus && *chapter == 8 {
-        Ok(36)
-    } else if book == &BibleBook::Leviticus && *chapter == 9 {
-        Ok(24)
-    } else if book == &BibleBook::Leviticus && *chapter == 10 {
-        Ok(20)
-    } else if book == &BibleBook::Leviticus && *chapter == 11 {
-        Ok(47)
-    } else if book == &BibleBook::Leviticus && *chapter == 12 {
-        Ok(8)
-    } else if book == &BibleBook::Leviticus && *chapter == 13 {
-        Ok(59)
-    } else if book == &BibleBook::Leviticus && *chapter == 14 {
-        Ok(57)
-    } else if book == &BibleBook::Leviticus && *chapter == 15 {
-        Ok(33)
-    } else if book == &BibleBook::Leviticus && *chapter == 16 {
-        Ok(34)
-    } else if book == &BibleBook::Leviticus && *chapter == 17 {
-        Ok(16)
-    } else if book == &BibleBook::Leviticus && *chapter == 18 {
-        Ok(30)
-    } else if book == &BibleBook::Leviticus && *chapter == 19 {
-        Ok(37)
-    } else if book == &BibleBook::Leviticus && *chapter == 20 {
-        Ok(27)
-    } else if book == &BibleBook::Leviticus && *chapter == 21 {
-        Ok(24)
-    } else if book == &BibleBook::Leviticus && *chapter == 22 {
-        Ok(33)
-    } else if book == &BibleBook::Leviticus && *chapter == 23 {
-        Ok(44)
-    } else if book == &BibleBook::Leviticus && *chapter == 24 {
-        Ok(23)
-    } else if book == &BibleBook::Leviticus && *chapter == 25 {
-        Ok(55)
-    } else if book == &BibleBook::Leviticus && *chapter == 26 {
-        Ok(46)
-    } else if book == &BibleBook::Leviticus && *chapter == 27 {
-        Ok(34)
-    } else if book == &BibleBook::Numbers && *chapter == 1 {
-        Ok(54)
-    } else if book == &BibleBook::Numbers && *chapter == 2 {
-        Ok(34)
-    } else if book == &BibleBook::Numbers && *chapter == 3 {
-        Ok(51)
-    } else if book == &BibleBook::Numbers && *chapter == 4 {
-        Ok(49)
-    } else if book == &BibleBook::Numbers && *chapter == 5 {
-        Ok(31)
-    } else if book == &BibleBook::Numbers && *chapter == 6 {
-        Ok(27)
-    } else if book == &BibleBook::Numbers && *chapter == 7 {
-        Ok(89)
-    } else if book == &BibleBook::Numbers && *chapter == 8 {
-        Ok(26)
-    } else if book == &BibleBook::Numbers && *chapter == 9 {
-        Ok(23)
-    } else if book == &BibleBook::Numbers && *chapter == 10 {
-        Ok(36)
-    } else if book == &BibleBook::Numbers && *chapter == 11 {
-        Ok(35)
-    } else if book == &BibleBook::Numbers && *chapter == 12 {
-        Ok(16)
-    } else if book == &BibleBook::Numbers && *chapter == 13 {
-        Ok(33)
-    } else if book == &BibleBook::Numbers && *chapter == 14 {
-        Ok(45)
-    } else if book == &BibleBook::Numbers && *chapter == 15 {
-        Ok(41)
-    } else if book == &BibleBook::Numbers && *chapter == 16 {
-        Ok(50)
-    } else if book == &BibleBook::Numbers && *chapter == 17 {
-        Ok(13)
-    } else if book == &BibleBook::Numbers && *chapter == 18 {
-        Ok(32)
-    } else if book == &BibleBook::Numbers && *chapter == 19 {
-        Ok(22)
-    } else if book == &BibleBook::Numbers && *chapter == 20 {
-        Ok(29)
-    } else if book == &BibleBook::Numbers && *chapter == 21 {
-        Ok(35)
-    } else if book == &BibleBook::Numbers && *chapter == 22 {
-        Ok(41)
-    } else if book == &BibleBook::Numbers && *chapter == 23 {
-        Ok(30)
-    } else if book == &BibleBook::Numbers && *chapter == 24 {
-        Ok(25)
-    } else if book == &BibleBook::Numbers && *chapter == 25 {
-        Ok(18)
-    } else if book == &BibleBook::Numbers && *chapter == 26 {
-        Ok(65)
-    } else if book == &BibleBook::Numbers && *chapter == 27 {
-        Ok(23)
-    } else if book == &BibleBook::Numbers && *chapter == 28 {
-        Ok(31)
-    } else if book == &BibleBook::Numbers && *chapter == 29 {
-        Ok(40)
-    } else if book == &BibleBook::Numbers && *chapter == 30 {
-        Ok(16)
-    } else if book == &BibleBook::Numbers && *chapter == 31 {
-        Ok(54)
-    } else if book == &BibleBook::Numbers && *chapter == 32 {
-        Ok(42)
-    } else if book == &BibleBook::Numbers && *chapter == 33 {
-        Ok(56)
-    } else if book == &BibleBook::Numbers && *chapter == 34 {
-        Ok(29)
-    } else if book == &BibleBook::Numbers && *chapter == 35 {
-        Ok(34)
-    } else if book == &BibleBook::Numbers && *chapter == 36 {
-        Ok(13)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 1 {
-        Ok(46)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 2 {
-        Ok(37)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 3 {
-        Ok(29)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 4 {
-        Ok(49)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 5 {
-        Ok(33)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 6 {
-        Ok(25)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 7 {
-        Ok(26)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 8 {
-        Ok(20)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 9 {
-        Ok(29)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 10 {
-        Ok(22)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 11 {
-        Ok(32)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 12 {
-        Ok(32)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 13 {
-        Ok(18)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 14 {
-        Ok(29)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 15 {
-        Ok(23)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 16 {
-        Ok(22)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 17 {
-        Ok(20)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 18 {
-        Ok(22)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 19 {
-        Ok(21)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 20 {
-        Ok(20)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 21 {
-        Ok(23)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 22 {
-        Ok(30)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 23 {
-        Ok(25)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 24 {
-        Ok(22)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 25 {
-        Ok(19)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 26 {
-        Ok(19)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 27 {
-        Ok(26)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 28 {
-        Ok(68)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 29 {
-        Ok(29)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 30 {
-        Ok(20)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 31 {
-        Ok(30)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 32 {
-        Ok(52)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 33 {
-        Ok(29)
-    } else if book == &BibleBook::Deuteronomy && *chapter == 34 {
-        Ok(12)
-    } else if book == &BibleBook::Joshua && *chapter == 1 {
-        Ok(18)
-    } else if book == &BibleBook::Joshua && *chapter == 2 {
-        Ok(24)
-    } else if book == &BibleBook::Joshua && *chapter == 3 {
-        Ok(17)
-    } else if book == &BibleBook::Joshua && *chapter == 4 {
-        Ok(24)
-    } else if book == &BibleBook::Joshua && *chapter == 5 {
-        Ok(15)
-    } else if book == &BibleBook::Joshua && *chapter == 6 {
-        Ok(27)
-    } else if book == &BibleBook::Joshua && *chapter == 7 {
-        Ok(26)
-    } else if book == &BibleBook::Joshua && *chapter == 8 {
-        Ok(35)
-    } else if book == &BibleBook::Joshua && *chapter == 9 {
-        Ok(27)
-    } else if book == &BibleBook::Joshua && *chapter == 10 {
-        Ok(43)
-    } else if book == &BibleBook::Joshua && *chapter == 11 {
-        Ok(23)
-    } else if book == &BibleBook::Joshua && *chapter == 12 {
-        Ok(24)
-    } else if book == &BibleBook::Joshua && *chapter == 13 {
-        Ok(33)
-    } else if book == &BibleBook::Joshua && *chapter == 14 {
-        Ok(15)
-    } else if book == &BibleBook::Joshua && *chapter == 15 {
-        Ok(63)
-    } else if book == &BibleBook::Joshua && *chapter == 16 {
-        Ok(10)
-    } else if book == &BibleBook::Joshua && *chapter == 17 {
-        Ok(18)
-    } else if book == &BibleBook::Joshua && *chapter == 18 {
-        Ok(28)
-    } else if book == &BibleBook::Joshua && *chapter == 19 {
-        Ok(51)
-    } else if book == &BibleBook::Joshua && *chapter == 20 {
-        Ok(9)
-    } else if book == &BibleBook::Joshua && *chapter == 21 {
-        Ok(45)
-    } else if book == &BibleBook::Joshua && *chapter == 22 {
-        Ok(34)
-    } else if book == &BibleBook::Joshua && *chapter == 23 {
-        Ok(16)
-    } else if book == &BibleBook::Joshua && *chapter == 24 {
-        Ok(33)
-    } else if book == &BibleBook::Judges && *chapter == 1 {
-        Ok(36)
-    } else if book == &BibleBook::Judges && *chapter == 2 {
-        Ok(23)
-    } else if book == &BibleBook::Judges && *chapter == 3 {
-        Ok(31)
-    } else if book == &BibleBook::Judges && *chapter == 4 {
-        Ok(24)
-    } else if book == &BibleBook::Judges && *chapter == 5 {
-        Ok(31)
-    } else if book == &BibleBook::Judges && *chapter == 6 {
-        Ok(40)
-    } else if book == &BibleBook::Judges && *chapter == 7 {
-        Ok(25)
-    } else if book == &BibleBook::Judges && *chapter == 8 {
-        Ok(35)
-    } else if book == &BibleBook::Judges && *chapter == 9 {
-        Ok(57)
-    } else if book == &BibleBook::Judges && *chapter == 10 {
-        Ok(18)
-    } else if book == &BibleBook::Judges && *chapter == 11 {
-        Ok(40)
-    } else if book == &BibleBook::Judges && *chapter == 12 {
-        Ok(15)
-    } else if book == &BibleBook::Judges && *chapter == 13 {
-        Ok(25)
-    } else if book == &BibleBook::Judges && *chapter == 14 {
-        Ok(20)
-    } else if book == &BibleBook::Judges && *chapter == 15 {
-        Ok(20)
-    } else if book == &BibleBook::Judges && *chapter == 16 {
-        Ok(31)
-    } else if book == &BibleBook::Judges && *chapter == 17 {
-        Ok(13)
-    } else if book == &BibleBook::Judges && *chapter == 18 {
-        Ok(31)
-    } else if book == &BibleBook::Judges && *chapter == 19 {
-        Ok(30)
-    } else if book == &BibleBook::Judges && *chapter == 20 {
-        Ok(48)
-    } else if book == &BibleBook::Judges && *chapter == 21 {
-        Ok(25)
-    } else if book == &BibleBook::Ruth && *chapter == 1 {
-        Ok(22)
-    } else if book == &BibleBook::Ruth && *chapter == 2 {
-        Ok(23)
-    } else if book == &BibleBook::Ruth && *chapter == 3 {
-        Ok(18)
-    } else if book == &BibleBook::Ruth && *chapter == 4 {
-        Ok(22)
-    } else if book == &BibleBook::ISamuel && *chapter == 1 {
-        Ok(28)
-    } else if book == &BibleBook::ISamuel && *chapter == 2 {
-        Ok(36)
-    } else if book == &BibleBook::ISamuel && *chapter == 3 {
-        Ok(21)
-    } else if book == &BibleBook::ISamuel && *chapter == 4 {
-        Ok(22)
-    } else if book == &BibleBook::ISamuel && *chapter == 5 {
-        Ok(12)
-    } else if book == &BibleBook::ISamuel && *chapter == 6 {
-        Ok(21)
-    } else if book == &BibleBook::ISamuel && *chapter == 7 {
-        Ok(17)
-    } else if book == &BibleBook::ISamuel && *chapter == 8 {
-        Ok(22)
-    } else if book == &BibleBook::ISamuel && *chapter == 9 {
-        Ok(27)
-    } else if book == &BibleBook::ISamuel && *chapter == 10 {
-        Ok(27)
-    } else if book == &BibleBook::ISamuel && *chapter == 11 {
-        Ok(15)
-    } else if book == &BibleBook::ISamuel && *chapter == 12 {
-        Ok(25)
-    } else if book == &BibleBook::ISamuel && *chapter == 13 {
-        Ok(23)
-    } else if book == &BibleBook::ISamuel && *chapter == 14 {
-        Ok(52)
-    } else if book == &BibleBook::ISamuel && *chapter == 15 {
-        Ok(35)
-    } else if book == &BibleBook::ISamuel && *chapter == 16 {
-        Ok(23)
-    } else if book == &BibleBook::ISamuel && *chapter == 17 {
-        Ok(58)
-    } else if book == &BibleBook::ISamuel && *chapter == 18 {
-        Ok(30)
-    } else if book == &BibleBook::ISamuel && *chapter == 19 {
-        Ok(24)
-    } else if book == &BibleBook::ISamuel && *chapter == 20 {
-        Ok(42)
-    } else if book == &BibleBook::ISamuel && *chapter == 21 {
-        Ok(15)
-    } else if book == &BibleBook::ISamuel && *chapter == 22 {
-        Ok(23)
-    } else if book == &BibleBook::ISamuel && *chapter == 23 {
-        Ok(29)
-    } else if book == &BibleBook::ISamuel && *chapter == 24 {
-        Ok(22)
-    } else if book == &BibleBook::ISamuel && *chapter == 25 {
-        Ok(44)
-    } else if book == &BibleBook::ISamuel && *chapter == 26 {
-        Ok(25)
-    } else if book == &BibleBook::ISamuel && *chapter == 27 {
-        Ok(12)
-    } else if book == &BibleBook::ISamuel && *chapter == 28 {
-        Ok(25)
-    } else if book == &BibleBook::ISamuel && *chapter == 29 {
-        Ok(11)
-    } else if book == &BibleBook::ISamuel && *chapter == 30 {
-        Ok(31)
-    } else if book == &BibleBook::ISamuel && *chapter == 31 {
-        Ok(13)
-    } else if book == &BibleBook::IISamuel && *chapter == 1 {
-        Ok(27)
-    } else if book == &BibleBook::IISamuel && *chapter == 2 {
-        Ok(32)
-    } else if book == &BibleBook::IISamuel && *chapter == 3 {
-        Ok(39)
-    } else if book == &BibleBook::IISamuel && *chapter == 4 {
-        Ok(12)
-    } else if book == &BibleBook::IISamuel && *chapter == 5 {
-        Ok(25)
-    } else if book == &BibleBook::IISamuel && *chapter == 6 {
-        Ok(23)
-    } else if book == &BibleBook::IISamuel && *chapter == 7 {
-        Ok(29)
-    } else if book == &BibleBook::IISamuel && *chapter == 8 {
-        Ok(18)
-    } else if book == &BibleBook::IISamuel && *chapter == 9 {
-        Ok(13)
-    } else if book == &BibleBook::IISamuel && *chapter == 10 {
-        Ok(19)
-    } else if book == &BibleBook::IISamuel && *chapter == 11 {
-        Ok(27)
-    } else if book == &BibleBook::IISamuel && *chapter == 12 {
-        Ok(31)
-    } else if book == &BibleBook::IISamuel && *chapter == 13 {
-        Ok(39)
-    } else if book == &BibleBook::IISamuel && *chapter == 14 {
-        Ok(33)
-    } else if book == &BibleBook::IISamuel && *chapter == 15 {
-        Ok(37)
-    } else if book == &BibleBook::IISamuel && *chapter == 16 {
-        Ok(23)
-    } else if book == &BibleBook::IISamuel && *chapter == 17 {
-        Ok(29)
-    } else if book == &BibleBook::IISamuel && *chapter == 18 {
-        Ok(33)
-    } else if book == &BibleBook::IISamuel && *chapter == 19 {
-        Ok(43)
-    } else if book == &BibleBook::IISamuel && *chapter == 20 {
-        Ok(26)
-    } else if book == &BibleBook::IISamuel && *chapter == 21 {
-        Ok(22)
-    } else if book == &BibleBook::IISamuel && *chapter == 22 {
-        Ok(51)
-    } else if book == &BibleBook::IISamuel && *chapter == 23 {
-        Ok(39)
-    } else if book == &BibleBook::IISamuel && *chapter == 24 {
-        Ok(25)
-    } else if book == &BibleBook::IKings && *chapter == 1 {
-        Ok(53)
-    } else if book == &BibleBook::IKings && *chapter == 2 {
-        Ok(46)
-    } else if book == &BibleBook::IKings && *chapter == 3 {
-        Ok(28)
-    } else if book == &BibleBook::IKings && *chapter == 4 {
-        Ok(34)
-    } else if book == &BibleBook::IKings && *chapter == 5 {
-        Ok(18)
-    } else if book == &BibleBook::IKings && *chapter == 6 {
-        Ok(38)
-    } else if book == &BibleBook::IKings && *chapter == 7 {
-        Ok(51)
-    } else if book == &BibleBook::IKings && *chapter == 8 {
-        Ok(66)
-    } else if book == &BibleBook::IKings && *chapter == 9 {
-        Ok(28)
-    } else if book == &BibleBook::IKings && *chapter == 10 {
-        Ok(29)
-    } else if book == &BibleBook::IKings && *chapter == 11 {
-        Ok(43)
-    } else if book == &BibleBook::IKings && *chapter == 12 {
-        Ok(33)
-    } else if book == &BibleBook::IKings && *chapter == 13 {
-        Ok(34)
-    } else if book == &BibleBook::IKings && *chapter == 14 {
-        Ok(31)
-    } else if book == &BibleBook::IKings && *chapter == 15 {
-        Ok(34)
-    } else if book == &BibleBook::IKings && *chapter == 16 {
-        Ok(34)
-    } else if book == &BibleBook::IKings && *chapter == 17 {
-        Ok(24)
-    } else if book == &BibleBook::IKings && *chapter == 18 {
-        Ok(46)
-    } else if book == &BibleBook::IKings && *chapter == 19 {
-        Ok(21)
-    } else if book == &BibleBook::IKings && *chapter == 20 {
-        Ok(43)
-    } else if book == &BibleBook::IKings && *chapter == 21 {
-        Ok(29)
-    } else if book == &BibleBook::IKings && *chapter == 22 {
-        Ok(53)
-    } else if book == &BibleBook::IIKings && *chapter == 1 {
-        Ok(18)
-    } else if book == &BibleBook::IIKings && *chapter == 2 {
-        Ok(25)
-    } else if book == &BibleBook::IIKings && *chapter == 3 {
-        Ok(27)
-    } else if book == &BibleBook::IIKings && *chapter == 4 {
-        Ok(44)
-    } else if book == &BibleBook::IIKings && *chapter == 5 {
-        Ok(27)
-    } else if book == &BibleBook::IIKings && *chapter == 6 {
-        Ok(33)
-    } else if book == &BibleBook::IIKings && *chapter == 7 {
-        Ok(20)
-    } else if book == &BibleBook::IIKings && *chapter == 8 {
-        Ok(29)
-    } else if book == &BibleBook::IIKings && *chapter == 9 {
-        Ok(37)
-    } else if book == &BibleBook::IIKings && *chapter == 10 {
-        Ok(36)
-    } else if book == &BibleBook::IIKings && *chapter == 11 {
-        Ok(21)
-    } else if book == &BibleBook::IIKings && *chapter == 12 {
-        Ok(21)
-    } else if book == &BibleBook::IIKings && *chapter == 13 {
-        Ok(25)
-    } else if book == &BibleBook::IIKings && *chapter == 14 {
-        Ok(29)
-    } else if book == &BibleBook::IIKings && *chapter == 15 {
-        Ok(38)
-    } else if book == &BibleBook::IIKings && *chapter == 16 {
-        Ok(20)
-    } else if book == &BibleBook::IIKings && *chapter == 17 {
-        Ok(41)
-    } else if book == &BibleBook::IIKings && *chapter == 18 {
-        Ok(37)
-    } else if book == &BibleBook::IIKings && *chapter == 19 {
-        Ok(37)
-    } else if book == &BibleBook::IIKings && *chapter == 20 {
-        Ok(21)
-    } else if book == &BibleBook::IIKings && *chapter == 21 {
-        Ok(26)
-    } else if book == &BibleBook::IIKings && *chapter == 22 {
-        Ok(20)
-    } else if book == &BibleBook::IIKings && *chapter == 23 {
-        Ok(37)
-    } else if book == &BibleBook::IIKings && *chapter == 24 {
-        Ok(20)
-    } else if book == &BibleBook::IIKings && *chapter == 25 {
-        Ok(30)
-    } else if book == &BibleBook::IChronicles && *chapter == 1 {
-        Ok(54)
-    } else if book == &BibleBook::IChronicles && *chapter == 2 {
-        Ok(55)
-    } else if book == &BibleBook::IChronicles && *chapter == 3 {
-        Ok(24)
-    } else if book == &BibleBook::IChronicles && *chapter == 4 {
-        Ok(43)
-    } else if book == &BibleBook::IChronicles && *chapter == 5 {
-        Ok(26)
-    } else if book == &BibleBook::IChronicles && *chapter == 6 {
-        Ok(81)
-    } else if book == &BibleBook::IChronicles && *chapter == 7 {
-        Ok(40)
-    } else if book == &BibleBook::IChronicles && *chapter == 8 {
-        Ok(40)
-    } else if book == &BibleBook::IChronicles && *chapter == 9 {
-        Ok(44)
-    } else if book == &BibleBook::IChronicles && *chapter == 10 {
-        Ok(14)
-    } else if book == &BibleBook::IChronicles && *chapter == 11 {
-        Ok(47)
-    } else if book == &BibleBook::IChronicles && *chapter == 12 {
-        Ok(40)
-    } else if book == &BibleBook::IChronicles && *chapter == 13 {
-        Ok(14)
-    } else if book == &BibleBook::IChronicles && *chapter == 14 {
-        Ok(17)
-    } else if book == &BibleBook::IChronicles && *chapter == 15 {
-        Ok(29)
-    } else if book == &BibleBook::IChronicles && *chapter == 16 {
-        Ok(43)
-    } else if book == &BibleBook::IChronicles && *chapter == 17 {
-        Ok(27)
-    } else if book == &BibleBook::IChronicles && *chapter == 18 {
-        Ok(17)
-    } else if book == &BibleBook::IChronicles && *chapter == 19 {
-        Ok(19)
-    } else if book == &BibleBook::IChronicles && *chapter == 20 {
-        Ok(8)
-    } else if book == &BibleBook::IChronicles && *chapter == 21 {
-        Ok(30)
-    } else if book == &BibleBook::IChronicles && *chapter == 22 {
-        Ok(19)
-    } else if book == &BibleBook::IChronicles && *chapter == 23 {
-        Ok(32)
-    } else if book == &BibleBook::IChronicles && *chapter == 24 {
-        Ok(31)
-    } else if book == &BibleBook::IChronicles && *chapter == 25 {
-        Ok(31)
-    } else if book == &BibleBook::IChronicles && *chapter == 26 {
-        Ok(32)
-    } else if book == &BibleBook::IChronicles && *chapter == 27 {
-        Ok(34)
-    } else if book == &BibleBook::IChronicles && *chapter == 28 {
-        Ok(21)
-    } else if book == &BibleBook::IChronicles && *chapter == 29 {
-        Ok(30)
-    } else if book == &BibleBook::IIChronicles && *chapter == 1 {
-        Ok(17)
-    } else if book == &BibleBook::IIChronicles && *chapter == 2 {
-        Ok(18)
-    } else if book == &BibleBook::IIChronicles && *chapter == 3 {
-        Ok(17)
-    } else if book == &BibleBook::IIChronicles && *chapter == 4 {
-        Ok(22)
-    } else if book == &BibleBook::IIChronicles && *chapter == 5 {
-        Ok(14)
-    } else if book == &BibleBook::IIChronicles && *chapter == 6 {
-        Ok(42)
-    } else if book == &BibleBook::IIChronicles && *chapter == 7 {
-        Ok(22)
-    } else if book == &BibleBook::IIChronicles && *chapter == 8 {
-        Ok(18)
-    } else if book == &BibleBook::IIChronicles && *chapter == 9 {
-        Ok(31)
-    } else if book == &BibleBook::IIChronicles && *chapter == 10 {
-        Ok(19)
-    } else if book == &BibleBook::IIChronicles && *chapter == 11 {
-        Ok(23)
-    } else if book == &BibleBook::IIChronicles && *chapter == 12 {
-        Ok(16)
-    } else if book == &BibleBook::IIChronicles && *chapter == 13 {
-        Ok(22)
-    } else if book == &BibleBook::IIChronicles && *chapter == 14 {
-        Ok(15)
-    } else if book == &BibleBook::IIChronicles && *chapter == 15 {
-        Ok(19)
-    } else if book == &BibleBook::IIChronicles && *chapter == 16 {
-        Ok(14)
-    } else if book == &BibleBook::IIChronicles && *chapter == 17 {
-        Ok(19)
-    } else if book == &BibleBook::IIChronicles && *chapter == 18 {
-        Ok(34)
-    } else if book == &BibleBook::IIChronicles && *chapter == 19 {
-        Ok(11)
-    } else if book == &BibleBook::IIChronicles && *chapter == 20 {
-        Ok(37)
-    } else if book == &BibleBook::IIChronicles && *chapter == 21 {
-        Ok(20)
-    } else if book == &BibleBook::IIChronicles && *chapter == 22 {
-        Ok(12)
-    } else if book == &BibleBook::IIChronicles && *chapter == 23 {
-        Ok(21)
-    } else if book == &BibleBook::IIChronicles && *chapter == 24 {
-        Ok(27)
-    } else if book == &BibleBook::IIChronicles && *chapter == 25 {
-        Ok(28)
-    } else if book == &BibleBook::IIChronicles && *chapter == 26 {
-        Ok(23)
-    } else if book == &BibleBook::IIChronicles && *chapter == 27 {
-        Ok(9)
-    } else if book == &BibleBook::IIChronicles && *chapter == 28 {
-        Ok(27)
-    } else if book == &BibleBook::IIChronicles && *chapter == 29 {
-        Ok(36)
-    } else if book == &BibleBook::IIChronicles && *chapter == 30 {
-        Ok(27)
-    } else if book == &BibleBook::IIChronicles && *chapter == 31 {
-        Ok(21)
-    } else if book == &BibleBook::IIChronicles && *chapter == 32 {
-        Ok(33)
-    } else if book == &BibleBook::IIChronicles && *chapter == 33 {
-        Ok(25)
-    } else if book == &BibleBook::IIChronicles && *chapter == 34 {
-        Ok(33)
-    } else if book == &BibleBook::IIChronicles && *chapter == 35 {
-        Ok(27)
-    } else if book == &BibleBook::IIChronicles && *chapter == 36 {
-        Ok(23)
-    } else if book == &BibleBook::Ezra && *chapter == 1 {
-        Ok(11)
-    } else if book == &BibleBook::Ezra && *chapter == 2 {
-        Ok(70)
-    } else if book == &BibleBook::Ezra && *chapter == 3 {
-        Ok(13)
-    } else if book == &BibleBook::Ezra && *chapter == 4 {
-        Ok(24)
-    } else if book == &BibleBook::Ezra && *chapter == 5 {
-        Ok(17)
-    } else if book == &BibleBook::Ezra && *chapter == 6 {
-        Ok(22)
-    } else if book == &BibleBook::Ezra && *chapter == 7 {
-        Ok(28)
-    } else if book == &BibleBook::Ezra && *chapter == 8 {
-        Ok(36)
-    } else if book == &BibleBook::Ezra && *chapter == 9 {
-        Ok(15)
-    } else if book == &BibleBook::Ezra && *chapter == 10 {
-        Ok(44)
-    } else if book == &BibleBook::Nehemiah && *chapter == 1 {
-        Ok(11)
-    } else if book == &BibleBook::Nehemiah && *chapter == 2 {
-        Ok(20)
-    } else if book == &BibleBook::Nehemiah && *chapter == 3 {
-        Ok(32)
-    } else if book == &BibleBook::Nehemiah && *chapter == 4 {
-        Ok(23)
-    } else if book == &BibleBook::Nehemiah && *chapter == 5 {
-        Ok(19)
-    } else if book == &BibleBook::Nehemiah && *chapter == 6 {
-        Ok(19)
-    } else if book == &BibleBook::Nehemiah && *chapter == 7 {
-        Ok(73)
-    } else if book == &BibleBook::Nehemiah && *chapter == 8 {
-        Ok(18)
-    } else if book == &BibleBook::Nehemiah && *chapter == 9 {
-        Ok(38)
-    } else if book == &BibleBook::Nehemiah && *chapter == 10 {
-        Ok(39)
-    } else if book == &BibleBook::Nehemiah && *chapter == 11 {
-        Ok(36)
-    } else if book == &BibleBook::Nehemiah && *chapter == 12 {
-        Ok(47)
-    } else if book == &BibleBook::Nehemiah && *chapter == 13 {
-        Ok(31)
-    } else if book == &BibleBook::Esther && *chapter == 1 {
-        Ok(22)
-    } else if book == &BibleBook::Esther && *chapter == 2 {
-        Ok(23)
-    } else if book == &BibleBook::Esther && *chapter == 3 {
-        Ok(15)
-    } else if book == &BibleBook::Esther && *chapter == 4 {
-        Ok(17)
-    } else if book == &BibleBook::Esther && *chapter == 5 {
-        Ok(14)
-    } else if book == &BibleBook::Esther && *chapter == 6 {
-        Ok(14)
-    } else if book == &BibleBook::Esther && *chapter == 7 {
-        Ok(10)
-    } else if book == &BibleBook::Esther && *chapter == 8 {
-        Ok(17)
-    } else if book == &BibleBook::Esther && *chapter == 9 {
-        Ok(32)
-    } else if book == &BibleBook::Esther && *chapter == 10 {
-        Ok(3)
-    } else if book == &BibleBook::Job && *chapter == 1 {
-        Ok(22)
-    } else if book == &BibleBook::Job && *chapter == 2 {
-        Ok(13)
-    } else if book == &BibleBook::Job && *chapter == 3 {
-        Ok(26)
-    } else if book == &BibleBook::Job && *chapter == 4 {
-        Ok(21)
-    } else if book == &BibleBook::Job && *chapter == 5 {
-        Ok(27)
-    } else if book == &BibleBook::Job && *chapter == 6 {
-        Ok(30)
-    } else if book == &BibleBook::Job && *chapter == 7 {
-        Ok(21)
-    } else if book == &BibleBook::Job && *chapter == 8 {
-        Ok(22)
-    } else if book == &BibleBook::Job && *chapter == 9 {
-        Ok(35)
-    } else if book == &BibleBook::Job && *chapter == 10 {
-        Ok(22)
-    } else if book == &BibleBook::Job && *chapter == 11 {
-        Ok(20)
-    } else if book == &BibleBook::Job && *chapter == 12 {
-        Ok(25)
-    } else if book == &BibleBook::Job && *chapter == 13 {
-        Ok(28)
-    } else if book == &BibleBook::Job && *chapter == 14 {
-        Ok(22)
-    } else if book == &BibleBook::Job && *chapter == 15 {
-        Ok(35)
-    } else if book == &BibleBook::Job && *chapter == 16 {
-        Ok(22)
-    } else if book == &BibleBook::Job && *chapter == 17 {
-        Ok(16)
-    } else if book == &BibleBook::Job && *chapter == 18 {
-        Ok(21)
-    } else if book == &BibleBook::Job && *chapter == 19 {
-        Ok(29)
-    } else if book == &BibleBook::Job && *chapter == 20 {
-        Ok(29)
-    } else if book == &BibleBook::Job && *chapter == 21 {
-        Ok(34)
-    } else if book == &BibleBook::Job && *chapter == 22 {
-        Ok(30)
-    } else if book == &BibleBook::Job && *chapter == 23 {
-        Ok(17)
-    } else if book == &BibleBook::Job && *chapter == 24 {
-        Ok(25)
-    } else if book == &BibleBook::Job && *chapter == 25 {
-        Ok(6)
-    } else if book == &BibleBook::Job && *chapter == 26 {
-        Ok(14)
-    } else if book == &BibleBook::Job && *chapter == 27 {
-        Ok(23)
-    } else if book == &BibleBook::Job && *chapter == 28 {
-        Ok(28)
-    } else if book == &BibleBook::Job && *chapter == 29 {
-        Ok(25)
-    } else if book == &BibleBook::Job && *chapter == 30 {
-        Ok(31)
-    } else if book == &BibleBook::Job && *chapter == 31 {
-        Ok(40)
-    } else if book == &BibleBook::Job && *chapter == 32 {
-        Ok(22)
-    } else if book == &BibleBook::Job && *chapter == 33 {
-        Ok(33)
-    } else if book == &BibleBook::Job && *chapter == 34 {
-        Ok(37)
-    } else if book == &BibleBook::Job && *chapter == 35 {
-        Ok(16)
-    } else if book == &BibleBook::Job && *chapter == 36 {
-        Ok(33)
-    } else if book == &BibleBook::Job && *chapter == 37 {
-        Ok(24)
-    } else if book == &BibleBook::Job && *chapter == 38 {
-        Ok(41)
-    } else if book == &BibleBook::Job && *chapter == 39 {
-        Ok(30)
-    } else if book == &BibleBook::Job && *chapter == 40 {
-        Ok(24)
-    } else if book == &BibleBook::Job && *chapter == 41 {
-        Ok(34)
-    } else if book == &BibleBook::Job && *chapter == 42 {
-        Ok(17)
-    } else if book == &BibleBook::Psalm && *chapter == 1 {
-        Ok(6)
-    } else if book == &BibleBook::Psalm && *chapter == 2 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 3 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 4 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 5 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 6 {
-        Ok(10)
-    } else if book == &BibleBook::Psalm && *chapter == 7 {
-        Ok(17)
-    } else if book == &BibleBook::Psalm && *chapter == 8 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 9 {
-        Ok(20)
-    } else if book == &BibleBook::Psalm && *chapter == 10 {
-        Ok(18)
-    } else if book == &BibleBook::Psalm && *chapter == 11 {
-        Ok(7)
-    } else if book == &BibleBook::Psalm && *chapter == 12 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 13 {
-        Ok(6)
-    } else if book == &BibleBook::Psalm && *chapter == 14 {
-        Ok(7)
-    } else if book == &BibleBook::Psalm && *chapter == 15 {
-        Ok(5)
-    } else if book == &BibleBook::Psalm && *chapter == 16 {
-        Ok(11)
-    } else if book == &BibleBook::Psalm && *chapter == 17 {
-        Ok(15)
-    } else if book == &BibleBook::Psalm && *chapter == 18 {
-        Ok(50)
-    } else if book == &BibleBook::Psalm && *chapter == 19 {
-        Ok(14)
-    } else if book == &BibleBook::Psalm && *chapter == 20 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 21 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 22 {
-        Ok(31)
-    } else if book == &BibleBook::Psalm && *chapter == 23 {
-        Ok(6)
-    } else if book == &BibleBook::Psalm && *chapter == 24 {
-        Ok(10)
-    } else if book == &BibleBook::Psalm && *chapter == 25 {
-        Ok(22)
-    } else if book == &BibleBook::Psalm && *chapter == 26 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 27 {
-        Ok(14)
-    } else if book == &BibleBook::Psalm && *chapter == 28 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 29 {
-        Ok(11)
-    } else if book == &BibleBook::Psalm && *chapter == 30 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 31 {
-        Ok(24)
-    } else if book == &BibleBook::Psalm && *chapter == 32 {
-        Ok(11)
-    } else if book == &BibleBook::Psalm && *chapter == 33 {
-        Ok(22)
-    } else if book == &BibleBook::Psalm && *chapter == 34 {
-        Ok(22)
-    } else if book == &BibleBook::Psalm && *chapter == 35 {
-        Ok(28)
-    } else if book == &BibleBook::Psalm && *chapter == 36 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 37 {
-        Ok(40)
-    } else if book == &BibleBook::Psalm && *chapter == 38 {
-        Ok(22)
-    } else if book == &BibleBook::Psalm && *chapter == 39 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 40 {
-        Ok(17)
-    } else if book == &BibleBook::Psalm && *chapter == 41 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 42 {
-        Ok(11)
-    } else if book == &BibleBook::Psalm && *chapter == 43 {
-        Ok(5)
-    } else if book == &BibleBook::Psalm && *chapter == 44 {
-        Ok(26)
-    } else if book == &BibleBook::Psalm && *chapter == 45 {
-        Ok(17)
-    } else if book == &BibleBook::Psalm && *chapter == 46 {
-        Ok(11)
-    } else if book == &BibleBook::Psalm && *chapter == 47 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 48 {
-        Ok(14)
-    } else if book == &BibleBook::Psalm && *chapter == 49 {
-        Ok(20)
-    } else if book == &BibleBook::Psalm && *chapter == 50 {
-        Ok(23)
-    } else if book == &BibleBook::Psalm && *chapter == 51 {
-        Ok(19)
-    } else if book == &BibleBook::Psalm && *chapter == 52 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 53 {
-        Ok(6)
-    } else if book == &BibleBook::Psalm && *chapter == 54 {
-        Ok(7)
-    } else if book == &BibleBook::Psalm && *chapter == 55 {
-        Ok(23)
-    } else if book == &BibleBook::Psalm && *chapter == 56 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 57 {
-        Ok(11)
-    } else if book == &BibleBook::Psalm && *chapter == 58 {
-        Ok(11)
-    } else if book == &BibleBook::Psalm && *chapter == 59 {
-        Ok(17)
-    } else if book == &BibleBook::Psalm && *chapter == 60 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 61 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 62 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 63 {
-        Ok(11)
-    } else if book == &BibleBook::Psalm && *chapter == 64 {
-        Ok(10)
-    } else if book == &BibleBook::Psalm && *chapter == 65 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 66 {
-        Ok(20)
-    } else if book == &BibleBook::Psalm && *chapter == 67 {
-        Ok(7)
-    } else if book == &BibleBook::Psalm && *chapter == 68 {
-        Ok(35)
-    } else if book == &BibleBook::Psalm && *chapter == 69 {
-        Ok(36)
-    } else if book == &BibleBook::Psalm && *chapter == 70 {
-        Ok(5)
-    } else if book == &BibleBook::Psalm && *chapter == 71 {
-        Ok(24)
-    } else if book == &BibleBook::Psalm && *chapter == 72 {
-        Ok(20)
-    } else if book == &BibleBook::Psalm && *chapter == 73 {
-        Ok(28)
-    } else if book == &BibleBook::Psalm && *chapter == 74 {
-        Ok(23)
-    } else if book == &BibleBook::Psalm && *chapter == 75 {
-        Ok(10)
-    } else if book == &BibleBook::Psalm && *chapter == 76 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 77 {
-        Ok(20)
-    } else if book == &BibleBook::Psalm && *chapter == 78 {
-        Ok(72)
-    } else if book == &BibleBook::Psalm && *chapter == 79 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 80 {
-        Ok(19)
-    } else if book == &BibleBook::Psalm && *chapter == 81 {
-        Ok(16)
-    } else if book == &BibleBook::Psalm && *chapter == 82 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 83 {
-        Ok(18)
-    } else if book == &BibleBook::Psalm && *chapter == 84 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 85 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 86 {
-        Ok(17)
-    } else if book == &BibleBook::Psalm && *chapter == 87 {
-        Ok(7)
-    } else if book == &BibleBook::Psalm && *chapter == 88 {
-        Ok(18)
-    } else if book == &BibleBook::Psalm && *chapter == 89 {
-        Ok(52)
-    } else if book == &BibleBook::Psalm && *chapter == 90 {
-        Ok(17)
-    } else if book == &BibleBook::Psalm && *chapter == 91 {
-        Ok(16)
-    } else if book == &BibleBook::Psalm && *chapter == 92 {
-        Ok(15)
-    } else if book == &BibleBook::Psalm && *chapter == 93 {
-        Ok(5)
-    } else if book == &BibleBook::Psalm && *chapter == 94 {
-        Ok(23)
-    } else if book == &BibleBook::Psalm && *chapter == 95 {
-        Ok(11)
-    } else if book == &BibleBook::Psalm && *chapter == 96 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 97 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 98 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 99 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 100 {
-        Ok(5)
-    } else if book == &BibleBook::Psalm && *chapter == 101 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 102 {
-        Ok(28)
-    } else if book == &BibleBook::Psalm && *chapter == 103 {
-        Ok(22)
-    } else if book == &BibleBook::Psalm && *chapter == 104 {
-        Ok(35)
-    } else if book == &BibleBook::Psalm && *chapter == 105 {
-        Ok(45)
-    } else if book == &BibleBook::Psalm && *chapter == 106 {
-        Ok(48)
-    } else if book == &BibleBook::Psalm && *chapter == 107 {
-        Ok(43)
-    } else if book == &BibleBook::Psalm && *chapter == 108 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 109 {
-        Ok(31)
-    } else if book == &BibleBook::Psalm && *chapter == 110 {
-        Ok(7)
-    } else if book == &BibleBook::Psalm && *chapter == 111 {
-        Ok(10)
-    } else if book == &BibleBook::Psalm && *chapter == 112 {
-        Ok(10)
-    } else if book == &BibleBook::Psalm && *chapter == 113 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 114 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 115 {
-        Ok(18)
-    } else if book == &BibleBook::Psalm && *chapter == 116 {
-        Ok(19)
-    } else if book == &BibleBook::Psalm && *chapter == 117 {
-        Ok(2)
-    } else if book == &BibleBook::Psalm && *chapter == 118 {
-        Ok(29)
-    } else if book == &BibleBook::Psalm && *chapter == 119 {
-        Ok(176)
-    } else if book == &BibleBook::Psalm && *chapter == 120 {
-        Ok(7)
-    } else if book == &BibleBook::Psalm && *chapter == 121 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 122 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 123 {
-        Ok(4)
-    } else if book == &BibleBook::Psalm && *chapter == 124 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 125 {
-        Ok(5)
-    } else if book == &BibleBook::Psalm && *chapter == 126 {
-        Ok(6)
-    } else if book == &BibleBook::Psalm && *chapter == 127 {
-        Ok(5)
-    } else if book == &BibleBook::Psalm && *chapter == 128 {
-        Ok(6)
-    } else if book == &BibleBook::Psalm && *chapter == 129 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 130 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 131 {
-        Ok(3)
-    } else if book == &BibleBook::Psalm && *chapter == 132 {
-        Ok(18)
-    } else if book == &BibleBook::Psalm && *chapter == 133 {
-        Ok(3)
-    } else if book == &BibleBook::Psalm && *chapter == 134 {
-        Ok(3)
-    } else if book == &BibleBook::Psalm && *chapter == 135 {
-        Ok(21)
-    } else if book == &BibleBook::Psalm && *chapter == 136 {
-        Ok(26)
-    } else if book == &BibleBook::Psalm && *chapter == 137 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 138 {
-        Ok(8)
-    } else if book == &BibleBook::Psalm && *chapter == 139 {
-        Ok(24)
-    } else if book == &BibleBook::Psalm && *chapter == 140 {
-        Ok(13)
-    } else if book == &BibleBook::Psalm && *chapter == 141 {
-        Ok(10)
-    } else if book == &BibleBook::Psalm && *chapter == 142 {
-        Ok(7)
-    } else if book == &BibleBook::Psalm && *chapter == 143 {
-        Ok(12)
-    } else if book == &BibleBook::Psalm && *chapter == 144 {
-        Ok(15)
-    } else if book == &BibleBook::Psalm && *chapter == 145 {
-        Ok(21)
-    } else if book == &BibleBook::Psalm && *chapter == 146 {
-        Ok(10)
-    } else if book == &BibleBook::Psalm && *chapter == 147 {
-        Ok(20)
-    } else if book == &BibleBook::Psalm && *chapter == 148 {
-        Ok(14)
-    } else if book == &BibleBook::Psalm && *chapter == 149 {
-        Ok(9)
-    } else if book == &BibleBook::Psalm && *chapter == 150 {
-        Ok(6)
-    } else if book == &BibleBook::Proverbs && *chapter == 1 {
-        Ok(33)
-    } else if book == &BibleBook::Proverbs && *chapter == 2 {
-        Ok(22)
-    } else if book == &BibleBook::Proverbs && *chapter == 3 {
-        Ok(35)
-    } else if book == &BibleBook::Proverbs && *chapter == 4 {
-        Ok(27)
-    } else if book == &BibleBook::Proverbs && *chapter == 5 {
-        Ok(23)
-    } else if book == &BibleBook::Proverbs && *chapter == 6 {
-        Ok(35)
-    } else if book == &BibleBook::Proverbs && *chapter == 7 {
-        Ok(27)
-    } else if book == &BibleBook::Proverbs && *chapter == 8 {
-        Ok(36)
-    } else if book == &BibleBook::Proverbs && *chapter == 9 {
-        Ok(18)
-    } else if book == &BibleBook::Proverbs && *chapter == 10 {
-        Ok(32)
-    } else if book == &BibleBook::Proverbs && *chapter == 11 {
-        Ok(31)
-    } else if book == &BibleBook::Proverbs && *chapter == 12 {
-        Ok(28)
-    } else if book == &BibleBook::Proverbs && *chapter == 13 {
-        Ok(25)
-    } else if book == &BibleBook::Proverbs && *chapter == 14 {
-        Ok(35)
-    } else if book == &BibleBook::Proverbs && *chapter == 15 {
-        Ok(33)
-    } else if book == &BibleBook::Proverbs && *chapter == 16 {
-        Ok(33)
-    } else if book == &BibleBook::Proverbs && *chapter == 17 {
-        Ok(28)
-    } else if book == &BibleBook::Proverbs && *chapter == 18 {
-        Ok(24)
-    } else if book == &BibleBook::Proverbs && *chapter == 19 {
-        Ok(29)
-    } else if book == &BibleBook::Proverbs && *chapter == 20 {
-        Ok(30)
-    } else if book == &BibleBook::Proverbs && *chapter == 21 {
-        Ok(31)
-    } else if book == &BibleBook::Proverbs && *chapter == 22 {
-        Ok(29)
-    } else if book == &BibleBook::Proverbs && *chapter == 23 {
-        Ok(35)
-    } else if book == &BibleBook::Proverbs && *chapter == 24 {
-        Ok(34)
-    } else if book == &BibleBook::Proverbs && *chapter == 25 {
-        Ok(28)
-    } else if book == &BibleBook::Proverbs && *chapter == 26 {
-        Ok(28)
-    } else if book == &BibleBook::Proverbs && *chapter == 27 {
-        Ok(27)
-    } else if book == &BibleBook::Proverbs && *chapter == 28 {
-        Ok(28)
-    } else if book == &BibleBook::Proverbs && *chapter == 29 {
-        Ok(27)
-    } else if book == &BibleBook::Proverbs && *chapter == 30 {
-        Ok(33)
-    } else if book == &BibleBook::Proverbs && *chapter == 31 {
-        Ok(31)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 1 {
-        Ok(18)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 2 {
-        Ok(26)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 3 {
-        Ok(22)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 4 {
-        Ok(16)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 5 {
-        Ok(20)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 6 {
-        Ok(12)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 7 {
-        Ok(29)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 8 {
-        Ok(17)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 9 {
-        Ok(18)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 10 {
-        Ok(20)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 11 {
-        Ok(10)
-    } else if book == &BibleBook::Ecclesiastes && *chapter == 12 {
-        Ok(14)
-    } else if book == &BibleBook::SongofSolomon && *chapter == 1 {
-        Ok(17)
-    } else if book == &BibleBook::SongofSolomon && *chapter == 2 {
-        Ok(17)
-    } else if book == &BibleBook::SongofSolomon && *chapter == 3 {
-        Ok(11)
-    } else if book == &BibleBook::SongofSolomon && *chapter == 4 {
-        Ok(16)
-    } else if book == &BibleBook::SongofSolomon && *chapter == 5 {
-        Ok(16)
-    } else if book == &BibleBook::SongofSolomon && *chapter == 6 {
-        Ok(13)
-    } else if book == &BibleBook::SongofSolomon && *chapter == 7 {
-        Ok(13)
-    } else if book == &BibleBook::SongofSolomon && *chapter == 8 {
-        Ok(14)
-    } else if book == &BibleBook::Isaiah && *chapter == 1 {
-        Ok(31)
-    } else if book == &BibleBook::Isaiah && *chapter == 2 {
-        Ok(22)
-    } else if book == &BibleBook::Isaiah && *chapter == 3 {
-        Ok(26)
-    } else if book == &BibleBook::Isaiah && *chapter == 4 {
-        Ok(6)
-    } else if book == &BibleBook::Isaiah && *chapter == 5 {
-        Ok(30)
-    } else if book == &BibleBook::Isaiah && *chapter == 6 {
-        Ok(13)
-    } else if book == &BibleBook::Isaiah && *chapter == 7 {
-        Ok(25)
-    } else if book == &BibleBook::Isaiah && *chapter == 8 {
-        Ok(22)
-    } else if book == &BibleBook::Isaiah && *chapter == 9 {
-        Ok(21)
-    } else if book == &BibleBook::Isaiah && *chapter == 10 {
-        Ok(34)
-    } else if book == &BibleBook::Isaiah && *chapter == 11 {
-        Ok(16)
-    } else if book == &BibleBook::Isaiah && *chapter == 12 {
-        Ok(6)
-    } else if book == &BibleBook::Isaiah && *chapter == 13 {
-        Ok(22)
-    } else if book == &BibleBook::Isaiah && *chapter == 14 {
-        Ok(32)
-    } else if book == &BibleBook::Isaiah && *chapter == 15 {
-        Ok(9)
-    } else if book == &BibleBook::Isaiah && *chapter == 16 {
-        Ok(14)
-    } else if book == &BibleBook::Isaiah && *chapter == 17 {
-        Ok(14)
-    } else if book == &BibleBook::Isaiah && *chapter == 18 {
-        Ok(7)
-    } else if book == &BibleBook::Isaiah && *chapter == 19 {
-        Ok(25)
-    } else if book == &BibleBook::Isaiah && *chapter == 20 {
-        Ok(6)
-    } else if book == &BibleBook::Isaiah && *chapter == 21 {
-        Ok(17)
-    } else if book == &BibleBook::Isaiah && *chapter == 22 {
-        Ok(25)
-    } else if book == &BibleBook::Isaiah && *chapter == 23 {
-        Ok(18)
-    } else if book == &BibleBook::Isaiah && *chapter == 24 {
-        Ok(23)
-    } else if book == &BibleBook::Isaiah && *chapter == 25 {
-        Ok(12)
-    } else if book == &BibleBook::Isaiah && *chapter == 26 {
-        Ok(21)
-    } else if book == &BibleBook::Isaiah && *chapter == 27 {
-        Ok(13)
-    } else if book == &BibleBook::Isaiah && *chapter == 28 {
-        Ok(29)
-    } else if book == &BibleBook::Isaiah && *chapter == 29 {
-        Ok(24)
-    } else if book == &BibleBook::Isaiah && *chapter == 30 {
-        Ok(33)
-    } else if book == &BibleBook::Isaiah && *chapter == 31 {
-        Ok(9)
-    } else if book == &BibleBook::Isaiah && *chapter == 32 {
-        Ok(20)
-    } else if book == &BibleBook::Isaiah && *chapter == 33 {
-        Ok(24)
-    } else if book == &BibleBook::Isaiah && *chapter == 34 {
-        Ok(17)
-    } else if book == &BibleBook::Isaiah && *chapter == 35 {
-        Ok(10)
-    } else if book == &BibleBook::Isaiah && *chapter == 36 {
-        Ok(22)
-    } else if book == &BibleBook::Isaiah && *chapter == 37 {
-        Ok(38)
-    } else if book == &BibleBook::Isaiah && *chapter == 38 {
-        Ok(22)
-    } else if book == &BibleBook::Isaiah && *chapter == 39 {
-        Ok(8)
-    } else if book == &BibleBook::Isaiah && *chapter == 40 {
-        Ok(31)
-    } else if book == &BibleBook::Isaiah && *chapter == 41 {
-        Ok(29)
-    } else if book == &BibleBook::Isaiah && *chapter == 42 {
-        Ok(25)
-    } else if book == &BibleBook::Isaiah && *chapter == 43 {
-        Ok(28)
-    } else if book == &BibleBook::Isaiah && *chapter == 44 {
-        Ok(28)
-    } else if book == &BibleBook::Isaiah && *chapter == 45 {
-        Ok(25)
-    } else if book == &BibleBook::Isaiah && *chapter == 46 {
-        Ok(13)
-    } else if book == &BibleBook::Isaiah && *chapter == 47 {
-        Ok(15)
-    } else if book == &BibleBook::Isaiah && *chapter == 48 {
-        Ok(22)
-    } else if book == &BibleBook::Isaiah && *chapter == 49 {
-        Ok(26)
-    } else if book == &BibleBook::Isaiah && *chapter == 50 {
-        Ok(11)
-    } else if book == &BibleBook::Isaiah && *chapter == 51 {
-        Ok(23)
-    } else if book == &BibleBook::Isaiah && *chapter == 52 {
-        Ok(15)
-    } else if book == &BibleBook::Isaiah && *chapter == 53 {
-        Ok(12)
-    } else if book == &BibleBook::Isaiah && *chapter == 54 {
-        Ok(17)
-    } else if book == &BibleBook::Isaiah && *chapter == 55 {
-        Ok(13)
-    } else if book == &BibleBook::Isaiah && *chapter == 56 {
-        Ok(12)
-    } else if book == &BibleBook::Isaiah && *chapter == 57 {
-        Ok(21)
-    } else if book == &BibleBook::Isaiah && *chapter == 58 {
-        Ok(14)
-    } else if book == &BibleBook::Isaiah && *chapter == 59 {
-        Ok(21)
-    } else if book == &BibleBook::Isaiah && *chapter == 60 {
-        Ok(22)
-    } else if book == &BibleBook::Isaiah && *chapter == 61 {
-        Ok(11)
-    } else if book == &BibleBook::Isaiah && *chapter == 62 {
-        Ok(12)
-    } else if book == &BibleBook::Isaiah && *chapter == 63 {
-        Ok(19)
-    } else if book == &BibleBook::Isaiah && *chapter == 64 {
-        Ok(12)
-    } else if book == &BibleBook::Isaiah && *chapter == 65 {
-        Ok(25)
-    } else if book == &BibleBook::Isaiah && *chapter == 66 {
-        Ok(24)
-    } else if book == &BibleBook::Jeremiah && *chapter == 1 {
-        Ok(19)
-    } else if book == &BibleBook::Jeremiah && *chapter == 2 {
-        Ok(37)
-    } else if book == &BibleBook::Jeremiah && *chapter == 3 {
-        Ok(25)
-    } else if book == &BibleBook::Jeremiah && *chapter == 4 {
-        Ok(31)
-    } else if book == &BibleBook::Jeremiah && *chapter == 5 {
-        Ok(31)
-    } else if book == &BibleBook::Jeremiah && *chapter == 6 {
-        Ok(30)
-    } else if book == &BibleBook::Jeremiah && *chapter == 7 {
-        Ok(34)
-    } else if book == &BibleBook::Jeremiah && *chapter == 8 {
-        Ok(22)
-    } else if book == &BibleBook::Jeremiah && *chapter == 9 {
-        Ok(26)
-    } else if book == &BibleBook::Jeremiah && *chapter == 10 {
-        Ok(25)
-    } else if book == &BibleBook::Jeremiah && *chapter == 11 {
-        Ok(23)
-    } else if book == &BibleBook::Jeremiah && *chapter == 12 {
-        Ok(17)
-    } else if book == &BibleBook::Jeremiah && *chapter == 13 {
-        Ok(27)
-    } else if book == &BibleBook::Jeremiah && *chapter == 14 {
-        Ok(22)
-    } else if book == &BibleBook::Jeremiah && *chapter == 15 {
-        Ok(21)
-    } else if book == &BibleBook::Jeremiah && *chapter == 16 {
-        Ok(21)
-    } else if book == &BibleBook::Jeremiah && *chapter == 17 {
-        Ok(27)
-    } else if book == &BibleBook::Jeremiah && *chapter == 18 {
-        Ok(23)
-    } else if book == &BibleBook::Jeremiah && *chapter == 19 {
-        Ok(15)
-    } else if book == &BibleBook::Jeremiah && *chapter == 20 {
-        Ok(18)
-    } else if book == &BibleBook::Jeremiah && *chapter == 21 {
-        Ok(14)
-    } else if book == &BibleBook::Jeremiah && *chapter == 22 {
-        Ok(30)
-    } else if book == &BibleBook::Jeremiah && *chapter == 23 {
-        Ok(40)
-    } else if book == &BibleBook::Jeremiah && *chapter == 24 {
-        Ok(10)
-    } else if book == &BibleBook::Jeremiah && *chapter == 25 {
-        Ok(38)
-    } else if book == &BibleBook::Jeremiah && *chapter == 26 {
-        Ok(24)
-    } else if book == &BibleBook::Jeremiah && *chapter == 27 {
-        Ok(22)
-    } else if book == &BibleBook::Jeremiah && *chapter == 28 {
-        Ok(17)
-    } else if book == &BibleBook::Jeremiah && *chapter == 29 {
-        Ok(32)
-    } else if book == &BibleBook::Jeremiah && *chapter == 30 {
-        Ok(24)
-    } else if book == &BibleBook::Jeremiah && *chapter == 31 {
-        Ok(40)
-    } else if book == &BibleBook::Jeremiah && *chapter == 32 {
-        Ok(44)
-    } else if book == &BibleBook::Jeremiah && *chapter == 33 {
-        Ok(26)
-    } else if book == &BibleBook::Jeremiah && *chapter == 34 {
-        Ok(22)
-    } else if book == &BibleBook::Jeremiah && *chapter == 35 {
-        Ok(19)
-    } else if book == &BibleBook::Jeremiah && *chapter == 36 {
-        Ok(32)
-    } else if book == &BibleBook::Jeremiah && *chapter == 37 {
-        Ok(21)
-    } else if book == &BibleBook::Jeremiah && *chapter == 38 {
-        Ok(28)
-    } else if book == &BibleBook::Jeremiah && *chapter == 39 {
-        Ok(18)
-    } else if book == &BibleBook::Jeremiah && *chapter == 40 {
-        Ok(16)
-    } else if book == &BibleBook::Jeremiah && *chapter == 41 {
-        Ok(18)
-    } else if book == &BibleBook::Jeremiah && *chapter == 42 {
-        Ok(22)
-    } else if book == &BibleBook::Jeremiah && *chapter == 43 {
-        Ok(13)
-    } else if book == &BibleBook::Jeremiah && *chapter == 44 {
-        Ok(30)
-    } else if book == &BibleBook::Jeremiah && *chapter == 45 {
-        Ok(5)
-    } else if book == &BibleBook::Jeremiah && *chapter == 46 {
-        Ok(28)
-    } else if book == &BibleBook::Jeremiah && *chapter == 47 {
-        Ok(7)
-    } else if book == &BibleBook::Jeremiah && *chapter == 48 {
-        Ok(47)
-    } else if book == &BibleBook::Jeremiah && *chapter == 49 {
-        Ok(39)
-    } else if book == &BibleBook::Jeremiah && *chapter == 50 {
-        Ok(46)
-    } else if book == &BibleBook::Jeremiah && *chapter == 51 {
-        Ok(64)
-    } else if book == &BibleBook::Jeremiah && *chapter == 52 {
-        Ok(34)
-    } else if book == &BibleBook::Lamentations && *chapter == 1 {
-        Ok(22)
-    } else if book == &BibleBook::Lamentations && *chapter == 2 {
-        Ok(22)
-    } else if book == &BibleBook::Lamentations && *chapter == 3 {
-        Ok(66)
-    } else if book == &BibleBook::Lamentations && *chapter == 4 {
-        Ok(22)
-    } else if book == &BibleBook::Lamentations && *chapter == 5 {
-        Ok(22)
-    } else if book == &BibleBook::Ezekiel && *chapter == 1 {
-        Ok(28)
-    } else if book == &BibleBook::Ezekiel && *chapter == 2 {
-        Ok(10)
-    } else if book == &BibleBook::Ezekiel && *chapter == 3 {
-        Ok(27)
-    } else if book == &BibleBook::Ezekiel && *chapter == 4 {
-        Ok(17)
-    } else if book == &BibleBook::Ezekiel && *chapter == 5 {
-        Ok(17)
-    } else if book == &BibleBook::Ezekiel && *chapter == 6 {
-        Ok(14)
-    } else if book == &BibleBook::Ezekiel && *chapter == 7 {
-        Ok(27)
-    } else if book == &BibleBook::Ezekiel && *chapter == 8 {
-        Ok(18)
-    } else if book == &BibleBook::Ezekiel && *chapter == 9 {
-        Ok(11)
-    } else if book == &BibleBook::Ezekiel && *chapter == 10 {
-        Ok(22)
-    } else if book == &BibleBook::Ezekiel && *chapter == 11 {
-        Ok(25)
-    } else if book == &BibleBook::Ezekiel && *chapter == 12 {
-        Ok(28)
-    } else if book == &BibleBook::Ezekiel && *chapter == 13 {
-        Ok(23)
-    } else if book == &BibleBook::Ezekiel && *chapter == 14 {
-        Ok(23)
-    } else if book == &BibleBook::Ezekiel && *chapter == 15 {
-        Ok(8)
-    } else if book == &BibleBook::Ezekiel && *chapter == 16 {
-        Ok(63)
-    } else if book == &BibleBook::Ezekiel && *chapter == 17 {
-        Ok(24)
-    } else if book == &BibleBook::Ezekiel && *chapter == 18 {
-        Ok(32)
-    } else if book == &BibleBook::Ezekiel && *chapter == 19 {
-        Ok(14)
-    } else if book == &BibleBook::Ezekiel && *chapter == 20 {
-        Ok(49)
-    } else if book == &BibleBook::Ezekiel && *chapter == 21 {
-        Ok(32)
-    } else if book == &BibleBook::Ezekiel && *chapter == 22 {
-        Ok(31)
-    } else if book == &BibleBook::Ezekiel && *chapter == 23 {
-        Ok(49)
-    } else if book == &BibleBook::Ezekiel && *chapter == 24 {
-        Ok(27)
-    } else if book == &BibleBook::Ezekiel && *chapter == 25 {
-        Ok(17)
-    } else if book == &BibleBook::Ezekiel && *chapter == 26 {
-        Ok(21)
-    } else if book == &BibleBook::Ezekiel && *chapter == 27 {
-        Ok(36)
-    } else if book == &BibleBook::Ezekiel && *chapter == 28 {
-        Ok(26)
-    } else if book == &BibleBook::Ezekiel && *chapter == 29 {
-        Ok(21)
-    } else if book == &BibleBook::Ezekiel && *chapter == 30 {
-        Ok(26)
-    } else if book == &BibleBook::Ezekiel && *chapter == 31 {
-        Ok(18)
-    } else if book == &BibleBook::Ezekiel && *chapter == 32 {
-        Ok(32)
-    } else if book == &BibleBook::Ezekiel && *chapter == 33 {
-        Ok(33)
-    } else if book == &BibleBook::Ezekiel && *chapter == 34 {
-        Ok(31)
-    } else if book == &BibleBook::Ezekiel && *chapter == 35 {
-        Ok(15)
-    } else if book == &BibleBook::Ezekiel && *chapter == 36 {
-        Ok(38)
-    } else if book == &BibleBook::Ezekiel && *chapter == 37 {
-        Ok(28)
-    } else if book == &BibleBook::Ezekiel && *chapter == 38 {
-        Ok(23)
-    } else if book == &BibleBook::Ezekiel && *chapter == 39 {
-        Ok(29)
-    } else if book == &BibleBook::Ezekiel && *chapter == 40 {
-        Ok(49)
-    } else if book == &BibleBook::Ezekiel && *chapter == 41 {
-        Ok(26)
-    } else if book == &BibleBook::Ezekiel && *chapter == 42 {
-        Ok(20)
-    } else if book == &BibleBook::Ezekiel && *chapter == 43 {
-        Ok(27)
-    } else if book == &BibleBook::Ezekiel && *chapter == 44 {
-        Ok(31)
-    } else if book == &BibleBook::Ezekiel && *chapter == 45 {
-        Ok(25)
-    } else if book == &BibleBook::Ezekiel && *chapter == 46 {
-        Ok(24)
-    } else if book == &BibleBook::Ezekiel && *chapter == 47 {
-        Ok(23)
-    } else if book == &BibleBook::Ezekiel && *chapter == 48 {
-        Ok(35)
-    } else if book == &BibleBook::Daniel && *chapter == 1 {
-        Ok(21)
-    } else if book == &BibleBook::Daniel && *chapter == 2 {
-        Ok(49)
-    } else if book == &BibleBook::Daniel && *chapter == 3 {
-        Ok(30)
-    } else if book == &BibleBook::Daniel && *chapter == 4 {
-        Ok(37)
-    } else if book == &BibleBook::Daniel && *chapter == 5 {
-        Ok(31)
-    } else if book == &BibleBook::Daniel && *chapter == 6 {
-        Ok(28)
-    } else if book == &BibleBook::Daniel && *chapter == 7 {
-        Ok(28)
-    } else if book == &BibleBook::Daniel && *chapter == 8 {
-        Ok(27)
-    } else if book == &BibleBook::Daniel && *chapter == 9 {
-        Ok(27)
-    } else if book == &BibleBook::Daniel && *chapter == 10 {
-        Ok(21)
-    } else if book == &BibleBook::Daniel && *chapter == 11 {
-        Ok(45)
-    } else if book == &BibleBook::Daniel && *chapter == 12 {
-        Ok(13)
-    } else if book == &BibleBook::Hosea && *chapter == 1 {
-        Ok(11)
-    } else if book == &BibleBook::Hosea && *chapter == 2 {
-        Ok(23)
-    } else if book == &BibleBook::Hosea && *chapter == 3 {
-        Ok(5)
-    } else if book == &BibleBook::Hosea && *chapter == 4 {
-        Ok(19)
-    } else if book == &BibleBook::Hosea && *chapter == 5 {
-        Ok(15)
-    } else if book == &BibleBook::Hosea && *chapter == 6 {
-        Ok(11)
-    } else if book == &BibleBook::Hosea && *chapter == 7 {
-        Ok(16)
-    } else if book == &BibleBook::Hosea && *chapter == 8 {
-        Ok(14)
-    } else if book == &BibleBook::Hosea && *chapter == 9 {
-        Ok(17)
-    } else if book == &BibleBook::Hosea && *chapter == 10 {
-        Ok(15)
-    } else if book == &BibleBook::Hosea && *chapter == 11 {
-        Ok(12)
-    } else if book == &BibleBook::Hosea && *chapter == 12 {
-        Ok(14)
-    } else if book == &BibleBook::Hosea && *chapter == 13 {
-        Ok(16)
-    } else if book == &BibleBook::Hosea && *chapter == 14 {
-        Ok(9)
-    } else if book == &BibleBook::Joel && *chapter == 1 {
-        Ok(20)
-    } else if book == &BibleBook::Joel && *chapter == 2 {
-        Ok(32)
-    } else if book == &BibleBook::Joel && *chapter == 3 {
-        Ok(21)
-    } else if book == &BibleBook::Amos && *chapter == 1 {
-        Ok(15)
-    } else if book == &BibleBook::Amos && *chapter == 2 {
-        Ok(16)
-    } else if book == &BibleBook::Amos && *chapter == 3 {
-        Ok(15)
-    } else if book == &BibleBook::Amos && *chapter == 4 {
-        Ok(13)
-    } else if book == &BibleBook::Amos && *chapter == 5 {
-        Ok(27)
-    } else if book == &BibleBook::Amos && *chapter == 6 {
-        Ok(14)
-    } else if book == &BibleBook::Amos && *chapter == 7 {
-        Ok(17)
-    } else if book == &BibleBook::Amos && *chapter == 8 {
-        Ok(14)
-    } else if book == &BibleBook::Amos && *chapter == 9 {
-        Ok(15)
-    } else if book == &BibleBook::Obadiah && *chapter == 1 {
-        Ok(21)
-    } else if book == &BibleBook::Jonah && *chapter == 1 {
-        Ok(17)
-    } else if book == &BibleBook::Jonah && *chapter == 2 {
-        Ok(10)
-    } else if book == &BibleBook::Jonah && *chapter == 3 {
-        Ok(10)
-    } else if book == &BibleBook::Jonah && *chapter == 4 {
-        Ok(11)
-    } else if book == &BibleBook::Micah && *chapter == 1 {
-        Ok(16)
-    } else if book == &BibleBook::Micah && *chapter == 2 {
-        Ok(13)
-    } else if book == &BibleBook::Micah && *chapter == 3 {
-        Ok(12)
-    } else if book == &BibleBook::Micah && *chapter == 4 {
-        Ok(13)
-    } else if book == &BibleBook::Micah && *chapter == 5 {
-        Ok(15)
-    } else if book == &BibleBook::Micah && *chapter == 6 {
-        Ok(16)
-    } else if book == &BibleBook::Micah && *chapter == 7 {
-        Ok(20)
-    } else if book == &BibleBook::Nahum && *chapter == 1 {
-        Ok(15)
-    } else if book == &BibleBook::Nahum && *chapter == 2 {
-        Ok(13)
-    } else if book == &BibleBook::Nahum && *chapter == 3 {
-        Ok(19)
-    } else if book == &BibleBook::Habakkuk && *chapter == 1 {
-        Ok(17)
-    } else if book == &BibleBook::Habakkuk && *chapter == 2 {
-        Ok(20)
-    } else if book == &BibleBook::Habakkuk && *chapter == 3 {
-        Ok(19)
-    } else if book == &BibleBook::Zephaniah && *chapter == 1 {
-        Ok(18)
-    } else if book == &BibleBook::Zephaniah && *chapter == 2 {
-        Ok(15)
-    } else if book == &BibleBook::Zephaniah && *chapter == 3 {
-        Ok(20)
-    } else if book == &BibleBook::Haggai && *chapter == 1 {
-        Ok(15)
-    } else if book == &BibleBook::Haggai && *chapter == 2 {
-        Ok(23)
-    } else if book == &BibleBook::Zechariah && *chapter == 1 {
-        Ok(21)
-    } else if book == &BibleBook::Zechariah && *chapter == 2 {
-        Ok(13)
-    } else if book == &BibleBook::Zechariah && *chapter == 3 {
-        Ok(10)
-    } else if book == &BibleBook::Zechariah && *chapter == 4 {
-        Ok(14)
-    } else if book == &BibleBook::Zechariah && *chapter == 5 {
-        Ok(11)
-    } else if book == &BibleBook::Zechariah && *chapter == 6 {
-        Ok(15)
-    } else if book == &BibleBook::Zechariah && *chapter == 7 {
-        Ok(14)
-    } else if book == &BibleBook::Zechariah && *chapter == 8 {
-        Ok(23)
-    } else if book == &BibleBook::Zechariah && *chapter == 9 {
-        Ok(17)
-    } else if book == &BibleBook::Zechariah && *chapter == 10 {
-        Ok(12)
-    } else if book == &BibleBook::Zechariah && *chapter == 11 {
-        Ok(17)
-    } else if book == &BibleBook::Zechariah && *chapter == 12 {
-        Ok(14)
-    } else if book == &BibleBook::Zechariah && *chapter == 13 {
-        Ok(9)
-    } else if book == &BibleBook::Zechariah && *chapter == 14 {
-        Ok(21)
-    } else if book == &BibleBook::Malachi && *chapter == 1 {
-        Ok(14)
-    } else if book == &BibleBook::Malachi && *chapter == 2 {
-        Ok(17)
-    } else if book == &BibleBook::Malachi && *chapter == 3 {
-        Ok(18)
-    } else if book == &BibleBook::Malachi && *chapter == 4 {
-        Ok(6)
-    } else if book == &BibleBook::Matthew && *chapter == 1 {
-        Ok(25)
-    } else if book == &BibleBook::Matthew && *chapter == 2 {
-        Ok(23)
-    } else if book == &BibleBook::Matthew && *chapter == 3 {
-        Ok(17)
-    } else if book == &BibleBook::Matthew && *chapter == 4 {
-        Ok(25)
-    } else if book == &BibleBook::Matthew && *chapter == 5 {
-        Ok(48)
-    } else if book == &BibleBook::Matthew && *chapter == 6 {
-        Ok(34)
-    } else if book == &BibleBook::Matthew && *chapter == 7 {
-        Ok(29)
-    } else if book == &BibleBook::Matthew && *chapter == 8 {
-        Ok(34)
-    } else if book == &BibleBook::Matthew && *chapter == 9 {
-        Ok(38)
-    } else if book == &BibleBook::Matthew && *chapter == 10 {
-        Ok(42)
-    } else if book == &BibleBook::Matthew && *chapter == 11 {
-        Ok(30)
-    } else if book == &BibleBook::Matthew && *chapter == 12 {
-        Ok(50)
-    } else if book == &BibleBook::Matthew && *chapter == 13 {
-        Ok(58)
-    } else if book == &BibleBook::Matthew && *chapter == 14 {
-        Ok(36)
-    } else if book == &BibleBook::Matthew && *chapter == 15 {
-        Ok(39)
-    } else if book == &BibleBook::Matthew && *chapter == 16 {
-        Ok(28)
-    } else if book == &BibleBook::Matthew && *chapter == 17 {
-        Ok(27)
-    } else if book == &BibleBook::Matthew && *chapter == 18 {
-        Ok(35)
-    } else if book == &BibleBook::Matthew && *chapter == 19 {
-        Ok(30)
-    } else if book == &BibleBook::Matthew && *chapter == 20 {
-        Ok(34)
-    } else if book == &BibleBook::Matthew && *chapter == 21 {
-        Ok(46)
-    } else if book == &BibleBook::Matthew && *chapter == 22 {
-        Ok(46)
-    } else if book == &BibleBook::Matthew && *chapter == 23 {
-        Ok(39)
-    } else if book == &BibleBook::Matthew && *chapter == 24 {
-        Ok(51)
-    } else if book == &BibleBook::Matthew && *chapter == 25 {
-        Ok(46)
-    } else if book == &BibleBook::Matthew && *chapter == 26 {
-        Ok(75)
-    } else if book == &BibleBook::Matthew && *chapter == 27 {
-        Ok(66)
-    } else if book == &BibleBook::Matthew && *chapter == 28 {
-        Ok(20)
-    } else if book == &BibleBook::Mark && *chapter == 1 {
-        Ok(45)
-    } else if book == &BibleBook::Mark && *chapter == 2 {
-        Ok(28)
-    } else if book == &BibleBook::Mark && *chapter == 3 {
-        Ok(35)
-    } else if book == &BibleBook::Mark && *chapter == 4 {
-        Ok(41)
-    } else if book == &BibleBook::Mark && *chapter == 5 {
-        Ok(43)
-    } else if book == &BibleBook::Mark && *chapter == 6 {
-        Ok(56)
-    } else if book == &BibleBook::Mark && *chapter == 7 {
-        Ok(37)
-    } else if book == &BibleBook::Mark && *chapter == 8 {
-        Ok(38)
-    } else if book == &BibleBook::Mark && *chapter == 9 {
-        Ok(50)
-    } else if book == &BibleBook::Mark && *chapter == 10 {
-        Ok(52)
-    } else if book == &BibleBook::Mark && *chapter == 11 {
-        Ok(33)
-    } else if book == &BibleBook::Mark && *chapter == 12 {
-        Ok(44)
-    } else if book == &BibleBook::Mark && *chapter == 13 {
-        Ok(37)
-    } else if book == &BibleBook::Mark && *chapter == 14 {
-        Ok(72)
-    } else if book == &BibleBook::Mark && *chapter == 15 {
-        Ok(47)
-    } else if book == &BibleBook::Mark && *chapter == 16 {
-        Ok(20)
-    } else if book == &BibleBook::Luke && *chapter == 1 {
-        Ok(80)
-    } else if book == &BibleBook::Luke && *chapter == 2 {
-        Ok(52)
-    } else if book == &BibleBook::Luke && *chapter == 3 {
-        Ok(38)
-    } else if book == &BibleBook::Luke && *chapter == 4 {
-        Ok(44)
-    } else if book == &BibleBook::Luke && *chapter == 5 {
-        Ok(39)
-    } else if book == &BibleBook::Luke && *chapter == 6 {
-        Ok(49)
-    } else if book == &BibleBook::Luke && *chapter == 7 {
-        Ok(50)
-    } else if book == &BibleBook::Luke && *chapter == 8 {
-        Ok(56)
-    } else if book == &BibleBook::Luke && *chapter == 9 {
-        Ok(62)
-    } else if book == &BibleBook::Luke && *chapter == 10 {
-        Ok(42)
-    } else if book == &BibleBook::Luke && *chapter == 11 {
-        Ok(54)
-    } else if book == &BibleBook::Luke && *chapter == 12 {
-        Ok(59)
-    } else if book == &BibleBook::Luke && *chapter == 13 {
-        Ok(35)
-    } else if book == &BibleBook::Luke && *chapter == 14 {
-        Ok(35)
-    } else if book == &BibleBook::Luke && *chapter == 15 {
-        Ok(32)
-    } else if book == &BibleBook::Luke && *chapter == 16 {
-        Ok(31)
-    } else if book == &BibleBook::Luke && *chapter == 17 {
-        Ok(37)
-    } else if book == &BibleBook::Luke && *chapter == 18 {
-        Ok(43)
-    } else if book == &BibleBook::Luke && *chapter == 19 {
-        Ok(48)
-    } else if book == &BibleBook::Luke && *chapter == 20 {
-        Ok(47)
-    } else if book == &BibleBook::Luke && *chapter == 21 {
-        Ok(38)
-    } else if book == &BibleBook::Luke && *chapter == 22 {
-        Ok(71)
-    } else if book == &BibleBook::Luke && *chapter == 23 {
-        Ok(56)
-    } else if book == &BibleBook::Luke && *chapter == 24 {
-        Ok(53)
-    } else if book == &BibleBook::John && *chapter == 1 {
-        Ok(51)
-    } else if book == &BibleBook::John && *chapter == 2 {
-        Ok(25)
-    } else if book == &BibleBook::John && *chapter == 3 {
-        Ok(36)
-    } else if book == &BibleBook::John && *chapter == 4 {
-        Ok(54)
-    } else if book == &BibleBook::John && *chapter == 5 {
-        Ok(47)
-    } else if book == &BibleBook::John && *chapter == 6 {
-        Ok(71)
-    } else if book == &BibleBook::John && *chapter == 7 {
-        Ok(53)
-    } else if book == &BibleBook::John && *chapter == 8 {
-        Ok(59)
-    } else if book == &BibleBook::John && *chapter == 9 {
-        Ok(41)
-    } else if book == &BibleBook::John && *chapter == 10 {
-        Ok(42)
-    } else if book == &BibleBook::John && *chapter == 11 {
-        Ok(57)
-    } else if book == &BibleBook::John && *chapter == 12 {
-        Ok(50)
-    } else if book == &BibleBook::John && *chapter == 13 {
-        Ok(38)
-    } else if book == &BibleBook::John && *chapter == 14 {
-        Ok(31)
-    } else if book == &BibleBook::John && *chapter == 15 {
-        Ok(27)
-    } else if book == &BibleBook::John && *chapter == 16 {
-        Ok(33)
-    } else if book == &BibleBook::John && *chapter == 17 {
-        Ok(26)
-    } else if book == &BibleBook::John && *chapter == 18 {
-        Ok(40)
-    } else if book == &BibleBook::John && *chapter == 19 {
-        Ok(42)
-    } else if book == &BibleBook::John && *chapter == 20 {
-        Ok(31)
-    } else if book == &BibleBook::John && *chapter == 21 {
-        Ok(25)
-    } else if book == &BibleBook::Acts && *chapter == 1 {
-        Ok(26)
-    } else if book == &BibleBook::Acts && *chapter == 2 {
-        Ok(47)
-    } else if book == &BibleBook::Acts && *chapter == 3 {
-        Ok(26)
-    } else if book == &BibleBook::Acts && *chapter == 4 {
-        Ok(37)
-    } else if book == &BibleBook::Acts && *chapter == 5 {
-        Ok(42)
-    } else if book == &BibleBook::Acts && *chapter == 6 {
-        Ok(15)
-    } else if book == &BibleBook::Acts && *chapter == 7 {
-        Ok(60)
-    } else if book == &BibleBook::Acts && *chapter == 8 {
-        Ok(40)
-    } else if book == &BibleBook::Acts && *chapter == 9 {
-        Ok(43)
-    } else if book == &BibleBook::Acts && *chapter == 10 {
-        Ok(48)
-    } else if book == &BibleBook::Acts && *chapter == 11 {
-        Ok(30)
-    } else if book == &BibleBook::Acts && *chapter == 12 {
-        Ok(25)
-    } else if book == &BibleBook::Acts && *chapter == 13 {
-        Ok(52)
-    } else if book == &BibleBook::Acts && *chapter == 14 {
-        Ok(28)
-    } else if book == &BibleBook::Acts && *chapter == 15 {
-        Ok(41)
-    } else if book == &BibleBook::Acts && *chapter == 16 {
-        Ok(40)
-    } else if book == &BibleBook::Acts && *chapter == 17 {
-        Ok(34)
-    } else if book == &BibleBook::Acts && *chapter == 18 {
-        Ok(28)
-    } else if book == &BibleBook::Acts && *chapter == 19 {
-        Ok(41)
-    } else if book == &BibleBook::Acts && *chapter == 20 {
-        Ok(38)
-    } else if book == &BibleBook::Acts && *chapter == 21 {
-        Ok(40)
-    } else if book == &BibleBook::Acts && *chapter == 22 {
-        Ok(30)
-    } else if book == &BibleBook::Acts && *chapter == 23 {
-        Ok(35)
-    } else if book == &BibleBook::Acts && *chapter == 24 {
-        Ok(27)
-    } else if book == &BibleBook::Acts && *chapter == 25 {
-        Ok(27)
-    } else if book == &BibleBook::Acts && *chapter == 26 {
-        Ok(32)
-    } else if book == &BibleBook::Acts && *chapter == 27 {
-        Ok(44)
-    } else if book == &BibleBook::Acts && *chapter == 28 {
-        Ok(31)
-    } else if book == &BibleBook::Romans && *chapter == 1 {
-        Ok(32)
-    } else if book == &BibleBook::Romans && *chapter == 2 {
-        Ok(29)
-    } else if book == &BibleBook::Romans && *chapter == 3 {
-        Ok(31)
-    } else if book == &BibleBook::Romans && *chapter == 4 {
-        Ok(25)
-    } else if book == &BibleBook::Romans && *chapter == 5 {
-        Ok(21)
-    } else if book == &BibleBook::Romans && *chapter == 6 {
-        Ok(23)
-    } else if book == &BibleBook::Romans && *chapter == 7 {
-        Ok(25)
-    } else if book == &BibleBook::Romans && *chapter == 8 {
-        Ok(39)
-    } else if book == &BibleBook::Romans && *chapter == 9 {
-        Ok(33)
-    } else if book == &BibleBook::Romans && *chapter == 10 {
-        Ok(21)
-    } else if book == &BibleBook::Romans && *chapter == 11 {
-        Ok(36)
-    } else if book == &BibleBook::Romans && *chapter == 12 {
-        Ok(21)
-    } else if book == &BibleBook::Romans && *chapter == 13 {
-        Ok(14)
-    } else if book == &BibleBook::Romans && *chapter == 14 {
-        Ok(23)
-    } else if book == &BibleBook::Romans && *chapter == 15 {
-        Ok(33)
-    } else if book == &BibleBook::Romans && *chapter == 16 {
-        Ok(27)
-    } else if book == &BibleBook::ICorinthians && *chapter == 1 {
-        Ok(31)
-    } else if book == &BibleBook::ICorinthians && *chapter == 2 {
-        Ok(16)
-    } else if book == &BibleBook::ICorinthians && *chapter == 3 {
-        Ok(23)
-    } else if book == &BibleBook::ICorinthians && *chapter == 4 {
-        Ok(21)
-    } else if book == &BibleBook::ICorinthians && *chapter == 5 {
-        Ok(13)
-    } else if book == &BibleBook::ICorinthians && *chapter == 6 {
-        Ok(20)
-    } else if book == &BibleBook::ICorinthians && *chapter == 7 {
-        Ok(40)
-    } else if book == &BibleBook::ICorinthians && *chapter == 8 {
-        Ok(13)
-    } else if book == &BibleBook::ICorinthians && *chapter == 9 {
-        Ok(27)
-    } else if book == &BibleBook::ICorinthians && *chapter == 10 {
-        Ok(33)
-    } else if book == &BibleBook::ICorinthians && *chapter == 11 {
-        Ok(34)
-    } else if book == &BibleBook::ICorinthians && *chapter == 12 {
-        Ok(31)
-    } else if book == &BibleBook::ICorinthians && *chapter == 13 {
-        Ok(13)
-    } else if book == &BibleBook::ICorinthians && *chapter == 14 {
-        Ok(40)
-    } else if book == &BibleBook::ICorinthians && *chapter == 15 {
-        Ok(58)
-    } else if book == &BibleBook::ICorinthians && *chapter == 16 {
-        Ok(24)
-    } else if book == &BibleBook::IICorinthians && *chapter == 1 {
-        Ok(24)
-    } else if book == &BibleBook::IICorinthians && *chapter == 2 {
-        Ok(17)
-    } else if book == &BibleBook::IICorinthians && *chapter == 3 {
-        Ok(18)
-    } else if book == &BibleBook::IICorinthians && *chapter == 4 {
-        Ok(18)
-    } else if book == &BibleBook::IICorinthians && *chapter == 5 {
-        Ok(21)
-    } else if book == &BibleBook::IICorinthians && *chapter == 6 {
-        Ok(18)
-    } else if book == &BibleBook::IICorinthians && *chapter == 7 {
-        Ok(16)
-    } else if book == &BibleBook::IICorinthians && *chapter == 8 {
-        Ok(24)
-    } else if book == &BibleBook::IICorinthians && *chapter == 9 {
-        Ok(15)
-    } else if book == &BibleBook::IICorinthians && *chapter == 10 {
-        Ok(18)
-    } else if book == &BibleBook::IICorinthians && *chapter == 11 {
-        Ok(33)
-    } else if book == &BibleBook::IICorinthians && *chapter == 12 {
-        Ok(21)
-    } else if book == &BibleBook::IICorinthians && *chapter == 13 {
-        Ok(14)
-    } else if book == &BibleBook::Galatians && *chapter == 1 {
-        Ok(24)
-    } else if book == &BibleBook::Galatians && *chapter == 2 {
-        Ok(21)
-    } else if book == &BibleBook::Galatians && *chapter == 3 {
-        Ok(29)
-    } else if book == &BibleBook::Galatians && *chapter == 4 {
-        Ok(31)
-    } else if book == &BibleBook::Galatians && *chapter == 5 {
-        Ok(26)
-    } else if book == &BibleBook::Galatians && *chapter == 6 {
-        Ok(18)
-    } else if book == &BibleBook::Ephesians && *chapter == 1 {
-        Ok(23)
-    } else if book == &BibleBook::Ephesians && *chapter == 2 {
-        Ok(22)
-    } else if book == &BibleBook::Ephesians && *chapter == 3 {
-        Ok(21)
-    } else if book == &BibleBook::Ephesians && *chapter == 4 {
-        Ok(32)
-    } else if book == &BibleBook::Ephesians && *chapter == 5 {
-        Ok(33)
-    } else if book == &BibleBook::Ephesians && *chapter == 6 {
-        Ok(24)
-    } else if book == &BibleBook::Philippians && *chapter == 1 {
-        Ok(30)
-    } else if book == &BibleBook::Philippians && *chapter == 2 {
-        Ok(30)
-    } else if book == &BibleBook::Philippians && *chapter == 3 {
-        Ok(21)
-    } else if book == &BibleBook::Philippians && *chapter == 4 {
-        Ok(23)
-    } else if book == &BibleBook::Colossians && *chapter == 1 {
-        Ok(29)
-    } else if book == &BibleBook::Colossians && *chapter == 2 {
-        Ok(23)
-    } else if book == &BibleBook::Colossians && *chapter == 3 {
-        Ok(25)
-    } else if book == &BibleBook::Colossians && *chapter == 4 {
-        Ok(18)
-    } else if book == &BibleBook::IThessalonians && *chapter == 1 {
-        Ok(10)
-    } else if book == &BibleBook::IThessalonians && *chapter == 2 {
-        Ok(20)
-    } else if book == &BibleBook::IThessalonians && *chapter == 3 {
-        Ok(13)
-    } else if book == &BibleBook::IThessalonians && *chapter == 4 {
-        Ok(18)
-    } else if book == &BibleBook::IThessalonians && *chapter == 5 {
-        Ok(28)
-    } else if book == &BibleBook::IIThessalonians && *chapter == 1 {
-        Ok(12)
-    } else if book == &BibleBook::IIThessalonians && *chapter == 2 {
-        Ok(17)
-    } else if book == &BibleBook::IIThessalonians && *chapter == 3 {
-        Ok(18)
-    } else if book == &BibleBook::ITimothy && *chapter == 1 {
-        Ok(20)
-    } else if book == &BibleBook::ITimothy && *chapter == 2 {
-        Ok(15)
-    } else if book == &BibleBook::ITimothy && *chapter == 3 {
-        Ok(16)
-    } else if book == &BibleBook::ITimothy && *chapter == 4 {
-        Ok(16)
-    } else if book == &BibleBook::ITimothy && *chapter == 5 {
-        Ok(25)
-    } else if book == &BibleBook::ITimothy && *chapter == 6 {
-        Ok(21)
-    } else if book == &BibleBook::IITimothy && *chapter == 1 {
-        Ok(18)
-    } else if book == &BibleBook::IITimothy && *chapter == 2 {
-        Ok(26)
-    } else if book == &BibleBook::IITimothy && *chapter == 3 {
-        Ok(17)
-    } else if book == &BibleBook::IITimothy && *chapter == 4 {
-        Ok(22)
-    } else if book == &BibleBook::Titus && *chapter == 1 {
-        Ok(16)
-    } else if book == &BibleBook::Titus && *chapter == 2 {
-        Ok(15)
-    } else if book == &BibleBook::Titus && *chapter == 3 {
-        Ok(15)
-    } else if book == &BibleBook::Philemon && *chapter == 1 {
-        Ok(25)
-    } else if book == &BibleBook::Hebrews && *chapter == 1 {
-        Ok(14)
-    } else if book == &BibleBook::Hebrews && *chapter == 2 {
-        Ok(18)
-    } else if book == &BibleBook::Hebrews && *chapter == 3 {
-        Ok(19)
-    } else if book == &BibleBook::Hebrews && *chapter == 4 {
-        Ok(16)
-    } else if book == &BibleBook::Hebrews && *chapter == 5 {
-        Ok(14)
-    } else if book == &BibleBook::Hebrews && *chapter == 6 {
-        Ok(20)
-    } else if book == &BibleBook::Hebrews && *chapter == 7 {
-        Ok(28)
-    } else if book == &BibleBook::Hebrews && *chapter == 8 {
-        Ok(13)
-    } else if book == &BibleBook::Hebrews && *chapter == 9 {
-        Ok(28)
-    } else if book == &BibleBook::Hebrews && *chapter == 10 {
-        Ok(39)
-    } else if book == &BibleBook::Hebrews && *chapter == 11 {
-        Ok(40)
-    } else if book == &BibleBook::Hebrews && *chapter == 12 {
-        Ok(29)
-    } else if book == &BibleBook::Hebrews && *chapter == 13 {
-        Ok(25)
-    } else if book == &BibleBook::James && *chapter == 1 {
-        Ok(27)
-    } else if book == &BibleBook::James && *chapter == 2 {
-        Ok(26)
-    } else if book == &BibleBook::James && *chapter == 3 {
-        Ok(18)
-    } else if book == &BibleBook::James && *chapter == 4 {
-        Ok(17)
-    } else if book == &BibleBook::James && *chapter == 5 {
-        Ok(20)
-    } else if book == &BibleBook::IPeter && *chapter == 1 {
-        Ok(25)
-    } else if book == &BibleBook::IPeter && *chapter == 2 {
-        Ok(25)
-    } else if book == &BibleBook::IPeter && *chapter == 3 {
-        Ok(22)
-    } else if book == &BibleBook::IPeter && *chapter == 4 {
-        Ok(19)
-    } else if book == &BibleBook::IPeter && *chapter == 5 {
-        Ok(14)
-    } else if book == &BibleBook::IIPeter && *chapter == 1 {
-        Ok(21)
-    } else if book == &BibleBook::IIPeter && *chapter == 2 {
-        Ok(22)
-    } else if book == &BibleBook::IIPeter && *chapter == 3 {
-        Ok(18)
-    } else if book == &BibleBook::IJohn && *chapter == 1 {
-        Ok(10)
-    } else if book == &BibleBook::IJohn && *chapter == 2 {
-        Ok(29)
-    } else if book == &BibleBook::IJohn && *chapter == 3 {
-        Ok(24)
-    } else if book == &BibleBook::IJohn && *chapter == 4 {
-        Ok(21)
-    } else if book == &BibleBook::IJohn && *chapter == 5 {
-        Ok(21)
-    } else if book == &BibleBook::IIJohn && *chapter == 1 {
-        Ok(13)
-    } else if book == &BibleBook::IIIJohn && *chapter == 1 {
-        Ok(14)
-    } else if book == &BibleBook::Jude && *chapter == 1 {
-        Ok(25)
-    } else if book == &BibleBook::Revelation && *chapter == 1 {
-        Ok(20)
-    } else if book == &BibleBook::Revelation && *chapter == 2 {
-        Ok(29)
-    } else if book == &BibleBook::Revelation && *chapter == 3 {
-        Ok(22)
-    } else if book == &BibleBook::Revelation && *chapter == 4 {
-        Ok(11)
-    } else if book == &BibleBook::Revelation && *chapter == 5 {
-        Ok(14)
-    } else if book == &BibleBook::Revelation && *chapter == 6 {
-        Ok(17)
-    } else if book == &BibleBook::Revelation && *chapter == 7 {
-        Ok(17)
-    } else if book == &BibleBook::Revelation && *chapter == 8 {
-        Ok(13)
-    } else if book == &BibleBook::Revelation && *chapter == 9 {
-        Ok(21)
-    } else if book == &BibleBook::Revelation && *chapter == 10 {
-        Ok(11)
-    } else if book == &BibleBook::Revelation && *chapter == 11 {
-        Ok(19)
-    } else if book == &BibleBook::Revelation && *chapter == 12 {
-        Ok(17)
-    } else if book == &BibleBook::Revelation && *chapter == 13 {
-        Ok(18)
-    } else if book == &BibleBook::Revelation && *chapter == 14 {
-        Ok(20)
-    } else if book == &BibleBook::Revelation && *chapter == 15 {
-        Ok(8)
-    } else if book == &BibleBook::Revelation && *chapter == 16 {
-        Ok(21)
-    } else if book == &BibleBook::Revelation && *chapter == 17 {
-        Ok(18)
-    } else if book == &BibleBook::Revelation && *chapter == 18 {
-        Ok(24)
-    } else if book == &BibleBook::Revelation && *chapter == 19 {
-        Ok(21)
-    } else if book == &BibleBook::Revelation && *chapter == 20 {
-        Ok(15)
-    } else if book == &BibleBook::Revelation && *chapter == 21 {
-        Ok(27)
-    } else if book == &BibleBook::Revelation && *chapter == 22 {
-        Ok(21)
-    } else {
-        Err(BibleReferenceValidationError {
+    get_number_of_verses_with_versification(book, chapter, &Versification::default())
+}
+
+/// Returns the number of Bible verses of a chapter (specified by book and chapter) according to the given [Versification]
+pub fn get_number_of_verses_with_versification(
+    book: &BibleBook,
+    chapter: &BibleChapter,
+    versification: &Versification,
+) -> Result<BibleVerse, BibleReferenceValidationError> {
+    versification.number_of_verses(book, chapter)
+}
+
+/// Returns the total number of verses of a [BibleBook] (without Psalm superscriptions), using the default [Versification]
+/// # Example
+/// ```
+/// use bibleref::bible::BibleBook;
+/// use bibleref::bible::validate::total_verses_in_book;
+/// assert_eq!(total_verses_in_book(&BibleBook::Genesis), 1533);
+/// assert_eq!(total_verses_in_book(&BibleBook::Jude), 25);
+/// ```
+pub fn total_verses_in_book(book: &BibleBook) -> u16 {
+    (1..=get_number_of_chapters(book))
+        .map(|chapter| get_number_of_verses(book, &chapter).unwrap() as u16)
+        .sum()
+}
+
+/// Returns the total number of verses of the whole Bible (without Psalm superscriptions), using the default [Versification]
+/// # Example
+/// ```
+/// use bibleref::bible::validate::total_number_of_verses;
+/// # #[cfg(not(feature = "deuterocanon"))]
+/// assert_eq!(total_number_of_verses(), 31102);
+/// ```
+pub fn total_number_of_verses() -> u32 {
+    BibleBook::all()
+        .iter()
+        .map(|book| total_verses_in_book(book) as u32)
+        .sum()
+}
+
+/// Returns the total number of chapters of the whole Bible, using the default [Versification]
+/// # Example
+/// ```
+/// use bibleref::bible::validate::total_number_of_chapters;
+/// # #[cfg(not(feature = "deuterocanon"))]
+/// assert_eq!(total_number_of_chapters(), 1189);
+/// ```
+pub fn total_number_of_chapters() -> u16 {
+    BibleBook::all()
+        .iter()
+        .map(|book| get_number_of_chapters(book) as u16)
+        .sum()
+}
+
+/// Returns the number of Bible verses of a chapter (specified by book and chapter) in the Masoretic versification
+pub(crate) fn get_masoretic_number_of_verses(
+    book: &BibleBook,
+    chapter: &BibleChapter,
+) -> Result<BibleVerse, BibleReferenceValidationError> {
+    let verses: &[BibleVerse] = match book {
+        BibleBook::Genesis => &[
+            31, 25, 24, 26, 32, 22, 24, 22, 29, 32, 32, 20, 18, 24, 21, 16, 27, 33, 38, 18, 34, 24,
+            20, 67, 34, 35, 46, 22, 35, 43, 55, 32, 20, 31, 29, 43, 36, 30, 23, 23, 57, 38, 34, 34,
+            28, 34, 31, 22, 33, 26,
+        ],
+        BibleBook::Exodus => &[
+            22, 25, 22, 31, 23, 30, 25, 32, 35, 29, 10, 51, 22, 31, 27, 36, 16, 27, 25, 26, 36, 31,
+            33, 18, 40, 37, 21, 43, 46, 38, 18, 35, 23, 35, 35, 38, 29, 31, 43, 38,
+        ],
+        BibleBook::Leviticus => &[
+            17, 16, 17, 35, 19, 30, 38, 36, 24, 20, 47, 8, 59, 57, 33, 34, 16, 30, 37, 27, 24, 33,
+            44, 23, 55, 46, 34,
+        ],
+        BibleBook::Numbers => &[
+            54, 34, 51, 49, 31, 27, 89, 26, 23, 36, 35, 16, 33, 45, 41, 50, 13, 32, 22, 29, 35, 41,
+            30, 25, 18, 65, 23, 31, 40, 16, 54, 42, 56, 29, 34, 13,
+        ],
+        BibleBook::Deuteronomy => &[
+            46, 37, 29, 49, 33, 25, 26, 20, 29, 22, 32, 32, 18, 29, 23, 22, 20, 22, 21, 20, 23, 30,
+            25, 22, 19, 19, 26, 68, 29, 20, 30, 52, 29, 12,
+        ],
+        BibleBook::Joshua => &[
+            18, 24, 17, 24, 15, 27, 26, 35, 27, 43, 23, 24, 33, 15, 63, 10, 18, 28, 51, 9, 45, 34,
+            16, 33,
+        ],
+        BibleBook::Judges => &[
+            36, 23, 31, 24, 31, 40, 25, 35, 57, 18, 40, 15, 25, 20, 20, 31, 13, 31, 30, 48, 25,
+        ],
+        BibleBook::Ruth => &[22, 23, 18, 22],
+        BibleBook::ISamuel => &[
+            28, 36, 21, 22, 12, 21, 17, 22, 27, 27, 15, 25, 23, 52, 35, 23, 58, 30, 24, 42, 15, 23,
+            29, 22, 44, 25, 12, 25, 11, 31, 13,
+        ],
+        BibleBook::IISamuel => &[
+            27, 32, 39, 12, 25, 23, 29, 18, 13, 19, 27, 31, 39, 33, 37, 23, 29, 33, 43, 26, 22, 51,
+            39, 25,
+        ],
+        BibleBook::IKings => &[
+            53, 46, 28, 34, 18, 38, 51, 66, 28, 29, 43, 33, 34, 31, 34, 34, 24, 46, 21, 43, 29, 53,
+        ],
+        BibleBook::IIKings => &[
+            18, 25, 27, 44, 27, 33, 20, 29, 37, 36, 21, 21, 25, 29, 38, 20, 41, 37, 37, 21, 26, 20,
+            37, 20, 30,
+        ],
+        BibleBook::IChronicles => &[
+            54, 55, 24, 43, 26, 81, 40, 40, 44, 14, 47, 40, 14, 17, 29, 43, 27, 17, 19, 8, 30, 19,
+            32, 31, 31, 32, 34, 21, 30,
+        ],
+        BibleBook::IIChronicles => &[
+            17, 18, 17, 22, 14, 42, 22, 18, 31, 19, 23, 16, 22, 15, 19, 14, 19, 34, 11, 37, 20, 12,
+            21, 27, 28, 23, 9, 27, 36, 27, 21, 33, 25, 33, 27, 23,
+        ],
+        BibleBook::Ezra => &[11, 70, 13, 24, 17, 22, 28, 36, 15, 44],
+        BibleBook::Nehemiah => &[11, 20, 32, 23, 19, 19, 73, 18, 38, 39, 36, 47, 31],
+        BibleBook::Esther => &[22, 23, 15, 17, 14, 14, 10, 17, 32, 3],
+        BibleBook::Job => &[
+            22, 13, 26, 21, 27, 30, 21, 22, 35, 22, 20, 25, 28, 22, 35, 22, 16, 21, 29, 29, 34, 30,
+            17, 25, 6, 14, 23, 28, 25, 31, 40, 22, 33, 37, 16, 33, 24, 41, 30, 24, 34, 17,
+        ],
+        BibleBook::Psalm => &[
+            6, 12, 8, 8, 12, 10, 17, 9, 20, 18, 7, 8, 6, 7, 5, 11, 15, 50, 14, 9, 13, 31, 6, 10,
+            22, 12, 14, 9, 11, 12, 24, 11, 22, 22, 28, 12, 40, 22, 13, 17, 13, 11, 5, 26, 17, 11,
+            9, 14, 20, 23, 19, 9, 6, 7, 23, 13, 11, 11, 17, 12, 8, 12, 11, 10, 13, 20, 7, 35, 36,
+            5, 24, 20, 28, 23, 10, 12, 20, 72, 13, 19, 16, 8, 18, 12, 13, 17, 7, 18, 52, 17, 16,
+            15, 5, 23, 11, 13, 12, 9, 9, 5, 8, 28, 22, 35, 45, 48, 43, 13, 31, 7, 10, 10, 9, 8, 18,
+            19, 2, 29, 176, 7, 8, 9, 4, 8, 5, 6, 5, 6, 8, 8, 3, 18, 3, 3, 21, 26, 9, 8, 24, 13, 10,
+            7, 12, 15, 21, 10, 20, 14, 9, 6,
+        ],
+        BibleBook::Proverbs => &[
+            33, 22, 35, 27, 23, 35, 27, 36, 18, 32, 31, 28, 25, 35, 33, 33, 28, 24, 29, 30, 31, 29,
+            35, 34, 28, 28, 27, 28, 27, 33, 31,
+        ],
+        BibleBook::Ecclesiastes => &[18, 26, 22, 16, 20, 12, 29, 17, 18, 20, 10, 14],
+        BibleBook::SongofSolomon => &[17, 17, 11, 16, 16, 13, 13, 14],
+        BibleBook::Isaiah => &[
+            31, 22, 26, 6, 30, 13, 25, 22, 21, 34, 16, 6, 22, 32, 9, 14, 14, 7, 25, 6, 17, 25, 18,
+            23, 12, 21, 13, 29, 24, 33, 9, 20, 24, 17, 10, 22, 38, 22, 8, 31, 29, 25, 28, 28, 25,
+            13, 15, 22, 26, 11, 23, 15, 12, 17, 13, 12, 21, 14, 21, 22, 11, 12, 19, 12, 25, 24,
+        ],
+        BibleBook::Jeremiah => &[
+            19, 37, 25, 31, 31, 30, 34, 22, 26, 25, 23, 17, 27, 22, 21, 21, 27, 23, 15, 18, 14, 30,
+            40, 10, 38, 24, 22, 17, 32, 24, 40, 44, 26, 22, 19, 32, 21, 28, 18, 16, 18, 22, 13, 30,
+            5, 28, 7, 47, 39, 46, 64, 34,
+        ],
+        BibleBook::Lamentations => &[22, 22, 66, 22, 22],
+        BibleBook::Ezekiel => &[
+            28, 10, 27, 17, 17, 14, 27, 18, 11, 22, 25, 28, 23, 23, 8, 63, 24, 32, 14, 49, 32, 31,
+            49, 27, 17, 21, 36, 26, 21, 26, 18, 32, 33, 31, 15, 38, 28, 23, 29, 49, 26, 20, 27, 31,
+            25, 24, 23, 35,
+        ],
+        BibleBook::Daniel => &[21, 49, 30, 37, 31, 28, 28, 27, 27, 21, 45, 13],
+        BibleBook::Hosea => &[11, 23, 5, 19, 15, 11, 16, 14, 17, 15, 12, 14, 16, 9],
+        BibleBook::Joel => &[20, 32, 21],
+        BibleBook::Amos => &[15, 16, 15, 13, 27, 14, 17, 14, 15],
+        BibleBook::Obadiah => &[21],
+        BibleBook::Jonah => &[17, 10, 10, 11],
+        BibleBook::Micah => &[16, 13, 12, 13, 15, 16, 20],
+        BibleBook::Nahum => &[15, 13, 19],
+        BibleBook::Habakkuk => &[17, 20, 19],
+        BibleBook::Zephaniah => &[18, 15, 20],
+        BibleBook::Haggai => &[15, 23],
+        BibleBook::Zechariah => &[21, 13, 10, 14, 11, 15, 14, 23, 17, 12, 17, 14, 9, 21],
+        BibleBook::Malachi => &[14, 17, 18, 6],
+        BibleBook::Matthew => &[
+            25, 23, 17, 25, 48, 34, 29, 34, 38, 42, 30, 50, 58, 36, 39, 28, 27, 35, 30, 34, 46, 46,
+            39, 51, 46, 75, 66, 20,
+        ],
+        BibleBook::Mark => &[
+            45, 28, 35, 41, 43, 56, 37, 38, 50, 52, 33, 44, 37, 72, 47, 20,
+        ],
+        BibleBook::Luke => &[
+            80, 52, 38, 44, 39, 49, 50, 56, 62, 42, 54, 59, 35, 35, 32, 31, 37, 43, 48, 47, 38, 71,
+            56, 53,
+        ],
+        BibleBook::John => &[
+            51, 25, 36, 54, 47, 71, 53, 59, 41, 42, 57, 50, 38, 31, 27, 33, 26, 40, 42, 31, 25,
+        ],
+        BibleBook::Acts => &[
+            26, 47, 26, 37, 42, 15, 60, 40, 43, 48, 30, 25, 52, 28, 41, 40, 34, 28, 41, 38, 40, 30,
+            35, 27, 27, 32, 44, 31,
+        ],
+        BibleBook::Romans => &[
+            32, 29, 31, 25, 21, 23, 25, 39, 33, 21, 36, 21, 14, 23, 33, 27,
+        ],
+        BibleBook::ICorinthians => &[
+            31, 16, 23, 21, 13, 20, 40, 13, 27, 33, 34, 31, 13, 40, 58, 24,
+        ],
+        BibleBook::IICorinthians => &[24, 17, 18, 18, 21, 18, 16, 24, 15, 18, 33, 21, 14],
+        BibleBook::Galatians => &[24, 21, 29, 31, 26, 18],
+        BibleBook::Ephesians => &[23, 22, 21, 32, 33, 24],
+        BibleBook::Philippians => &[30, 30, 21, 23],
+        BibleBook::Colossians => &[29, 23, 25, 18],
+        BibleBook::IThessalonians => &[10, 20, 13, 18, 28],
+        BibleBook::IIThessalonians => &[12, 17, 18],
+        BibleBook::ITimothy => &[20, 15, 16, 16, 25, 21],
+        BibleBook::IITimothy => &[18, 26, 17, 22],
+        BibleBook::Titus => &[16, 15, 15],
+        BibleBook::Philemon => &[25],
+        BibleBook::Hebrews => &[14, 18, 19, 16, 14, 20, 28, 13, 28, 39, 40, 29, 25],
+        BibleBook::James => &[27, 26, 18, 17, 20],
+        BibleBook::IPeter => &[25, 25, 22, 19, 14],
+        BibleBook::IIPeter => &[21, 22, 18],
+        BibleBook::IJohn => &[10, 29, 24, 21, 21],
+        BibleBook::IIJohn => &[13],
+        BibleBook::IIIJohn => &[14],
+        BibleBook::Jude => &[25],
+        BibleBook::Revelation => &[
+            20, 29, 22, 11, 14, 17, 17, 13, 21, 11, 19, 17, 18, 20, 8, 21, 18, 24, 21, 15, 27, 21,
+        ],
+        #[cfg(feature = "deuterocanon")]
+        _ => return get_number_of_deuterocanonical_verses(book, chapter),
+    };
+    chapter
+        .checked_sub(1)
+        .and_then(|index| verses.get(index as usize))
+        .copied()
+        .ok_or(BibleReferenceValidationError {
+            problem: BibleReferenceProblem::ChapterDoesNotExist,
+        })
+}
+
+/// Returns the number of Bible verses of a chapter of a deuterocanonical book.
+/// The Additions to Esther are counted as six chapters which correspond to the additions A-F.
+#[cfg(feature = "deuterocanon")]
+fn get_number_of_deuterocanonical_verses(
+    book: &BibleBook,
+    chapter: &BibleChapter,
+) -> Result<BibleVerse, BibleReferenceValidationError> {
+    let verses: &[BibleVerse] = match book {
+        BibleBook::Tobit => &[22, 14, 17, 21, 22, 17, 18, 21, 6, 12, 19, 22, 18, 15],
+        BibleBook::Judith => &[
+            16, 28, 10, 15, 24, 21, 32, 36, 14, 23, 23, 20, 20, 19, 14, 25,
+        ],
+        BibleBook::AdditionsToEsther => &[17, 7, 30, 16, 24, 11],
+        BibleBook::Wisdom => &[
+            16, 24, 19, 20, 23, 25, 30, 21, 18, 21, 26, 27, 19, 31, 19, 29, 21, 25, 22,
+        ],
+        BibleBook::Sirach => &[
+            30, 18, 31, 31, 15, 37, 36, 19, 18, 31, 34, 18, 26, 27, 20, 30, 32, 33, 30, 32, 28, 27,
+            28, 34, 26, 29, 30, 26, 28, 25, 31, 24, 31, 26, 20, 26, 31, 34, 35, 30, 24, 25, 33, 23,
+            26, 20, 25, 25, 16, 29, 30,
+        ],
+        BibleBook::Baruch => &[22, 35, 37, 37, 9, 73],
+        BibleBook::PrayerOfAzariah => &[68],
+        BibleBook::Susanna => &[64],
+        BibleBook::BelAndTheDragon => &[42],
+        BibleBook::IMaccabees => &[
+            64, 70, 60, 61, 68, 63, 50, 32, 73, 89, 74, 53, 53, 49, 41, 24,
+        ],
+        BibleBook::IIMaccabees => &[36, 32, 40, 50, 27, 31, 42, 36, 29, 38, 38, 45, 26, 46, 39],
+        _ => &[],
+    };
+    chapter
+        .checked_sub(1)
+        .and_then(|index| verses.get(index as usize))
+        .copied()
+        .ok_or(BibleReferenceValidationError {
             problem: BibleReferenceProblem::ChapterDoesNotExist,
         })
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// The former if/else implementation of [get_masoretic_number_of_verses], kept to verify the table
+    #[allow(clippy::all)]
+    fn legacy_number_of_verses(
+        book: &BibleBook,
+        chapter: &BibleChapter,
+    ) -> Result<BibleVerse, BibleReferenceValidationError> {
+        if book == &BibleBook::Genesis && *chapter == 1 {
+            Ok(31)
+        } else if book == &BibleBook::Genesis && *chapter == 2 {
+            Ok(25)
+        } else if book == &BibleBook::Genesis && *chapter == 3 {
+            Ok(24)
+        } else if book == &BibleBook::Genesis && *chapter == 4 {
+            Ok(26)
+        } else if book == &BibleBook::Genesis && *chapter == 5 {
+            Ok(32)
+        } else if book == &BibleBook::Genesis && *chapter == 6 {
+            Ok(22)
+        } else if book == &BibleBook::Genesis && *chapter == 7 {
+            Ok(24)
+        } else if book == &BibleBook::Genesis && *chapter == 8 {
+            Ok(22)
+        } else if book == &BibleBook::Genesis && *chapter == 9 {
+            Ok(29)
+        } else if book == &BibleBook::Genesis && *chapter == 10 {
+            Ok(32)
+        } else if book == &BibleBook::Genesis && *chapter == 11 {
+            Ok(32)
+        } else if book == &BibleBook::Genesis && *chapter == 12 {
+            Ok(20)
+        } else if book == &BibleBook::Genesis && *chapter == 13 {
+            Ok(18)
+        } else if book == &BibleBook::Genesis && *chapter == 14 {
+            Ok(24)
+        } else if book == &BibleBook::Genesis && *chapter == 15 {
+            Ok(21)
+        } else if book == &BibleBook::Genesis && *chapter == 16 {
+            Ok(16)
+        } else if book == &BibleBook::Genesis && *chapter == 17 {
+            Ok(27)
+        } else if book == &BibleBook::Genesis && *chapter == 18 {
+            Ok(33)
+        } else if book == &BibleBook::Genesis && *chapter == 19 {
+            Ok(38)
+        } else if book == &BibleBook::Genesis && *chapter == 20 {
+            Ok(18)
+        } else if book == &BibleBook::Genesis && *chapter == 21 {
+            Ok(34)
+        } else if book == &BibleBook::Genesis && *chapter == 22 {
+            Ok(24)
+        } else if book == &BibleBook::Genesis && *chapter == 23 {
+            Ok(20)
+        } else if book == &BibleBook::Genesis && *chapter == 24 {
+            Ok(67)
+        } else if book == &BibleBook::Genesis && *chapter == 25 {
+            Ok(34)
+        } else if book == &BibleBook::Genesis && *chapter == 26 {
+            Ok(35)
+        } else if book == &BibleBook::Genesis && *chapter == 27 {
+            Ok(46)
+        } else if book == &BibleBook::Genesis && *chapter == 28 {
+            Ok(22)
+        } else if book == &BibleBook::Genesis && *chapter == 29 {
+            Ok(35)
+        } else if book == &BibleBook::Genesis && *chapter == 30 {
+            Ok(43)
+        } else if book == &BibleBook::Genesis && *chapter == 31 {
+            Ok(55)
+        } else if book == &BibleBook::Genesis && *chapter == 32 {
+            Ok(32)
+        } else if book == &BibleBook::Genesis && *chapter == 33 {
+            Ok(20)
+        } else if book == &BibleBook::Genesis && *chapter == 34 {
+            Ok(31)
+        } else if book == &BibleBook::Genesis && *chapter == 35 {
+            Ok(29)
+        } else if book == &BibleBook::Genesis && *chapter == 36 {
+            Ok(43)
+        } else if book == &BibleBook::Genesis && *chapter == 37 {
+            Ok(36)
+        } else if book == &BibleBook::Genesis && *chapter == 38 {
+            Ok(30)
+        } else if book == &BibleBook::Genesis && *chapter == 39 {
+            Ok(23)
+        } else if book == &BibleBook::Genesis && *chapter == 40 {
+            Ok(23)
+        } else if book == &BibleBook::Genesis && *chapter == 41 {
+            Ok(57)
+        } else if book == &BibleBook::Genesis && *chapter == 42 {
+            Ok(38)
+        } else if book == &BibleBook::Genesis && *chapter == 43 {
+            Ok(34)
+        } else if book == &BibleBook::Genesis && *chapter == 44 {
+            Ok(34)
+        } else if book == &BibleBook::Genesis && *chapter == 45 {
+            Ok(28)
+        } else if book == &BibleBook::Genesis && *chapter == 46 {
+            Ok(34)
+        } else if book == &BibleBook::Genesis && *chapter == 47 {
+            Ok(31)
+        } else if book == &BibleBook::Genesis && *chapter == 48 {
+            Ok(22)
+        } else if book == &BibleBook::Genesis && *chapter == 49 {
+            Ok(33)
+        } else if book == &BibleBook::Genesis && *chapter == 50 {
+            Ok(26)
+        } else if book == &BibleBook::Exodus && *chapter == 1 {
+            Ok(22)
+        } else if book == &BibleBook::Exodus && *chapter == 2 {
+            Ok(25)
+        } else if book == &BibleBook::Exodus && *chapter == 3 {
+            Ok(22)
+        } else if book == &BibleBook::Exodus && *chapter == 4 {
+            Ok(31)
+        } else if book == &BibleBook::Exodus && *chapter == 5 {
+            Ok(23)
+        } else if book == &BibleBook::Exodus && *chapter == 6 {
+            Ok(30)
+        } else if book == &BibleBook::Exodus && *chapter == 7 {
+            Ok(25)
+        } else if book == &BibleBook::Exodus && *chapter == 8 {
+            Ok(32)
+        } else if book == &BibleBook::Exodus && *chapter == 9 {
+            Ok(35)
+        } else if book == &BibleBook::Exodus && *chapter == 10 {
+            Ok(29)
+        } else if book == &BibleBook::Exodus && *chapter == 11 {
+            Ok(10)
+        } else if book == &BibleBook::Exodus && *chapter == 12 {
+            Ok(51)
+        } else if book == &BibleBook::Exodus && *chapter == 13 {
+            Ok(22)
+        } else if book == &BibleBook::Exodus && *chapter == 14 {
+            Ok(31)
+        } else if book == &BibleBook::Exodus && *chapter == 15 {
+            Ok(27)
+        } else if book == &BibleBook::Exodus && *chapter == 16 {
+            Ok(36)
+        } else if book == &BibleBook::Exodus && *chapter == 17 {
+            Ok(16)
+        } else if book == &BibleBook::Exodus && *chapter == 18 {
+            Ok(27)
+        } else if book == &BibleBook::Exodus && *chapter == 19 {
+            Ok(25)
+        } else if book == &BibleBook::Exodus && *chapter == 20 {
+            Ok(26)
+        } else if book == &BibleBook::Exodus && *chapter == 21 {
+            Ok(36)
+        } else if book == &BibleBook::Exodus && *chapter == 22 {
+            Ok(31)
+        } else if book == &BibleBook::Exodus && *chapter == 23 {
+            Ok(33)
+        } else if book == &BibleBook::Exodus && *chapter == 24 {
+            Ok(18)
+        } else if book == &BibleBook::Exodus && *chapter == 25 {
+            Ok(40)
+        } else if book == &BibleBook::Exodus && *chapter == 26 {
+            Ok(37)
+        } else if book == &BibleBook::Exodus && *chapter == 27 {
+            Ok(21)
+        } else if book == &BibleBook::Exodus && *chapter == 28 {
+            Ok(43)
+        } else if book == &BibleBook::Exodus && *chapter == 29 {
+            Ok(46)
+        } else if book == &BibleBook::Exodus && *chapter == 30 {
+            Ok(38)
+        } else if book == &BibleBook::Exodus && *chapter == 31 {
+            Ok(18)
+        } else if book == &BibleBook::Exodus && *chapter == 32 {
+            Ok(35)
+        } else if book == &BibleBook::Exodus && *chapter == 33 {
+            Ok(23)
+        } else if book == &BibleBook::Exodus && *chapter == 34 {
+            Ok(35)
+        } else if book == &BibleBook::Exodus && *chapter == 35 {
+            Ok(35)
+        } else if book == &BibleBook::Exodus && *chapter == 36 {
+            Ok(38)
+        } else if book == &BibleBook::Exodus && *chapter == 37 {
+            Ok(29)
+        } else if book == &BibleBook::Exodus && *chapter == 38 {
+            Ok(31)
+        } else if book == &BibleBook::Exodus && *chapter == 39 {
+            Ok(43)
+        } else if book == &BibleBook::Exodus && *chapter == 40 {
+            Ok(38)
+        } else if book == &BibleBook::Leviticus && *chapter == 1 {
+            Ok(17)
+        } else if book == &BibleBook::Leviticus && *chapter == 2 {
+            Ok(16)
+        } else if book == &BibleBook::Leviticus && *chapter == 3 {
+            Ok(17)
+        } else if book == &BibleBook::Leviticus && *chapter == 4 {
+            Ok(35)
+        } else if book == &BibleBook::Leviticus && *chapter == 5 {
+            Ok(19)
+        } else if book == &BibleBook::Leviticus && *chapter == 6 {
+            Ok(30)
+        } else if book == &BibleBook::Leviticus && *chapter == 7 {
+            Ok(38)
+        } else if book == &BibleBook::Leviticus && *chapter == 8 {
+            Ok(36)
+        } else if book == &BibleBook::Leviticus && *chapter == 9 {
+            Ok(24)
+        } else if book == &BibleBook::Leviticus && *chapter == 10 {
+            Ok(20)
+        } else if book == &BibleBook::Leviticus && *chapter == 11 {
+            Ok(47)
+        } else if book == &BibleBook::Leviticus && *chapter == 12 {
+            Ok(8)
+        } else if book == &BibleBook::Leviticus && *chapter == 13 {
+            Ok(59)
+        } else if book == &BibleBook::Leviticus && *chapter == 14 {
+            Ok(57)
+        } else if book == &BibleBook::Leviticus && *chapter == 15 {
+            Ok(33)
+        } else if book == &BibleBook::Leviticus && *chapter == 16 {
+            Ok(34)
+        } else if book == &BibleBook::Leviticus && *chapter == 17 {
+            Ok(16)
+        } else if book == &BibleBook::Leviticus && *chapter == 18 {
+            Ok(30)
+        } else if book == &BibleBook::Leviticus && *chapter == 19 {
+            Ok(37)
+        } else if book == &BibleBook::Leviticus && *chapter == 20 {
+            Ok(27)
+        } else if book == &BibleBook::Leviticus && *chapter == 21 {
+            Ok(24)
+        } else if book == &BibleBook::Leviticus && *chapter == 22 {
+            Ok(33)
+        } else if book == &BibleBook::Leviticus && *chapter == 23 {
+            Ok(44)
+        } else if book == &BibleBook::Leviticus && *chapter == 24 {
+            Ok(23)
+        } else if book == &BibleBook::Leviticus && *chapter == 25 {
+            Ok(55)
+        } else if book == &BibleBook::Leviticus && *chapter == 26 {
+            Ok(46)
+        } else if book == &BibleBook::Leviticus && *chapter == 27 {
+            Ok(34)
+        } else if book == &BibleBook::Numbers && *chapter == 1 {
+            Ok(54)
+        } else if book == &BibleBook::Numbers && *chapter == 2 {
+            Ok(34)
+        } else if book == &BibleBook::Numbers && *chapter == 3 {
+            Ok(51)
+        } else if book == &BibleBook::Numbers && *chapter == 4 {
+            Ok(49)
+        } else if book == &BibleBook::Numbers && *chapter == 5 {
+            Ok(31)
+        } else if book == &BibleBook::Numbers && *chapter == 6 {
+            Ok(27)
+        } else if book == &BibleBook::Numbers && *chapter == 7 {
+            Ok(89)
+        } else if book == &BibleBook::Numbers && *chapter == 8 {
+            Ok(26)
+        } else if book == &BibleBook::Numbers && *chapter == 9 {
+            Ok(23)
+        } else if book == &BibleBook::Numbers && *chapter == 10 {
+            Ok(36)
+        } else if book == &BibleBook::Numbers && *chapter == 11 {
+            Ok(35)
+        } else if book == &BibleBook::Numbers && *chapter == 12 {
+            Ok(16)
+        } else if book == &BibleBook::Numbers && *chapter == 13 {
+            Ok(33)
+        } else if book == &BibleBook::Numbers && *chapter == 14 {
+            Ok(45)
+        } else if book == &BibleBook::Numbers && *chapter == 15 {
+            Ok(41)
+        } else if book == &BibleBook::Numbers && *chapter == 16 {
+            Ok(50)
+        } else if book == &BibleBook::Numbers && *chapter == 17 {
+            Ok(13)
+        } else if book == &BibleBook::Numbers && *chapter == 18 {
+            Ok(32)
+        } else if book == &BibleBook::Numbers && *chapter == 19 {
+            Ok(22)
+        } else if book == &BibleBook::Numbers && *chapter == 20 {
+            Ok(29)
+        } else if book == &BibleBook::Numbers && *chapter == 21 {
+            Ok(35)
+        } else if book == &BibleBook::Numbers && *chapter == 22 {
+            Ok(41)
+        } else if book == &BibleBook::Numbers && *chapter == 23 {
+            Ok(30)
+        } else if book == &BibleBook::Numbers && *chapter == 24 {
+            Ok(25)
+        } else if book == &BibleBook::Numbers && *chapter == 25 {
+            Ok(18)
+        } else if book == &BibleBook::Numbers && *chapter == 26 {
+            Ok(65)
+        } else if book == &BibleBook::Numbers && *chapter == 27 {
+            Ok(23)
+        } else if book == &BibleBook::Numbers && *chapter == 28 {
+            Ok(31)
+        } else if book == &BibleBook::Numbers && *chapter == 29 {
+            Ok(40)
+        } else if book == &BibleBook::Numbers && *chapter == 30 {
+            Ok(16)
+        } else if book == &BibleBook::Numbers && *chapter == 31 {
+            Ok(54)
+        } else if book == &BibleBook::Numbers && *chapter == 32 {
+            Ok(42)
+        } else if book == &BibleBook::Numbers && *chapter == 33 {
+            Ok(56)
+        } else if book == &BibleBook::Numbers && *chapter == 34 {
+            Ok(29)
+        } else if book == &BibleBook::Numbers && *chapter == 35 {
+            Ok(34)
+        } else if book == &BibleBook::Numbers && *chapter == 36 {
+            Ok(13)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 1 {
+            Ok(46)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 2 {
+            Ok(37)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 3 {
+            Ok(29)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 4 {
+            Ok(49)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 5 {
+            Ok(33)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 6 {
+            Ok(25)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 7 {
+            Ok(26)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 8 {
+            Ok(20)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 9 {
+            Ok(29)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 10 {
+            Ok(22)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 11 {
+            Ok(32)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 12 {
+            Ok(32)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 13 {
+            Ok(18)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 14 {
+            Ok(29)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 15 {
+            Ok(23)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 16 {
+            Ok(22)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 17 {
+            Ok(20)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 18 {
+            Ok(22)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 19 {
+            Ok(21)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 20 {
+            Ok(20)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 21 {
+            Ok(23)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 22 {
+            Ok(30)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 23 {
+            Ok(25)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 24 {
+            Ok(22)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 25 {
+            Ok(19)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 26 {
+            Ok(19)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 27 {
+            Ok(26)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 28 {
+            Ok(68)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 29 {
+            Ok(29)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 30 {
+            Ok(20)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 31 {
+            Ok(30)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 32 {
+            Ok(52)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 33 {
+            Ok(29)
+        } else if book == &BibleBook::Deuteronomy && *chapter == 34 {
+            Ok(12)
+        } else if book == &BibleBook::Joshua && *chapter == 1 {
+            Ok(18)
+        } else if book == &BibleBook::Joshua && *chapter == 2 {
+            Ok(24)
+        } else if book == &BibleBook::Joshua && *chapter == 3 {
+            Ok(17)
+        } else if book == &BibleBook::Joshua && *chapter == 4 {
+            Ok(24)
+        } else if book == &BibleBook::Joshua && *chapter == 5 {
+            Ok(15)
+        } else if book == &BibleBook::Joshua && *chapter == 6 {
+            Ok(27)
+        } else if book == &BibleBook::Joshua && *chapter == 7 {
+            Ok(26)
+        } else if book == &BibleBook::Joshua && *chapter == 8 {
+            Ok(35)
+        } else if book == &BibleBook::Joshua && *chapter == 9 {
+            Ok(27)
+        } else if book == &BibleBook::Joshua && *chapter == 10 {
+            Ok(43)
+        } else if book == &BibleBook::Joshua && *chapter == 11 {
+            Ok(23)
+        } else if book == &BibleBook::Joshua && *chapter == 12 {
+            Ok(24)
+        } else if book == &BibleBook::Joshua && *chapter == 13 {
+            Ok(33)
+        } else if book == &BibleBook::Joshua && *chapter == 14 {
+            Ok(15)
+        } else if book == &BibleBook::Joshua && *chapter == 15 {
+            Ok(63)
+        } else if book == &BibleBook::Joshua && *chapter == 16 {
+            Ok(10)
+        } else if book == &BibleBook::Joshua && *chapter == 17 {
+            Ok(18)
+        } else if book == &BibleBook::Joshua && *chapter == 18 {
+            Ok(28)
+        } else if book == &BibleBook::Joshua && *chapter == 19 {
+            Ok(51)
+        } else if book == &BibleBook::Joshua && *chapter == 20 {
+            Ok(9)
+        } else if book == &BibleBook::Joshua && *chapter == 21 {
+            Ok(45)
+        } else if book == &BibleBook::Joshua && *chapter == 22 {
+            Ok(34)
+        } else if book == &BibleBook::Joshua && *chapter == 23 {
+            Ok(16)
+        } else if book == &BibleBook::Joshua && *chapter == 24 {
+            Ok(33)
+        } else if book == &BibleBook::Judges && *chapter == 1 {
+            Ok(36)
+        } else if book == &BibleBook::Judges && *chapter == 2 {
+            Ok(23)
+        } else if book == &BibleBook::Judges && *chapter == 3 {
+            Ok(31)
+        } else if book == &BibleBook::Judges && *chapter == 4 {
+            Ok(24)
+        } else if book == &BibleBook::Judges && *chapter == 5 {
+            Ok(31)
+        } else if book == &BibleBook::Judges && *chapter == 6 {
+            Ok(40)
+        } else if book == &BibleBook::Judges && *chapter == 7 {
+            Ok(25)
+        } else if book == &BibleBook::Judges && *chapter == 8 {
+            Ok(35)
+        } else if book == &BibleBook::Judges && *chapter == 9 {
+            Ok(57)
+        } else if book == &BibleBook::Judges && *chapter == 10 {
+            Ok(18)
+        } else if book == &BibleBook::Judges && *chapter == 11 {
+            Ok(40)
+        } else if book == &BibleBook::Judges && *chapter == 12 {
+            Ok(15)
+        } else if book == &BibleBook::Judges && *chapter == 13 {
+            Ok(25)
+        } else if book == &BibleBook::Judges && *chapter == 14 {
+            Ok(20)
+        } else if book == &BibleBook::Judges && *chapter == 15 {
+            Ok(20)
+        } else if book == &BibleBook::Judges && *chapter == 16 {
+            Ok(31)
+        } else if book == &BibleBook::Judges && *chapter == 17 {
+            Ok(13)
+        } else if book == &BibleBook::Judges && *chapter == 18 {
+            Ok(31)
+        } else if book == &BibleBook::Judges && *chapter == 19 {
+            Ok(30)
+        } else if book == &BibleBook::Judges && *chapter == 20 {
+            Ok(48)
+        } else if book == &BibleBook::Judges && *chapter == 21 {
+            Ok(25)
+        } else if book == &BibleBook::Ruth && *chapter == 1 {
+            Ok(22)
+        } else if book == &BibleBook::Ruth && *chapter == 2 {
+            Ok(23)
+        } else if book == &BibleBook::Ruth && *chapter == 3 {
+            Ok(18)
+        } else if book == &BibleBook::Ruth && *chapter == 4 {
+            Ok(22)
+        } else if book == &BibleBook::ISamuel && *chapter == 1 {
+            Ok(28)
+        } else if book == &BibleBook::ISamuel && *chapter == 2 {
+            Ok(36)
+        } else if book == &BibleBook::ISamuel && *chapter == 3 {
+            Ok(21)
+        } else if book == &BibleBook::ISamuel && *chapter == 4 {
+            Ok(22)
+        } else if book == &BibleBook::ISamuel && *chapter == 5 {
+            Ok(12)
+        } else if book == &BibleBook::ISamuel && *chapter == 6 {
+            Ok(21)
+        } else if book == &BibleBook::ISamuel && *chapter == 7 {
+            Ok(17)
+        } else if book == &BibleBook::ISamuel && *chapter == 8 {
+            Ok(22)
+        } else if book == &BibleBook::ISamuel && *chapter == 9 {
+            Ok(27)
+        } else if book == &BibleBook::ISamuel && *chapter == 10 {
+            Ok(27)
+        } else if book == &BibleBook::ISamuel && *chapter == 11 {
+            Ok(15)
+        } else if book == &BibleBook::ISamuel && *chapter == 12 {
+            Ok(25)
+        } else if book == &BibleBook::ISamuel && *chapter == 13 {
+            Ok(23)
+        } else if book == &BibleBook::ISamuel && *chapter == 14 {
+            Ok(52)
+        } else if book == &BibleBook::ISamuel && *chapter == 15 {
+            Ok(35)
+        } else if book == &BibleBook::ISamuel && *chapter == 16 {
+            Ok(23)
+        } else if book == &BibleBook::ISamuel && *chapter == 17 {
+            Ok(58)
+        } else if book == &BibleBook::ISamuel && *chapter == 18 {
+            Ok(30)
+        } else if book == &BibleBook::ISamuel && *chapter == 19 {
+            Ok(24)
+        } else if book == &BibleBook::ISamuel && *chapter == 20 {
+            Ok(42)
+        } else if book == &BibleBook::ISamuel && *chapter == 21 {
+            Ok(15)
+        } else if book == &BibleBook::ISamuel && *chapter == 22 {
+            Ok(23)
+        } else if book == &BibleBook::ISamuel && *chapter == 23 {
+            Ok(29)
+        } else if book == &BibleBook::ISamuel && *chapter == 24 {
+            Ok(22)
+        } else if book == &BibleBook::ISamuel && *chapter == 25 {
+            Ok(44)
+        } else if book == &BibleBook::ISamuel && *chapter == 26 {
+            Ok(25)
+        } else if book == &BibleBook::ISamuel && *chapter == 27 {
+            Ok(12)
+        } else if book == &BibleBook::ISamuel && *chapter == 28 {
+            Ok(25)
+        } else if book == &BibleBook::ISamuel && *chapter == 29 {
+            Ok(11)
+        } else if book == &BibleBook::ISamuel && *chapter == 30 {
+            Ok(31)
+        } else if book == &BibleBook::ISamuel && *chapter == 31 {
+            Ok(13)
+        } else if book == &BibleBook::IISamuel && *chapter == 1 {
+            Ok(27)
+        } else if book == &BibleBook::IISamuel && *chapter == 2 {
+            Ok(32)
+        } else if book == &BibleBook::IISamuel && *chapter == 3 {
+            Ok(39)
+        } else if book == &BibleBook::IISamuel && *chapter == 4 {
+            Ok(12)
+        } else if book == &BibleBook::IISamuel && *chapter == 5 {
+            Ok(25)
+        } else if book == &BibleBook::IISamuel && *chapter == 6 {
+            Ok(23)
+        } else if book == &BibleBook::IISamuel && *chapter == 7 {
+            Ok(29)
+        } else if book == &BibleBook::IISamuel && *chapter == 8 {
+            Ok(18)
+        } else if book == &BibleBook::IISamuel && *chapter == 9 {
+            Ok(13)
+        } else if book == &BibleBook::IISamuel && *chapter == 10 {
+            Ok(19)
+        } else if book == &BibleBook::IISamuel && *chapter == 11 {
+            Ok(27)
+        } else if book == &BibleBook::IISamuel && *chapter == 12 {
+            Ok(31)
+        } else if book == &BibleBook::IISamuel && *chapter == 13 {
+            Ok(39)
+        } else if book == &BibleBook::IISamuel && *chapter == 14 {
+            Ok(33)
+        } else if book == &BibleBook::IISamuel && *chapter == 15 {
+            Ok(37)
+        } else if book == &BibleBook::IISamuel && *chapter == 16 {
+            Ok(23)
+        } else if book == &BibleBook::IISamuel && *chapter == 17 {
+            Ok(29)
+        } else if book == &BibleBook::IISamuel && *chapter == 18 {
+            Ok(33)
+        } else if book == &BibleBook::IISamuel && *chapter == 19 {
+            Ok(43)
+        } else if book == &BibleBook::IISamuel && *chapter == 20 {
+            Ok(26)
+        } else if book == &BibleBook::IISamuel && *chapter == 21 {
+            Ok(22)
+        } else if book == &BibleBook::IISamuel && *chapter == 22 {
+            Ok(51)
+        } else if book == &BibleBook::IISamuel && *chapter == 23 {
+            Ok(39)
+        } else if book == &BibleBook::IISamuel && *chapter == 24 {
+            Ok(25)
+        } else if book == &BibleBook::IKings && *chapter == 1 {
+            Ok(53)
+        } else if book == &BibleBook::IKings && *chapter == 2 {
+            Ok(46)
+        } else if book == &BibleBook::IKings && *chapter == 3 {
+            Ok(28)
+        } else if book == &BibleBook::IKings && *chapter == 4 {
+            Ok(34)
+        } else if book == &BibleBook::IKings && *chapter == 5 {
+            Ok(18)
+        } else if book == &BibleBook::IKings && *chapter == 6 {
+            Ok(38)
+        } else if book == &BibleBook::IKings && *chapter == 7 {
+            Ok(51)
+        } else if book == &BibleBook::IKings && *chapter == 8 {
+            Ok(66)
+        } else if book == &BibleBook::IKings && *chapter == 9 {
+            Ok(28)
+        } else if book == &BibleBook::IKings && *chapter == 10 {
+            Ok(29)
+        } else if book == &BibleBook::IKings && *chapter == 11 {
+            Ok(43)
+        } else if book == &BibleBook::IKings && *chapter == 12 {
+            Ok(33)
+        } else if book == &BibleBook::IKings && *chapter == 13 {
+            Ok(34)
+        } else if book == &BibleBook::IKings && *chapter == 14 {
+            Ok(31)
+        } else if book == &BibleBook::IKings && *chapter == 15 {
+            Ok(34)
+        } else if book == &BibleBook::IKings && *chapter == 16 {
+            Ok(34)
+        } else if book == &BibleBook::IKings && *chapter == 17 {
+            Ok(24)
+        } else if book == &BibleBook::IKings && *chapter == 18 {
+            Ok(46)
+        } else if book == &BibleBook::IKings && *chapter == 19 {
+            Ok(21)
+        } else if book == &BibleBook::IKings && *chapter == 20 {
+            Ok(43)
+        } else if book == &BibleBook::IKings && *chapter == 21 {
+            Ok(29)
+        } else if book == &BibleBook::IKings && *chapter == 22 {
+            Ok(53)
+        } else if book == &BibleBook::IIKings && *chapter == 1 {
+            Ok(18)
+        } else if book == &BibleBook::IIKings && *chapter == 2 {
+            Ok(25)
+        } else if book == &BibleBook::IIKings && *chapter == 3 {
+            Ok(27)
+        } else if book == &BibleBook::IIKings && *chapter == 4 {
+            Ok(44)
+        } else if book == &BibleBook::IIKings && *chapter == 5 {
+            Ok(27)
+        } else if book == &BibleBook::IIKings && *chapter == 6 {
+            Ok(33)
+        } else if book == &BibleBook::IIKings && *chapter == 7 {
+            Ok(20)
+        } else if book == &BibleBook::IIKings && *chapter == 8 {
+            Ok(29)
+        } else if book == &BibleBook::IIKings && *chapter == 9 {
+            Ok(37)
+        } else if book == &BibleBook::IIKings && *chapter == 10 {
+            Ok(36)
+        } else if book == &BibleBook::IIKings && *chapter == 11 {
+            Ok(21)
+        } else if book == &BibleBook::IIKings && *chapter == 12 {
+            Ok(21)
+        } else if book == &BibleBook::IIKings && *chapter == 13 {
+            Ok(25)
+        } else if book == &BibleBook::IIKings && *chapter == 14 {
+            Ok(29)
+        } else if book == &BibleBook::IIKings && *chapter == 15 {
+            Ok(38)
+        } else if book == &BibleBook::IIKings && *chapter == 16 {
+            Ok(20)
+        } else if book == &BibleBook::IIKings && *chapter == 17 {
+            Ok(41)
+        } else if book == &BibleBook::IIKings && *chapter == 18 {
+            Ok(37)
+        } else if book == &BibleBook::IIKings && *chapter == 19 {
+            Ok(37)
+        } else if book == &BibleBook::IIKings && *chapter == 20 {
+            Ok(21)
+        } else if book == &BibleBook::IIKings && *chapter == 21 {
+            Ok(26)
+        } else if book == &BibleBook::IIKings && *chapter == 22 {
+            Ok(20)
+        } else if book == &BibleBook::IIKings && *chapter == 23 {
+            Ok(37)
+        } else if book == &BibleBook::IIKings && *chapter == 24 {
+            Ok(20)
+        } else if book == &BibleBook::IIKings && *chapter == 25 {
+            Ok(30)
+        } else if book == &BibleBook::IChronicles && *chapter == 1 {
+            Ok(54)
+        } else if book == &BibleBook::IChronicles && *chapter == 2 {
+            Ok(55)
+        } else if book == &BibleBook::IChronicles && *chapter == 3 {
+            Ok(24)
+        } else if book == &BibleBook::IChronicles && *chapter == 4 {
+            Ok(43)
+        } else if book == &BibleBook::IChronicles && *chapter == 5 {
+            Ok(26)
+        } else if book == &BibleBook::IChronicles && *chapter == 6 {
+            Ok(81)
+        } else if book == &BibleBook::IChronicles && *chapter == 7 {
+            Ok(40)
+        } else if book == &BibleBook::IChronicles && *chapter == 8 {
+            Ok(40)
+        } else if book == &BibleBook::IChronicles && *chapter == 9 {
+            Ok(44)
+        } else if book == &BibleBook::IChronicles && *chapter == 10 {
+            Ok(14)
+        } else if book == &BibleBook::IChronicles && *chapter == 11 {
+            Ok(47)
+        } else if book == &BibleBook::IChronicles && *chapter == 12 {
+            Ok(40)
+        } else if book == &BibleBook::IChronicles && *chapter == 13 {
+            Ok(14)
+        } else if book == &BibleBook::IChronicles && *chapter == 14 {
+            Ok(17)
+        } else if book == &BibleBook::IChronicles && *chapter == 15 {
+            Ok(29)
+        } else if book == &BibleBook::IChronicles && *chapter == 16 {
+            Ok(43)
+        } else if book == &BibleBook::IChronicles && *chapter == 17 {
+            Ok(27)
+        } else if book == &BibleBook::IChronicles && *chapter == 18 {
+            Ok(17)
+        } else if book == &BibleBook::IChronicles && *chapter == 19 {
+            Ok(19)
+        } else if book == &BibleBook::IChronicles && *chapter == 20 {
+            Ok(8)
+        } else if book == &BibleBook::IChronicles && *chapter == 21 {
+            Ok(30)
+        } else if book == &BibleBook::IChronicles && *chapter == 22 {
+            Ok(19)
+        } else if book == &BibleBook::IChronicles && *chapter == 23 {
+            Ok(32)
+        } else if book == &BibleBook::IChronicles && *chapter == 24 {
+            Ok(31)
+        } else if book == &BibleBook::IChronicles && *chapter == 25 {
+            Ok(31)
+        } else if book == &BibleBook::IChronicles && *chapter == 26 {
+            Ok(32)
+        } else if book == &BibleBook::IChronicles && *chapter == 27 {
+            Ok(34)
+        } else if book == &BibleBook::IChronicles && *chapter == 28 {
+            Ok(21)
+        } else if book == &BibleBook::IChronicles && *chapter == 29 {
+            Ok(30)
+        } else if book == &BibleBook::IIChronicles && *chapter == 1 {
+            Ok(17)
+        } else if book == &BibleBook::IIChronicles && *chapter == 2 {
+            Ok(18)
+        } else if book == &BibleBook::IIChronicles && *chapter == 3 {
+            Ok(17)
+        } else if book == &BibleBook::IIChronicles && *chapter == 4 {
+            Ok(22)
+        } else if book == &BibleBook::IIChronicles && *chapter == 5 {
+            Ok(14)
+        } else if book == &BibleBook::IIChronicles && *chapter == 6 {
+            Ok(42)
+        } else if book == &BibleBook::IIChronicles && *chapter == 7 {
+            Ok(22)
+        } else if book == &BibleBook::IIChronicles && *chapter == 8 {
+            Ok(18)
+        } else if book == &BibleBook::IIChronicles && *chapter == 9 {
+            Ok(31)
+        } else if book == &BibleBook::IIChronicles && *chapter == 10 {
+            Ok(19)
+        } else if book == &BibleBook::IIChronicles && *chapter == 11 {
+            Ok(23)
+        } else if book == &BibleBook::IIChronicles && *chapter == 12 {
+            Ok(16)
+        } else if book == &BibleBook::IIChronicles && *chapter == 13 {
+            Ok(22)
+        } else if book == &BibleBook::IIChronicles && *chapter == 14 {
+            Ok(15)
+        } else if book == &BibleBook::IIChronicles && *chapter == 15 {
+            Ok(19)
+        } else if book == &BibleBook::IIChronicles && *chapter == 16 {
+            Ok(14)
+        } else if book == &BibleBook::IIChronicles && *chapter == 17 {
+            Ok(19)
+        } else if book == &BibleBook::IIChronicles && *chapter == 18 {
+            Ok(34)
+        } else if book == &BibleBook::IIChronicles && *chapter == 19 {
+            Ok(11)
+        } else if book == &BibleBook::IIChronicles && *chapter == 20 {
+            Ok(37)
+        } else if book == &BibleBook::IIChronicles && *chapter == 21 {
+            Ok(20)
+        } else if book == &BibleBook::IIChronicles && *chapter == 22 {
+            Ok(12)
+        } else if book == &BibleBook::IIChronicles && *chapter == 23 {
+            Ok(21)
+        } else if book == &BibleBook::IIChronicles && *chapter == 24 {
+            Ok(27)
+        } else if book == &BibleBook::IIChronicles && *chapter == 25 {
+            Ok(28)
+        } else if book == &BibleBook::IIChronicles && *chapter == 26 {
+            Ok(23)
+        } else if book == &BibleBook::IIChronicles && *chapter == 27 {
+            Ok(9)
+        } else if book == &BibleBook::IIChronicles && *chapter == 28 {
+            Ok(27)
+        } else if book == &BibleBook::IIChronicles && *chapter == 29 {
+            Ok(36)
+        } else if book == &BibleBook::IIChronicles && *chapter == 30 {
+            Ok(27)
+        } else if book == &BibleBook::IIChronicles && *chapter == 31 {
+            Ok(21)
+        } else if book == &BibleBook::IIChronicles && *chapter == 32 {
+            Ok(33)
+        } else if book == &BibleBook::IIChronicles && *chapter == 33 {
+            Ok(25)
+        } else if book == &BibleBook::IIChronicles && *chapter == 34 {
+            Ok(33)
+        } else if book == &BibleBook::IIChronicles && *chapter == 35 {
+            Ok(27)
+        } else if book == &BibleBook::IIChronicles && *chapter == 36 {
+            Ok(23)
+        } else if book == &BibleBook::Ezra && *chapter == 1 {
+            Ok(11)
+        } else if book == &BibleBook::Ezra && *chapter == 2 {
+            Ok(70)
+        } else if book == &BibleBook::Ezra && *chapter == 3 {
+            Ok(13)
+        } else if book == &BibleBook::Ezra && *chapter == 4 {
+            Ok(24)
+        } else if book == &BibleBook::Ezra && *chapter == 5 {
+            Ok(17)
+        } else if book == &BibleBook::Ezra && *chapter == 6 {
+            Ok(22)
+        } else if book == &BibleBook::Ezra && *chapter == 7 {
+            Ok(28)
+        } else if book == &BibleBook::Ezra && *chapter == 8 {
+            Ok(36)
+        } else if book == &BibleBook::Ezra && *chapter == 9 {
+            Ok(15)
+        } else if book == &BibleBook::Ezra && *chapter == 10 {
+            Ok(44)
+        } else if book == &BibleBook::Nehemiah && *chapter == 1 {
+            Ok(11)
+        } else if book == &BibleBook::Nehemiah && *chapter == 2 {
+            Ok(20)
+        } else if book == &BibleBook::Nehemiah && *chapter == 3 {
+            Ok(32)
+        } else if book == &BibleBook::Nehemiah && *chapter == 4 {
+            Ok(23)
+        } else if book == &BibleBook::Nehemiah && *chapter == 5 {
+            Ok(19)
+        } else if book == &BibleBook::Nehemiah && *chapter == 6 {
+            Ok(19)
+        } else if book == &BibleBook::Nehemiah && *chapter == 7 {
+            Ok(73)
+        } else if book == &BibleBook::Nehemiah && *chapter == 8 {
+            Ok(18)
+        } else if book == &BibleBook::Nehemiah && *chapter == 9 {
+            Ok(38)
+        } else if book == &BibleBook::Nehemiah && *chapter == 10 {
+            Ok(39)
+        } else if book == &BibleBook::Nehemiah && *chapter == 11 {
+            Ok(36)
+        } else if book == &BibleBook::Nehemiah && *chapter == 12 {
+            Ok(47)
+        } else if book == &BibleBook::Nehemiah && *chapter == 13 {
+            Ok(31)
+        } else if book == &BibleBook::Esther && *chapter == 1 {
+            Ok(22)
+        } else if book == &BibleBook::Esther && *chapter == 2 {
+            Ok(23)
+        } else if book == &BibleBook::Esther && *chapter == 3 {
+            Ok(15)
+        } else if book == &BibleBook::Esther && *chapter == 4 {
+            Ok(17)
+        } else if book == &BibleBook::Esther && *chapter == 5 {
+            Ok(14)
+        } else if book == &BibleBook::Esther && *chapter == 6 {
+            Ok(14)
+        } else if book == &BibleBook::Esther && *chapter == 7 {
+            Ok(10)
+        } else if book == &BibleBook::Esther && *chapter == 8 {
+            Ok(17)
+        } else if book == &BibleBook::Esther && *chapter == 9 {
+            Ok(32)
+        } else if book == &BibleBook::Esther && *chapter == 10 {
+            Ok(3)
+        } else if book == &BibleBook::Job && *chapter == 1 {
+            Ok(22)
+        } else if book == &BibleBook::Job && *chapter == 2 {
+            Ok(13)
+        } else if book == &BibleBook::Job && *chapter == 3 {
+            Ok(26)
+        } else if book == &BibleBook::Job && *chapter == 4 {
+            Ok(21)
+        } else if book == &BibleBook::Job && *chapter == 5 {
+            Ok(27)
+        } else if book == &BibleBook::Job && *chapter == 6 {
+            Ok(30)
+        } else if book == &BibleBook::Job && *chapter == 7 {
+            Ok(21)
+        } else if book == &BibleBook::Job && *chapter == 8 {
+            Ok(22)
+        } else if book == &BibleBook::Job && *chapter == 9 {
+            Ok(35)
+        } else if book == &BibleBook::Job && *chapter == 10 {
+            Ok(22)
+        } else if book == &BibleBook::Job && *chapter == 11 {
+            Ok(20)
+        } else if book == &BibleBook::Job && *chapter == 12 {
+            Ok(25)
+        } else if book == &BibleBook::Job && *chapter == 13 {
+            Ok(28)
+        } else if book == &BibleBook::Job && *chapter == 14 {
+            Ok(22)
+        } else if book == &BibleBook::Job && *chapter == 15 {
+            Ok(35)
+        } else if book == &BibleBook::Job && *chapter == 16 {
+            Ok(22)
+        } else if book == &BibleBook::Job && *chapter == 17 {
+            Ok(16)
+        } else if book == &BibleBook::Job && *chapter == 18 {
+            Ok(21)
+        } else if book == &BibleBook::Job && *chapter == 19 {
+            Ok(29)
+        } else if book == &BibleBook::Job && *chapter == 20 {
+            Ok(29)
+        } else if book == &BibleBook::Job && *chapter == 21 {
+            Ok(34)
+        } else if book == &BibleBook::Job && *chapter == 22 {
+            Ok(30)
+        } else if book == &BibleBook::Job && *chapter == 23 {
+            Ok(17)
+        } else if book == &BibleBook::Job && *chapter == 24 {
+            Ok(25)
+        } else if book == &BibleBook::Job && *chapter == 25 {
+            Ok(6)
+        } else if book == &BibleBook::Job && *chapter == 26 {
+            Ok(14)
+        } else if book == &BibleBook::Job && *chapter == 27 {
+            Ok(23)
+        } else if book == &BibleBook::Job && *chapter == 28 {
+            Ok(28)
+        } else if book == &BibleBook::Job && *chapter == 29 {
+            Ok(25)
+        } else if book == &BibleBook::Job && *chapter == 30 {
+            Ok(31)
+        } else if book == &BibleBook::Job && *chapter == 31 {
+            Ok(40)
+        } else if book == &BibleBook::Job && *chapter == 32 {
+            Ok(22)
+        } else if book == &BibleBook::Job && *chapter == 33 {
+            Ok(33)
+        } else if book == &BibleBook::Job && *chapter == 34 {
+            Ok(37)
+        } else if book == &BibleBook::Job && *chapter == 35 {
+            Ok(16)
+        } else if book == &BibleBook::Job && *chapter == 36 {
+            Ok(33)
+        } else if book == &BibleBook::Job && *chapter == 37 {
+            Ok(24)
+        } else if book == &BibleBook::Job && *chapter == 38 {
+            Ok(41)
+        } else if book == &BibleBook::Job && *chapter == 39 {
+            Ok(30)
+        } else if book == &BibleBook::Job && *chapter == 40 {
+            Ok(24)
+        } else if book == &BibleBook::Job && *chapter == 41 {
+            Ok(34)
+        } else if book == &BibleBook::Job && *chapter == 42 {
+            Ok(17)
+        } else if book == &BibleBook::Psalm && *chapter == 1 {
+            Ok(6)
+        } else if book == &BibleBook::Psalm && *chapter == 2 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 3 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 4 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 5 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 6 {
+            Ok(10)
+        } else if book == &BibleBook::Psalm && *chapter == 7 {
+            Ok(17)
+        } else if book == &BibleBook::Psalm && *chapter == 8 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 9 {
+            Ok(20)
+        } else if book == &BibleBook::Psalm && *chapter == 10 {
+            Ok(18)
+        } else if book == &BibleBook::Psalm && *chapter == 11 {
+            Ok(7)
+        } else if book == &BibleBook::Psalm && *chapter == 12 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 13 {
+            Ok(6)
+        } else if book == &BibleBook::Psalm && *chapter == 14 {
+            Ok(7)
+        } else if book == &BibleBook::Psalm && *chapter == 15 {
+            Ok(5)
+        } else if book == &BibleBook::Psalm && *chapter == 16 {
+            Ok(11)
+        } else if book == &BibleBook::Psalm && *chapter == 17 {
+            Ok(15)
+        } else if book == &BibleBook::Psalm && *chapter == 18 {
+            Ok(50)
+        } else if book == &BibleBook::Psalm && *chapter == 19 {
+            Ok(14)
+        } else if book == &BibleBook::Psalm && *chapter == 20 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 21 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 22 {
+            Ok(31)
+        } else if book == &BibleBook::Psalm && *chapter == 23 {
+            Ok(6)
+        } else if book == &BibleBook::Psalm && *chapter == 24 {
+            Ok(10)
+        } else if book == &BibleBook::Psalm && *chapter == 25 {
+            Ok(22)
+        } else if book == &BibleBook::Psalm && *chapter == 26 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 27 {
+            Ok(14)
+        } else if book == &BibleBook::Psalm && *chapter == 28 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 29 {
+            Ok(11)
+        } else if book == &BibleBook::Psalm && *chapter == 30 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 31 {
+            Ok(24)
+        } else if book == &BibleBook::Psalm && *chapter == 32 {
+            Ok(11)
+        } else if book == &BibleBook::Psalm && *chapter == 33 {
+            Ok(22)
+        } else if book == &BibleBook::Psalm && *chapter == 34 {
+            Ok(22)
+        } else if book == &BibleBook::Psalm && *chapter == 35 {
+            Ok(28)
+        } else if book == &BibleBook::Psalm && *chapter == 36 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 37 {
+            Ok(40)
+        } else if book == &BibleBook::Psalm && *chapter == 38 {
+            Ok(22)
+        } else if book == &BibleBook::Psalm && *chapter == 39 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 40 {
+            Ok(17)
+        } else if book == &BibleBook::Psalm && *chapter == 41 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 42 {
+            Ok(11)
+        } else if book == &BibleBook::Psalm && *chapter == 43 {
+            Ok(5)
+        } else if book == &BibleBook::Psalm && *chapter == 44 {
+            Ok(26)
+        } else if book == &BibleBook::Psalm && *chapter == 45 {
+            Ok(17)
+        } else if book == &BibleBook::Psalm && *chapter == 46 {
+            Ok(11)
+        } else if book == &BibleBook::Psalm && *chapter == 47 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 48 {
+            Ok(14)
+        } else if book == &BibleBook::Psalm && *chapter == 49 {
+            Ok(20)
+        } else if book == &BibleBook::Psalm && *chapter == 50 {
+            Ok(23)
+        } else if book == &BibleBook::Psalm && *chapter == 51 {
+            Ok(19)
+        } else if book == &BibleBook::Psalm && *chapter == 52 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 53 {
+            Ok(6)
+        } else if book == &BibleBook::Psalm && *chapter == 54 {
+            Ok(7)
+        } else if book == &BibleBook::Psalm && *chapter == 55 {
+            Ok(23)
+        } else if book == &BibleBook::Psalm && *chapter == 56 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 57 {
+            Ok(11)
+        } else if book == &BibleBook::Psalm && *chapter == 58 {
+            Ok(11)
+        } else if book == &BibleBook::Psalm && *chapter == 59 {
+            Ok(17)
+        } else if book == &BibleBook::Psalm && *chapter == 60 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 61 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 62 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 63 {
+            Ok(11)
+        } else if book == &BibleBook::Psalm && *chapter == 64 {
+            Ok(10)
+        } else if book == &BibleBook::Psalm && *chapter == 65 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 66 {
+            Ok(20)
+        } else if book == &BibleBook::Psalm && *chapter == 67 {
+            Ok(7)
+        } else if book == &BibleBook::Psalm && *chapter == 68 {
+            Ok(35)
+        } else if book == &BibleBook::Psalm && *chapter == 69 {
+            Ok(36)
+        } else if book == &BibleBook::Psalm && *chapter == 70 {
+            Ok(5)
+        } else if book == &BibleBook::Psalm && *chapter == 71 {
+            Ok(24)
+        } else if book == &BibleBook::Psalm && *chapter == 72 {
+            Ok(20)
+        } else if book == &BibleBook::Psalm && *chapter == 73 {
+            Ok(28)
+        } else if book == &BibleBook::Psalm && *chapter == 74 {
+            Ok(23)
+        } else if book == &BibleBook::Psalm && *chapter == 75 {
+            Ok(10)
+        } else if book == &BibleBook::Psalm && *chapter == 76 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 77 {
+            Ok(20)
+        } else if book == &BibleBook::Psalm && *chapter == 78 {
+            Ok(72)
+        } else if book == &BibleBook::Psalm && *chapter == 79 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 80 {
+            Ok(19)
+        } else if book == &BibleBook::Psalm && *chapter == 81 {
+            Ok(16)
+        } else if book == &BibleBook::Psalm && *chapter == 82 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 83 {
+            Ok(18)
+        } else if book == &BibleBook::Psalm && *chapter == 84 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 85 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 86 {
+            Ok(17)
+        } else if book == &BibleBook::Psalm && *chapter == 87 {
+            Ok(7)
+        } else if book == &BibleBook::Psalm && *chapter == 88 {
+            Ok(18)
+        } else if book == &BibleBook::Psalm && *chapter == 89 {
+            Ok(52)
+        } else if book == &BibleBook::Psalm && *chapter == 90 {
+            Ok(17)
+        } else if book == &BibleBook::Psalm && *chapter == 91 {
+            Ok(16)
+        } else if book == &BibleBook::Psalm && *chapter == 92 {
+            Ok(15)
+        } else if book == &BibleBook::Psalm && *chapter == 93 {
+            Ok(5)
+        } else if book == &BibleBook::Psalm && *chapter == 94 {
+            Ok(23)
+        } else if book == &BibleBook::Psalm && *chapter == 95 {
+            Ok(11)
+        } else if book == &BibleBook::Psalm && *chapter == 96 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 97 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 98 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 99 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 100 {
+            Ok(5)
+        } else if book == &BibleBook::Psalm && *chapter == 101 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 102 {
+            Ok(28)
+        } else if book == &BibleBook::Psalm && *chapter == 103 {
+            Ok(22)
+        } else if book == &BibleBook::Psalm && *chapter == 104 {
+            Ok(35)
+        } else if book == &BibleBook::Psalm && *chapter == 105 {
+            Ok(45)
+        } else if book == &BibleBook::Psalm && *chapter == 106 {
+            Ok(48)
+        } else if book == &BibleBook::Psalm && *chapter == 107 {
+            Ok(43)
+        } else if book == &BibleBook::Psalm && *chapter == 108 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 109 {
+            Ok(31)
+        } else if book == &BibleBook::Psalm && *chapter == 110 {
+            Ok(7)
+        } else if book == &BibleBook::Psalm && *chapter == 111 {
+            Ok(10)
+        } else if book == &BibleBook::Psalm && *chapter == 112 {
+            Ok(10)
+        } else if book == &BibleBook::Psalm && *chapter == 113 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 114 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 115 {
+            Ok(18)
+        } else if book == &BibleBook::Psalm && *chapter == 116 {
+            Ok(19)
+        } else if book == &BibleBook::Psalm && *chapter == 117 {
+            Ok(2)
+        } else if book == &BibleBook::Psalm && *chapter == 118 {
+            Ok(29)
+        } else if book == &BibleBook::Psalm && *chapter == 119 {
+            Ok(176)
+        } else if book == &BibleBook::Psalm && *chapter == 120 {
+            Ok(7)
+        } else if book == &BibleBook::Psalm && *chapter == 121 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 122 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 123 {
+            Ok(4)
+        } else if book == &BibleBook::Psalm && *chapter == 124 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 125 {
+            Ok(5)
+        } else if book == &BibleBook::Psalm && *chapter == 126 {
+            Ok(6)
+        } else if book == &BibleBook::Psalm && *chapter == 127 {
+            Ok(5)
+        } else if book == &BibleBook::Psalm && *chapter == 128 {
+            Ok(6)
+        } else if book == &BibleBook::Psalm && *chapter == 129 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 130 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 131 {
+            Ok(3)
+        } else if book == &BibleBook::Psalm && *chapter == 132 {
+            Ok(18)
+        } else if book == &BibleBook::Psalm && *chapter == 133 {
+            Ok(3)
+        } else if book == &BibleBook::Psalm && *chapter == 134 {
+            Ok(3)
+        } else if book == &BibleBook::Psalm && *chapter == 135 {
+            Ok(21)
+        } else if book == &BibleBook::Psalm && *chapter == 136 {
+            Ok(26)
+        } else if book == &BibleBook::Psalm && *chapter == 137 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 138 {
+            Ok(8)
+        } else if book == &BibleBook::Psalm && *chapter == 139 {
+            Ok(24)
+        } else if book == &BibleBook::Psalm && *chapter == 140 {
+            Ok(13)
+        } else if book == &BibleBook::Psalm && *chapter == 141 {
+            Ok(10)
+        } else if book == &BibleBook::Psalm && *chapter == 142 {
+            Ok(7)
+        } else if book == &BibleBook::Psalm && *chapter == 143 {
+            Ok(12)
+        } else if book == &BibleBook::Psalm && *chapter == 144 {
+            Ok(15)
+        } else if book == &BibleBook::Psalm && *chapter == 145 {
+            Ok(21)
+        } else if book == &BibleBook::Psalm && *chapter == 146 {
+            Ok(10)
+        } else if book == &BibleBook::Psalm && *chapter == 147 {
+            Ok(20)
+        } else if book == &BibleBook::Psalm && *chapter == 148 {
+            Ok(14)
+        } else if book == &BibleBook::Psalm && *chapter == 149 {
+            Ok(9)
+        } else if book == &BibleBook::Psalm && *chapter == 150 {
+            Ok(6)
+        } else if book == &BibleBook::Proverbs && *chapter == 1 {
+            Ok(33)
+        } else if book == &BibleBook::Proverbs && *chapter == 2 {
+            Ok(22)
+        } else if book == &BibleBook::Proverbs && *chapter == 3 {
+            Ok(35)
+        } else if book == &BibleBook::Proverbs && *chapter == 4 {
+            Ok(27)
+        } else if book == &BibleBook::Proverbs && *chapter == 5 {
+            Ok(23)
+        } else if book == &BibleBook::Proverbs && *chapter == 6 {
+            Ok(35)
+        } else if book == &BibleBook::Proverbs && *chapter == 7 {
+            Ok(27)
+        } else if book == &BibleBook::Proverbs && *chapter == 8 {
+            Ok(36)
+        } else if book == &BibleBook::Proverbs && *chapter == 9 {
+            Ok(18)
+        } else if book == &BibleBook::Proverbs && *chapter == 10 {
+            Ok(32)
+        } else if book == &BibleBook::Proverbs && *chapter == 11 {
+            Ok(31)
+        } else if book == &BibleBook::Proverbs && *chapter == 12 {
+            Ok(28)
+        } else if book == &BibleBook::Proverbs && *chapter == 13 {
+            Ok(25)
+        } else if book == &BibleBook::Proverbs && *chapter == 14 {
+            Ok(35)
+        } else if book == &BibleBook::Proverbs && *chapter == 15 {
+            Ok(33)
+        } else if book == &BibleBook::Proverbs && *chapter == 16 {
+            Ok(33)
+        } else if book == &BibleBook::Proverbs && *chapter == 17 {
+            Ok(28)
+        } else if book == &BibleBook::Proverbs && *chapter == 18 {
+            Ok(24)
+        } else if book == &BibleBook::Proverbs && *chapter == 19 {
+            Ok(29)
+        } else if book == &BibleBook::Proverbs && *chapter == 20 {
+            Ok(30)
+        } else if book == &BibleBook::Proverbs && *chapter == 21 {
+            Ok(31)
+        } else if book == &BibleBook::Proverbs && *chapter == 22 {
+            Ok(29)
+        } else if book == &BibleBook::Proverbs && *chapter == 23 {
+            Ok(35)
+        } else if book == &BibleBook::Proverbs && *chapter == 24 {
+            Ok(34)
+        } else if book == &BibleBook::Proverbs && *chapter == 25 {
+            Ok(28)
+        } else if book == &BibleBook::Proverbs && *chapter == 26 {
+            Ok(28)
+        } else if book == &BibleBook::Proverbs && *chapter == 27 {
+            Ok(27)
+        } else if book == &BibleBook::Proverbs && *chapter == 28 {
+            Ok(28)
+        } else if book == &BibleBook::Proverbs && *chapter == 29 {
+            Ok(27)
+        } else if book == &BibleBook::Proverbs && *chapter == 30 {
+            Ok(33)
+        } else if book == &BibleBook::Proverbs && *chapter == 31 {
+            Ok(31)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 1 {
+            Ok(18)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 2 {
+            Ok(26)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 3 {
+            Ok(22)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 4 {
+            Ok(16)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 5 {
+            Ok(20)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 6 {
+            Ok(12)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 7 {
+            Ok(29)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 8 {
+            Ok(17)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 9 {
+            Ok(18)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 10 {
+            Ok(20)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 11 {
+            Ok(10)
+        } else if book == &BibleBook::Ecclesiastes && *chapter == 12 {
+            Ok(14)
+        } else if book == &BibleBook::SongofSolomon && *chapter == 1 {
+            Ok(17)
+        } else if book == &BibleBook::SongofSolomon && *chapter == 2 {
+            Ok(17)
+        } else if book == &BibleBook::SongofSolomon && *chapter == 3 {
+            Ok(11)
+        } else if book == &BibleBook::SongofSolomon && *chapter == 4 {
+            Ok(16)
+        } else if book == &BibleBook::SongofSolomon && *chapter == 5 {
+            Ok(16)
+        } else if book == &BibleBook::SongofSolomon && *chapter == 6 {
+            Ok(13)
+        } else if book == &BibleBook::SongofSolomon && *chapter == 7 {
+            Ok(13)
+        } else if book == &BibleBook::SongofSolomon && *chapter == 8 {
+            Ok(14)
+        } else if book == &BibleBook::Isaiah && *chapter == 1 {
+            Ok(31)
+        } else if book == &BibleBook::Isaiah && *chapter == 2 {
+            Ok(22)
+        } else if book == &BibleBook::Isaiah && *chapter == 3 {
+            Ok(26)
+        } else if book == &BibleBook::Isaiah && *chapter == 4 {
+            Ok(6)
+        } else if book == &BibleBook::Isaiah && *chapter == 5 {
+            Ok(30)
+        } else if book == &BibleBook::Isaiah && *chapter == 6 {
+            Ok(13)
+        } else if book == &BibleBook::Isaiah && *chapter == 7 {
+            Ok(25)
+        } else if book == &BibleBook::Isaiah && *chapter == 8 {
+            Ok(22)
+        } else if book == &BibleBook::Isaiah && *chapter == 9 {
+            Ok(21)
+        } else if book == &BibleBook::Isaiah && *chapter == 10 {
+            Ok(34)
+        } else if book == &BibleBook::Isaiah && *chapter == 11 {
+            Ok(16)
+        } else if book == &BibleBook::Isaiah && *chapter == 12 {
+            Ok(6)
+        } else if book == &BibleBook::Isaiah && *chapter == 13 {
+            Ok(22)
+        } else if book == &BibleBook::Isaiah && *chapter == 14 {
+            Ok(32)
+        } else if book == &BibleBook::Isaiah && *chapter == 15 {
+            Ok(9)
+        } else if book == &BibleBook::Isaiah && *chapter == 16 {
+            Ok(14)
+        } else if book == &BibleBook::Isaiah && *chapter == 17 {
+            Ok(14)
+        } else if book == &BibleBook::Isaiah && *chapter == 18 {
+            Ok(7)
+        } else if book == &BibleBook::Isaiah && *chapter == 19 {
+            Ok(25)
+        } else if book == &BibleBook::Isaiah && *chapter == 20 {
+            Ok(6)
+        } else if book == &BibleBook::Isaiah && *chapter == 21 {
+            Ok(17)
+        } else if book == &BibleBook::Isaiah && *chapter == 22 {
+            Ok(25)
+        } else if book == &BibleBook::Isaiah && *chapter == 23 {
+            Ok(18)
+        } else if book == &BibleBook::Isaiah && *chapter == 24 {
+            Ok(23)
+        } else if book == &BibleBook::Isaiah && *chapter == 25 {
+            Ok(12)
+        } else if book == &BibleBook::Isaiah && *chapter == 26 {
+            Ok(21)
+        } else if book == &BibleBook::Isaiah && *chapter == 27 {
+            Ok(13)
+        } else if book == &BibleBook::Isaiah && *chapter == 28 {
+            Ok(29)
+        } else if book == &BibleBook::Isaiah && *chapter == 29 {
+            Ok(24)
+        } else if book == &BibleBook::Isaiah && *chapter == 30 {
+            Ok(33)
+        } else if book == &BibleBook::Isaiah && *chapter == 31 {
+            Ok(9)
+        } else if book == &BibleBook::Isaiah && *chapter == 32 {
+            Ok(20)
+        } else if book == &BibleBook::Isaiah && *chapter == 33 {
+            Ok(24)
+        } else if book == &BibleBook::Isaiah && *chapter == 34 {
+            Ok(17)
+        } else if book == &BibleBook::Isaiah && *chapter == 35 {
+            Ok(10)
+        } else if book == &BibleBook::Isaiah && *chapter == 36 {
+            Ok(22)
+        } else if book == &BibleBook::Isaiah && *chapter == 37 {
+            Ok(38)
+        } else if book == &BibleBook::Isaiah && *chapter == 38 {
+            Ok(22)
+        } else if book == &BibleBook::Isaiah && *chapter == 39 {
+            Ok(8)
+        } else if book == &BibleBook::Isaiah && *chapter == 40 {
+            Ok(31)
+        } else if book == &BibleBook::Isaiah && *chapter == 41 {
+            Ok(29)
+        } else if book == &BibleBook::Isaiah && *chapter == 42 {
+            Ok(25)
+        } else if book == &BibleBook::Isaiah && *chapter == 43 {
+            Ok(28)
+        } else if book == &BibleBook::Isaiah && *chapter == 44 {
+            Ok(28)
+        } else if book == &BibleBook::Isaiah && *chapter == 45 {
+            Ok(25)
+        } else if book == &BibleBook::Isaiah && *chapter == 46 {
+            Ok(13)
+        } else if book == &BibleBook::Isaiah && *chapter == 47 {
+            Ok(15)
+        } else if book == &BibleBook::Isaiah && *chapter == 48 {
+            Ok(22)
+        } else if book == &BibleBook::Isaiah && *chapter == 49 {
+            Ok(26)
+        } else if book == &BibleBook::Isaiah && *chapter == 50 {
+            Ok(11)
+        } else if book == &BibleBook::Isaiah && *chapter == 51 {
+            Ok(23)
+        } else if book == &BibleBook::Isaiah && *chapter == 52 {
+            Ok(15)
+        } else if book == &BibleBook::Isaiah && *chapter == 53 {
+            Ok(12)
+        } else if book == &BibleBook::Isaiah && *chapter == 54 {
+            Ok(17)
+        } else if book == &BibleBook::Isaiah && *chapter == 55 {
+            Ok(13)
+        } else if book == &BibleBook::Isaiah && *chapter == 56 {
+            Ok(12)
+        } else if book == &BibleBook::Isaiah && *chapter == 57 {
+            Ok(21)
+        } else if book == &BibleBook::Isaiah && *chapter == 58 {
+            Ok(14)
+        } else if book == &BibleBook::Isaiah && *chapter == 59 {
+            Ok(21)
+        } else if book == &BibleBook::Isaiah && *chapter == 60 {
+            Ok(22)
+        } else if book == &BibleBook::Isaiah && *chapter == 61 {
+            Ok(11)
+        } else if book == &BibleBook::Isaiah && *chapter == 62 {
+            Ok(12)
+        } else if book == &BibleBook::Isaiah && *chapter == 63 {
+            Ok(19)
+        } else if book == &BibleBook::Isaiah && *chapter == 64 {
+            Ok(12)
+        } else if book == &BibleBook::Isaiah && *chapter == 65 {
+            Ok(25)
+        } else if book == &BibleBook::Isaiah && *chapter == 66 {
+            Ok(24)
+        } else if book == &BibleBook::Jeremiah && *chapter == 1 {
+            Ok(19)
+        } else if book == &BibleBook::Jeremiah && *chapter == 2 {
+            Ok(37)
+        } else if book == &BibleBook::Jeremiah && *chapter == 3 {
+            Ok(25)
+        } else if book == &BibleBook::Jeremiah && *chapter == 4 {
+            Ok(31)
+        } else if book == &BibleBook::Jeremiah && *chapter == 5 {
+            Ok(31)
+        } else if book == &BibleBook::Jeremiah && *chapter == 6 {
+            Ok(30)
+        } else if book == &BibleBook::Jeremiah && *chapter == 7 {
+            Ok(34)
+        } else if book == &BibleBook::Jeremiah && *chapter == 8 {
+            Ok(22)
+        } else if book == &BibleBook::Jeremiah && *chapter == 9 {
+            Ok(26)
+        } else if book == &BibleBook::Jeremiah && *chapter == 10 {
+            Ok(25)
+        } else if book == &BibleBook::Jeremiah && *chapter == 11 {
+            Ok(23)
+        } else if book == &BibleBook::Jeremiah && *chapter == 12 {
+            Ok(17)
+        } else if book == &BibleBook::Jeremiah && *chapter == 13 {
+            Ok(27)
+        } else if book == &BibleBook::Jeremiah && *chapter == 14 {
+            Ok(22)
+        } else if book == &BibleBook::Jeremiah && *chapter == 15 {
+            Ok(21)
+        } else if book == &BibleBook::Jeremiah && *chapter == 16 {
+            Ok(21)
+        } else if book == &BibleBook::Jeremiah && *chapter == 17 {
+            Ok(27)
+        } else if book == &BibleBook::Jeremiah && *chapter == 18 {
+            Ok(23)
+        } else if book == &BibleBook::Jeremiah && *chapter == 19 {
+            Ok(15)
+        } else if book == &BibleBook::Jeremiah && *chapter == 20 {
+            Ok(18)
+        } else if book == &BibleBook::Jeremiah && *chapter == 21 {
+            Ok(14)
+        } else if book == &BibleBook::Jeremiah && *chapter == 22 {
+            Ok(30)
+        } else if book == &BibleBook::Jeremiah && *chapter == 23 {
+            Ok(40)
+        } else if book == &BibleBook::Jeremiah && *chapter == 24 {
+            Ok(10)
+        } else if book == &BibleBook::Jeremiah && *chapter == 25 {
+            Ok(38)
+        } else if book == &BibleBook::Jeremiah && *chapter == 26 {
+            Ok(24)
+        } else if book == &BibleBook::Jeremiah && *chapter == 27 {
+            Ok(22)
+        } else if book == &BibleBook::Jeremiah && *chapter == 28 {
+            Ok(17)
+        } else if book == &BibleBook::Jeremiah && *chapter == 29 {
+            Ok(32)
+        } else if book == &BibleBook::Jeremiah && *chapter == 30 {
+            Ok(24)
+        } else if book == &BibleBook::Jeremiah && *chapter == 31 {
+            Ok(40)
+        } else if book == &BibleBook::Jeremiah && *chapter == 32 {
+            Ok(44)
+        } else if book == &BibleBook::Jeremiah && *chapter == 33 {
+            Ok(26)
+        } else if book == &BibleBook::Jeremiah && *chapter == 34 {
+            Ok(22)
+        } else if book == &BibleBook::Jeremiah && *chapter == 35 {
+            Ok(19)
+        } else if book == &BibleBook::Jeremiah && *chapter == 36 {
+            Ok(32)
+        } else if book == &BibleBook::Jeremiah && *chapter == 37 {
+            Ok(21)
+        } else if book == &BibleBook::Jeremiah && *chapter == 38 {
+            Ok(28)
+        } else if book == &BibleBook::Jeremiah && *chapter == 39 {
+            Ok(18)
+        } else if book == &BibleBook::Jeremiah && *chapter == 40 {
+            Ok(16)
+        } else if book == &BibleBook::Jeremiah && *chapter == 41 {
+            Ok(18)
+        } else if book == &BibleBook::Jeremiah && *chapter == 42 {
+            Ok(22)
+        } else if book == &BibleBook::Jeremiah && *chapter == 43 {
+            Ok(13)
+        } else if book == &BibleBook::Jeremiah && *chapter == 44 {
+            Ok(30)
+        } else if book == &BibleBook::Jeremiah && *chapter == 45 {
+            Ok(5)
+        } else if book == &BibleBook::Jeremiah && *chapter == 46 {
+            Ok(28)
+        } else if book == &BibleBook::Jeremiah && *chapter == 47 {
+            Ok(7)
+        } else if book == &BibleBook::Jeremiah && *chapter == 48 {
+            Ok(47)
+        } else if book == &BibleBook::Jeremiah && *chapter == 49 {
+            Ok(39)
+        } else if book == &BibleBook::Jeremiah && *chapter == 50 {
+            Ok(46)
+        } else if book == &BibleBook::Jeremiah && *chapter == 51 {
+            Ok(64)
+        } else if book == &BibleBook::Jeremiah && *chapter == 52 {
+            Ok(34)
+        } else if book == &BibleBook::Lamentations && *chapter == 1 {
+            Ok(22)
+        } else if book == &BibleBook::Lamentations && *chapter == 2 {
+            Ok(22)
+        } else if book == &BibleBook::Lamentations && *chapter == 3 {
+            Ok(66)
+        } else if book == &BibleBook::Lamentations && *chapter == 4 {
+            Ok(22)
+        } else if book == &BibleBook::Lamentations && *chapter == 5 {
+            Ok(22)
+        } else if book == &BibleBook::Ezekiel && *chapter == 1 {
+            Ok(28)
+        } else if book == &BibleBook::Ezekiel && *chapter == 2 {
+            Ok(10)
+        } else if book == &BibleBook::Ezekiel && *chapter == 3 {
+            Ok(27)
+        } else if book == &BibleBook::Ezekiel && *chapter == 4 {
+            Ok(17)
+        } else if book == &BibleBook::Ezekiel && *chapter == 5 {
+            Ok(17)
+        } else if book == &BibleBook::Ezekiel && *chapter == 6 {
+            Ok(14)
+        } else if book == &BibleBook::Ezekiel && *chapter == 7 {
+            Ok(27)
+        } else if book == &BibleBook::Ezekiel && *chapter == 8 {
+            Ok(18)
+        } else if book == &BibleBook::Ezekiel && *chapter == 9 {
+            Ok(11)
+        } else if book == &BibleBook::Ezekiel && *chapter == 10 {
+            Ok(22)
+        } else if book == &BibleBook::Ezekiel && *chapter == 11 {
+            Ok(25)
+        } else if book == &BibleBook::Ezekiel && *chapter == 12 {
+            Ok(28)
+        } else if book == &BibleBook::Ezekiel && *chapter == 13 {
+            Ok(23)
+        } else if book == &BibleBook::Ezekiel && *chapter == 14 {
+            Ok(23)
+        } else if book == &BibleBook::Ezekiel && *chapter == 15 {
+            Ok(8)
+        } else if book == &BibleBook::Ezekiel && *chapter == 16 {
+            Ok(63)
+        } else if book == &BibleBook::Ezekiel && *chapter == 17 {
+            Ok(24)
+        } else if book == &BibleBook::Ezekiel && *chapter == 18 {
+            Ok(32)
+        } else if book == &BibleBook::Ezekiel && *chapter == 19 {
+            Ok(14)
+        } else if book == &BibleBook::Ezekiel && *chapter == 20 {
+            Ok(49)
+        } else if book == &BibleBook::Ezekiel && *chapter == 21 {
+            Ok(32)
+        } else if book == &BibleBook::Ezekiel && *chapter == 22 {
+            Ok(31)
+        } else if book == &BibleBook::Ezekiel && *chapter == 23 {
+            Ok(49)
+        } else if book == &BibleBook::Ezekiel && *chapter == 24 {
+            Ok(27)
+        } else if book == &BibleBook::Ezekiel && *chapter == 25 {
+            Ok(17)
+        } else if book == &BibleBook::Ezekiel && *chapter == 26 {
+            Ok(21)
+        } else if book == &BibleBook::Ezekiel && *chapter == 27 {
+            Ok(36)
+        } else if book == &BibleBook::Ezekiel && *chapter == 28 {
+            Ok(26)
+        } else if book == &BibleBook::Ezekiel && *chapter == 29 {
+            Ok(21)
+        } else if book == &BibleBook::Ezekiel && *chapter == 30 {
+            Ok(26)
+        } else if book == &BibleBook::Ezekiel && *chapter == 31 {
+            Ok(18)
+        } else if book == &BibleBook::Ezekiel && *chapter == 32 {
+            Ok(32)
+        } else if book == &BibleBook::Ezekiel && *chapter == 33 {
+            Ok(33)
+        } else if book == &BibleBook::Ezekiel && *chapter == 34 {
+            Ok(31)
+        } else if book == &BibleBook::Ezekiel && *chapter == 35 {
+            Ok(15)
+        } else if book == &BibleBook::Ezekiel && *chapter == 36 {
+            Ok(38)
+        } else if book == &BibleBook::Ezekiel && *chapter == 37 {
+            Ok(28)
+        } else if book == &BibleBook::Ezekiel && *chapter == 38 {
+            Ok(23)
+        } else if book == &BibleBook::Ezekiel && *chapter == 39 {
+            Ok(29)
+        } else if book == &BibleBook::Ezekiel && *chapter == 40 {
+            Ok(49)
+        } else if book == &BibleBook::Ezekiel && *chapter == 41 {
+            Ok(26)
+        } else if book == &BibleBook::Ezekiel && *chapter == 42 {
+            Ok(20)
+        } else if book == &BibleBook::Ezekiel && *chapter == 43 {
+            Ok(27)
+        } else if book == &BibleBook::Ezekiel && *chapter == 44 {
+            Ok(31)
+        } else if book == &BibleBook::Ezekiel && *chapter == 45 {
+            Ok(25)
+        } else if book == &BibleBook::Ezekiel && *chapter == 46 {
+            Ok(24)
+        } else if book == &BibleBook::Ezekiel && *chapter == 47 {
+            Ok(23)
+        } else if book == &BibleBook::Ezekiel && *chapter == 48 {
+            Ok(35)
+        } else if book == &BibleBook::Daniel && *chapter == 1 {
+            Ok(21)
+        } else if book == &BibleBook::Daniel && *chapter == 2 {
+            Ok(49)
+        } else if book == &BibleBook::Daniel && *chapter == 3 {
+            Ok(30)
+        } else if book == &BibleBook::Daniel && *chapter == 4 {
+            Ok(37)
+        } else if book == &BibleBook::Daniel && *chapter == 5 {
+            Ok(31)
+        } else if book == &BibleBook::Daniel && *chapter == 6 {
+            Ok(28)
+        } else if book == &BibleBook::Daniel && *chapter == 7 {
+            Ok(28)
+        } else if book == &BibleBook::Daniel && *chapter == 8 {
+            Ok(27)
+        } else if book == &BibleBook::Daniel && *chapter == 9 {
+            Ok(27)
+        } else if book == &BibleBook::Daniel && *chapter == 10 {
+            Ok(21)
+        } else if book == &BibleBook::Daniel && *chapter == 11 {
+            Ok(45)
+        } else if book == &BibleBook::Daniel && *chapter == 12 {
+            Ok(13)
+        } else if book == &BibleBook::Hosea && *chapter == 1 {
+            Ok(11)
+        } else if book == &BibleBook::Hosea && *chapter == 2 {
+            Ok(23)
+        } else if book == &BibleBook::Hosea && *chapter == 3 {
+            Ok(5)
+        } else if book == &BibleBook::Hosea && *chapter == 4 {
+            Ok(19)
+        } else if book == &BibleBook::Hosea && *chapter == 5 {
+            Ok(15)
+        } else if book == &BibleBook::Hosea && *chapter == 6 {
+            Ok(11)
+        } else if book == &BibleBook::Hosea && *chapter == 7 {
+            Ok(16)
+        } else if book == &BibleBook::Hosea && *chapter == 8 {
+            Ok(14)
+        } else if book == &BibleBook::Hosea && *chapter == 9 {
+            Ok(17)
+        } else if book == &BibleBook::Hosea && *chapter == 10 {
+            Ok(15)
+        } else if book == &BibleBook::Hosea && *chapter == 11 {
+            Ok(12)
+        } else if book == &BibleBook::Hosea && *chapter == 12 {
+            Ok(14)
+        } else if book == &BibleBook::Hosea && *chapter == 13 {
+            Ok(16)
+        } else if book == &BibleBook::Hosea && *chapter == 14 {
+            Ok(9)
+        } else if book == &BibleBook::Joel && *chapter == 1 {
+            Ok(20)
+        } else if book == &BibleBook::Joel && *chapter == 2 {
+            Ok(32)
+        } else if book == &BibleBook::Joel && *chapter == 3 {
+            Ok(21)
+        } else if book == &BibleBook::Amos && *chapter == 1 {
+            Ok(15)
+        } else if book == &BibleBook::Amos && *chapter == 2 {
+            Ok(16)
+        } else if book == &BibleBook::Amos && *chapter == 3 {
+            Ok(15)
+        } else if book == &BibleBook::Amos && *chapter == 4 {
+            Ok(13)
+        } else if book == &BibleBook::Amos && *chapter == 5 {
+            Ok(27)
+        } else if book == &BibleBook::Amos && *chapter == 6 {
+            Ok(14)
+        } else if book == &BibleBook::Amos && *chapter == 7 {
+            Ok(17)
+        } else if book == &BibleBook::Amos && *chapter == 8 {
+            Ok(14)
+        } else if book == &BibleBook::Amos && *chapter == 9 {
+            Ok(15)
+        } else if book == &BibleBook::Obadiah && *chapter == 1 {
+            Ok(21)
+        } else if book == &BibleBook::Jonah && *chapter == 1 {
+            Ok(17)
+        } else if book == &BibleBook::Jonah && *chapter == 2 {
+            Ok(10)
+        } else if book == &BibleBook::Jonah && *chapter == 3 {
+            Ok(10)
+        } else if book == &BibleBook::Jonah && *chapter == 4 {
+            Ok(11)
+        } else if book == &BibleBook::Micah && *chapter == 1 {
+            Ok(16)
+        } else if book == &BibleBook::Micah && *chapter == 2 {
+            Ok(13)
+        } else if book == &BibleBook::Micah && *chapter == 3 {
+            Ok(12)
+        } else if book == &BibleBook::Micah && *chapter == 4 {
+            Ok(13)
+        } else if book == &BibleBook::Micah && *chapter == 5 {
+            Ok(15)
+        } else if book == &BibleBook::Micah && *chapter == 6 {
+            Ok(16)
+        } else if book == &BibleBook::Micah && *chapter == 7 {
+            Ok(20)
+        } else if book == &BibleBook::Nahum && *chapter == 1 {
+            Ok(15)
+        } else if book == &BibleBook::Nahum && *chapter == 2 {
+            Ok(13)
+        } else if book == &BibleBook::Nahum && *chapter == 3 {
+            Ok(19)
+        } else if book == &BibleBook::Habakkuk && *chapter == 1 {
+            Ok(17)
+        } else if book == &BibleBook::Habakkuk && *chapter == 2 {
+            Ok(20)
+        } else if book == &BibleBook::Habakkuk && *chapter == 3 {
+            Ok(19)
+        } else if book == &BibleBook::Zephaniah && *chapter == 1 {
+            Ok(18)
+        } else if book == &BibleBook::Zephaniah && *chapter == 2 {
+            Ok(15)
+        } else if book == &BibleBook::Zephaniah && *chapter == 3 {
+            Ok(20)
+        } else if book == &BibleBook::Haggai && *chapter == 1 {
+            Ok(15)
+        } else if book == &BibleBook::Haggai && *chapter == 2 {
+            Ok(23)
+        } else if book == &BibleBook::Zechariah && *chapter == 1 {
+            Ok(21)
+        } else if book == &BibleBook::Zechariah && *chapter == 2 {
+            Ok(13)
+        } else if book == &BibleBook::Zechariah && *chapter == 3 {
+            Ok(10)
+        } else if book == &BibleBook::Zechariah && *chapter == 4 {
+            Ok(14)
+        } else if book == &BibleBook::Zechariah && *chapter == 5 {
+            Ok(11)
+        } else if book == &BibleBook::Zechariah && *chapter == 6 {
+            Ok(15)
+        } else if book == &BibleBook::Zechariah && *chapter == 7 {
+            Ok(14)
+        } else if book == &BibleBook::Zechariah && *chapter == 8 {
+            Ok(23)
+        } else if book == &BibleBook::Zechariah && *chapter == 9 {
+            Ok(17)
+        } else if book == &BibleBook::Zechariah && *chapter == 10 {
+            Ok(12)
+        } else if book == &BibleBook::Zechariah && *chapter == 11 {
+            Ok(17)
+        } else if book == &BibleBook::Zechariah && *chapter == 12 {
+            Ok(14)
+        } else if book == &BibleBook::Zechariah && *chapter == 13 {
+            Ok(9)
+        } else if book == &BibleBook::Zechariah && *chapter == 14 {
+            Ok(21)
+        } else if book == &BibleBook::Malachi && *chapter == 1 {
+            Ok(14)
+        } else if book == &BibleBook::Malachi && *chapter == 2 {
+            Ok(17)
+        } else if book == &BibleBook::Malachi && *chapter == 3 {
+            Ok(18)
+        } else if book == &BibleBook::Malachi && *chapter == 4 {
+            Ok(6)
+        } else if book == &BibleBook::Matthew && *chapter == 1 {
+            Ok(25)
+        } else if book == &BibleBook::Matthew && *chapter == 2 {
+            Ok(23)
+        } else if book == &BibleBook::Matthew && *chapter == 3 {
+            Ok(17)
+        } else if book == &BibleBook::Matthew && *chapter == 4 {
+            Ok(25)
+        } else if book == &BibleBook::Matthew && *chapter == 5 {
+            Ok(48)
+        } else if book == &BibleBook::Matthew && *chapter == 6 {
+            Ok(34)
+        } else if book == &BibleBook::Matthew && *chapter == 7 {
+            Ok(29)
+        } else if book == &BibleBook::Matthew && *chapter == 8 {
+            Ok(34)
+        } else if book == &BibleBook::Matthew && *chapter == 9 {
+            Ok(38)
+        } else if book == &BibleBook::Matthew && *chapter == 10 {
+            Ok(42)
+        } else if book == &BibleBook::Matthew && *chapter == 11 {
+            Ok(30)
+        } else if book == &BibleBook::Matthew && *chapter == 12 {
+            Ok(50)
+        } else if book == &BibleBook::Matthew && *chapter == 13 {
+            Ok(58)
+        } else if book == &BibleBook::Matthew && *chapter == 14 {
+            Ok(36)
+        } else if book == &BibleBook::Matthew && *chapter == 15 {
+            Ok(39)
+        } else if book == &BibleBook::Matthew && *chapter == 16 {
+            Ok(28)
+        } else if book == &BibleBook::Matthew && *chapter == 17 {
+            Ok(27)
+        } else if book == &BibleBook::Matthew && *chapter == 18 {
+            Ok(35)
+        } else if book == &BibleBook::Matthew && *chapter == 19 {
+            Ok(30)
+        } else if book == &BibleBook::Matthew && *chapter == 20 {
+            Ok(34)
+        } else if book == &BibleBook::Matthew && *chapter == 21 {
+            Ok(46)
+        } else if book == &BibleBook::Matthew && *chapter == 22 {
+            Ok(46)
+        } else if book == &BibleBook::Matthew && *chapter == 23 {
+            Ok(39)
+        } else if book == &BibleBook::Matthew && *chapter == 24 {
+            Ok(51)
+        } else if book == &BibleBook::Matthew && *chapter == 25 {
+            Ok(46)
+        } else if book == &BibleBook::Matthew && *chapter == 26 {
+            Ok(75)
+        } else if book == &BibleBook::Matthew && *chapter == 27 {
+            Ok(66)
+        } else if book == &BibleBook::Matthew && *chapter == 28 {
+            Ok(20)
+        } else if book == &BibleBook::Mark && *chapter == 1 {
+            Ok(45)
+        } else if book == &BibleBook::Mark && *chapter == 2 {
+            Ok(28)
+        } else if book == &BibleBook::Mark && *chapter == 3 {
+            Ok(35)
+        } else if book == &BibleBook::Mark && *chapter == 4 {
+            Ok(41)
+        } else if book == &BibleBook::Mark && *chapter == 5 {
+            Ok(43)
+        } else if book == &BibleBook::Mark && *chapter == 6 {
+            Ok(56)
+        } else if book == &BibleBook::Mark && *chapter == 7 {
+            Ok(37)
+        } else if book == &BibleBook::Mark && *chapter == 8 {
+            Ok(38)
+        } else if book == &BibleBook::Mark && *chapter == 9 {
+            Ok(50)
+        } else if book == &BibleBook::Mark && *chapter == 10 {
+            Ok(52)
+        } else if book == &BibleBook::Mark && *chapter == 11 {
+            Ok(33)
+        } else if book == &BibleBook::Mark && *chapter == 12 {
+            Ok(44)
+        } else if book == &BibleBook::Mark && *chapter == 13 {
+            Ok(37)
+        } else if book == &BibleBook::Mark && *chapter == 14 {
+            Ok(72)
+        } else if book == &BibleBook::Mark && *chapter == 15 {
+            Ok(47)
+        } else if book == &BibleBook::Mark && *chapter == 16 {
+            Ok(20)
+        } else if book == &BibleBook::Luke && *chapter == 1 {
+            Ok(80)
+        } else if book == &BibleBook::Luke && *chapter == 2 {
+            Ok(52)
+        } else if book == &BibleBook::Luke && *chapter == 3 {
+            Ok(38)
+        } else if book == &BibleBook::Luke && *chapter == 4 {
+            Ok(44)
+        } else if book == &BibleBook::Luke && *chapter == 5 {
+            Ok(39)
+        } else if book == &BibleBook::Luke && *chapter == 6 {
+            Ok(49)
+        } else if book == &BibleBook::Luke && *chapter == 7 {
+            Ok(50)
+        } else if book == &BibleBook::Luke && *chapter == 8 {
+            Ok(56)
+        } else if book == &BibleBook::Luke && *chapter == 9 {
+            Ok(62)
+        } else if book == &BibleBook::Luke && *chapter == 10 {
+            Ok(42)
+        } else if book == &BibleBook::Luke && *chapter == 11 {
+            Ok(54)
+        } else if book == &BibleBook::Luke && *chapter == 12 {
+            Ok(59)
+        } else if book == &BibleBook::Luke && *chapter == 13 {
+            Ok(35)
+        } else if book == &BibleBook::Luke && *chapter == 14 {
+            Ok(35)
+        } else if book == &BibleBook::Luke && *chapter == 15 {
+            Ok(32)
+        } else if book == &BibleBook::Luke && *chapter == 16 {
+            Ok(31)
+        } else if book == &BibleBook::Luke && *chapter == 17 {
+            Ok(37)
+        } else if book == &BibleBook::Luke && *chapter == 18 {
+            Ok(43)
+        } else if book == &BibleBook::Luke && *chapter == 19 {
+            Ok(48)
+        } else if book == &BibleBook::Luke && *chapter == 20 {
+            Ok(47)
+        } else if book == &BibleBook::Luke && *chapter == 21 {
+            Ok(38)
+        } else if book == &BibleBook::Luke && *chapter == 22 {
+            Ok(71)
+        } else if book == &BibleBook::Luke && *chapter == 23 {
+            Ok(56)
+        } else if book == &BibleBook::Luke && *chapter == 24 {
+            Ok(53)
+        } else if book == &BibleBook::John && *chapter == 1 {
+            Ok(51)
+        } else if book == &BibleBook::John && *chapter == 2 {
+            Ok(25)
+        } else if book == &BibleBook::John && *chapter == 3 {
+            Ok(36)
+        } else if book == &BibleBook::John && *chapter == 4 {
+            Ok(54)
+        } else if book == &BibleBook::John && *chapter == 5 {
+            Ok(47)
+        } else if book == &BibleBook::John && *chapter == 6 {
+            Ok(71)
+        } else if book == &BibleBook::John && *chapter == 7 {
+            Ok(53)
+        } else if book == &BibleBook::John && *chapter == 8 {
+            Ok(59)
+        } else if book == &BibleBook::John && *chapter == 9 {
+            Ok(41)
+        } else if book == &BibleBook::John && *chapter == 10 {
+            Ok(42)
+        } else if book == &BibleBook::John && *chapter == 11 {
+            Ok(57)
+        } else if book == &BibleBook::John && *chapter == 12 {
+            Ok(50)
+        } else if book == &BibleBook::John && *chapter == 13 {
+            Ok(38)
+        } else if book == &BibleBook::John && *chapter == 14 {
+            Ok(31)
+        } else if book == &BibleBook::John && *chapter == 15 {
+            Ok(27)
+        } else if book == &BibleBook::John && *chapter == 16 {
+            Ok(33)
+        } else if book == &BibleBook::John && *chapter == 17 {
+            Ok(26)
+        } else if book == &BibleBook::John && *chapter == 18 {
+            Ok(40)
+        } else if book == &BibleBook::John && *chapter == 19 {
+            Ok(42)
+        } else if book == &BibleBook::John && *chapter == 20 {
+            Ok(31)
+        } else if book == &BibleBook::John && *chapter == 21 {
+            Ok(25)
+        } else if book == &BibleBook::Acts && *chapter == 1 {
+            Ok(26)
+        } else if book == &BibleBook::Acts && *chapter == 2 {
+            Ok(47)
+        } else if book == &BibleBook::Acts && *chapter == 3 {
+            Ok(26)
+        } else if book == &BibleBook::Acts && *chapter == 4 {
+            Ok(37)
+        } else if book == &BibleBook::Acts && *chapter == 5 {
+            Ok(42)
+        } else if book == &BibleBook::Acts && *chapter == 6 {
+            Ok(15)
+        } else if book == &BibleBook::Acts && *chapter == 7 {
+            Ok(60)
+        } else if book == &BibleBook::Acts && *chapter == 8 {
+            Ok(40)
+        } else if book == &BibleBook::Acts && *chapter == 9 {
+            Ok(43)
+        } else if book == &BibleBook::Acts && *chapter == 10 {
+            Ok(48)
+        } else if book == &BibleBook::Acts && *chapter == 11 {
+            Ok(30)
+        } else if book == &BibleBook::Acts && *chapter == 12 {
+            Ok(25)
+        } else if book == &BibleBook::Acts && *chapter == 13 {
+            Ok(52)
+        } else if book == &BibleBook::Acts && *chapter == 14 {
+            Ok(28)
+        } else if book == &BibleBook::Acts && *chapter == 15 {
+            Ok(41)
+        } else if book == &BibleBook::Acts && *chapter == 16 {
+            Ok(40)
+        } else if book == &BibleBook::Acts && *chapter == 17 {
+            Ok(34)
+        } else if book == &BibleBook::Acts && *chapter == 18 {
+            Ok(28)
+        } else if book == &BibleBook::Acts && *chapter == 19 {
+            Ok(41)
+        } else if book == &BibleBook::Acts && *chapter == 20 {
+            Ok(38)
+        } else if book == &BibleBook::Acts && *chapter == 21 {
+            Ok(40)
+        } else if book == &BibleBook::Acts && *chapter == 22 {
+            Ok(30)
+        } else if book == &BibleBook::Acts && *chapter == 23 {
+            Ok(35)
+        } else if book == &BibleBook::Acts && *chapter == 24 {
+            Ok(27)
+        } else if book == &BibleBook::Acts && *chapter == 25 {
+            Ok(27)
+        } else if book == &BibleBook::Acts && *chapter == 26 {
+            Ok(32)
+        } else if book == &BibleBook::Acts && *chapter == 27 {
+            Ok(44)
+        } else if book == &BibleBook::Acts && *chapter == 28 {
+            Ok(31)
+        } else if book == &BibleBook::Romans && *chapter == 1 {
+            Ok(32)
+        } else if book == &BibleBook::Romans && *chapter == 2 {
+            Ok(29)
+        } else if book == &BibleBook::Romans && *chapter == 3 {
+            Ok(31)
+        } else if book == &BibleBook::Romans && *chapter == 4 {
+            Ok(25)
+        } else if book == &BibleBook::Romans && *chapter == 5 {
+            Ok(21)
+        } else if book == &BibleBook::Romans && *chapter == 6 {
+            Ok(23)
+        } else if book == &BibleBook::Romans && *chapter == 7 {
+            Ok(25)
+        } else if book == &BibleBook::Romans && *chapter == 8 {
+            Ok(39)
+        } else if book == &BibleBook::Romans && *chapter == 9 {
+            Ok(33)
+        } else if book == &BibleBook::Romans && *chapter == 10 {
+            Ok(21)
+        } else if book == &BibleBook::Romans && *chapter == 11 {
+            Ok(36)
+        } else if book == &BibleBook::Romans && *chapter == 12 {
+            Ok(21)
+        } else if book == &BibleBook::Romans && *chapter == 13 {
+            Ok(14)
+        } else if book == &BibleBook::Romans && *chapter == 14 {
+            Ok(23)
+        } else if book == &BibleBook::Romans && *chapter == 15 {
+            Ok(33)
+        } else if book == &BibleBook::Romans && *chapter == 16 {
+            Ok(27)
+        } else if book == &BibleBook::ICorinthians && *chapter == 1 {
+            Ok(31)
+        } else if book == &BibleBook::ICorinthians && *chapter == 2 {
+            Ok(16)
+        } else if book == &BibleBook::ICorinthians && *chapter == 3 {
+            Ok(23)
+        } else if book == &BibleBook::ICorinthians && *chapter == 4 {
+            Ok(21)
+        } else if book == &BibleBook::ICorinthians && *chapter == 5 {
+            Ok(13)
+        } else if book == &BibleBook::ICorinthians && *chapter == 6 {
+            Ok(20)
+        } else if book == &BibleBook::ICorinthians && *chapter == 7 {
+            Ok(40)
+        } else if book == &BibleBook::ICorinthians && *chapter == 8 {
+            Ok(13)
+        } else if book == &BibleBook::ICorinthians && *chapter == 9 {
+            Ok(27)
+        } else if book == &BibleBook::ICorinthians && *chapter == 10 {
+            Ok(33)
+        } else if book == &BibleBook::ICorinthians && *chapter == 11 {
+            Ok(34)
+        } else if book == &BibleBook::ICorinthians && *chapter == 12 {
+            Ok(31)
+        } else if book == &BibleBook::ICorinthians && *chapter == 13 {
+            Ok(13)
+        } else if book == &BibleBook::ICorinthians && *chapter == 14 {
+            Ok(40)
+        } else if book == &BibleBook::ICorinthians && *chapter == 15 {
+            Ok(58)
+        } else if book == &BibleBook::ICorinthians && *chapter == 16 {
+            Ok(24)
+        } else if book == &BibleBook::IICorinthians && *chapter == 1 {
+            Ok(24)
+        } else if book == &BibleBook::IICorinthians && *chapter == 2 {
+            Ok(17)
+        } else if book == &BibleBook::IICorinthians && *chapter == 3 {
+            Ok(18)
+        } else if book == &BibleBook::IICorinthians && *chapter == 4 {
+            Ok(18)
+        } else if book == &BibleBook::IICorinthians && *chapter == 5 {
+            Ok(21)
+        } else if book == &BibleBook::IICorinthians && *chapter == 6 {
+            Ok(18)
+        } else if book == &BibleBook::IICorinthians && *chapter == 7 {
+            Ok(16)
+        } else if book == &BibleBook::IICorinthians && *chapter == 8 {
+            Ok(24)
+        } else if book == &BibleBook::IICorinthians && *chapter == 9 {
+            Ok(15)
+        } else if book == &BibleBook::IICorinthians && *chapter == 10 {
+            Ok(18)
+        } else if book == &BibleBook::IICorinthians && *chapter == 11 {
+            Ok(33)
+        } else if book == &BibleBook::IICorinthians && *chapter == 12 {
+            Ok(21)
+        } else if book == &BibleBook::IICorinthians && *chapter == 13 {
+            Ok(14)
+        } else if book == &BibleBook::Galatians && *chapter == 1 {
+            Ok(24)
+        } else if book == &BibleBook::Galatians && *chapter == 2 {
+            Ok(21)
+        } else if book == &BibleBook::Galatians && *chapter == 3 {
+            Ok(29)
+        } else if book == &BibleBook::Galatians && *chapter == 4 {
+            Ok(31)
+        } else if book == &BibleBook::Galatians && *chapter == 5 {
+            Ok(26)
+        } else if book == &BibleBook::Galatians && *chapter == 6 {
+            Ok(18)
+        } else if book == &BibleBook::Ephesians && *chapter == 1 {
+            Ok(23)
+        } else if book == &BibleBook::Ephesians && *chapter == 2 {
+            Ok(22)
+        } else if book == &BibleBook::Ephesians && *chapter == 3 {
+            Ok(21)
+        } else if book == &BibleBook::Ephesians && *chapter == 4 {
+            Ok(32)
+        } else if book == &BibleBook::Ephesians && *chapter == 5 {
+            Ok(33)
+        } else if book == &BibleBook::Ephesians && *chapter == 6 {
+            Ok(24)
+        } else if book == &BibleBook::Philippians && *chapter == 1 {
+            Ok(30)
+        } else if book == &BibleBook::Philippians && *chapter == 2 {
+            Ok(30)
+        } else if book == &BibleBook::Philippians && *chapter == 3 {
+            Ok(21)
+        } else if book == &BibleBook::Philippians && *chapter == 4 {
+            Ok(23)
+        } else if book == &BibleBook::Colossians && *chapter == 1 {
+            Ok(29)
+        } else if book == &BibleBook::Colossians && *chapter == 2 {
+            Ok(23)
+        } else if book == &BibleBook::Colossians && *chapter == 3 {
+            Ok(25)
+        } else if book == &BibleBook::Colossians && *chapter == 4 {
+            Ok(18)
+        } else if book == &BibleBook::IThessalonians && *chapter == 1 {
+            Ok(10)
+        } else if book == &BibleBook::IThessalonians && *chapter == 2 {
+            Ok(20)
+        } else if book == &BibleBook::IThessalonians && *chapter == 3 {
+            Ok(13)
+        } else if book == &BibleBook::IThessalonians && *chapter == 4 {
+            Ok(18)
+        } else if book == &BibleBook::IThessalonians && *chapter == 5 {
+            Ok(28)
+        } else if book == &BibleBook::IIThessalonians && *chapter == 1 {
+            Ok(12)
+        } else if book == &BibleBook::IIThessalonians && *chapter == 2 {
+            Ok(17)
+        } else if book == &BibleBook::IIThessalonians && *chapter == 3 {
+            Ok(18)
+        } else if book == &BibleBook::ITimothy && *chapter == 1 {
+            Ok(20)
+        } else if book == &BibleBook::ITimothy && *chapter == 2 {
+            Ok(15)
+        } else if book == &BibleBook::ITimothy && *chapter == 3 {
+            Ok(16)
+        } else if book == &BibleBook::ITimothy && *chapter == 4 {
+            Ok(16)
+        } else if book == &BibleBook::ITimothy && *chapter == 5 {
+            Ok(25)
+        } else if book == &BibleBook::ITimothy && *chapter == 6 {
+            Ok(21)
+        } else if book == &BibleBook::IITimothy && *chapter == 1 {
+            Ok(18)
+        } else if book == &BibleBook::IITimothy && *chapter == 2 {
+            Ok(26)
+        } else if book == &BibleBook::IITimothy && *chapter == 3 {
+            Ok(17)
+        } else if book == &BibleBook::IITimothy && *chapter == 4 {
+            Ok(22)
+        } else if book == &BibleBook::Titus && *chapter == 1 {
+            Ok(16)
+        } else if book == &BibleBook::Titus && *chapter == 2 {
+            Ok(15)
+        } else if book == &BibleBook::Titus && *chapter == 3 {
+            Ok(15)
+        } else if book == &BibleBook::Philemon && *chapter == 1 {
+            Ok(25)
+        } else if book == &BibleBook::Hebrews && *chapter == 1 {
+            Ok(14)
+        } else if book == &BibleBook::Hebrews && *chapter == 2 {
+            Ok(18)
+        } else if book == &BibleBook::Hebrews && *chapter == 3 {
+            Ok(19)
+        } else if book == &BibleBook::Hebrews && *chapter == 4 {
+            Ok(16)
+        } else if book == &BibleBook::Hebrews && *chapter == 5 {
+            Ok(14)
+        } else if book == &BibleBook::Hebrews && *chapter == 6 {
+            Ok(20)
+        } else if book == &BibleBook::Hebrews && *chapter == 7 {
+            Ok(28)
+        } else if book == &BibleBook::Hebrews && *chapter == 8 {
+            Ok(13)
+        } else if book == &BibleBook::Hebrews && *chapter == 9 {
+            Ok(28)
+        } else if book == &BibleBook::Hebrews && *chapter == 10 {
+            Ok(39)
+        } else if book == &BibleBook::Hebrews && *chapter == 11 {
+            Ok(40)
+        } else if book == &BibleBook::Hebrews && *chapter == 12 {
+            Ok(29)
+        } else if book == &BibleBook::Hebrews && *chapter == 13 {
+            Ok(25)
+        } else if book == &BibleBook::James && *chapter == 1 {
+            Ok(27)
+        } else if book == &BibleBook::James && *chapter == 2 {
+            Ok(26)
+        } else if book == &BibleBook::James && *chapter == 3 {
+            Ok(18)
+        } else if book == &BibleBook::James && *chapter == 4 {
+            Ok(17)
+        } else if book == &BibleBook::James && *chapter == 5 {
+            Ok(20)
+        } else if book == &BibleBook::IPeter && *chapter == 1 {
+            Ok(25)
+        } else if book == &BibleBook::IPeter && *chapter == 2 {
+            Ok(25)
+        } else if book == &BibleBook::IPeter && *chapter == 3 {
+            Ok(22)
+        } else if book == &BibleBook::IPeter && *chapter == 4 {
+            Ok(19)
+        } else if book == &BibleBook::IPeter && *chapter == 5 {
+            Ok(14)
+        } else if book == &BibleBook::IIPeter && *chapter == 1 {
+            Ok(21)
+        } else if book == &BibleBook::IIPeter && *chapter == 2 {
+            Ok(22)
+        } else if book == &BibleBook::IIPeter && *chapter == 3 {
+            Ok(18)
+        } else if book == &BibleBook::IJohn && *chapter == 1 {
+            Ok(10)
+        } else if book == &BibleBook::IJohn && *chapter == 2 {
+            Ok(29)
+        } else if book == &BibleBook::IJohn && *chapter == 3 {
+            Ok(24)
+        } else if book == &BibleBook::IJohn && *chapter == 4 {
+            Ok(21)
+        } else if book == &BibleBook::IJohn && *chapter == 5 {
+            Ok(21)
+        } else if book == &BibleBook::IIJohn && *chapter == 1 {
+            Ok(13)
+        } else if book == &BibleBook::IIIJohn && *chapter == 1 {
+            Ok(14)
+        } else if book == &BibleBook::Jude && *chapter == 1 {
+            Ok(25)
+        } else if book == &BibleBook::Revelation && *chapter == 1 {
+            Ok(20)
+        } else if book == &BibleBook::Revelation && *chapter == 2 {
+            Ok(29)
+        } else if book == &BibleBook::Revelation && *chapter == 3 {
+            Ok(22)
+        } else if book == &BibleBook::Revelation && *chapter == 4 {
+            Ok(11)
+        } else if book == &BibleBook::Revelation && *chapter == 5 {
+            Ok(14)
+        } else if book == &BibleBook::Revelation && *chapter == 6 {
+            Ok(17)
+        } else if book == &BibleBook::Revelation && *chapter == 7 {
+            Ok(17)
+        } else if book == &BibleBook::Revelation && *chapter == 8 {
+            Ok(13)
+        } else if book == &BibleBook::Revelation && *chapter == 9 {
+            Ok(21)
+        } else if book == &BibleBook::Revelation && *chapter == 10 {
+            Ok(11)
+        } else if book == &BibleBook::Revelation && *chapter == 11 {
+            Ok(19)
+        } else if book == &BibleBook::Revelation && *chapter == 12 {
+            Ok(17)
+        } else if book == &BibleBook::Revelation && *chapter == 13 {
+            Ok(18)
+        } else if book == &BibleBook::Revelation && *chapter == 14 {
+            Ok(20)
+        } else if book == &BibleBook::Revelation && *chapter == 15 {
+            Ok(8)
+        } else if book == &BibleBook::Revelation && *chapter == 16 {
+            Ok(21)
+        } else if book == &BibleBook::Revelation && *chapter == 17 {
+            Ok(18)
+        } else if book == &BibleBook::Revelation && *chapter == 18 {
+            Ok(24)
+        } else if book == &BibleBook::Revelation && *chapter == 19 {
+            Ok(21)
+        } else if book == &BibleBook::Revelation && *chapter == 20 {
+            Ok(15)
+        } else if book == &BibleBook::Revelation && *chapter == 21 {
+            Ok(27)
+        } else if book == &BibleBook::Revelation && *chapter == 22 {
+            Ok(21)
+        } else {
+            Err(BibleReferenceValidationError {
+                problem: BibleReferenceProblem::ChapterDoesNotExist,
+            })
+        }
+    }
+
+    #[test]
+    fn test_verse_table_matches_legacy_implementation() {
+        for book in BibleBook::all()
+            .iter()
+            .filter(|book| !book.is_deuterocanonical())
+        {
+            for chapter in 0..=BibleChapter::MAX {
+                assert_eq!(
+                    get_masoretic_number_of_verses(book, &chapter).ok(),
+                    legacy_number_of_verses(book, &chapter).ok(),
+                    "{:?} {}",
+                    book,
+                    chapter
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_totals() {
+        assert_eq!(total_verses_in_book(&BibleBook::Psalm), 2461);
+        assert_eq!(total_verses_in_book(&BibleBook::Revelation), 404);
+        #[cfg(not(feature = "deuterocanon"))]
+        {
+            assert_eq!(total_number_of_verses(), 31102);
+            assert_eq!(total_number_of_chapters(), 1189);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_validate_all() {
+        let results = validate_all(&[
+            (BibleBook::Genesis, 1, 1),
+            (BibleBook::Genesis, 51, 1),
+            (BibleBook::Jude, 1, 26),
+            (BibleBook::Psalm, 3, 0),
+        ]);
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results[0],
+            Ok(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap())
+        );
+        assert_eq!(
+            results[1].as_ref().unwrap_err().problem,
+            BibleReferenceProblem::ChapterDoesNotExist
+        );
+        assert_eq!(
+            results[2].as_ref().unwrap_err().problem,
+            BibleReferenceProblem::VerseDoesNotExist
+        );
+        assert!(results[3].is_ok());
+        assert!(validate_all(&[]).is_empty());
+
+        let results =
+            validate_all_strings(&["John 3:16-18", "Johannes 3,16", "Foo 1:1", "Jude 30"]);
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().to_string(), "John 3:16-18");
+        assert!(results[1].is_ok());
+        assert!(results[2].is_err());
+        assert!(results[3].is_err());
+    }
+
     #[test]
     fn test_bibleverse_exists() {
         assert!(validate_book_chapter_verse(&BibleBook::John, &3, &16).is_ok());
@@ -2545,4 +3004,66 @@ mod tests {
                 == BibleReferenceProblem::ChapterDoesNotExist
         );
     }
+
+    #[cfg(feature = "deuterocanon")]
+    #[test]
+    fn test_deuterocanonical_verses() {
+        assert!(validate_book_chapter_verse(&BibleBook::Tobit, &14, &15).is_ok());
+        assert!(validate_book_chapter_verse(&BibleBook::Tobit, &14, &16).is_err());
+        assert!(validate_book_chapter_verse(&BibleBook::Baruch, &6, &73).is_ok());
+        assert!(validate_book_chapter_verse(&BibleBook::Susanna, &2, &1).is_err());
+        assert!(validate_book_chapter(&BibleBook::Sirach, &51).is_ok());
+        for book in BibleBook::all()
+            .iter()
+            .filter(|book| book.is_deuterocanonical())
+        {
+            for chapter in 1..=get_number_of_chapters(book) {
+                assert!(get_number_of_verses(book, &chapter).is_ok());
+            }
+            assert!(get_number_of_verses(book, &(get_number_of_chapters(book) + 1)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validation_with_versification() {
+        // Psalm 151 only exists in the Septuagint
+        assert!(validate_book_chapter(&BibleBook::Psalm, &151).is_err());
+        assert!(
+            validate_book_chapter_with_versification(
+                &BibleBook::Psalm,
+                &151,
+                &Versification::Septuagint
+            )
+            .is_ok()
+        );
+        // Psalm 9 of the Septuagint contains Psalm 9 and 10 of the Masoretic text
+        assert!(validate_book_chapter_verse(&BibleBook::Psalm, &9, &38).is_err());
+        assert!(
+            validate_book_chapter_verse_with_versification(
+                &BibleBook::Psalm,
+                &9,
+                &38,
+                &Versification::Septuagint
+            )
+            .is_ok()
+        );
+        assert_eq!(
+            get_number_of_chapters_with_versification(&BibleBook::Psalm, &Versification::Masoretic),
+            get_number_of_chapters(&BibleBook::Psalm)
+        );
+    }
+
+    #[test]
+    fn test_superscription_validation() {
+        assert!(validate_book_chapter_verse(&BibleBook::Psalm, &3, &0).is_ok());
+        assert!(validate_book_chapter_verse(&BibleBook::Psalm, &1, &0).is_err());
+        assert!(validate_book_chapter_verse(&BibleBook::Psalm, &151, &0).is_err());
+        assert!(validate_book_chapter_verse(&BibleBook::John, &3, &0).is_err());
+        assert_eq!(
+            (1..=150)
+                .filter(|chapter| has_superscription(&BibleBook::Psalm, chapter))
+                .count(),
+            116
+        );
+    }
 }
@@ -0,0 +1,115 @@
+//! This submodule contains the versification schemes which define the number of chapters and verses of the Bible books.
+//! The numbering of some chapters and verses differs between the traditions, e.g. the Septuagint merges Psalm 9 and 10 of the Masoretic text.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::bible::errors::*;
+use crate::bible::validate::{get_masoretic_number_of_chapters, get_masoretic_number_of_verses};
+use crate::bible::{BibleBook, BibleChapter, BibleVerse};
+
+/// A versification scheme which defines the number of chapters and verses of the Bible books.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Versification {
+    /// The versification of the Masoretic text which is used by most English translations. This is the default versification.
+    #[default]
+    Masoretic,
+
+    /// The versification of the Septuagint.
+    /// # Note
+    /// This is a stub which currently only differs from the Masoretic versification in the numbering of the Psalms (including Psalm 151).
+    Septuagint,
+}
+
+impl Versification {
+    /// Returns the number of chapters of a given [BibleBook] in this versification
+    pub fn number_of_chapters(&self, book: &BibleBook) -> BibleChapter {
+        match (self, book) {
+            (Versification::Septuagint, BibleBook::Psalm) => 151,
+            _ => get_masoretic_number_of_chapters(book),
+        }
+    }
+
+    /// Returns the number of verses of a chapter in this versification
+    /// # Returns
+    /// - The number of verses if the chapter exists
+    /// - A [BibleReferenceValidationError] if the chapter does not exist
+    pub fn number_of_verses(
+        &self,
+        book: &BibleBook,
+        chapter: &BibleChapter,
+    ) -> Result<BibleVerse, BibleReferenceValidationError> {
+        match (self, book) {
+            (Versification::Septuagint, BibleBook::Psalm) => {
+                get_septuagint_number_of_psalm_verses(chapter)
+            }
+            _ => get_masoretic_number_of_verses(book, chapter),
+        }
+    }
+}
+
+/// Returns the number of verses of a Psalm in the Septuagint numbering, based on the Masoretic verses.
+fn get_septuagint_number_of_psalm_verses(
+    chapter: &BibleChapter,
+) -> Result<BibleVerse, BibleReferenceValidationError> {
+    let masoretic_verses =
+        |chapter: BibleChapter| get_masoretic_number_of_verses(&BibleBook::Psalm, &chapter);
+
+    match *chapter {
+        1..=8 | 148..=150 => masoretic_verses(*chapter),
+        // Psalm 9 and 10 are one psalm
+        9 => Ok(masoretic_verses(9)? + masoretic_verses(10)?),
+        10..=112 | 116..=145 => masoretic_verses(*chapter + 1),
+        // Psalm 114 and 115 are one psalm
+        113 => Ok(masoretic_verses(114)? + masoretic_verses(115)?),
+        // Psalm 116 is split into two psalms (116:1-9 and 116:10-19)
+        114 => Ok(9),
+        115 => Ok(masoretic_verses(116)? - 9),
+        // Psalm 147 is split into two psalms (147:1-11 and 147:12-20)
+        146 => Ok(11),
+        147 => Ok(masoretic_verses(147)? - 11),
+        151 => Ok(7),
+        _ => Err(BibleReferenceValidationError {
+            problem: BibleReferenceProblem::ChapterDoesNotExist,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_septuagint_psalms() {
+        let septuagint_verses: u32 = (1..=150)
+            .map(|chapter| {
+                Versification::Septuagint
+                    .number_of_verses(&BibleBook::Psalm, &chapter)
+                    .unwrap() as u32
+            })
+            .sum();
+        let masoretic_verses: u32 = (1..=150)
+            .map(|chapter| {
+                Versification::Masoretic
+                    .number_of_verses(&BibleBook::Psalm, &chapter)
+                    .unwrap() as u32
+            })
+            .sum();
+        // The Psalms 1-150 contain the same verses in both versifications
+        assert_eq!(septuagint_verses, masoretic_verses);
+        assert_eq!(
+            Versification::Septuagint.number_of_verses(&BibleBook::Psalm, &22),
+            Versification::Masoretic.number_of_verses(&BibleBook::Psalm, &23)
+        );
+        assert!(
+            Versification::Septuagint
+                .number_of_verses(&BibleBook::Psalm, &152)
+                .is_err()
+        );
+        assert_eq!(
+            Versification::Septuagint.number_of_chapters(&BibleBook::John),
+            Versification::Masoretic.number_of_chapters(&BibleBook::John)
+        );
+    }
+}
@@ -13,45 +13,54 @@
 //! - Upcast/downcast Bible references to/from different types
 //! - Iterating over Bible references (e.g all books of the Bible, all chapters of a book, all verses of a chapter)
 //!
+//! # `no_std` support
+//! Without the default `std` feature, the crate is `#![no_std]` and only requires `alloc`. The [bible] module (books, references, validation and versification) is available in this mode,
+//! while the `referencing` module, the top-level parsing and translation functions and the English `Display` implementations of the references require `std`.
+//!
 //! # Examples
 //! ## Does Genesis 4:5 exist?
-//! 
+//!
 //! ```
 //! // Genesis 4:5 exists and is a valid Bible reference (of type BibleVerse)
+//! # #[cfg(feature = "std")]
 //! assert!(bibleref::parse("Genesis 4:5").is_ok());
 //! ```
 //!
 //! ## How about 出埃及记2:3 (Exodus 2:3 in Chinese)?
-//! 
+//!
 //! ```
 //! // 出埃及记2:3 exists and is a valid Bible reference (of type BibleVerse)
+//! # #[cfg(feature = "std")]
 //! assert!(bibleref::parse("出埃及记2:3").is_ok());
 //! ```
 //!
 //! ## Translate "John 3:16-18" into German
-//! 
+//!
 //! ```
 //! // The German translation of John 3:16-18 is "Johannes 3,16-18"
+//! # #[cfg(feature = "std")] {
 //! let german_reference: String = bibleref::translate("John 3:16-18", "de").unwrap();
 //! assert_eq!(german_reference, "Johannes 3,16-18");
+//! # }
 //! ```
 //!
 //! ## Get the number of chapters in the book of Revelation
-//! 
+//!
 //! ```
 //! use bibleref::bible::validate::get_number_of_chapters;
 //! use bibleref::bible::BibleBook;
 //! // The book of Revelation has 22 chapters
 //! assert_eq!(get_number_of_chapters(&BibleBook::Revelation), 22);
 //! ```
-//! 
+//!
 //! ## Print all books of the Bible in English and German
-//! 
+//!
 //! ```
+//! # #[cfg(feature = "std")] {
 //! use bibleref::bible::{BibleReference, BibleBook, BibleBookReference};
 //! use bibleref::referencing::language::{get_reference_in_language, BookReferenceType};
 //! BibleBook::all().iter().for_each(|book| {
-//!     println!("English: {}, German: {}", 
+//!     println!("English: {}, German: {}",
 //!         get_reference_in_language(
 //!             &BibleReference::BibleBook(BibleBookReference::new(*book)),
 //!             "en",
@@ -64,16 +73,35 @@
 //!         ).unwrap()
 //!     );
 //! });
+//! # }
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod bible;
 
+#[cfg(feature = "std")]
 pub mod referencing;
 
+#[cfg(feature = "std")]
 pub mod errors;
 
-use bible::BibleReferenceRepresentation;
-use referencing::{language::get_reference_representation_in_language, parser::parse_reference};
+#[cfg(feature = "std")]
+use bible::{BibleReferenceRepresentation, aggregate_bible_representations};
+#[cfg(feature = "std")]
+use referencing::{
+    errors::{LanguageDoesNotExistError, ReferenceCreationError, ReferenceSegmentParsingError},
+    language::{
+        BookReferenceType, get_default_language, get_language_by_code,
+        get_reference_representation_in_language,
+    },
+    parser::{find_references_after, parse_reference, parse_reference_fuzzy, parse_verse_list},
+};
+#[cfg(feature = "std")]
 use std::error::Error;
+#[cfg(feature = "std")]
+use std::ops::Range;
 
 /// Parses a given bible reference with all supported languages and returns an [`Result<BibleReference, Box<dyn Error>>`] depending on whether the parsing was successful.
 /// # Params
@@ -98,6 +126,7 @@ use std::error::Error;
 /// // An empty string is not a valid Bible reference
 /// assert!(parse("").err().unwrap().downcast_ref::<ReferenceIsEmptyError>().is_some());
 /// ```
+#[cfg(feature = "std")]
 pub fn parse(bible_reference: &str) -> Result<BibleReferenceRepresentation, Box<dyn Error>> {
     match parse_reference(bible_reference) {
         Ok(bible_reference_representation_search_result) => {
@@ -109,6 +138,59 @@ pub fn parse(bible_reference: &str) -> Result<BibleReferenceRepresentation, Box<
     }
 }
 
+/// Parses a given bible reference like [parse] and additionally returns the code of the language in which it has been recognized (e.g. "de" for "Johannes 3,16").
+/// As the languages are searched in the order of their registration, a book name which exists in several languages is attributed to the first of them.
+/// # Params
+/// - `input`: the given bible reference as a string
+/// # Returns
+/// - a tuple of the [BibleReferenceRepresentation] and the language code if the BibleReference could be successfully parsed and is valid
+/// - [`Box<dyn Error>`] if parsing failed or the Bible reference is not valid.
+///
+/// # Example
+/// ```
+/// # use bibleref::parse_with_language;
+/// let (reference, language_code) = parse_with_language("Johannes 3,16").unwrap();
+/// assert_eq!(reference.to_string(), "John 3:16");
+/// assert_eq!(language_code, "de");
+/// assert_eq!(parse_with_language("Matthew 5:3-12").unwrap().1, "en");
+/// ```
+#[cfg(feature = "std")]
+pub fn parse_with_language(
+    input: &str,
+) -> Result<(BibleReferenceRepresentation, String), Box<dyn Error>> {
+    parse_reference(input).map(|search_result| {
+        (
+            search_result.bible_reference().clone(),
+            search_result.language_code().clone(),
+        )
+    })
+}
+
+/// Parses a given bible reference like [parse], but tolerates typos in the book name (e.g. "Philipians 4:13").
+/// If the book name cannot be found in any language, the closest known book name within a Levenshtein distance of `max_distance` is used instead.
+/// # Params
+/// - `input`: the given bible reference as a string
+/// - `max_distance`: the maximum number of inserted, deleted or replaced characters in the book name
+/// # Returns
+/// - [`Ok<BibleReferenceRepresentation>`] if the BibleReference could be parsed (after correcting the book name) and is valid
+/// - [`Box<dyn Error>`] if parsing failed, the Bible reference is not valid or several books are equally close to the misspelled name (an [AmbiguousBookNameError](referencing::errors::AmbiguousBookNameError))
+///
+/// # Example
+/// ```
+/// # use bibleref::parse_fuzzy;
+/// assert_eq!(parse_fuzzy("Philipians 4:13", 2).unwrap().to_string(), "Philippians 4:13");
+/// assert_eq!(parse_fuzzy("Ecclesiates 3:1-8", 2).unwrap().to_string(), "Ecclesiastes 3:1-8");
+/// assert!(parse_fuzzy("Philipians 4:13", 0).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn parse_fuzzy(
+    input: &str,
+    max_distance: usize,
+) -> Result<BibleReferenceRepresentation, Box<dyn Error>> {
+    parse_reference_fuzzy(input, max_distance)
+        .map(|search_result| search_result.bible_reference().clone())
+}
+
 /// Translates a Bible reference in an other language
 ///
 /// # Params
@@ -134,20 +216,233 @@ pub fn parse(bible_reference: &str) -> Result<BibleReferenceRepresentation, Box<
 /// let german_chapter: String = bibleref::translate("Matthew   19", "de").unwrap();
 /// assert_eq!(german_chapter, "Matthäus 19");
 /// ```
+#[cfg(feature = "std")]
 pub fn translate(bible_reference: &str, target_lang_code: &str) -> Result<String, Box<dyn Error>> {
     match parse_reference(bible_reference) {
         Ok(bible_reference_representation_search_result) => {
             match get_reference_representation_in_language(
                 bible_reference_representation_search_result.bible_reference(),
                 target_lang_code,
-                *bible_reference_representation_search_result
-                    .reference_type(),
+                *bible_reference_representation_search_result.reference_type(),
                 true,
             ) {
                 Ok(translated_reference) => Ok(translated_reference),
-                Err(err) => Err(Box::new(err)),
+                Err(err) => Err(err.into_inner()),
             }
         }
         Err(boxed_error) => Err(boxed_error),
     }
 }
+
+/// Translates a Bible reference like [translate] into the default target language, which can be configured with [set_default_language](referencing::language::set_default_language).
+/// If no default language has been set, the reference is translated into English.
+///
+/// # Params
+/// - `bible_reference`: The Bible reference in any supported language
+///
+/// # Returns
+/// A [`Result<String, Box<dyn Error>>`] with the following possible outcomes:
+/// - If the translation was successful, a String with the translated Bible reference will be returned.
+/// - If an error occurred, a [`Box<dyn Error>`] with the specific error will be returned.
+///
+/// # Example
+/// ```
+/// use bibleref::referencing::language::set_default_language;
+/// assert_eq!(bibleref::translate_default("Johannes 3,16").unwrap(), "John 3:16");
+/// set_default_language("de").unwrap();
+/// assert_eq!(bibleref::translate_default("John 3:16").unwrap(), "Johannes 3,16");
+/// ```
+#[cfg(feature = "std")]
+pub fn translate_default(bible_reference: &str) -> Result<String, Box<dyn Error>> {
+    translate(bible_reference, &get_default_language())
+}
+
+/// Translates several Bible references into an other language. The target language is only resolved once, which makes this faster than calling [translate] for every reference.
+///
+/// # Params
+/// - `refs`: The Bible references in any supported language
+/// - `target_lang_code`: The language code of the target language (such as `de`, `en`, `zh_sim`)
+///
+/// # Returns
+/// A [Vec] with one [`Result<String, Box<dyn Error>>`] per reference, in the same order:
+/// - If the translation was successful, a String with the translated Bible reference
+/// - If an error occurred (e.g. the reference is invalid or the target language does not exist), a [`Box<dyn Error>`] with the specific error
+///
+/// # Example
+/// ```
+/// let translations = bibleref::translate_many(&["Genesis 1:1", "Exodus 72", "John 3:16-18"], "de");
+/// assert_eq!(translations[0].as_ref().unwrap(), "1. Mose 1,1");
+/// assert!(translations[1].is_err());
+/// assert_eq!(translations[2].as_ref().unwrap(), "Johannes 3,16-18");
+/// ```
+#[cfg(feature = "std")]
+pub fn translate_many(
+    refs: &[&str],
+    target_lang_code: &str,
+) -> Vec<Result<String, Box<dyn Error>>> {
+    let Some(target_language) = get_language_by_code(target_lang_code) else {
+        let language_code = target_lang_code.trim().to_lowercase();
+        return refs
+            .iter()
+            .map(|_| -> Result<String, Box<dyn Error>> {
+                Err(Box::new(LanguageDoesNotExistError {
+                    language_code: language_code.clone(),
+                }))
+            })
+            .collect();
+    };
+
+    refs.iter()
+        .map(|bible_reference| {
+            let search_result = parse_reference(bible_reference)?;
+            let reference_type = *search_result.reference_type();
+            Ok(match search_result.bible_reference() {
+                BibleReferenceRepresentation::Single(reference) => {
+                    target_language.try_create_reference(reference, reference_type)?
+                }
+                BibleReferenceRepresentation::Range(range) => {
+                    target_language.try_create_bible_range(range, reference_type, true)?
+                }
+            })
+        })
+        .collect()
+}
+
+/// Normalizes a Bible reference into a canonical string, e.g. for deduplicating references entered by users.
+/// The reference is parsed with all supported languages, upcasted as far as possible (see [BibleReferenceRepresentation::try_upcast]) and rendered in the given language and style.
+/// Unlike [translate], ranges which only cover a single verse, chapter or book are always collapsed (e.g. "John 3:16-16" becomes "John 3:16") and the style of the output does not depend on the input.
+///
+/// # Params
+/// - `input`: The Bible reference in any supported language
+/// - `language_code`: The language code of the target language (such as `de`, `en`, `zh_sim`)
+/// - `ty`: The [BookReferenceType] of the book names in the output
+///
+/// # Returns
+/// A [`Result<String, Box<dyn Error>>`] with the following possible outcomes:
+/// - If the normalization was successful, a String with the normalized Bible reference will be returned.
+/// - If an error occurred (e.g. the reference is invalid or the language does not exist), a [`Box<dyn Error>`] with the specific error will be returned.
+///
+/// # Example
+/// ```
+/// # use bibleref::normalize;
+/// # use bibleref::referencing::language::BookReferenceType;
+/// assert_eq!(normalize("John 3:16-16", "en", BookReferenceType::Long).unwrap(), "John 3:16");
+/// assert_eq!(normalize("Joh 3,16", "en", BookReferenceType::Long).unwrap(), "John 3:16");
+/// // A range over a complete chapter becomes the chapter
+/// assert_eq!(normalize("Psalms 23:1-6", "de", BookReferenceType::Long).unwrap(), "Psalmen 23");
+/// assert!(normalize("Exodus 72", "en", BookReferenceType::Long).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn normalize(
+    input: &str,
+    language_code: &str,
+    ty: BookReferenceType,
+) -> Result<String, Box<dyn Error>> {
+    let search_result = parse_reference(input)?;
+    get_reference_representation_in_language(
+        &search_result.bible_reference().try_upcast(),
+        language_code,
+        ty,
+        true,
+    )
+    .map_err(ReferenceCreationError::into_inner)
+}
+
+/// Parses several Bible references which are separated by semicolons (`;`) or line breaks, e.g. "John 3:16; Romans 8:28; Psalms 23".
+/// Each segment is trimmed and parsed individually with all supported languages. Empty segments (e.g. caused by a trailing `;`) are skipped.
+/// A segment with a list of verses like "John 3:16,18" results in one reference per item, see [parse_verse_list].
+/// # Params
+/// - `input`: the given Bible references as a string
+/// # Returns
+/// A [`Result<Vec<BibleReferenceRepresentation>, Box<dyn Error>>`] with the following possible outcomes:
+/// - A [`Vec<BibleReferenceRepresentation>`] with the parsed references in the order of their appearance if all segments could be parsed
+/// - A [`Box<dyn Error>`] containing a [ReferenceSegmentParsingError] with the offending segment if one of the segments could not be parsed
+///
+/// # Example
+/// ```
+/// # use bibleref::parse_multiple;
+/// # use bibleref::referencing::errors::ReferenceSegmentParsingError;
+/// let references = parse_multiple("John 3:16; Romans 8:28; Psalms 23").unwrap();
+/// assert_eq!(references.len(), 3);
+/// // Empty segments will be skipped
+/// assert_eq!(parse_multiple("John 3:16;; Romans 8:28;").unwrap().len(), 2);
+/// // Lists of verses are split into their items
+/// assert_eq!(parse_multiple("John 3:16,18; Romans 8:28").unwrap().len(), 3);
+/// // The error contains the segment which could not be parsed
+/// let error = parse_multiple("John 3:16; Revelation 24").err().unwrap();
+/// assert_eq!(error.downcast_ref::<ReferenceSegmentParsingError>().unwrap().segment, "Revelation 24");
+/// ```
+#[cfg(feature = "std")]
+pub fn parse_multiple(input: &str) -> Result<Vec<BibleReferenceRepresentation>, Box<dyn Error>> {
+    let mut references: Vec<BibleReferenceRepresentation> = vec![];
+
+    for segment in input.split([';', '\n']) {
+        let segment = segment.trim();
+        if segment.is_empty() {
+            continue;
+        }
+        match parse_verse_list(segment) {
+            Ok(mut segment_references) => references.append(&mut segment_references),
+            Err(error) => {
+                return Err(Box::new(ReferenceSegmentParsingError {
+                    segment: segment.to_string(),
+                    error,
+                }));
+            }
+        }
+    }
+
+    Ok(references)
+}
+
+/// Finds all Bible references within a longer text (e.g. a sermon transcript or a note) in all supported languages and returns them with their position in the text, e.g. for highlighting or linking them.
+/// The references are recognized like with [find_reference_in](referencing::parser::find_reference_in): a book name followed by a chapter and/or verse, e.g. "John 3:16" or "Röm 8,28-30".
+/// Consecutive references which are only separated by spaces, commas or semicolons are merged into one entry if their passages overlap or are adjacent (e.g. "John 3:16; John 3:17-18" becomes John 3:16-18).
+/// A list of verses like "Gen 1:1, 3" is split into its items (see [parse_verse_list]), the following items only cover their verses in the text (e.g. "3").
+/// # Params
+/// - `text`: the text which is searched
+/// # Returns
+/// - A [Vec] with the byte range of each reference within `text` and the parsed reference, in the order of their appearance. The vector is empty if the text does not contain any reference.
+///
+/// # Example
+/// ```
+/// # use bibleref::extract_references;
+/// let text = "Today we read John 3:16 and Röm 8,28. Tomorrow we continue with Psalms 23.";
+/// let references = extract_references(text);
+/// assert_eq!(references.len(), 3);
+/// assert_eq!(&text[references[1].0.clone()], "Röm 8,28");
+/// assert_eq!(references[1].1.to_string(), "Romans 8:28");
+/// ```
+#[cfg(feature = "std")]
+pub fn extract_references(text: &str) -> Vec<(Range<usize>, BibleReferenceRepresentation)> {
+    let mut references: Vec<(Range<usize>, BibleReferenceRepresentation)> = vec![];
+    let mut offset = 0;
+
+    while let Some(found_references) = find_references_after(text, offset) {
+        for (span, search_result) in found_references {
+            offset = span.end;
+            let reference = search_result.bible_reference().clone();
+
+            if let Some((previous_span, previous_reference)) = references.last_mut() {
+                let is_adjacent = text[previous_span.end..span.start]
+                    .chars()
+                    .all(|c| c.is_whitespace() || matches!(c, ',' | ';' | '，' | '；'));
+                if is_adjacent {
+                    if let [merged_reference] = aggregate_bible_representations(vec![
+                        previous_reference.clone(),
+                        reference.clone(),
+                    ])
+                    .as_slice()
+                    {
+                        *previous_reference = merged_reference.clone();
+                        previous_span.end = span.end;
+                        continue;
+                    }
+                }
+            }
+            references.push((span, reference));
+        }
+    }
+
+    references
+}
@@ -1,27 +1,40 @@
 //! This module contains errors which occur while parsing Bible references into human languages.
 
-use std::fmt::{Display, Formatter};
 use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use super::language::BookReferenceType;
+use crate::bible::BibleBook;
+#[cfg(feature = "serde")]
+use crate::bible::errors::BibleReferenceValidationError;
 
 #[derive(Debug)]
 pub struct LanguageDoesNotExistError {
-    pub language_code: String
+    pub language_code: String,
 }
 
 impl Display for LanguageDoesNotExistError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "The language with language code '{}' is unknown.", self.language_code)
+        write!(
+            f,
+            "The language with language code '{}' is unknown.",
+            self.language_code
+        )
     }
 }
 impl Error for LanguageDoesNotExistError {}
 
 #[derive(Debug)]
 pub struct LanguageHasNoChapterVersDelimiterError {
-    pub language_code: String
+    pub language_code: String,
 }
 impl Display for LanguageHasNoChapterVersDelimiterError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "The language with language code '{}' has no chapter/verse delimiter.", self.language_code)
+        write!(
+            f,
+            "The language with language code '{}' has no chapter/verse delimiter.",
+            self.language_code
+        )
     }
 }
 impl Error for LanguageHasNoChapterVersDelimiterError {}
@@ -38,33 +51,288 @@ impl Error for ReferenceIsEmptyError {}
 
 #[derive(Debug)]
 pub struct BibleBookNotFoundError {
-    pub provided_bible_book_string: String
+    pub provided_bible_book_string: String,
 }
 
 impl std::fmt::Display for BibleBookNotFoundError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "The Bible book {} found in any provided language.", 
-        self.provided_bible_book_string)
+        write!(
+            f,
+            "The Bible book {} found in any provided language.",
+            self.provided_bible_book_string
+        )
     }
 }
 impl Error for BibleBookNotFoundError {}
 
+/// The [AmbiguousBookNameError] is returned by fuzzy parsing if several Bible books have a name with the same (smallest) edit distance to the provided book name.
+#[derive(Debug)]
+pub struct AmbiguousBookNameError {
+    /// The book name which has been provided
+    pub provided_bible_book_string: String,
+    /// The Bible books which are equally close to the provided book name
+    pub candidates: Vec<BibleBook>,
+}
+
+impl Display for AmbiguousBookNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The Bible book {} is ambiguous, it could be one of: {}",
+            self.provided_bible_book_string,
+            self.candidates
+                .iter()
+                .map(|book| format!("{:?}", book))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+impl Error for AmbiguousBookNameError {}
+
+/// The [VerseListError] is returned if a single Bible reference is expected, but a list of verses like "John 3:16,18,20" has been provided.
+/// Such a list can be parsed with [parse_verse_list](crate::referencing::parser::parse_verse_list).
+#[derive(Debug)]
+pub struct VerseListError {
+    /// The provided list of verses
+    pub provided_reference: String,
+}
+
+impl Display for VerseListError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}' is a list of several verses and not a single Bible reference.",
+            self.provided_reference
+        )
+    }
+}
+impl Error for VerseListError {}
+
 #[derive(Debug)]
 pub enum BibleRangeParsingError {
     InvalidFirstPart,
     InvalidSecondPart,
     NoSecondPartProvided,
     DelimiterNotFound,
+    /// Start and end of the range have different granularities and it is unclear which passage is meant, e.g. "John 3-3:16" or "John 3:16-John 4"
+    AmbiguousRangeGranularity,
 }
 impl Display for BibleRangeParsingError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            BibleRangeParsingError::InvalidFirstPart => write!(f, "The first part of the Bible range is invalid."),
-            BibleRangeParsingError::InvalidSecondPart => write!(f, "The second part of the Bible range is invalid."),
-            BibleRangeParsingError::NoSecondPartProvided => write!(f, "The second part of the Bible range is missing."),
-            BibleRangeParsingError::DelimiterNotFound => write!(f, "The delimiter between the first and second part of the Bible range is missing."),
+            BibleRangeParsingError::InvalidFirstPart => {
+                write!(f, "The first part of the Bible range is invalid.")
+            }
+            BibleRangeParsingError::InvalidSecondPart => {
+                write!(f, "The second part of the Bible range is invalid.")
+            }
+            BibleRangeParsingError::NoSecondPartProvided => {
+                write!(f, "The second part of the Bible range is missing.")
+            }
+            BibleRangeParsingError::DelimiterNotFound => write!(
+                f,
+                "The delimiter between the first and second part of the Bible range is missing."
+            ),
+            BibleRangeParsingError::AmbiguousRangeGranularity => write!(
+                f,
+                "The start and the end of the Bible range have different granularities which make the range ambiguous."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BibleRangeParsingError {}
+
+/// The [OsisParsingError] is returned if an OSIS ID (e.g. "John.3.16") is malformed or contains an unknown book abbreviation.
+#[derive(Debug)]
+pub struct OsisParsingError {
+    pub provided_osis_id: String,
+}
+
+impl Display for OsisParsingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is no valid OSIS ID.", self.provided_osis_id)
+    }
+}
+impl Error for OsisParsingError {}
+
+/// The [ReferenceSegmentParsingError] is returned when one segment of an input containing several Bible references (e.g. "John 3:16; Romans 8:28") could not be parsed.
+/// The field `segment` contains the text of the offending segment, the field `error` the original error which occurred while parsing it.
+#[derive(Debug)]
+pub struct ReferenceSegmentParsingError {
+    /// The (trimmed) text of the segment which could not be parsed
+    pub segment: String,
+    /// The error which occurred while parsing the segment
+    pub error: Box<dyn Error>,
+}
+
+impl Display for ReferenceSegmentParsingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The Bible reference '{}' could not be parsed: {}",
+            self.segment, self.error
+        )
+    }
+}
+
+impl Error for ReferenceSegmentParsingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+/// The [IncompleteLanguageError] is returned if a [ReferenceLanguage](crate::referencing::language::ReferenceLanguage) does not contain a name for every Bible book or lacks a delimiter.
+#[derive(Debug)]
+pub struct IncompleteLanguageError {
+    /// The language code of the incomplete language
+    pub language_code: String,
+    /// The first part of the language which is missing
+    pub missing_part: MissingLanguagePart,
+}
+
+/// The part of a [ReferenceLanguage](crate::referencing::language::ReferenceLanguage) which is missing, see [IncompleteLanguageError].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingLanguagePart {
+    /// The long or short name of a Bible book
+    BookName(BibleBook, BookReferenceType),
+    /// A delimiter between chapter and verse
+    ChapterVerseDelimiter,
+    /// The delimiter between the start and the end of a range
+    RangeDelimiter,
+}
+
+impl Display for IncompleteLanguageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.missing_part {
+            MissingLanguagePart::BookName(book, reference_type) => write!(
+                f,
+                "The language with language code '{}' has no {} name for the Bible book {:?}.",
+                self.language_code,
+                match reference_type {
+                    BookReferenceType::Long => "long",
+                    BookReferenceType::Short => "short",
+                },
+                book
+            ),
+            MissingLanguagePart::ChapterVerseDelimiter => write!(
+                f,
+                "The language with language code '{}' has no chapter/verse delimiter.",
+                self.language_code
+            ),
+            MissingLanguagePart::RangeDelimiter => write!(
+                f,
+                "The language with language code '{}' has no range delimiter.",
+                self.language_code
+            ),
         }
     }
 }
+impl Error for IncompleteLanguageError {}
 
-impl std::error::Error for BibleRangeParsingError { }
\ No newline at end of file
+/// The [ReferenceCreationError] is returned if a Bible reference could not be created in a human language.
+#[derive(Debug)]
+pub enum ReferenceCreationError {
+    /// There is no language with the given language code
+    LanguageDoesNotExist(LanguageDoesNotExistError),
+    /// The language contains no name for a referenced Bible book
+    IncompleteLanguage(IncompleteLanguageError),
+}
+
+impl Display for ReferenceCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReferenceCreationError::LanguageDoesNotExist(error) => write!(f, "{}", error),
+            ReferenceCreationError::IncompleteLanguage(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl ReferenceCreationError {
+    /// Returns the wrapped error as a [`Box<dyn Error>`], so that it can be downcasted to [LanguageDoesNotExistError] or [IncompleteLanguageError].
+    pub(crate) fn into_inner(self) -> Box<dyn Error> {
+        match self {
+            ReferenceCreationError::LanguageDoesNotExist(error) => Box::new(error),
+            ReferenceCreationError::IncompleteLanguage(error) => Box::new(error),
+        }
+    }
+}
+
+impl Error for ReferenceCreationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReferenceCreationError::LanguageDoesNotExist(error) => Some(error),
+            ReferenceCreationError::IncompleteLanguage(error) => Some(error),
+        }
+    }
+}
+
+impl From<LanguageDoesNotExistError> for ReferenceCreationError {
+    fn from(error: LanguageDoesNotExistError) -> Self {
+        ReferenceCreationError::LanguageDoesNotExist(error)
+    }
+}
+
+impl From<IncompleteLanguageError> for ReferenceCreationError {
+    fn from(error: IncompleteLanguageError) -> Self {
+        ReferenceCreationError::IncompleteLanguage(error)
+    }
+}
+
+/// The [LanguageParseError] is returned if a [ReferenceLanguage](crate::referencing::language::ReferenceLanguage) could not be read from a language file.
+/// The field `message` contains the description of the problem provided by the parser.
+#[cfg(feature = "toml")]
+#[derive(Debug)]
+pub struct LanguageParseError {
+    pub message: String,
+}
+
+#[cfg(feature = "toml")]
+impl Display for LanguageParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The language could not be parsed: {}", self.message)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl Error for LanguageParseError {}
+
+/// The [FlatReferenceError] is returned if a [FlatReference](crate::referencing::flat::FlatReference) could not be converted into a [BibleVerseReference](crate::bible::BibleVerseReference).
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum FlatReferenceError {
+    /// No Bible book has the given number
+    BookNumberDoesNotExist(u8),
+    /// The book name does not belong to the book with the given number
+    BookNameDoesNotMatch { book: String, book_number: u8 },
+    /// The chapter or verse does not exist in the book
+    InvalidReference(BibleReferenceValidationError),
+}
+
+#[cfg(feature = "serde")]
+impl Display for FlatReferenceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlatReferenceError::BookNumberDoesNotExist(book_number) => {
+                write!(f, "There is no Bible book with the number {}.", book_number)
+            }
+            FlatReferenceError::BookNameDoesNotMatch { book, book_number } => write!(
+                f,
+                "The Bible book '{}' does not have the number {}.",
+                book, book_number
+            ),
+            FlatReferenceError::InvalidReference(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Error for FlatReferenceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FlatReferenceError::InvalidReference(error) => Some(error),
+            _ => None,
+        }
+    }
+}
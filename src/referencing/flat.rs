@@ -0,0 +1,158 @@
+//! This module contains a flat representation of a Bible verse for APIs, e.g. `{"book": "John", "book_number": 43, "chapter": 3, "verse": 16}` in JSON.
+//! In contrast to the serde implementation of [BibleVerseReference], which serializes the book as an enum variant, the [FlatReference] only consists of strings and numbers,
+//! so typed clients of an API do not need to know the Rust enum tagging.
+//!
+//! # Example
+//! ```
+//! use bibleref::bible::{BibleBook, BibleVerseReference};
+//! use bibleref::referencing::flat::FlatReference;
+//!
+//! let john_3_16 = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+//! let flat = FlatReference::from(&john_3_16);
+//! assert_eq!(flat.book, "John");
+//! assert_eq!(flat.book_number, 43);
+//! assert_eq!(BibleVerseReference::try_from(flat).unwrap(), john_3_16);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::bible::{
+    BibleBook, BibleBookReference, BibleChapter, BibleVerse, BibleVerseReference,
+    get_bible_book_by_number,
+};
+
+use super::errors::FlatReferenceError;
+
+/// A Bible verse as a flat structure of its English long book name, the number of the book (see [BibleBook::number]), the chapter and the verse.
+#[derive(PartialEq, Eq, Debug, Clone, Hash, Serialize, Deserialize)]
+pub struct FlatReference {
+    /// The English long name of the book, e.g. "John"
+    pub book: String,
+    /// The number of the book in the Bible, e.g. 43 for John
+    pub book_number: u8,
+    /// The chapter of the verse
+    pub chapter: BibleChapter,
+    /// The verse
+    pub verse: BibleVerse,
+}
+
+impl From<&BibleVerseReference> for FlatReference {
+    fn from(reference: &BibleVerseReference) -> Self {
+        FlatReference {
+            book: BibleBookReference::new(reference.book()).to_string(),
+            book_number: reference.book().number(),
+            chapter: reference.chapter(),
+            verse: reference.verse(),
+        }
+    }
+}
+
+impl TryFrom<FlatReference> for BibleVerseReference {
+    type Error = FlatReferenceError;
+
+    /// Converts a [FlatReference] back into a [BibleVerseReference].
+    /// The book is determined by its number. The book name must be an English long or short name of the same book (see [BibleBook::from_str](std::str::FromStr)).
+    /// # Returns
+    /// - The [BibleVerseReference] if the book exists, the name matches the number and the verse exists
+    /// - A [FlatReferenceError] otherwise
+    fn try_from(flat: FlatReference) -> Result<Self, Self::Error> {
+        let book = get_bible_book_by_number(flat.book_number)
+            .ok_or(FlatReferenceError::BookNumberDoesNotExist(flat.book_number))?;
+        if flat.book.parse::<BibleBook>() != Ok(book) {
+            return Err(FlatReferenceError::BookNameDoesNotMatch {
+                book: flat.book,
+                book_number: flat.book_number,
+            });
+        }
+        BibleVerseReference::new(book, flat.chapter, flat.verse)
+            .map_err(FlatReferenceError::InvalidReference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_reference_conversion() {
+        let john_3_16 = BibleVerseReference::new(BibleBook::John, 3, 16).unwrap();
+        let flat = FlatReference::from(&john_3_16);
+        assert_eq!(
+            flat,
+            FlatReference {
+                book: "John".to_string(),
+                book_number: 43,
+                chapter: 3,
+                verse: 16,
+            }
+        );
+        assert_eq!(
+            FlatReference::from(&BibleVerseReference::new(BibleBook::ISamuel, 3, 10).unwrap()).book,
+            "1 Samuel"
+        );
+
+        // Every verse of a book survives the round trip
+        for verse in BibleBookReference::new(BibleBook::Ruth)
+            .first_chapter()
+            .verses()
+        {
+            assert_eq!(
+                BibleVerseReference::try_from(FlatReference::from(&verse)).unwrap(),
+                verse
+            );
+        }
+
+        // Short and lower case names are accepted
+        let short_name = FlatReference {
+            book: "1 sam".to_string(),
+            book_number: 9,
+            ..flat.clone()
+        };
+        assert_eq!(
+            BibleVerseReference::try_from(short_name).unwrap(),
+            BibleVerseReference::new(BibleBook::ISamuel, 3, 16).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_flat_reference_errors() {
+        let flat = FlatReference::from(&BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+
+        assert!(matches!(
+            BibleVerseReference::try_from(FlatReference {
+                book_number: 0,
+                ..flat.clone()
+            }),
+            Err(FlatReferenceError::BookNumberDoesNotExist(0))
+        ));
+        assert!(matches!(
+            BibleVerseReference::try_from(FlatReference {
+                book_number: 42,
+                ..flat.clone()
+            }),
+            Err(FlatReferenceError::BookNameDoesNotMatch {
+                book_number: 42,
+                ..
+            })
+        ));
+        assert!(matches!(
+            BibleVerseReference::try_from(FlatReference {
+                verse: 37,
+                ..flat.clone()
+            }),
+            Err(FlatReferenceError::InvalidReference(_))
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_flat_reference_serialization() {
+        let flat = FlatReference::from(&BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        let serialized = toml::to_string(&flat).unwrap();
+        assert_eq!(
+            serialized,
+            "book = \"John\"\nbook_number = 43\nchapter = 3\nverse = 16\n"
+        );
+        assert_eq!(toml::from_str::<FlatReference>(&serialized).unwrap(), flat);
+    }
+}
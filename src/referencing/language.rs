@@ -6,9 +6,17 @@ use std::{collections::HashMap, sync::RwLock};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::bible::{BibleBook, BibleRange, BibleReference, BibleReferenceRepresentation};
+use crate::bible::{
+    BibleBook, BibleRange, BibleReference, BibleReferenceRepresentation,
+    validate::{get_number_of_chapters, get_number_of_verses},
+};
 
-use super::errors::LanguageDoesNotExistError;
+#[cfg(feature = "toml")]
+use super::errors::LanguageParseError;
+use super::errors::{
+    IncompleteLanguageError, LanguageDoesNotExistError, MissingLanguagePart, ReferenceCreationError,
+};
+use super::parser::normalize_book_name;
 
 /// A static Read-Write-Lock vector of ReferenceLanguage instances using Lazy. Here, all the languages which are supported by default are loaded and saved in.
 /// As this is inside a [RwLock], it is possible to manipulate the languages during runtime.
@@ -21,28 +29,86 @@ use super::errors::LanguageDoesNotExistError;
 /// languages.push(your_defined_language);
 /// // Add a language or do anything else
 /// ```
+///
+/// To add a language safely, use [register_language] which checks the completeness of the language first.
 pub static REFERENCE_LANGUAGES: Lazy<RwLock<Vec<ReferenceLanguage>>> = Lazy::new(|| {
-    RwLock::new(vec![
+    let languages = vec![
         get_german_reference_language(), // German first for test compatibility
+        get_arabic_reference_language(),
         get_chinese_simplified_reference_language(),
         get_chinese_traditional_reference_language(),
         get_czech_reference_language(),
         get_dutch_reference_language(),
         get_english_reference_language(),
         get_french_reference_language(),
+        get_greek_reference_language(),
         get_hungarian_reference_language(),
         get_indonesian_reference_language(),
         get_italian_reference_language(),
         get_japanese_reference_language(),
         get_korean_reference_language(),
         get_polish_reference_language(),
+        get_portuguese_reference_language(),
         get_russian_reference_language(),
         get_spanish_reference_language(),
         get_ukrainian_reference_language(),
         get_vietnamese_reference_language(),
-    ])
+        get_latin_reference_language(),
+    ];
+    *LANGUAGE_INDEX.write().unwrap() = build_language_index(&languages);
+    RwLock::new(languages)
 });
 
+/// An index from the lowercased language code to the position of the language in [REFERENCE_LANGUAGES], which avoids a linear scan of all languages per lookup.
+/// It is built together with [REFERENCE_LANGUAGES] and rebuilt by [register_language] while the languages are locked for writing.
+static LANGUAGE_INDEX: Lazy<RwLock<HashMap<String, usize>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Creates the content of [LANGUAGE_INDEX] for the given languages.
+fn build_language_index(reference_languages: &[ReferenceLanguage]) -> HashMap<String, usize> {
+    // Inserting in reverse order lets the first language with a code win, as in a linear scan
+    reference_languages
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(position, language)| (language.language_code.to_lowercase(), position))
+        .collect()
+}
+
+/// Returns the position of the language with the given code in the registered languages.
+/// If the languages have been changed directly through [REFERENCE_LANGUAGES] instead of [register_language], the index might be outdated. In this case, the languages are scanned instead.
+/// # Params
+/// - `reference_languages`: The content of [REFERENCE_LANGUAGES]
+/// - `language_code`: The trimmed and lowercased language code
+/// # Returns
+/// - The position of the first language with the code or [None] if no such language is registered
+fn find_language_position(
+    reference_languages: &[ReferenceLanguage],
+    language_code: &str,
+) -> Option<usize> {
+    let has_code = |language: &ReferenceLanguage| {
+        language
+            .language_code
+            .chars()
+            .flat_map(char::to_lowercase)
+            .eq(language_code.chars())
+    };
+    match LANGUAGE_INDEX.read().unwrap().get(language_code) {
+        Some(&position) if reference_languages.get(position).is_some_and(has_code) => {
+            Some(position)
+        }
+        _ => reference_languages.iter().position(has_code),
+    }
+}
+
+/// The code of the language which is used by [translate_default](crate::translate_default). It can be changed with [set_default_language] and is English ("en") by default.
+static DEFAULT_LANGUAGE_CODE: Lazy<RwLock<String>> = Lazy::new(|| RwLock::new("en".to_string()));
+
+/// The built-in English [ReferenceLanguage] which is used as the default language, e.g. for the [Display](std::fmt::Display) implementations of the Bible reference types.
+/// In contrast to [REFERENCE_LANGUAGES], it is not affected by changes of the registered languages during runtime.
+pub(crate) static ENGLISH_REFERENCE_LANGUAGE: Lazy<ReferenceLanguage> =
+    Lazy::new(get_english_reference_language);
+
 /// A struct representing a human used language where Bible references can be reprsented.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -71,156 +137,694 @@ pub struct ReferenceLanguage {
 
     /// A vector of strings used as delimiter between several Bible reference representations (most likely ';')
     pub multiple_representations_delimiters: Vec<String>,
+
+    /// The direction in which the language is written. The created references are always in logical order, so this information is meant for the rendering layer (e.g. to add bidirectional markers).
+    /// It defaults to [TextDirection::Ltr] if it is missing in a language file.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub directionality: TextDirection,
 }
 
 impl ReferenceLanguage {
+    /// Returns a [ReferenceLanguageBuilder] to define a custom language.
+    pub fn builder() -> ReferenceLanguageBuilder {
+        ReferenceLanguageBuilder::new()
+    }
+
+    /// Reads a [ReferenceLanguage] from a string in the [TOML](https://toml.io) format, e.g. the content of a language file.
+    /// This allows to ship additional languages without recompiling the crate. The resulting language can be added to [REFERENCE_LANGUAGES].
+    ///
+    /// The file contains the fields of the [ReferenceLanguage] struct. The book names are given in the tables `long_names` and `short_names`, using the names of the [BibleBook] variants as keys.
+    ///
+    /// This function is only available with the `toml` feature.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// use bibleref::referencing::language::ReferenceLanguage;
+    /// let language_file = r#"
+    /// long_language_name = "Latin"
+    /// language_code = "la"
+    /// chapter_vers_delimiters = [",", ":"]
+    /// space_separation = true
+    /// range_delimiter = "-"
+    /// multiple_representations_delimiters = [";"]
+    ///
+    /// [long_names]
+    /// Genesis = ["Genesis"]
+    /// John = ["Ioannes"]
+    ///
+    /// [short_names]
+    /// Genesis = ["Gn"]
+    /// John = ["Io"]
+    /// "#;
+    /// let language = ReferenceLanguage::from_toml(language_file).unwrap();
+    /// assert_eq!(language.language_code, "la");
+    /// assert_eq!(language.long_names[&BibleBook::John], vec!["Ioannes".to_string()]);
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(s: &str) -> Result<ReferenceLanguage, LanguageParseError> {
+        toml::from_str(s).map_err(|err| LanguageParseError {
+            message: err.to_string(),
+        })
+    }
+
+    /// Checks whether the language is complete, which means that each of the 66 Bible books has at least one non-empty long and short name
+    /// and that the language has at least one chapter/verse delimiter and a range delimiter, none of which are empty.
+    /// Only complete languages can be used safely to create references. Names for the deuterocanonical books are optional.
+    /// # Returns
+    /// - `Ok(())` if the language is complete
+    /// - An [IncompleteLanguageError] which names the first book without a long or short name or the missing delimiter otherwise
+    pub fn validate(&self) -> Result<(), IncompleteLanguageError> {
+        for book in BibleBook::all()
+            .into_iter()
+            .filter(|book| !book.is_deuterocanonical())
+        {
+            for (names, reference_type) in [
+                (&self.long_names, BookReferenceType::Long),
+                (&self.short_names, BookReferenceType::Short),
+            ] {
+                let has_name = names
+                    .get(&book)
+                    .and_then(|names| names.first())
+                    .is_some_and(|name| !name.trim().is_empty());
+                if !has_name {
+                    return Err(IncompleteLanguageError {
+                        language_code: self.language_code.clone(),
+                        missing_part: MissingLanguagePart::BookName(book, reference_type),
+                    });
+                }
+            }
+        }
+        let missing_part = if self.chapter_vers_delimiters.is_empty()
+            || self
+                .chapter_vers_delimiters
+                .iter()
+                .any(|delimiter| delimiter.is_empty())
+        {
+            Some(MissingLanguagePart::ChapterVerseDelimiter)
+        } else if self.range_delimiter.is_empty() {
+            Some(MissingLanguagePart::RangeDelimiter)
+        } else {
+            None
+        };
+        match missing_part {
+            Some(missing_part) => Err(IncompleteLanguageError {
+                language_code: self.language_code.clone(),
+                missing_part,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Finds book names of this language which make references ambiguous, which helps to check a custom language.
+    /// The parser ignores spaces, the case and diacritics of book names, and reads everything up to the chapter number as the book name. Therefore, two books conflict if
+    /// - one of their (long or short) names are equal when spaces, case and diacritics are ignored (e.g. "Job" and "JOB"), or
+    /// - a name of one book is the beginning of a name of the other book and the rest of that name starts with a number (e.g. "Ps" and "Ps 151"), as "Ps 151" would be read as a chapter of the first book.
+    /// # Returns
+    /// - A [Vec] with the conflicting books and the (shorter) name which causes the conflict, sorted by the books. It is empty if the language contains no ambiguities.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::BibleBook;
+    /// use bibleref::referencing::language::get_language_by_code;
+    /// let mut english = get_language_by_code("en").unwrap();
+    /// assert!(english.find_ambiguities().is_empty());
+    ///
+    /// // "John" is also a name of Jude now
+    /// english.short_names.get_mut(&BibleBook::Jude).unwrap().push("John".to_string());
+    /// assert_eq!(english.find_ambiguities(), vec![(BibleBook::John, BibleBook::Jude, "John".to_string())]);
+    /// ```
+    pub fn find_ambiguities(&self) -> Vec<(BibleBook, BibleBook, String)> {
+        let names: Vec<(BibleBook, &String, String)> = self
+            .long_names
+            .iter()
+            .chain(self.short_names.iter())
+            .flat_map(|(book, names)| {
+                names
+                    .iter()
+                    .map(|name| (*book, name, normalize_book_name(&name.replace(" ", ""))))
+            })
+            .collect();
+
+        let mut ambiguities: Vec<(BibleBook, BibleBook, String)> = vec![];
+        for (book, name, normalized_name) in names.iter() {
+            for (other_book, _, other_normalized_name) in names.iter() {
+                if book == other_book {
+                    continue;
+                }
+                let conflicts = match other_normalized_name.strip_prefix(normalized_name.as_str()) {
+                    // Equal names are reported once
+                    Some("") => book < other_book,
+                    Some(rest) => rest.starts_with(char::is_numeric),
+                    None => false,
+                };
+                if conflicts {
+                    let (first_book, second_book) = match book < other_book {
+                        true => (*book, *other_book),
+                        false => (*other_book, *book),
+                    };
+                    ambiguities.push((first_book, second_book, name.to_string()));
+                }
+            }
+        }
+        ambiguities.sort();
+        ambiguities.dedup();
+        ambiguities
+    }
+
+    /// Returns the first name of a Bible book in this language.
+    /// # Returns
+    /// - The first long or short name of the book
+    /// - A [IncompleteLanguageError] if the language contains no name of the requested type for the book
+    fn book_name(
+        &self,
+        book: BibleBook,
+        book_reference_type: BookReferenceType,
+    ) -> Result<&str, IncompleteLanguageError> {
+        let names = match book_reference_type {
+            BookReferenceType::Long => &self.long_names,
+            BookReferenceType::Short => &self.short_names,
+        };
+        names
+            .get(&book)
+            .and_then(|names| names.first())
+            .map(|name| name.as_str())
+            .ok_or_else(|| IncompleteLanguageError {
+                language_code: self.language_code.clone(),
+                missing_part: MissingLanguagePart::BookName(book, book_reference_type),
+            })
+    }
+
+    /// Returns the delimiter between chapter and verse according to a [ReferenceStyle].
+    /// # Returns
+    /// - The delimiter of the style or the language
+    /// - A [IncompleteLanguageError] if the style has no custom delimiter and the language contains no chapter/verse delimiter
+    fn chapter_verse_delimiter<'a>(
+        &'a self,
+        style: &'a ReferenceStyle,
+    ) -> Result<&'a str, IncompleteLanguageError> {
+        match &style.custom_delimiter {
+            Some(delimiter) => Ok(delimiter),
+            None => self
+                .chapter_vers_delimiters
+                .get(style.delimiter_index)
+                .or(self.chapter_vers_delimiters.first())
+                .map(|delimiter| delimiter.as_str())
+                .ok_or_else(|| IncompleteLanguageError {
+                    language_code: self.language_code.clone(),
+                    missing_part: MissingLanguagePart::ChapterVerseDelimiter,
+                }),
+        }
+    }
+
+    /// Creates a Bible reference in this language.
+    /// # Panics
+    /// Panics if the language contains no name for the referenced book or no chapter/verse delimiter. Use [ReferenceLanguage::try_create_reference] to handle this case.
     pub fn create_reference(
         &self,
         bible_reference: &BibleReference,
         book_reference_type: BookReferenceType,
     ) -> String {
+        let mut reference = String::new();
+        self.write_reference(&mut reference, bible_reference, book_reference_type)
+            .expect("writing into a String does not fail");
+        reference
+    }
+
+    /// Writes a Bible reference in this language into a buffer without allocating a new [String], e.g. to reuse one buffer for many references.
+    /// # Params
+    /// - `out`: The buffer (any [std::fmt::Write]) into which the reference is written
+    /// - `bible_reference`: The Bible reference from which the expression should be created
+    /// - `book_reference_type`: The type of the book reference (short or long)
+    /// # Returns
+    /// - The [std::fmt::Result] of writing into the buffer
+    /// # Panics
+    /// Panics if the language contains no name for the referenced book or no chapter/verse delimiter. Use [ReferenceLanguage::try_create_reference] to handle this case.
+    /// # Example
+    /// ```
+    /// use std::fmt::Write;
+    /// use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
+    /// use bibleref::referencing::language::{get_language_by_code, BookReferenceType};
+    /// let english = get_language_by_code("en").unwrap();
+    /// let mut buffer = String::new();
+    /// for verse in 16..=18 {
+    ///     buffer.clear();
+    ///     let reference = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, verse).unwrap());
+    ///     english.write_reference(&mut buffer, &reference, BookReferenceType::Long).unwrap();
+    ///     assert_eq!(buffer, format!("John 3:{}", verse));
+    /// }
+    /// write!(buffer, " (NIV)").unwrap();
+    /// assert_eq!(buffer, "John 3:18 (NIV)");
+    /// ```
+    pub fn write_reference(
+        &self,
+        out: &mut impl std::fmt::Write,
+        bible_reference: &BibleReference,
+        book_reference_type: BookReferenceType,
+    ) -> std::fmt::Result {
+        let style = ReferenceStyle::from(book_reference_type);
+        let book_name = self
+            .book_name(bible_reference.book(), style.book_reference_type)
+            .unwrap_or_else(|err| panic!("{}", err));
+        let chapter_verse_delimiter = self
+            .reference_chapter_verse_delimiter(bible_reference, &style)
+            .unwrap_or_else(|err| panic!("{}", err));
+        self.write_reference_with_book_name(
+            out,
+            bible_reference,
+            book_name,
+            chapter_verse_delimiter,
+            &style,
+        )
+    }
+
+    /// Returns the delimiter between chapter and verse which is needed to write a Bible reference.
+    /// Only verses and chapters of books with one chapter contain this delimiter, so an empty string is returned for other references.
+    fn reference_chapter_verse_delimiter<'a>(
+        &'a self,
+        bible_reference: &BibleReference,
+        style: &'a ReferenceStyle,
+    ) -> Result<&'a str, IncompleteLanguageError> {
         match bible_reference {
-            BibleReference::BibleBook(book) => match book_reference_type {
-                BookReferenceType::Long => {
-                    self.long_names[&book.book()].first().unwrap().to_string()
-                }
-                BookReferenceType::Short => {
-                    self.short_names[&book.book()].first().unwrap().to_string()
-                }
-            },
-            BibleReference::BibleChapter(chapter) => format!(
-                "{}{}{}",
-                match book_reference_type {
-                    BookReferenceType::Long => self.long_names[&chapter.book()]
-                        .first()
-                        .unwrap()
-                        .to_string(),
-                    BookReferenceType::Short => self.short_names[&chapter.book()]
-                        .first()
-                        .unwrap()
-                        .to_string(),
-                },
-                match self.space_separation {
-                    true => " ",
-                    false => "",
-                },
-                chapter.chapter()
+            BibleReference::BibleVerse(_) => self.chapter_verse_delimiter(style),
+            BibleReference::BibleChapter(chapter) if has_single_chapter(chapter.book()) => {
+                self.chapter_verse_delimiter(style)
+            }
+            _ => Ok(""),
+        }
+    }
+
+    /// Writes a Bible reference formatted according to a [ReferenceStyle] into a buffer, using the given (unstyled) book name and chapter/verse delimiter.
+    fn write_reference_with_book_name(
+        &self,
+        out: &mut impl std::fmt::Write,
+        bible_reference: &BibleReference,
+        book_name: &str,
+        chapter_verse_delimiter: &str,
+        style: &ReferenceStyle,
+    ) -> std::fmt::Result {
+        match style.uppercase_book_names {
+            true => out.write_str(&book_name.to_uppercase())?,
+            false => out.write_str(book_name)?,
+        }
+        let space = match self.space_separation {
+            true => " ",
+            false => "",
+        };
+        let width = style.number_width;
+        match bible_reference {
+            BibleReference::BibleBook(_) => Ok(()),
+            // A single number after a book with one chapter is read as a verse, so its chapter is written as a range of all verses, e.g. "Jude 1:1-25"
+            BibleReference::BibleChapter(chapter) if has_single_chapter(chapter.book()) => write!(
+                out,
+                "{}{:0width$}{}{:0width$}{}{:0width$}",
+                space,
+                chapter.chapter(),
+                chapter_verse_delimiter,
+                1,
+                self.range_delimiter,
+                get_number_of_verses(&chapter.book(), &chapter.chapter()).unwrap()
             ),
-            BibleReference::BibleVerse(verse) => format!(
-                "{}{}{}{}{}",
-                match book_reference_type {
-                    BookReferenceType::Long =>
-                        self.long_names[&verse.book()].first().unwrap().to_string(),
-                    BookReferenceType::Short =>
-                        self.short_names[&verse.book()].first().unwrap().to_string(),
-                },
-                match self.space_separation {
-                    true => " ",
-                    false => "",
-                },
+            BibleReference::BibleChapter(chapter) => {
+                write!(out, "{}{:0width$}", space, chapter.chapter())
+            }
+            BibleReference::BibleVerse(verse) => write!(
+                out,
+                "{}{:0width$}{}{:0width$}",
+                space,
                 verse.chapter(),
-                self.chapter_vers_delimiters.first().unwrap(),
+                chapter_verse_delimiter,
                 verse.verse()
             ),
         }
     }
 
+    /// Creates a Bible reference in this language.
+    /// # Params
+    /// - `bible_reference`: The Bible reference from which the expression should be created
+    /// - `book_reference_type`: The type of the book reference (short or long)
+    /// # Returns
+    /// - The Bible reference as a [String]
+    /// - A [IncompleteLanguageError] if the language contains no name for the referenced book or no chapter/verse delimiter
+    pub fn try_create_reference(
+        &self,
+        bible_reference: &BibleReference,
+        book_reference_type: BookReferenceType,
+    ) -> Result<String, IncompleteLanguageError> {
+        self.try_create_reference_with_style(bible_reference, &book_reference_type.into())
+    }
+
+    /// Creates a Bible reference in this language which is formatted according to a [ReferenceStyle].
+    /// # Panics
+    /// Panics if the language contains no name for the referenced book or no chapter/verse delimiter. Use [ReferenceLanguage::try_create_reference_with_style] to handle this case.
+    pub fn create_reference_with_style(
+        &self,
+        bible_reference: &BibleReference,
+        style: &ReferenceStyle,
+    ) -> String {
+        self.try_create_reference_with_style(bible_reference, style)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a Bible reference in this language which is formatted according to a [ReferenceStyle].
+    /// # Params
+    /// - `bible_reference`: The Bible reference from which the expression should be created
+    /// - `style`: The [ReferenceStyle] which determines the book name, the delimiter and the numbers
+    /// # Returns
+    /// - The Bible reference as a [String]
+    /// - A [IncompleteLanguageError] if the language contains no name for the referenced book or no chapter/verse delimiter
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
+    /// use bibleref::referencing::language::{get_language_by_code, ReferenceStyle};
+    /// let english = get_language_by_code("en").unwrap();
+    /// let reference = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Psalm, 23, 1).unwrap());
+    /// assert_eq!(english.try_create_reference_with_style(&reference, &ReferenceStyle::period_delimited()).unwrap(), "Ps 23.1");
+    /// assert_eq!(english.try_create_reference_with_style(&reference, &ReferenceStyle::uppercase()).unwrap(), "PS 23:1");
+    /// ```
+    pub fn try_create_reference_with_style(
+        &self,
+        bible_reference: &BibleReference,
+        style: &ReferenceStyle,
+    ) -> Result<String, IncompleteLanguageError> {
+        let book_name = self.book_name(bible_reference.book(), style.book_reference_type)?;
+        let chapter_verse_delimiter =
+            self.reference_chapter_verse_delimiter(bible_reference, style)?;
+        let mut reference = String::new();
+        self.write_reference_with_book_name(
+            &mut reference,
+            bible_reference,
+            book_name,
+            chapter_verse_delimiter,
+            style,
+        )
+        .expect("writing into a String does not fail");
+        Ok(reference)
+    }
+
+    /// Creates a Bible range in this language.
+    /// # Panics
+    /// Panics if the language contains no name for one of the referenced books or no chapter/verse delimiter. Use [ReferenceLanguage::try_create_bible_range] to handle this case.
     pub fn create_bible_range(
         &self,
         bible_range: &BibleRange,
         book_reference_type: BookReferenceType,
         shortened_string: bool,
     ) -> String {
+        self.try_create_bible_range(bible_range, book_reference_type, shortened_string)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a Bible range in this language.
+    /// # Params
+    /// - `bible_range`: The Bible range from which the expression should be created
+    /// - `book_reference_type`: The type of the book reference (short or long)
+    /// - `shortened_string`: A boolean indicating whether the string should be shortened or not
+    /// # Returns
+    /// - The Bible range as a [String]
+    /// - A [IncompleteLanguageError] if the language contains no name for one of the referenced books or no chapter/verse delimiter
+    pub fn try_create_bible_range(
+        &self,
+        bible_range: &BibleRange,
+        book_reference_type: BookReferenceType,
+        shortened_string: bool,
+    ) -> Result<String, IncompleteLanguageError> {
+        self.try_create_bible_range_with_style(
+            bible_range,
+            &book_reference_type.into(),
+            shortened_string,
+        )
+    }
+
+    /// Creates a Bible range in this language which is formatted according to a [ReferenceStyle].
+    /// # Panics
+    /// Panics if the language contains no name for one of the referenced books or no chapter/verse delimiter. Use [ReferenceLanguage::try_create_bible_range_with_style] to handle this case.
+    pub fn create_bible_range_with_style(
+        &self,
+        bible_range: &BibleRange,
+        style: &ReferenceStyle,
+        shortened_string: bool,
+    ) -> String {
+        self.try_create_bible_range_with_style(bible_range, style, shortened_string)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a Bible range in this language which is formatted according to a [ReferenceStyle].
+    /// # Params
+    /// - `bible_range`: The Bible range from which the expression should be created
+    /// - `style`: The [ReferenceStyle] which determines the book names, the delimiter and the numbers
+    /// - `shortened_string`: A boolean indicating whether the string should be shortened or not
+    /// # Returns
+    /// - The Bible range as a [String]
+    /// - A [IncompleteLanguageError] if the language contains no name for one of the referenced books or no chapter/verse delimiter
+    pub fn try_create_bible_range_with_style(
+        &self,
+        bible_range: &BibleRange,
+        style: &ReferenceStyle,
+        shortened_string: bool,
+    ) -> Result<String, IncompleteLanguageError> {
+        // Chapters of books with one chapter are written as verses, so ranges of them are written as verse ranges
+        let verse_range;
+        let bible_range = match bible_range {
+            BibleRange::ChapterRange(chapter_range)
+                if has_single_chapter(chapter_range.start().book())
+                    || has_single_chapter(chapter_range.end().book()) =>
+            {
+                verse_range = BibleRange::VerseRange(chapter_range.as_verse_range());
+                &verse_range
+            }
+            _ => bible_range,
+        };
         match shortened_string {
-            true => self.create_bible_range_shortened(bible_range, book_reference_type),
-            false => self.create_bible_range_unshortened(bible_range, book_reference_type),
+            true => self.create_bible_range_shortened(bible_range, style),
+            false => self.create_bible_range_unshortened(bible_range, style),
         }
     }
 
     fn create_bible_range_unshortened(
         &self,
         bible_range: &BibleRange,
-        book_reference_type: BookReferenceType,
-    ) -> String {
-        let start = self.create_reference(&bible_range.start(), book_reference_type);
-        let end = self.create_reference(&bible_range.end(), book_reference_type);
-        format!("{}{}", start, end)
+        style: &ReferenceStyle,
+    ) -> Result<String, IncompleteLanguageError> {
+        let start = self.try_create_reference_with_style(&bible_range.start(), style)?;
+        let end = self.try_create_reference_with_style(&bible_range.end(), style)?;
+        Ok(format!("{}{}{}", start, self.range_delimiter, end))
     }
 
     fn create_bible_range_shortened(
         &self,
         bible_range: &BibleRange,
-        book_reference_type: BookReferenceType,
-    ) -> String {
+        style: &ReferenceStyle,
+    ) -> Result<String, IncompleteLanguageError> {
         match bible_range {
             BibleRange::BookRange(book_range) => {
                 if book_range.start() == book_range.end() {
-                    self.create_reference(
+                    self.try_create_reference_with_style(
                         &BibleReference::BibleBook(book_range.start()),
-                        book_reference_type,
+                        style,
                     )
                 } else {
-                    self.create_bible_range_unshortened(bible_range, book_reference_type)
+                    self.create_bible_range_unshortened(bible_range, style)
                 }
             }
             BibleRange::ChapterRange(chapter_range) => {
                 if chapter_range.start() == chapter_range.end() {
-                    self.create_reference(
+                    self.try_create_reference_with_style(
                         &BibleReference::BibleChapter(chapter_range.start()),
-                        book_reference_type,
+                        style,
                     )
                 } else if chapter_range.start().book() == chapter_range.end().book() {
-                    return format!(
+                    Ok(format!(
                         "{}{}{}",
-                        self.create_reference(
+                        self.try_create_reference_with_style(
                             &BibleReference::BibleChapter(chapter_range.start()),
-                            book_reference_type
-                        ),
+                            style
+                        )?,
                         self.range_delimiter,
-                        chapter_range.end().chapter()
-                    );
+                        style.format_number(chapter_range.end().chapter())
+                    ))
                 } else {
-                    return self.create_bible_range_unshortened(bible_range, book_reference_type);
+                    self.create_bible_range_unshortened(bible_range, style)
                 }
             }
             BibleRange::VerseRange(verse_range) => {
                 if verse_range.start() == verse_range.end() {
-                    self.create_reference(
+                    self.try_create_reference_with_style(
                         &BibleReference::BibleVerse(verse_range.start()),
-                        book_reference_type,
+                        style,
                     )
                 } else if verse_range.start().book() == verse_range.end().book()
                     && verse_range.start().chapter() == verse_range.end().chapter()
                 {
-                    return format!(
+                    Ok(format!(
                         "{}{}{}",
-                        self.create_reference(
+                        self.try_create_reference_with_style(
                             &BibleReference::BibleVerse(verse_range.start()),
-                            book_reference_type
-                        ),
+                            style
+                        )?,
                         self.range_delimiter,
-                        verse_range.end().verse()
-                    );
+                        style.format_number(verse_range.end().verse())
+                    ))
                 } else if verse_range.start().book() == verse_range.end().book() {
-                    return format!(
+                    Ok(format!(
                         "{}{}{}{}{}",
-                        self.create_reference(
+                        self.try_create_reference_with_style(
                             &BibleReference::BibleVerse(verse_range.start()),
-                            book_reference_type
-                        ),
+                            style
+                        )?,
                         self.range_delimiter,
-                        verse_range.end().chapter(),
-                        self.chapter_vers_delimiters.first().unwrap(),
-                        verse_range.end().verse()
-                    );
+                        style.format_number(verse_range.end().chapter()),
+                        self.chapter_verse_delimiter(style)?,
+                        style.format_number(verse_range.end().verse())
+                    ))
                 } else {
-                    return self.create_bible_range_unshortened(bible_range, book_reference_type);
+                    self.create_bible_range_unshortened(bible_range, style)
                 }
             }
         }
     }
 }
 
+/// Returns true if the book has only one chapter (Obadiah, Philemon, 2 John, 3 John and Jude).
+fn has_single_chapter(book: BibleBook) -> bool {
+    get_number_of_chapters(&book) == 1
+}
+
+/// A builder for a custom [ReferenceLanguage], which avoids filling the book name maps by hand.
+/// Unless they are set, the delimiters are ":" (chapter/verse), "-" (range) and ";" (multiple references), the book name and the chapter are separated by a space and the language is written from left to right.
+/// # Example
+/// ```
+/// use bibleref::bible::BibleBook;
+/// use bibleref::referencing::language::ReferenceLanguage;
+/// let mut builder = ReferenceLanguage::builder().code("xx").name("Example");
+/// for book in BibleBook::all().into_iter().filter(|book| !book.is_deuterocanonical()) {
+///     let name = format!("{:?}", book);
+///     builder = builder.book(book, &name, &[&name[..3]]);
+/// }
+/// let language = builder.delimiters(&[",", ":"]).build().unwrap();
+/// assert_eq!(language.chapter_vers_delimiters, vec![",".to_string(), ":".to_string()]);
+///
+/// // A language without any book names is incomplete
+/// assert!(ReferenceLanguage::builder().code("xx").build().is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReferenceLanguageBuilder {
+    language: ReferenceLanguage,
+}
+
+impl ReferenceLanguageBuilder {
+    /// Creates a builder for a language without a code, a name and book names.
+    pub fn new() -> Self {
+        ReferenceLanguageBuilder {
+            language: ReferenceLanguage {
+                long_language_name: String::new(),
+                language_code: String::new(),
+                long_names: HashMap::new(),
+                short_names: HashMap::new(),
+                chapter_vers_delimiters: vec![":".to_string()],
+                space_separation: true,
+                range_delimiter: "-".to_string(),
+                multiple_representations_delimiters: vec![";".to_string()],
+                directionality: TextDirection::Ltr,
+            },
+        }
+    }
+
+    /// Sets the language code (e.g. "en").
+    pub fn code(mut self, language_code: &str) -> Self {
+        self.language.language_code = language_code.to_string();
+        self
+    }
+
+    /// Sets the long name of the language (e.g. "English").
+    pub fn name(mut self, long_language_name: &str) -> Self {
+        self.language.long_language_name = long_language_name.to_string();
+        self
+    }
+
+    /// Adds names of a Bible book. If it is called several times for the same book, the names are appended and the first ones stay the default names.
+    /// # Params
+    /// - `book`: The Bible book
+    /// - `long_name`: A long name of the book (e.g. "Genesis")
+    /// - `short_names`: The short names of the book (e.g. `&["Gen", "Gn"]`)
+    pub fn book(mut self, book: BibleBook, long_name: &str, short_names: &[&str]) -> Self {
+        self.language
+            .long_names
+            .entry(book)
+            .or_default()
+            .push(long_name.to_string());
+        self.language
+            .short_names
+            .entry(book)
+            .or_default()
+            .extend(short_names.iter().map(|name| name.to_string()));
+        self
+    }
+
+    /// Sets the delimiters between chapter and verse. The first one is used when creating references.
+    pub fn delimiters(mut self, chapter_vers_delimiters: &[&str]) -> Self {
+        self.language.chapter_vers_delimiters = chapter_vers_delimiters
+            .iter()
+            .map(|delimiter| delimiter.to_string())
+            .collect();
+        self
+    }
+
+    /// Determines whether a space is added between the book name and the chapter.
+    pub fn space_separation(mut self, space_separation: bool) -> Self {
+        self.language.space_separation = space_separation;
+        self
+    }
+
+    /// Sets the delimiter of ranges (e.g. "-").
+    pub fn range_delimiter(mut self, range_delimiter: &str) -> Self {
+        self.language.range_delimiter = range_delimiter.to_string();
+        self
+    }
+
+    /// Sets the delimiters between several references (e.g. ";").
+    pub fn multiple_representations_delimiters(mut self, delimiters: &[&str]) -> Self {
+        self.language.multiple_representations_delimiters = delimiters
+            .iter()
+            .map(|delimiter| delimiter.to_string())
+            .collect();
+        self
+    }
+
+    /// Sets the direction in which the language is written.
+    pub fn directionality(mut self, directionality: TextDirection) -> Self {
+        self.language.directionality = directionality;
+        self
+    }
+
+    /// Creates the language after checking that it is complete (see [ReferenceLanguage::validate]).
+    /// # Returns
+    /// - The [ReferenceLanguage] if each of the 66 Bible books has a long and a short name
+    /// - An [IncompleteLanguageError] which names the first book without a long or short name or the missing delimiter otherwise
+    pub fn build(self) -> Result<ReferenceLanguage, IncompleteLanguageError> {
+        self.language.validate()?;
+        Ok(self.language)
+    }
+}
+
+impl Default for ReferenceLanguageBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adds a language to the [REFERENCE_LANGUAGES] after checking that it is complete (see [ReferenceLanguage::validate]).
+/// # Params
+/// - `language`: The [ReferenceLanguage] which should be registered
+/// # Returns
+/// - `Ok(())` if the language has been added
+/// - An [IncompleteLanguageError] if the language is incomplete. In this case, the language will not be added.
+pub fn register_language(language: ReferenceLanguage) -> Result<(), IncompleteLanguageError> {
+    language.validate()?;
+    let mut reference_languages = REFERENCE_LANGUAGES.write().unwrap();
+    reference_languages.push(language);
+    *LANGUAGE_INDEX.write().unwrap() = build_language_index(&reference_languages);
+    Ok(())
+}
+
 /// This function creates a Bible reference in a human language.
 ///
 /// # Params
@@ -228,22 +832,65 @@ impl ReferenceLanguage {
 /// - `language_code`: The language code of the human language in which the reference should be created
 ///
 /// # Returns
-/// An [`Option<String>`] which is [`Some(bible_reference_string)`] if the language specified with the `language_code` exists
-/// or [None] if the language can't be found.
+/// - The Bible reference as a [String] if the language specified with the `language_code` exists
+/// - A [ReferenceCreationError::LanguageDoesNotExist] if the language can't be found
+/// - A [ReferenceCreationError::IncompleteLanguage] if the language contains no name for the referenced book
 pub fn get_reference_in_language(
     bible_reference: &BibleReference,
     language_code: &str,
     book_reference_type: BookReferenceType,
-) -> Result<String, LanguageDoesNotExistError> {
+) -> Result<String, ReferenceCreationError> {
     let language_code = language_code.trim().to_lowercase();
     let reference_languages = &*REFERENCE_LANGUAGES.read().unwrap();
 
-    for language in reference_languages {
-        if language.language_code.to_lowercase().eq(&language_code) {
-            return Ok(language.create_reference(bible_reference, book_reference_type));
-        }
+    match find_language_position(reference_languages, &language_code) {
+        Some(position) => Ok(reference_languages[position]
+            .try_create_reference(bible_reference, book_reference_type)?),
+        None => Err(LanguageDoesNotExistError { language_code }.into()),
+    }
+}
+
+/// This function creates a Bible reference in a human language like [get_reference_in_language], but allows to override the delimiter between chapter and verse.
+/// The book names stay localized, e.g. "Johannes 3.16" in German with "." as delimiter.
+///
+/// # Params
+/// - `bible_reference`: The Bible reference from which the expression should be created
+/// - `language_code`: The language code of the human language in which the reference should be created
+/// - `book_reference_type`: The type of the book reference (short or long)
+/// - `delimiter_override`: A delimiter which is used between chapter and verse instead of the delimiter of the language, or [None] to use the delimiter of the language
+///
+/// # Returns
+/// - The Bible reference as a [String] if the language specified with the `language_code` exists
+/// - A [ReferenceCreationError::LanguageDoesNotExist] if the language can't be found
+/// - A [ReferenceCreationError::IncompleteLanguage] if the language contains no name for the referenced book
+///
+/// # Example
+/// ```
+/// use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
+/// use bibleref::referencing::language::{get_reference_in_language_styled, BookReferenceType};
+/// let reference = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+/// assert_eq!(get_reference_in_language_styled(&reference, "en", BookReferenceType::Long, Some(".")).unwrap(), "John 3.16");
+/// assert_eq!(get_reference_in_language_styled(&reference, "de", BookReferenceType::Long, Some(".")).unwrap(), "Johannes 3.16");
+/// assert_eq!(get_reference_in_language_styled(&reference, "de", BookReferenceType::Long, None).unwrap(), "Johannes 3,16");
+/// ```
+pub fn get_reference_in_language_styled(
+    bible_reference: &BibleReference,
+    language_code: &str,
+    book_reference_type: BookReferenceType,
+    delimiter_override: Option<&str>,
+) -> Result<String, ReferenceCreationError> {
+    let language_code = language_code.trim().to_lowercase();
+    let reference_languages = &*REFERENCE_LANGUAGES.read().unwrap();
+    let style = ReferenceStyle {
+        custom_delimiter: delimiter_override.map(|delimiter| delimiter.to_string()),
+        ..book_reference_type.into()
+    };
+
+    match find_language_position(reference_languages, &language_code) {
+        Some(position) => Ok(reference_languages[position]
+            .try_create_reference_with_style(bible_reference, &style)?),
+        None => Err(LanguageDoesNotExistError { language_code }.into()),
     }
-    Err(LanguageDoesNotExistError { language_code })
 }
 
 /// This function creates a Bible range in a human language.
@@ -254,27 +901,26 @@ pub fn get_reference_in_language(
 /// - `shortened_string`: A boolean indicating whether the string should be shortened or not
 ///
 /// # Returns
-/// An [`Option<String>`] which is [`Some(bible_range_string)`] if the language specified with the `language_code` exists
-/// or [None] if the language can't be found.
+/// - The Bible range as a [String] if the language specified with the `language_code` exists
+/// - A [ReferenceCreationError::LanguageDoesNotExist] if the language can't be found
+/// - A [ReferenceCreationError::IncompleteLanguage] if the language contains no name for one of the referenced books
 pub fn get_range_in_language(
     bible_range: &BibleRange,
     language_code: &str,
     book_reference_type: BookReferenceType,
     shortened_string: bool,
-) -> Result<String, LanguageDoesNotExistError> {
+) -> Result<String, ReferenceCreationError> {
     let language_code = language_code.trim().to_lowercase();
     let reference_languages = &*REFERENCE_LANGUAGES.read().unwrap();
 
-    for language in reference_languages {
-        if language.language_code.to_lowercase().eq(&language_code) {
-            return Ok(language.create_bible_range(
-                bible_range,
-                book_reference_type,
-                shortened_string,
-            ));
-        }
+    match find_language_position(reference_languages, &language_code) {
+        Some(position) => Ok(reference_languages[position].try_create_bible_range(
+            bible_range,
+            book_reference_type,
+            shortened_string,
+        )?),
+        None => Err(LanguageDoesNotExistError { language_code }.into()),
     }
-    Err(LanguageDoesNotExistError { language_code })
 }
 
 /// Creates a [`String`] representation of a [`BibleReferenceRepresentation`] in the specified language.
@@ -289,13 +935,13 @@ pub fn get_range_in_language(
 /// # Returns
 ///
 /// A [`Result`] containing a [`String`] representation of the [`BibleReferenceRepresentation`] in the specified language
-/// or a [`LanguageDoesNotExistError`] if the language does not exist.
+/// or a [`ReferenceCreationError`] if the language does not exist or contains no name for a referenced book.
 pub fn get_reference_representation_in_language(
     reference: &BibleReferenceRepresentation,
     language_code: &str,
     book_reference_type: BookReferenceType,
     shortened_string: bool,
-) -> Result<String, LanguageDoesNotExistError> {
+) -> Result<String, ReferenceCreationError> {
     match reference {
         BibleReferenceRepresentation::Single(single_repr) => {
             get_reference_in_language(single_repr, language_code, book_reference_type)
@@ -320,6 +966,120 @@ pub enum BookReferenceType {
     Long,
 }
 
+/// The direction in which a [ReferenceLanguage] is written.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextDirection {
+    /// Left-to-right, e.g. English, German or Chinese
+    #[default]
+    Ltr,
+
+    /// Right-to-left, e.g. Arabic or Hebrew
+    Rtl,
+}
+
+/// Determines how a Bible reference is formatted by [ReferenceLanguage::create_reference_with_style] and [ReferenceLanguage::create_bible_range_with_style], e.g. "John 3:16", "Jn 3.16" or "JOHN 03:016".
+/// A [BookReferenceType] can be converted into the default style with this type of book names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReferenceStyle {
+    /// The type of the book names (short or long)
+    pub book_reference_type: BookReferenceType,
+
+    /// The index of the chapter/verse delimiter of the language which should be used. If the language has no delimiter with this index, the first one is used.
+    pub delimiter_index: usize,
+
+    /// A chapter/verse delimiter which is used instead of the delimiters of the language (e.g. "." for "Ps 23.1")
+    pub custom_delimiter: Option<String>,
+
+    /// The minimal number of digits of chapter and verse numbers. Shorter numbers are padded with leading zeros, 0 disables the padding.
+    pub number_width: usize,
+
+    /// Determines whether the book names are written in upper case (e.g. "PS 23:1")
+    pub uppercase_book_names: bool,
+}
+
+impl ReferenceStyle {
+    /// The default style with long book names, the first delimiter of the language and no padding, e.g. "Psalms 23:1". It is also returned by [ReferenceStyle::default].
+    pub const DEFAULT: ReferenceStyle = ReferenceStyle {
+        book_reference_type: BookReferenceType::Long,
+        delimiter_index: 0,
+        custom_delimiter: None,
+        number_width: 0,
+        uppercase_book_names: false,
+    };
+
+    /// The default style with long book names, e.g. "Psalms 23:1"
+    pub fn long() -> Self {
+        BookReferenceType::Long.into()
+    }
+
+    /// The default style with short book names, e.g. "Ps 23:1"
+    pub fn short() -> Self {
+        BookReferenceType::Short.into()
+    }
+
+    /// Short book names with a period between chapter and verse, e.g. "Ps 23.1"
+    pub fn period_delimited() -> Self {
+        ReferenceStyle {
+            custom_delimiter: Some(".".to_string()),
+            ..Self::short()
+        }
+    }
+
+    /// Short book names in upper case, e.g. "PS 23:1"
+    pub fn uppercase() -> Self {
+        ReferenceStyle {
+            uppercase_book_names: true,
+            ..Self::short()
+        }
+    }
+
+    /// Formats a chapter or verse number, padding it with leading zeros if necessary.
+    fn format_number(&self, number: u8) -> String {
+        format!("{:0width$}", number, width = self.number_width)
+    }
+}
+
+impl Default for ReferenceStyle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<BookReferenceType> for ReferenceStyle {
+    fn from(book_reference_type: BookReferenceType) -> Self {
+        ReferenceStyle {
+            book_reference_type,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+/// Returns the language codes and names of all registered languages (including the languages which have been registered during runtime), e.g. for a language picker.
+/// # Returns
+/// - A [Vec] of `(language_code, long_language_name)` pairs in the order in which the languages are searched when parsing
+/// # Example
+/// ```
+/// use bibleref::referencing::language::supported_languages;
+/// let languages = supported_languages();
+/// assert!(languages.contains(&("en".to_string(), "English".to_string())));
+/// assert!(languages.contains(&("de".to_string(), "German".to_string())));
+/// ```
+pub fn supported_languages() -> Vec<(String, String)> {
+    REFERENCE_LANGUAGES
+        .read()
+        .unwrap()
+        .iter()
+        .map(|language| {
+            (
+                language.language_code.clone(),
+                language.long_language_name.clone(),
+            )
+        })
+        .collect()
+}
+
 /// This function returns a reference language by its language code.
 /// # Params
 /// - `language_code`: The language code of the human language
@@ -330,9 +1090,111 @@ pub fn get_language_by_code(language_code: &str) -> Option<ReferenceLanguage> {
     let language_code = language_code.trim().to_lowercase();
     let reference_languages = &*REFERENCE_LANGUAGES.read().unwrap();
 
+    find_language_position(reference_languages, &language_code)
+        .map(|position| reference_languages[position].clone())
+}
+
+/// Checks whether a language with the given language code is registered, e.g. to validate user input before translating.
+/// Like all lookups by language code, surrounding whitespace and the case are ignored.
+/// # Params
+/// - `language_code`: The language code of the human language
+/// # Returns
+/// - `true` if the language exists, `false` otherwise
+/// # Example
+/// ```
+/// use bibleref::referencing::language::language_exists;
+/// assert!(language_exists("de"));
+/// assert!(language_exists(" ZH_SIM "));
+/// assert!(!language_exists("xx"));
+/// ```
+pub fn language_exists(language_code: &str) -> bool {
+    let language_code = language_code.trim().to_lowercase();
+    let reference_languages = &*REFERENCE_LANGUAGES.read().unwrap();
+
+    find_language_position(reference_languages, &language_code).is_some()
+}
+
+/// Sets the default target language which is used by [translate_default](crate::translate_default), e.g. for applications which mostly work in one language.
+/// The setting applies to all threads. Until it is set, English ("en") is used.
+/// # Params
+/// - `language_code`: The language code of a registered language. Like all lookups by language code, surrounding whitespace and the case are ignored.
+/// # Returns
+/// - `Ok(())` if the default language has been changed
+/// - A [LanguageDoesNotExistError] if the language can't be found. In this case, the default language is not changed.
+/// # Example
+/// ```
+/// use bibleref::referencing::language::{get_default_language, set_default_language};
+/// set_default_language("de").unwrap();
+/// assert_eq!(get_default_language(), "de");
+/// assert!(set_default_language("xx").is_err());
+/// assert_eq!(get_default_language(), "de");
+/// ```
+pub fn set_default_language(language_code: &str) -> Result<(), LanguageDoesNotExistError> {
+    let language_code = language_code.trim().to_lowercase();
+    if !language_exists(&language_code) {
+        return Err(LanguageDoesNotExistError { language_code });
+    }
+    *DEFAULT_LANGUAGE_CODE.write().unwrap() = language_code;
+    Ok(())
+}
+
+/// Returns the code of the default target language which has been set with [set_default_language], or "en" if it has not been set.
+pub fn get_default_language() -> String {
+    DEFAULT_LANGUAGE_CODE.read().unwrap().clone()
+}
+
+/// Returns the text direction of a language, which allows to wrap a created reference in the right bidirectional markers.
+/// # Params
+/// - `language_code`: The language code of the human language
+/// # Returns
+/// - The [TextDirection] of the language or [None] if the language can't be found
+/// # Example
+/// ```
+/// use bibleref::referencing::language::{TextDirection, get_text_direction};
+/// assert_eq!(get_text_direction("en"), Some(TextDirection::Ltr));
+/// assert_eq!(get_text_direction("ar"), Some(TextDirection::Rtl));
+/// assert_eq!(get_text_direction("xx"), None);
+/// ```
+pub fn get_text_direction(language_code: &str) -> Option<TextDirection> {
+    let language_code = language_code.trim().to_lowercase();
+    let reference_languages = &*REFERENCE_LANGUAGES.read().unwrap();
+
+    find_language_position(reference_languages, &language_code)
+        .map(|position| reference_languages[position].directionality)
+}
+
+/// Finds a Bible book by its long or short name in any registered language of [REFERENCE_LANGUAGES].
+/// The comparison is case-insensitive and surrounding whitespace is ignored.
+/// # Params
+/// - `name`: The name of the Bible book in any supported language (e.g. "Genesis", "1. Mose" or "创")
+/// # Returns
+/// An [`Option<BibleBook>`] which is [`Some(bible_book)`] for the first language containing the name
+/// or [None] if the name can't be found.
+/// # Example
+/// ```
+/// use bibleref::bible::BibleBook;
+/// use bibleref::referencing::language::find_book_by_name;
+/// assert_eq!(find_book_by_name("Johannes"), Some(BibleBook::John));
+/// assert_eq!(find_book_by_name("rev"), Some(BibleBook::Revelation));
+/// assert_eq!(find_book_by_name("Mose"), None);
+/// ```
+pub fn find_book_by_name(name: &str) -> Option<BibleBook> {
+    let name = name.trim().to_lowercase();
+    let reference_languages = &*REFERENCE_LANGUAGES.read().unwrap();
+
     for language in reference_languages {
-        if language.language_code.to_lowercase().eq(&language_code) {
-            return Some(language.clone());
+        for book in BibleBook::all() {
+            let names = language
+                .long_names
+                .get(&book)
+                .into_iter()
+                .chain(language.short_names.get(&book))
+                .flatten();
+            for book_name in names {
+                if book_name.to_lowercase() == name {
+                    return Some(book);
+                }
+            }
         }
     }
     None
@@ -358,12 +1220,24 @@ fn get_english_reference_language() -> ReferenceLanguage {
         (BibleBook::Nehemiah, vec!["Nehemiah".to_string()]),
         (BibleBook::Esther, vec!["Esther".to_string()]),
         (BibleBook::Job, vec!["Job".to_string()]),
-        (BibleBook::Psalm, vec!["Psalms".to_string()]), // Commonly plural in English
+        // Commonly plural in English, the singular is used for single Psalms
+        (
+            BibleBook::Psalm,
+            vec!["Psalms".to_string(), "Psalm".to_string()],
+        ),
         (BibleBook::Proverbs, vec!["Proverbs".to_string()]),
-        (BibleBook::Ecclesiastes, vec!["Ecclesiastes".to_string()]),
+        (
+            BibleBook::Ecclesiastes,
+            vec!["Ecclesiastes".to_string(), "Qoheleth".to_string()],
+        ),
         (
             BibleBook::SongofSolomon,
-            vec!["Song of Solomon".to_string()],
+            vec![
+                "Song of Solomon".to_string(),
+                "Song of Songs".to_string(),
+                "Canticles".to_string(),
+                "Canticle of Canticles".to_string(),
+            ],
         ),
         (BibleBook::Isaiah, vec!["Isaiah".to_string()]),
         (BibleBook::Jeremiah, vec!["Jeremiah".to_string()]),
@@ -414,11 +1288,47 @@ fn get_english_reference_language() -> ReferenceLanguage {
         (BibleBook::IIJohn, vec!["2 John".to_string()]),
         (BibleBook::IIIJohn, vec!["3 John".to_string()]),
         (BibleBook::Jude, vec!["Jude".to_string()]),
-        (BibleBook::Revelation, vec!["Revelation".to_string()]),
+        (
+            BibleBook::Revelation,
+            vec!["Revelation".to_string(), "Revelations".to_string()],
+        ),
     ];
-    let long_names: HashMap<BibleBook, Vec<String>> = long_names_vec.into_iter().collect();
-
-    let short_names_vec = vec![
+    #[cfg(feature = "deuterocanon")]
+    let long_names_vec = [
+        long_names_vec,
+        vec![
+            (BibleBook::Tobit, vec!["Tobit".to_string()]),
+            (BibleBook::Judith, vec!["Judith".to_string()]),
+            (
+                BibleBook::AdditionsToEsther,
+                vec!["Additions to Esther".to_string()],
+            ),
+            (
+                BibleBook::Wisdom,
+                vec!["Wisdom of Solomon".to_string(), "Wisdom".to_string()],
+            ),
+            (
+                BibleBook::Sirach,
+                vec!["Sirach".to_string(), "Ecclesiasticus".to_string()],
+            ),
+            (BibleBook::Baruch, vec!["Baruch".to_string()]),
+            (
+                BibleBook::PrayerOfAzariah,
+                vec!["Prayer of Azariah".to_string()],
+            ),
+            (BibleBook::Susanna, vec!["Susanna".to_string()]),
+            (
+                BibleBook::BelAndTheDragon,
+                vec!["Bel and the Dragon".to_string()],
+            ),
+            (BibleBook::IMaccabees, vec!["1 Maccabees".to_string()]),
+            (BibleBook::IIMaccabees, vec!["2 Maccabees".to_string()]),
+        ],
+    ]
+    .concat();
+    let long_names: HashMap<BibleBook, Vec<String>> = long_names_vec.into_iter().collect();
+
+    let short_names_vec = vec![
         (BibleBook::Genesis, vec!["Gen".to_string()]),
         (BibleBook::Exodus, vec!["Exod".to_string()]),
         (BibleBook::Leviticus, vec!["Lev".to_string()]),
@@ -437,10 +1347,23 @@ fn get_english_reference_language() -> ReferenceLanguage {
         (BibleBook::Nehemiah, vec!["Neh".to_string()]),
         (BibleBook::Esther, vec!["Esth".to_string()]),
         (BibleBook::Job, vec!["Job".to_string()]),
-        (BibleBook::Psalm, vec!["Ps".to_string()]), // "Ps" for Psalms
+        (
+            BibleBook::Psalm,
+            vec!["Ps".to_string(), "Psa".to_string(), "Pss".to_string()],
+        ), // "Ps" for Psalms
         (BibleBook::Proverbs, vec!["Prov".to_string()]),
-        (BibleBook::Ecclesiastes, vec!["Eccl".to_string()]),
-        (BibleBook::SongofSolomon, vec!["Song".to_string()]),
+        (
+            BibleBook::Ecclesiastes,
+            vec!["Eccl".to_string(), "Eccles".to_string(), "Qoh".to_string()],
+        ),
+        (
+            BibleBook::SongofSolomon,
+            vec![
+                "Song".to_string(),
+                "Song of Sol".to_string(),
+                "Cant".to_string(),
+            ],
+        ),
         (BibleBook::Isaiah, vec!["Isa".to_string()]),
         (BibleBook::Jeremiah, vec!["Jer".to_string()]),
         (BibleBook::Lamentations, vec!["Lam".to_string()]),
@@ -486,6 +1409,24 @@ fn get_english_reference_language() -> ReferenceLanguage {
         (BibleBook::Jude, vec!["Jude".to_string()]),
         (BibleBook::Revelation, vec!["Rev".to_string()]),
     ];
+    #[cfg(feature = "deuterocanon")]
+    let short_names_vec = [
+        short_names_vec,
+        vec![
+            (BibleBook::Tobit, vec!["Tob".to_string()]),
+            (BibleBook::Judith, vec!["Jdt".to_string()]),
+            (BibleBook::AdditionsToEsther, vec!["Add Esth".to_string()]),
+            (BibleBook::Wisdom, vec!["Wis".to_string()]),
+            (BibleBook::Sirach, vec!["Sir".to_string()]),
+            (BibleBook::Baruch, vec!["Bar".to_string()]),
+            (BibleBook::PrayerOfAzariah, vec!["Pr Azar".to_string()]),
+            (BibleBook::Susanna, vec!["Sus".to_string()]),
+            (BibleBook::BelAndTheDragon, vec!["Bel".to_string()]),
+            (BibleBook::IMaccabees, vec!["1 Macc".to_string()]),
+            (BibleBook::IIMaccabees, vec!["2 Macc".to_string()]),
+        ],
+    ]
+    .concat();
     let short_names: HashMap<BibleBook, Vec<String>> = short_names_vec.into_iter().collect();
 
     ReferenceLanguage {
@@ -497,6 +1438,7 @@ fn get_english_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "and".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -537,8 +1479,14 @@ fn get_german_reference_language() -> ReferenceLanguage {
         (BibleBook::Job, vec!["Hiob".to_string()]),
         (BibleBook::Psalm, vec!["Psalmen".to_string()]), // Plural in German
         (BibleBook::Proverbs, vec!["Sprüche".to_string()]),
-        (BibleBook::Ecclesiastes, vec!["Prediger".to_string()]),
-        (BibleBook::SongofSolomon, vec!["Hohelied".to_string()]),
+        (
+            BibleBook::Ecclesiastes,
+            vec!["Prediger".to_string(), "Kohelet".to_string()],
+        ),
+        (
+            BibleBook::SongofSolomon,
+            vec!["Hohelied".to_string(), "Hoheslied".to_string()],
+        ),
         (BibleBook::Isaiah, vec!["Jesaja".to_string()]),
         (BibleBook::Jeremiah, vec!["Jeremia".to_string()]),
         (BibleBook::Lamentations, vec!["Klagelieder".to_string()]),
@@ -588,8 +1536,41 @@ fn get_german_reference_language() -> ReferenceLanguage {
         (BibleBook::IIJohn, vec!["2. Johannes".to_string()]),
         (BibleBook::IIIJohn, vec!["3. Johannes".to_string()]),
         (BibleBook::Jude, vec!["Judas".to_string()]),
-        (BibleBook::Revelation, vec!["Offenbarung".to_string()]),
+        (
+            BibleBook::Revelation,
+            vec!["Offenbarung".to_string(), "Apokalypse".to_string()],
+        ),
     ];
+    #[cfg(feature = "deuterocanon")]
+    let long_names_vec = [
+        long_names_vec,
+        vec![
+            (BibleBook::Tobit, vec!["Tobit".to_string()]),
+            (BibleBook::Judith, vec!["Judit".to_string()]),
+            (
+                BibleBook::AdditionsToEsther,
+                vec!["Stücke zu Ester".to_string()],
+            ),
+            (BibleBook::Wisdom, vec!["Weisheit".to_string()]),
+            (
+                BibleBook::Sirach,
+                vec!["Jesus Sirach".to_string(), "Sirach".to_string()],
+            ),
+            (BibleBook::Baruch, vec!["Baruch".to_string()]),
+            (
+                BibleBook::PrayerOfAzariah,
+                vec!["Gebet Asarjas".to_string()],
+            ),
+            (BibleBook::Susanna, vec!["Susanna".to_string()]),
+            (
+                BibleBook::BelAndTheDragon,
+                vec!["Bel und der Drache".to_string()],
+            ),
+            (BibleBook::IMaccabees, vec!["1. Makkabäer".to_string()]),
+            (BibleBook::IIMaccabees, vec!["2. Makkabäer".to_string()]),
+        ],
+    ]
+    .concat();
     let long_names: HashMap<BibleBook, Vec<String>> = long_names_vec.into_iter().collect();
 
     let short_names_vec = vec![
@@ -660,6 +1641,24 @@ fn get_german_reference_language() -> ReferenceLanguage {
         (BibleBook::Jude, vec!["Jud".to_string()]),
         (BibleBook::Revelation, vec!["Offb".to_string()]),
     ];
+    #[cfg(feature = "deuterocanon")]
+    let short_names_vec = [
+        short_names_vec,
+        vec![
+            (BibleBook::Tobit, vec!["Tob".to_string()]),
+            (BibleBook::Judith, vec!["Jdt".to_string()]),
+            (BibleBook::AdditionsToEsther, vec!["StEst".to_string()]),
+            (BibleBook::Wisdom, vec!["Weish".to_string()]),
+            (BibleBook::Sirach, vec!["Sir".to_string()]),
+            (BibleBook::Baruch, vec!["Bar".to_string()]),
+            (BibleBook::PrayerOfAzariah, vec!["GebAsar".to_string()]),
+            (BibleBook::Susanna, vec!["Sus".to_string()]),
+            (BibleBook::BelAndTheDragon, vec!["Bel".to_string()]),
+            (BibleBook::IMaccabees, vec!["1Makk".to_string()]),
+            (BibleBook::IIMaccabees, vec!["2Makk".to_string()]),
+        ],
+    ]
+    .concat();
     let short_names: HashMap<BibleBook, Vec<String>> = short_names_vec.into_iter().collect();
 
     ReferenceLanguage {
@@ -671,6 +1670,7 @@ fn get_german_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "und".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -830,6 +1830,7 @@ fn get_chinese_simplified_reference_language() -> ReferenceLanguage {
         space_separation: false,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec!["；".to_string(), "和".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -989,6 +1990,7 @@ fn get_chinese_traditional_reference_language() -> ReferenceLanguage {
         space_separation: false,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec!["；".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -1012,7 +2014,10 @@ fn get_french_reference_language() -> ReferenceLanguage {
         (BibleBook::Nehemiah, vec!["Néhémie".to_string()]),
         (BibleBook::Esther, vec!["Esther".to_string()]),
         (BibleBook::Job, vec!["Job".to_string()]),
-        (BibleBook::Psalm, vec!["Psaumes".to_string()]), // Plural in French
+        (
+            BibleBook::Psalm,
+            vec!["Psaumes".to_string(), "Psaume".to_string()],
+        ), // Plural in French
         (BibleBook::Proverbs, vec!["Proverbes".to_string()]),
         (BibleBook::Ecclesiastes, vec!["Ecclésiaste".to_string()]),
         (
@@ -1151,6 +2156,7 @@ fn get_french_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "et".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -1310,6 +2316,7 @@ pub fn get_russian_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "и".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -1466,6 +2473,7 @@ pub fn get_ukrainian_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "і".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -1625,6 +2633,7 @@ pub fn get_spanish_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "y".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -1634,7 +2643,10 @@ fn get_polish_reference_language() -> ReferenceLanguage {
         (BibleBook::Exodus, vec!["Księga Wyjścia".to_string()]),
         (BibleBook::Leviticus, vec!["Księga Kapłańska".to_string()]),
         (BibleBook::Numbers, vec!["Księga Liczb".to_string()]),
-        (BibleBook::Deuteronomy, vec!["Księga Powtórzonego Prawa".to_string()]),
+        (
+            BibleBook::Deuteronomy,
+            vec!["Księga Powtórzonego Prawa".to_string()],
+        ),
         (BibleBook::Joshua, vec!["Księga Jozuego".to_string()]),
         (BibleBook::Judges, vec!["Księga Sędziów".to_string()]),
         (BibleBook::Ruth, vec!["Księga Rut".to_string()]),
@@ -1651,7 +2663,10 @@ fn get_polish_reference_language() -> ReferenceLanguage {
         (BibleBook::Psalm, vec!["Księga Psalmów".to_string()]),
         (BibleBook::Proverbs, vec!["Księga Przysłów".to_string()]),
         (BibleBook::Ecclesiastes, vec!["Księga Koheleta".to_string()]),
-        (BibleBook::SongofSolomon, vec!["Pieśń nad Pieśniami".to_string()]),
+        (
+            BibleBook::SongofSolomon,
+            vec!["Pieśń nad Pieśniami".to_string()],
+        ),
         (BibleBook::Isaiah, vec!["Księga Izajasza".to_string()]),
         (BibleBook::Jeremiah, vec!["Księga Jeremiasza".to_string()]),
         (BibleBook::Lamentations, vec!["Lamentacje".to_string()]),
@@ -1675,16 +2690,34 @@ fn get_polish_reference_language() -> ReferenceLanguage {
         (BibleBook::John, vec!["Ewangelia Jana".to_string()]),
         (BibleBook::Acts, vec!["Dzieje Apostolskie".to_string()]),
         (BibleBook::Romans, vec!["List do Rzymian".to_string()]),
-        (BibleBook::ICorinthians, vec!["1 List do Koryntian".to_string()]),
-        (BibleBook::IICorinthians, vec!["2 List do Koryntian".to_string()]),
+        (
+            BibleBook::ICorinthians,
+            vec!["1 List do Koryntian".to_string()],
+        ),
+        (
+            BibleBook::IICorinthians,
+            vec!["2 List do Koryntian".to_string()],
+        ),
         (BibleBook::Galatians, vec!["List do Galatów".to_string()]),
         (BibleBook::Ephesians, vec!["List do Efezjan".to_string()]),
         (BibleBook::Philippians, vec!["List do Filipian".to_string()]),
         (BibleBook::Colossians, vec!["List do Kolosan".to_string()]),
-        (BibleBook::IThessalonians, vec!["1 List do Tesaloniczan".to_string()]),
-        (BibleBook::IIThessalonians, vec!["2 List do Tesaloniczan".to_string()]),
-        (BibleBook::ITimothy, vec!["1 List do Tymoteusza".to_string()]),
-        (BibleBook::IITimothy, vec!["2 List do Tymoteusza".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["1 List do Tesaloniczan".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["2 List do Tesaloniczan".to_string()],
+        ),
+        (
+            BibleBook::ITimothy,
+            vec!["1 List do Tymoteusza".to_string()],
+        ),
+        (
+            BibleBook::IITimothy,
+            vec!["2 List do Tymoteusza".to_string()],
+        ),
         (BibleBook::Titus, vec!["List do Tytusa".to_string()]),
         (BibleBook::Philemon, vec!["List do Filemona".to_string()]),
         (BibleBook::Hebrews, vec!["List do Hebrajczyków".to_string()]),
@@ -1778,6 +2811,167 @@ fn get_polish_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "i".to_string()],
+        directionality: TextDirection::Ltr,
+    }
+}
+
+fn get_portuguese_reference_language() -> ReferenceLanguage {
+    let long_names_vec = vec![
+        (BibleBook::Genesis, vec!["Gênesis".to_string()]),
+        (BibleBook::Exodus, vec!["Êxodo".to_string()]),
+        (BibleBook::Leviticus, vec!["Levítico".to_string()]),
+        (BibleBook::Numbers, vec!["Números".to_string()]),
+        (BibleBook::Deuteronomy, vec!["Deuteronômio".to_string()]),
+        (BibleBook::Joshua, vec!["Josué".to_string()]),
+        (BibleBook::Judges, vec!["Juízes".to_string()]),
+        (BibleBook::Ruth, vec!["Rute".to_string()]),
+        (BibleBook::ISamuel, vec!["1 Samuel".to_string()]),
+        (BibleBook::IISamuel, vec!["2 Samuel".to_string()]),
+        (BibleBook::IKings, vec!["1 Reis".to_string()]),
+        (BibleBook::IIKings, vec!["2 Reis".to_string()]),
+        (BibleBook::IChronicles, vec!["1 Crônicas".to_string()]),
+        (BibleBook::IIChronicles, vec!["2 Crônicas".to_string()]),
+        (BibleBook::Ezra, vec!["Esdras".to_string()]),
+        (BibleBook::Nehemiah, vec!["Neemias".to_string()]),
+        (BibleBook::Esther, vec!["Ester".to_string()]),
+        (BibleBook::Job, vec!["Jó".to_string()]),
+        (BibleBook::Psalm, vec!["Salmos".to_string()]),
+        (BibleBook::Proverbs, vec!["Provérbios".to_string()]),
+        (BibleBook::Ecclesiastes, vec!["Eclesiastes".to_string()]),
+        (BibleBook::SongofSolomon, vec!["Cânticos".to_string()]),
+        (BibleBook::Isaiah, vec!["Isaías".to_string()]),
+        (BibleBook::Jeremiah, vec!["Jeremias".to_string()]),
+        (BibleBook::Lamentations, vec!["Lamentações".to_string()]),
+        (BibleBook::Ezekiel, vec!["Ezequiel".to_string()]),
+        (BibleBook::Daniel, vec!["Daniel".to_string()]),
+        (BibleBook::Hosea, vec!["Oseias".to_string()]),
+        (BibleBook::Joel, vec!["Joel".to_string()]),
+        (BibleBook::Amos, vec!["Amós".to_string()]),
+        (BibleBook::Obadiah, vec!["Obadias".to_string()]),
+        (BibleBook::Jonah, vec!["Jonas".to_string()]),
+        (BibleBook::Micah, vec!["Miqueias".to_string()]),
+        (BibleBook::Nahum, vec!["Naum".to_string()]),
+        (BibleBook::Habakkuk, vec!["Habacuque".to_string()]),
+        (BibleBook::Zephaniah, vec!["Sofonias".to_string()]),
+        (BibleBook::Haggai, vec!["Ageu".to_string()]),
+        (BibleBook::Zechariah, vec!["Zacarias".to_string()]),
+        (BibleBook::Malachi, vec!["Malaquias".to_string()]),
+        (BibleBook::Matthew, vec!["Mateus".to_string()]),
+        (BibleBook::Mark, vec!["Marcos".to_string()]),
+        (BibleBook::Luke, vec!["Lucas".to_string()]),
+        (BibleBook::John, vec!["João".to_string()]),
+        (BibleBook::Acts, vec!["Atos".to_string()]),
+        (BibleBook::Romans, vec!["Romanos".to_string()]),
+        (BibleBook::ICorinthians, vec!["1 Coríntios".to_string()]),
+        (BibleBook::IICorinthians, vec!["2 Coríntios".to_string()]),
+        (BibleBook::Galatians, vec!["Gálatas".to_string()]),
+        (BibleBook::Ephesians, vec!["Efésios".to_string()]),
+        (BibleBook::Philippians, vec!["Filipenses".to_string()]),
+        (BibleBook::Colossians, vec!["Colossenses".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["1 Tessalonicenses".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["2 Tessalonicenses".to_string()],
+        ),
+        (BibleBook::ITimothy, vec!["1 Timóteo".to_string()]),
+        (BibleBook::IITimothy, vec!["2 Timóteo".to_string()]),
+        (BibleBook::Titus, vec!["Tito".to_string()]),
+        (BibleBook::Philemon, vec!["Filemom".to_string()]),
+        (BibleBook::Hebrews, vec!["Hebreus".to_string()]),
+        (BibleBook::James, vec!["Tiago".to_string()]),
+        (BibleBook::IPeter, vec!["1 Pedro".to_string()]),
+        (BibleBook::IIPeter, vec!["2 Pedro".to_string()]),
+        (BibleBook::IJohn, vec!["1 João".to_string()]),
+        (BibleBook::IIJohn, vec!["2 João".to_string()]),
+        (BibleBook::IIIJohn, vec!["3 João".to_string()]),
+        (BibleBook::Jude, vec!["Judas".to_string()]),
+        (BibleBook::Revelation, vec!["Apocalipse".to_string()]),
+    ];
+    let long_names: HashMap<BibleBook, Vec<String>> = long_names_vec.into_iter().collect();
+
+    let short_names_vec = vec![
+        (BibleBook::Genesis, vec!["Gn".to_string()]),
+        (BibleBook::Exodus, vec!["Êx".to_string()]),
+        (BibleBook::Leviticus, vec!["Lv".to_string()]),
+        (BibleBook::Numbers, vec!["Nm".to_string()]),
+        (BibleBook::Deuteronomy, vec!["Dt".to_string()]),
+        (BibleBook::Joshua, vec!["Js".to_string()]),
+        (BibleBook::Judges, vec!["Jz".to_string()]),
+        (BibleBook::Ruth, vec!["Rt".to_string()]),
+        (BibleBook::ISamuel, vec!["1 Sm".to_string()]),
+        (BibleBook::IISamuel, vec!["2 Sm".to_string()]),
+        (BibleBook::IKings, vec!["1 Rs".to_string()]),
+        (BibleBook::IIKings, vec!["2 Rs".to_string()]),
+        (BibleBook::IChronicles, vec!["1 Cr".to_string()]),
+        (BibleBook::IIChronicles, vec!["2 Cr".to_string()]),
+        (BibleBook::Ezra, vec!["Ed".to_string()]),
+        (BibleBook::Nehemiah, vec!["Ne".to_string()]),
+        (BibleBook::Esther, vec!["Et".to_string()]),
+        (BibleBook::Job, vec!["Jó".to_string()]),
+        (BibleBook::Psalm, vec!["Sl".to_string()]),
+        (BibleBook::Proverbs, vec!["Pv".to_string()]),
+        (BibleBook::Ecclesiastes, vec!["Ec".to_string()]),
+        (BibleBook::SongofSolomon, vec!["Ct".to_string()]),
+        (BibleBook::Isaiah, vec!["Is".to_string()]),
+        (BibleBook::Jeremiah, vec!["Jr".to_string()]),
+        (BibleBook::Lamentations, vec!["Lm".to_string()]),
+        (BibleBook::Ezekiel, vec!["Ez".to_string()]),
+        (BibleBook::Daniel, vec!["Dn".to_string()]),
+        (BibleBook::Hosea, vec!["Os".to_string()]),
+        (BibleBook::Joel, vec!["Jl".to_string()]),
+        (BibleBook::Amos, vec!["Am".to_string()]),
+        (BibleBook::Obadiah, vec!["Ob".to_string()]),
+        (BibleBook::Jonah, vec!["Jon".to_string()]),
+        (BibleBook::Micah, vec!["Mq".to_string()]),
+        (BibleBook::Nahum, vec!["Na".to_string()]),
+        (BibleBook::Habakkuk, vec!["Hc".to_string()]),
+        (BibleBook::Zephaniah, vec!["Sf".to_string()]),
+        (BibleBook::Haggai, vec!["Ag".to_string()]),
+        (BibleBook::Zechariah, vec!["Zc".to_string()]),
+        (BibleBook::Malachi, vec!["Ml".to_string()]),
+        (BibleBook::Matthew, vec!["Mt".to_string()]),
+        (BibleBook::Mark, vec!["Mc".to_string()]),
+        (BibleBook::Luke, vec!["Lc".to_string()]),
+        (BibleBook::John, vec!["Jn".to_string()]),
+        (BibleBook::Acts, vec!["At".to_string()]),
+        (BibleBook::Romans, vec!["Rm".to_string()]),
+        (BibleBook::ICorinthians, vec!["1 Co".to_string()]),
+        (BibleBook::IICorinthians, vec!["2 Co".to_string()]),
+        (BibleBook::Galatians, vec!["Gl".to_string()]),
+        (BibleBook::Ephesians, vec!["Ef".to_string()]),
+        (BibleBook::Philippians, vec!["Fp".to_string()]),
+        (BibleBook::Colossians, vec!["Cl".to_string()]),
+        (BibleBook::IThessalonians, vec!["1 Ts".to_string()]),
+        (BibleBook::IIThessalonians, vec!["2 Ts".to_string()]),
+        (BibleBook::ITimothy, vec!["1 Tm".to_string()]),
+        (BibleBook::IITimothy, vec!["2 Tm".to_string()]),
+        (BibleBook::Titus, vec!["Tt".to_string()]),
+        (BibleBook::Philemon, vec!["Fm".to_string()]),
+        (BibleBook::Hebrews, vec!["Hb".to_string()]),
+        (BibleBook::James, vec!["Tg".to_string()]),
+        (BibleBook::IPeter, vec!["1 Pe".to_string()]),
+        (BibleBook::IIPeter, vec!["2 Pe".to_string()]),
+        (BibleBook::IJohn, vec!["1 Jo".to_string()]),
+        (BibleBook::IIJohn, vec!["2 Jo".to_string()]),
+        (BibleBook::IIIJohn, vec!["3 Jo".to_string()]),
+        (BibleBook::Jude, vec!["Jd".to_string()]),
+        (BibleBook::Revelation, vec!["Ap".to_string()]),
+    ];
+    let short_names: HashMap<BibleBook, Vec<String>> = short_names_vec.into_iter().collect();
+
+    ReferenceLanguage {
+        long_language_name: "Portuguese".to_string(),
+        language_code: "pt".to_string(),
+        long_names,
+        short_names,
+        chapter_vers_delimiters: vec![":".to_string(), ",".to_string()],
+        space_separation: true,
+        range_delimiter: "-".to_string(),
+        multiple_representations_delimiters: vec![";".to_string(), "e".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -1796,7 +2990,10 @@ fn get_czech_reference_language() -> ReferenceLanguage {
         (BibleBook::IKings, vec!["1. Královská".to_string()]),
         (BibleBook::IIKings, vec!["2. Královská".to_string()]),
         (BibleBook::IChronicles, vec!["1. Paralipomenon".to_string()]),
-        (BibleBook::IIChronicles, vec!["2. Paralipomenon".to_string()]),
+        (
+            BibleBook::IIChronicles,
+            vec!["2. Paralipomenon".to_string()],
+        ),
         (BibleBook::Ezra, vec!["Ezdráš".to_string()]),
         (BibleBook::Nehemiah, vec!["Nehemjáš".to_string()]),
         (BibleBook::Esther, vec!["Ester".to_string()]),
@@ -1834,8 +3031,14 @@ fn get_czech_reference_language() -> ReferenceLanguage {
         (BibleBook::Ephesians, vec!["Efezským".to_string()]),
         (BibleBook::Philippians, vec!["Filipským".to_string()]),
         (BibleBook::Colossians, vec!["Koloským".to_string()]),
-        (BibleBook::IThessalonians, vec!["1. Tesalonickým".to_string()]),
-        (BibleBook::IIThessalonians, vec!["2. Tesalonickým".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["1. Tesalonickým".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["2. Tesalonickým".to_string()],
+        ),
         (BibleBook::ITimothy, vec!["1. Timoteovi".to_string()]),
         (BibleBook::IITimothy, vec!["2. Timoteovi".to_string()]),
         (BibleBook::Titus, vec!["Titovi".to_string()]),
@@ -1931,6 +3134,7 @@ fn get_czech_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "a".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -1987,8 +3191,14 @@ fn get_korean_reference_language() -> ReferenceLanguage {
         (BibleBook::Ephesians, vec!["에베소서".to_string()]),
         (BibleBook::Philippians, vec!["빌립보서".to_string()]),
         (BibleBook::Colossians, vec!["골로새서".to_string()]),
-        (BibleBook::IThessalonians, vec!["데살로니가전서".to_string()]),
-        (BibleBook::IIThessalonians, vec!["데살로니가후서".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["데살로니가전서".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["데살로니가후서".to_string()],
+        ),
         (BibleBook::ITimothy, vec!["디모데전서".to_string()]),
         (BibleBook::IITimothy, vec!["디모데후서".to_string()]),
         (BibleBook::Titus, vec!["디도서".to_string()]),
@@ -2080,10 +3290,11 @@ fn get_korean_reference_language() -> ReferenceLanguage {
         language_code: "ko".to_string(),
         long_names,
         short_names,
-        chapter_vers_delimiters: vec![":".to_string()],
+        chapter_vers_delimiters: vec![":".to_string(), "：".to_string()],
         space_separation: false,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "그리고".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -2110,7 +3321,10 @@ fn get_italian_reference_language() -> ReferenceLanguage {
         (BibleBook::Psalm, vec!["Salmi".to_string()]),
         (BibleBook::Proverbs, vec!["Proverbi".to_string()]),
         (BibleBook::Ecclesiastes, vec!["Ecclesiaste".to_string()]),
-        (BibleBook::SongofSolomon, vec!["Cantico dei Cantici".to_string()]),
+        (
+            BibleBook::SongofSolomon,
+            vec!["Cantico dei Cantici".to_string()],
+        ),
         (BibleBook::Isaiah, vec!["Isaia".to_string()]),
         (BibleBook::Jeremiah, vec!["Geremia".to_string()]),
         (BibleBook::Lamentations, vec!["Lamentazioni".to_string()]),
@@ -2140,8 +3354,14 @@ fn get_italian_reference_language() -> ReferenceLanguage {
         (BibleBook::Ephesians, vec!["Efesini".to_string()]),
         (BibleBook::Philippians, vec!["Filippesi".to_string()]),
         (BibleBook::Colossians, vec!["Colossesi".to_string()]),
-        (BibleBook::IThessalonians, vec!["1 Tessalonicesi".to_string()]),
-        (BibleBook::IIThessalonians, vec!["2 Tessalonicesi".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["1 Tessalonicesi".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["2 Tessalonicesi".to_string()],
+        ),
         (BibleBook::ITimothy, vec!["1 Timoteo".to_string()]),
         (BibleBook::IITimothy, vec!["2 Timoteo".to_string()]),
         (BibleBook::Titus, vec!["Tito".to_string()]),
@@ -2237,6 +3457,7 @@ fn get_italian_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "e".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -2293,8 +3514,14 @@ fn get_dutch_reference_language() -> ReferenceLanguage {
         (BibleBook::Ephesians, vec!["Efeziërs".to_string()]),
         (BibleBook::Philippians, vec!["Filippenzen".to_string()]),
         (BibleBook::Colossians, vec!["Kolossenzen".to_string()]),
-        (BibleBook::IThessalonians, vec!["1 Thessalonicenzen".to_string()]),
-        (BibleBook::IIThessalonians, vec!["2 Thessalonicenzen".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["1 Thessalonicenzen".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["2 Thessalonicenzen".to_string()],
+        ),
         (BibleBook::ITimothy, vec!["1 Timotheüs".to_string()]),
         (BibleBook::IITimothy, vec!["2 Timotheüs".to_string()]),
         (BibleBook::Titus, vec!["Titus".to_string()]),
@@ -2386,10 +3613,11 @@ fn get_dutch_reference_language() -> ReferenceLanguage {
         language_code: "nl".to_string(),
         long_names,
         short_names,
-        chapter_vers_delimiters: vec![":".to_string()],
+        chapter_vers_delimiters: vec![":".to_string(), ",".to_string()],
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "en".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -2399,7 +3627,10 @@ fn get_vietnamese_reference_language() -> ReferenceLanguage {
         (BibleBook::Exodus, vec!["Xuất Ê-díp-tô Ký".to_string()]),
         (BibleBook::Leviticus, vec!["Lê-vi Ký".to_string()]),
         (BibleBook::Numbers, vec!["Dân Số Ký".to_string()]),
-        (BibleBook::Deuteronomy, vec!["Phục Truyền Luật Lệ Ký".to_string()]),
+        (
+            BibleBook::Deuteronomy,
+            vec!["Phục Truyền Luật Lệ Ký".to_string()],
+        ),
         (BibleBook::Joshua, vec!["Giô-suê".to_string()]),
         (BibleBook::Judges, vec!["Các Quan Xét".to_string()]),
         (BibleBook::Ruth, vec!["Ru-tơ".to_string()]),
@@ -2446,8 +3677,14 @@ fn get_vietnamese_reference_language() -> ReferenceLanguage {
         (BibleBook::Ephesians, vec!["Ê-phê-sô".to_string()]),
         (BibleBook::Philippians, vec!["Phi-líp".to_string()]),
         (BibleBook::Colossians, vec!["Cô-lô-se".to_string()]),
-        (BibleBook::IThessalonians, vec!["1 Tê-sa-lô-ni-ca".to_string()]),
-        (BibleBook::IIThessalonians, vec!["2 Tê-sa-lô-ni-ca".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["1 Tê-sa-lô-ni-ca".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["2 Tê-sa-lô-ni-ca".to_string()],
+        ),
         (BibleBook::ITimothy, vec!["1 Ti-mô-thê".to_string()]),
         (BibleBook::IITimothy, vec!["2 Ti-mô-thê".to_string()]),
         (BibleBook::Titus, vec!["Tít".to_string()]),
@@ -2543,6 +3780,7 @@ fn get_vietnamese_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "và".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -2696,6 +3934,7 @@ fn get_indonesian_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "dan".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -2705,27 +3944,51 @@ fn get_hungarian_reference_language() -> ReferenceLanguage {
         (BibleBook::Exodus, vec!["Kivonulás könyve".to_string()]),
         (BibleBook::Leviticus, vec!["Leviták könyve".to_string()]),
         (BibleBook::Numbers, vec!["Számok könyve".to_string()]),
-        (BibleBook::Deuteronomy, vec!["Második Törvénykönyv".to_string()]),
+        (
+            BibleBook::Deuteronomy,
+            vec!["Második Törvénykönyv".to_string()],
+        ),
         (BibleBook::Joshua, vec!["Józsue könyve".to_string()]),
         (BibleBook::Judges, vec!["Bírák könyve".to_string()]),
         (BibleBook::Ruth, vec!["Rút könyve".to_string()]),
         (BibleBook::ISamuel, vec!["Sámuel első könyve".to_string()]),
-        (BibleBook::IISamuel, vec!["Sámuel második könyve".to_string()]),
+        (
+            BibleBook::IISamuel,
+            vec!["Sámuel második könyve".to_string()],
+        ),
         (BibleBook::IKings, vec!["Királyok első könyve".to_string()]),
-        (BibleBook::IIKings, vec!["Királyok második könyve".to_string()]),
-        (BibleBook::IChronicles, vec!["Krónikák első könyve".to_string()]),
-        (BibleBook::IIChronicles, vec!["Krónikák második könyve".to_string()]),
+        (
+            BibleBook::IIKings,
+            vec!["Királyok második könyve".to_string()],
+        ),
+        (
+            BibleBook::IChronicles,
+            vec!["Krónikák első könyve".to_string()],
+        ),
+        (
+            BibleBook::IIChronicles,
+            vec!["Krónikák második könyve".to_string()],
+        ),
         (BibleBook::Ezra, vec!["Ezdrás könyve".to_string()]),
         (BibleBook::Nehemiah, vec!["Nehemiás könyve".to_string()]),
         (BibleBook::Esther, vec!["Eszter könyve".to_string()]),
         (BibleBook::Job, vec!["Jób könyve".to_string()]),
         (BibleBook::Psalm, vec!["Zsoltárok könyve".to_string()]),
-        (BibleBook::Proverbs, vec!["Példabeszédek könyve".to_string()]),
-        (BibleBook::Ecclesiastes, vec!["Prédikátor könyve".to_string()]),
+        (
+            BibleBook::Proverbs,
+            vec!["Példabeszédek könyve".to_string()],
+        ),
+        (
+            BibleBook::Ecclesiastes,
+            vec!["Prédikátor könyve".to_string()],
+        ),
         (BibleBook::SongofSolomon, vec!["Énekek éneke".to_string()]),
         (BibleBook::Isaiah, vec!["Ézsaiás könyve".to_string()]),
         (BibleBook::Jeremiah, vec!["Jeremiás könyve".to_string()]),
-        (BibleBook::Lamentations, vec!["Jeremiás siralmai".to_string()]),
+        (
+            BibleBook::Lamentations,
+            vec!["Jeremiás siralmai".to_string()],
+        ),
         (BibleBook::Ezekiel, vec!["Ezékiel könyve".to_string()]),
         (BibleBook::Daniel, vec!["Dániel könyve".to_string()]),
         (BibleBook::Hosea, vec!["Hóseás könyve".to_string()]),
@@ -2746,25 +4009,61 @@ fn get_hungarian_reference_language() -> ReferenceLanguage {
         (BibleBook::John, vec!["János evangéliuma".to_string()]),
         (BibleBook::Acts, vec!["Apostolok cselekedetei".to_string()]),
         (BibleBook::Romans, vec!["Rómaiakhoz írt levél".to_string()]),
-        (BibleBook::ICorinthians, vec!["Korintusiakhoz írt első levél".to_string()]),
-        (BibleBook::IICorinthians, vec!["Korintusiakhoz írt második levél".to_string()]),
-        (BibleBook::Galatians, vec!["Galatákhoz írt levél".to_string()]),
-        (BibleBook::Ephesians, vec!["Efezusiakhoz írt levél".to_string()]),
-        (BibleBook::Philippians, vec!["Filippiekhez írt levél".to_string()]),
-        (BibleBook::Colossians, vec!["Kolosséiakhoz írt levél".to_string()]),
-        (BibleBook::IThessalonians, vec!["Thesszalonikaiakhoz írt első levél".to_string()]),
-        (BibleBook::IIThessalonians, vec!["Thesszalonikaiakhoz írt második levél".to_string()]),
-        (BibleBook::ITimothy, vec!["Timóteushoz írt első levél".to_string()]),
-        (BibleBook::IITimothy, vec!["Timóteushoz írt második levél".to_string()]),
+        (
+            BibleBook::ICorinthians,
+            vec!["Korintusiakhoz írt első levél".to_string()],
+        ),
+        (
+            BibleBook::IICorinthians,
+            vec!["Korintusiakhoz írt második levél".to_string()],
+        ),
+        (
+            BibleBook::Galatians,
+            vec!["Galatákhoz írt levél".to_string()],
+        ),
+        (
+            BibleBook::Ephesians,
+            vec!["Efezusiakhoz írt levél".to_string()],
+        ),
+        (
+            BibleBook::Philippians,
+            vec!["Filippiekhez írt levél".to_string()],
+        ),
+        (
+            BibleBook::Colossians,
+            vec!["Kolosséiakhoz írt levél".to_string()],
+        ),
+        (
+            BibleBook::IThessalonians,
+            vec!["Thesszalonikaiakhoz írt első levél".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["Thesszalonikaiakhoz írt második levél".to_string()],
+        ),
+        (
+            BibleBook::ITimothy,
+            vec!["Timóteushoz írt első levél".to_string()],
+        ),
+        (
+            BibleBook::IITimothy,
+            vec!["Timóteushoz írt második levél".to_string()],
+        ),
         (BibleBook::Titus, vec!["Tituszhoz írt levél".to_string()]),
-        (BibleBook::Philemon, vec!["Filemonhoz írt levél".to_string()]),
+        (
+            BibleBook::Philemon,
+            vec!["Filemonhoz írt levél".to_string()],
+        ),
         (BibleBook::Hebrews, vec!["Zsidókhoz írt levél".to_string()]),
         (BibleBook::James, vec!["Jakab levele".to_string()]),
         (BibleBook::IPeter, vec!["Péter első levele".to_string()]),
         (BibleBook::IIPeter, vec!["Péter második levele".to_string()]),
         (BibleBook::IJohn, vec!["János első levele".to_string()]),
         (BibleBook::IIJohn, vec!["János második levele".to_string()]),
-        (BibleBook::IIIJohn, vec!["János harmadik levele".to_string()]),
+        (
+            BibleBook::IIIJohn,
+            vec!["János harmadik levele".to_string()],
+        ),
         (BibleBook::Jude, vec!["Júdás levele".to_string()]),
         (BibleBook::Revelation, vec!["Jelenések könyve".to_string()]),
     ];
@@ -2849,6 +4148,7 @@ fn get_hungarian_reference_language() -> ReferenceLanguage {
         space_separation: true,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "és".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
@@ -2899,16 +4199,37 @@ fn get_japanese_reference_language() -> ReferenceLanguage {
         (BibleBook::John, vec!["ヨハネによる福音書".to_string()]),
         (BibleBook::Acts, vec!["使徒行伝".to_string()]),
         (BibleBook::Romans, vec!["ローマ人への手紙".to_string()]),
-        (BibleBook::ICorinthians, vec!["コリント人への第一の手紙".to_string()]),
-        (BibleBook::IICorinthians, vec!["コリント人への第二の手紙".to_string()]),
+        (
+            BibleBook::ICorinthians,
+            vec!["コリント人への第一の手紙".to_string()],
+        ),
+        (
+            BibleBook::IICorinthians,
+            vec!["コリント人への第二の手紙".to_string()],
+        ),
         (BibleBook::Galatians, vec!["ガラテヤ人への手紙".to_string()]),
         (BibleBook::Ephesians, vec!["エペソ人への手紙".to_string()]),
         (BibleBook::Philippians, vec!["ピリピ人への手紙".to_string()]),
-        (BibleBook::Colossians, vec!["コロサイ人への手紙".to_string()]),
-        (BibleBook::IThessalonians, vec!["テサロニケ人への第一の手紙".to_string()]),
-        (BibleBook::IIThessalonians, vec!["テサロニケ人への第二の手紙".to_string()]),
-        (BibleBook::ITimothy, vec!["テモテへの第一の手紙".to_string()]),
-        (BibleBook::IITimothy, vec!["テモテへの第二の手紙".to_string()]),
+        (
+            BibleBook::Colossians,
+            vec!["コロサイ人への手紙".to_string()],
+        ),
+        (
+            BibleBook::IThessalonians,
+            vec!["テサロニケ人への第一の手紙".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["テサロニケ人への第二の手紙".to_string()],
+        ),
+        (
+            BibleBook::ITimothy,
+            vec!["テモテへの第一の手紙".to_string()],
+        ),
+        (
+            BibleBook::IITimothy,
+            vec!["テモテへの第二の手紙".to_string()],
+        ),
         (BibleBook::Titus, vec!["テトスへの手紙".to_string()]),
         (BibleBook::Philemon, vec!["ピレモンへの手紙".to_string()]),
         (BibleBook::Hebrews, vec!["ヘブル人への手紙".to_string()]),
@@ -3002,28 +4323,867 @@ fn get_japanese_reference_language() -> ReferenceLanguage {
         space_separation: false,
         range_delimiter: "-".to_string(),
         multiple_representations_delimiters: vec![";".to_string(), "と".to_string()],
+        directionality: TextDirection::Ltr,
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::bible::{
-        BibleChapterRange, BibleChapterReference, BibleVerseRange, BibleVerseReference,
-    };
-
-    use super::*;
-
-    #[test]
-    fn test_references_to_human_language() {
-        // Test John 3:16 in multiple languages
-        let reference1: BibleReference =
-            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
-        assert_eq!(
-            get_reference_in_language(&reference1, "en", BookReferenceType::Long).unwrap(),
-            "John 3:16".to_string(),
-        );
-        assert_eq!(
-            get_reference_in_language(&reference1, "de", BookReferenceType::Long).unwrap(),
+fn get_latin_reference_language() -> ReferenceLanguage {
+    let long_names_vec = vec![
+        (BibleBook::Genesis, vec!["Genesis".to_string()]),
+        (BibleBook::Exodus, vec!["Exodus".to_string()]),
+        (BibleBook::Leviticus, vec!["Leviticus".to_string()]),
+        (BibleBook::Numbers, vec!["Numeri".to_string()]),
+        (BibleBook::Deuteronomy, vec!["Deuteronomium".to_string()]),
+        (BibleBook::Joshua, vec!["Iosue".to_string()]),
+        (BibleBook::Judges, vec!["Iudicum".to_string()]),
+        (BibleBook::Ruth, vec!["Ruth".to_string()]),
+        (BibleBook::ISamuel, vec!["1 Samuelis".to_string()]),
+        (BibleBook::IISamuel, vec!["2 Samuelis".to_string()]),
+        (BibleBook::IKings, vec!["1 Regum".to_string()]),
+        (BibleBook::IIKings, vec!["2 Regum".to_string()]),
+        (BibleBook::IChronicles, vec!["1 Paralipomenon".to_string()]),
+        (BibleBook::IIChronicles, vec!["2 Paralipomenon".to_string()]),
+        (BibleBook::Ezra, vec!["Esdrae".to_string()]),
+        (BibleBook::Nehemiah, vec!["Nehemiae".to_string()]),
+        (BibleBook::Esther, vec!["Esther".to_string()]),
+        (BibleBook::Job, vec!["Iob".to_string()]),
+        (BibleBook::Psalm, vec!["Psalmi".to_string()]),
+        (BibleBook::Proverbs, vec!["Proverbia".to_string()]),
+        (BibleBook::Ecclesiastes, vec!["Ecclesiastes".to_string()]),
+        (
+            BibleBook::SongofSolomon,
+            vec!["Canticum Canticorum".to_string()],
+        ),
+        (BibleBook::Isaiah, vec!["Isaias".to_string()]),
+        (BibleBook::Jeremiah, vec!["Ieremias".to_string()]),
+        (BibleBook::Lamentations, vec!["Lamentationes".to_string()]),
+        (BibleBook::Ezekiel, vec!["Ezechiel".to_string()]),
+        (BibleBook::Daniel, vec!["Daniel".to_string()]),
+        (BibleBook::Hosea, vec!["Osee".to_string()]),
+        (BibleBook::Joel, vec!["Ioel".to_string()]),
+        (BibleBook::Amos, vec!["Amos".to_string()]),
+        (BibleBook::Obadiah, vec!["Abdias".to_string()]),
+        (BibleBook::Jonah, vec!["Ionas".to_string()]),
+        (BibleBook::Micah, vec!["Michaeas".to_string()]),
+        (BibleBook::Nahum, vec!["Nahum".to_string()]),
+        (BibleBook::Habakkuk, vec!["Habacuc".to_string()]),
+        (BibleBook::Zephaniah, vec!["Sophonias".to_string()]),
+        (BibleBook::Haggai, vec!["Aggaeus".to_string()]),
+        (BibleBook::Zechariah, vec!["Zacharias".to_string()]),
+        (BibleBook::Malachi, vec!["Malachias".to_string()]),
+        (BibleBook::Matthew, vec!["Matthaeus".to_string()]),
+        (BibleBook::Mark, vec!["Marcus".to_string()]),
+        (BibleBook::Luke, vec!["Lucas".to_string()]),
+        (BibleBook::John, vec!["Ioannes".to_string()]),
+        (BibleBook::Acts, vec!["Actus Apostolorum".to_string()]),
+        (BibleBook::Romans, vec!["Ad Romanos".to_string()]),
+        (BibleBook::ICorinthians, vec!["1 ad Corinthios".to_string()]),
+        (
+            BibleBook::IICorinthians,
+            vec!["2 ad Corinthios".to_string()],
+        ),
+        (BibleBook::Galatians, vec!["Ad Galatas".to_string()]),
+        (BibleBook::Ephesians, vec!["Ad Ephesios".to_string()]),
+        (BibleBook::Philippians, vec!["Ad Philippenses".to_string()]),
+        (BibleBook::Colossians, vec!["Ad Colossenses".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["1 ad Thessalonicenses".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["2 ad Thessalonicenses".to_string()],
+        ),
+        (BibleBook::ITimothy, vec!["1 ad Timotheum".to_string()]),
+        (BibleBook::IITimothy, vec!["2 ad Timotheum".to_string()]),
+        (BibleBook::Titus, vec!["Ad Titum".to_string()]),
+        (BibleBook::Philemon, vec!["Ad Philemonem".to_string()]),
+        (BibleBook::Hebrews, vec!["Ad Hebraeos".to_string()]),
+        (BibleBook::James, vec!["Iacobi".to_string()]),
+        (BibleBook::IPeter, vec!["1 Petri".to_string()]),
+        (BibleBook::IIPeter, vec!["2 Petri".to_string()]),
+        (BibleBook::IJohn, vec!["1 Ioannis".to_string()]),
+        (BibleBook::IIJohn, vec!["2 Ioannis".to_string()]),
+        (BibleBook::IIIJohn, vec!["3 Ioannis".to_string()]),
+        (BibleBook::Jude, vec!["Iudae".to_string()]),
+        (BibleBook::Revelation, vec!["Apocalypsis".to_string()]),
+    ];
+    #[cfg(feature = "deuterocanon")]
+    let long_names_vec = [
+        long_names_vec,
+        vec![
+            (BibleBook::Tobit, vec!["Tobias".to_string()]),
+            (BibleBook::Judith, vec!["Iudith".to_string()]),
+            (
+                BibleBook::AdditionsToEsther,
+                vec!["Additamenta Esther".to_string()],
+            ),
+            (BibleBook::Wisdom, vec!["Sapientia".to_string()]),
+            (
+                BibleBook::Sirach,
+                vec!["Siracides".to_string(), "Ecclesiasticus".to_string()],
+            ),
+            (BibleBook::Baruch, vec!["Baruch".to_string()]),
+            (
+                BibleBook::PrayerOfAzariah,
+                vec!["Oratio Azariae".to_string()],
+            ),
+            (BibleBook::Susanna, vec!["Susanna".to_string()]),
+            (BibleBook::BelAndTheDragon, vec!["Bel et Draco".to_string()]),
+            (BibleBook::IMaccabees, vec!["1 Machabaeorum".to_string()]),
+            (BibleBook::IIMaccabees, vec!["2 Machabaeorum".to_string()]),
+        ],
+    ]
+    .concat();
+    let long_names: HashMap<BibleBook, Vec<String>> = long_names_vec.into_iter().collect();
+
+    let short_names_vec = vec![
+        (BibleBook::Genesis, vec!["Gn".to_string()]),
+        (BibleBook::Exodus, vec!["Ex".to_string()]),
+        (BibleBook::Leviticus, vec!["Lv".to_string()]),
+        (BibleBook::Numbers, vec!["Nm".to_string()]),
+        (BibleBook::Deuteronomy, vec!["Dt".to_string()]),
+        (BibleBook::Joshua, vec!["Ios".to_string()]),
+        (BibleBook::Judges, vec!["Idc".to_string()]),
+        (BibleBook::Ruth, vec!["Rt".to_string()]),
+        (BibleBook::ISamuel, vec!["1 Sm".to_string()]),
+        (BibleBook::IISamuel, vec!["2 Sm".to_string()]),
+        (BibleBook::IKings, vec!["1 Rg".to_string()]),
+        (BibleBook::IIKings, vec!["2 Rg".to_string()]),
+        (BibleBook::IChronicles, vec!["1 Par".to_string()]),
+        (BibleBook::IIChronicles, vec!["2 Par".to_string()]),
+        (BibleBook::Ezra, vec!["Esd".to_string()]),
+        (BibleBook::Nehemiah, vec!["Neh".to_string()]),
+        (BibleBook::Esther, vec!["Est".to_string()]),
+        (BibleBook::Job, vec!["Iob".to_string()]),
+        (BibleBook::Psalm, vec!["Ps".to_string()]),
+        (BibleBook::Proverbs, vec!["Prv".to_string()]),
+        (BibleBook::Ecclesiastes, vec!["Eccle".to_string()]),
+        (BibleBook::SongofSolomon, vec!["Ct".to_string()]),
+        (BibleBook::Isaiah, vec!["Is".to_string()]),
+        (BibleBook::Jeremiah, vec!["Ier".to_string()]),
+        (BibleBook::Lamentations, vec!["Lam".to_string()]),
+        (BibleBook::Ezekiel, vec!["Ez".to_string()]),
+        (BibleBook::Daniel, vec!["Dn".to_string()]),
+        (BibleBook::Hosea, vec!["Os".to_string()]),
+        (BibleBook::Joel, vec!["Ioel".to_string()]),
+        (BibleBook::Amos, vec!["Am".to_string()]),
+        (BibleBook::Obadiah, vec!["Abd".to_string()]),
+        (BibleBook::Jonah, vec!["Ion".to_string()]),
+        (BibleBook::Micah, vec!["Mi".to_string()]),
+        (BibleBook::Nahum, vec!["Na".to_string()]),
+        (BibleBook::Habakkuk, vec!["Hab".to_string()]),
+        (BibleBook::Zephaniah, vec!["So".to_string()]),
+        (BibleBook::Haggai, vec!["Agg".to_string()]),
+        (BibleBook::Zechariah, vec!["Za".to_string()]),
+        (BibleBook::Malachi, vec!["Mal".to_string()]),
+        (BibleBook::Matthew, vec!["Mt".to_string()]),
+        (BibleBook::Mark, vec!["Mc".to_string()]),
+        (BibleBook::Luke, vec!["Lc".to_string()]),
+        (BibleBook::John, vec!["Io".to_string()]),
+        (BibleBook::Acts, vec!["Act".to_string()]),
+        (BibleBook::Romans, vec!["Rom".to_string()]),
+        (BibleBook::ICorinthians, vec!["1 Cor".to_string()]),
+        (BibleBook::IICorinthians, vec!["2 Cor".to_string()]),
+        (BibleBook::Galatians, vec!["Gal".to_string()]),
+        (BibleBook::Ephesians, vec!["Eph".to_string()]),
+        (BibleBook::Philippians, vec!["Phil".to_string()]),
+        (BibleBook::Colossians, vec!["Col".to_string()]),
+        (BibleBook::IThessalonians, vec!["1 Thess".to_string()]),
+        (BibleBook::IIThessalonians, vec!["2 Thess".to_string()]),
+        (BibleBook::ITimothy, vec!["1 Tim".to_string()]),
+        (BibleBook::IITimothy, vec!["2 Tim".to_string()]),
+        (BibleBook::Titus, vec!["Tit".to_string()]),
+        (BibleBook::Philemon, vec!["Phlm".to_string()]),
+        (BibleBook::Hebrews, vec!["Hebr".to_string()]),
+        (BibleBook::James, vec!["Iac".to_string()]),
+        (BibleBook::IPeter, vec!["1 Pt".to_string()]),
+        (BibleBook::IIPeter, vec!["2 Pt".to_string()]),
+        (BibleBook::IJohn, vec!["1 Io".to_string()]),
+        (BibleBook::IIJohn, vec!["2 Io".to_string()]),
+        (BibleBook::IIIJohn, vec!["3 Io".to_string()]),
+        (BibleBook::Jude, vec!["Iud".to_string()]),
+        (BibleBook::Revelation, vec!["Ap".to_string()]),
+    ];
+    #[cfg(feature = "deuterocanon")]
+    let short_names_vec = [
+        short_names_vec,
+        vec![
+            (BibleBook::Tobit, vec!["Tb".to_string()]),
+            (BibleBook::Judith, vec!["Idt".to_string()]),
+            (BibleBook::AdditionsToEsther, vec!["Add Est".to_string()]),
+            (BibleBook::Wisdom, vec!["Sap".to_string()]),
+            (BibleBook::Sirach, vec!["Sir".to_string()]),
+            (BibleBook::Baruch, vec!["Bar".to_string()]),
+            (BibleBook::PrayerOfAzariah, vec!["Or Az".to_string()]),
+            (BibleBook::Susanna, vec!["Sus".to_string()]),
+            (BibleBook::BelAndTheDragon, vec!["Bel".to_string()]),
+            (BibleBook::IMaccabees, vec!["1 Mac".to_string()]),
+            (BibleBook::IIMaccabees, vec!["2 Mac".to_string()]),
+        ],
+    ]
+    .concat();
+    let short_names: HashMap<BibleBook, Vec<String>> = short_names_vec.into_iter().collect();
+
+    ReferenceLanguage {
+        long_language_name: "Latin".to_string(),
+        language_code: "la".to_string(),
+        long_names,
+        short_names,
+        chapter_vers_delimiters: vec![",".to_string(), ":".to_string()],
+        space_separation: true,
+        range_delimiter: "-".to_string(),
+        multiple_representations_delimiters: vec![";".to_string()],
+        directionality: TextDirection::Ltr,
+    }
+}
+
+fn get_greek_reference_language() -> ReferenceLanguage {
+    let long_names_vec = vec![
+        (BibleBook::Genesis, vec!["Γένεσις".to_string()]),
+        (BibleBook::Exodus, vec!["Έξοδος".to_string()]),
+        (BibleBook::Leviticus, vec!["Λευιτικόν".to_string()]),
+        (BibleBook::Numbers, vec!["Αριθμοί".to_string()]),
+        (BibleBook::Deuteronomy, vec!["Δευτερονόμιον".to_string()]),
+        (BibleBook::Joshua, vec!["Ιησούς του Ναυή".to_string()]),
+        (BibleBook::Judges, vec!["Κριταί".to_string()]),
+        (BibleBook::Ruth, vec!["Ρουθ".to_string()]),
+        (
+            BibleBook::ISamuel,
+            vec!["Α΄ Σαμουήλ".to_string(), "1 Σαμουήλ".to_string()],
+        ),
+        (
+            BibleBook::IISamuel,
+            vec!["Β΄ Σαμουήλ".to_string(), "2 Σαμουήλ".to_string()],
+        ),
+        (
+            BibleBook::IKings,
+            vec!["Α΄ Βασιλέων".to_string(), "1 Βασιλέων".to_string()],
+        ),
+        (
+            BibleBook::IIKings,
+            vec!["Β΄ Βασιλέων".to_string(), "2 Βασιλέων".to_string()],
+        ),
+        (
+            BibleBook::IChronicles,
+            vec![
+                "Α΄ Παραλειπομένων".to_string(),
+                "1 Παραλειπομένων".to_string(),
+            ],
+        ),
+        (
+            BibleBook::IIChronicles,
+            vec![
+                "Β΄ Παραλειπομένων".to_string(),
+                "2 Παραλειπομένων".to_string(),
+            ],
+        ),
+        (BibleBook::Ezra, vec!["Έσδρας".to_string()]),
+        (BibleBook::Nehemiah, vec!["Νεεμίας".to_string()]),
+        (BibleBook::Esther, vec!["Εσθήρ".to_string()]),
+        (BibleBook::Job, vec!["Ιώβ".to_string()]),
+        (BibleBook::Psalm, vec!["Ψαλμοί".to_string()]),
+        (BibleBook::Proverbs, vec!["Παροιμίαι".to_string()]),
+        (BibleBook::Ecclesiastes, vec!["Εκκλησιαστής".to_string()]),
+        (BibleBook::SongofSolomon, vec!["Άσμα Ασμάτων".to_string()]),
+        (BibleBook::Isaiah, vec!["Ησαΐας".to_string()]),
+        (BibleBook::Jeremiah, vec!["Ιερεμίας".to_string()]),
+        (BibleBook::Lamentations, vec!["Θρήνοι".to_string()]),
+        (BibleBook::Ezekiel, vec!["Ιεζεκιήλ".to_string()]),
+        (BibleBook::Daniel, vec!["Δανιήλ".to_string()]),
+        (BibleBook::Hosea, vec!["Ωσηέ".to_string()]),
+        (BibleBook::Joel, vec!["Ιωήλ".to_string()]),
+        (BibleBook::Amos, vec!["Αμώς".to_string()]),
+        (BibleBook::Obadiah, vec!["Αβδιού".to_string()]),
+        (BibleBook::Jonah, vec!["Ιωνάς".to_string()]),
+        (BibleBook::Micah, vec!["Μιχαίας".to_string()]),
+        (BibleBook::Nahum, vec!["Ναούμ".to_string()]),
+        (BibleBook::Habakkuk, vec!["Αββακούμ".to_string()]),
+        (BibleBook::Zephaniah, vec!["Σοφονίας".to_string()]),
+        (BibleBook::Haggai, vec!["Αγγαίος".to_string()]),
+        (BibleBook::Zechariah, vec!["Ζαχαρίας".to_string()]),
+        (BibleBook::Malachi, vec!["Μαλαχίας".to_string()]),
+        (BibleBook::Matthew, vec!["Κατά Ματθαίον".to_string()]),
+        (BibleBook::Mark, vec!["Κατά Μάρκον".to_string()]),
+        (BibleBook::Luke, vec!["Κατά Λουκάν".to_string()]),
+        (BibleBook::John, vec!["Κατά Ιωάννην".to_string()]),
+        (
+            BibleBook::Acts,
+            vec!["Πράξεις των Αποστόλων".to_string(), "Πράξεις".to_string()],
+        ),
+        (BibleBook::Romans, vec!["Προς Ρωμαίους".to_string()]),
+        (
+            BibleBook::ICorinthians,
+            vec![
+                "Α΄ Προς Κορινθίους".to_string(),
+                "1 Προς Κορινθίους".to_string(),
+            ],
+        ),
+        (
+            BibleBook::IICorinthians,
+            vec![
+                "Β΄ Προς Κορινθίους".to_string(),
+                "2 Προς Κορινθίους".to_string(),
+            ],
+        ),
+        (BibleBook::Galatians, vec!["Προς Γαλάτας".to_string()]),
+        (BibleBook::Ephesians, vec!["Προς Εφεσίους".to_string()]),
+        (
+            BibleBook::Philippians,
+            vec!["Προς Φιλιππησίους".to_string()],
+        ),
+        (BibleBook::Colossians, vec!["Προς Κολοσσαείς".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec![
+                "Α΄ Προς Θεσσαλονικείς".to_string(),
+                "1 Προς Θεσσαλονικείς".to_string(),
+            ],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec![
+                "Β΄ Προς Θεσσαλονικείς".to_string(),
+                "2 Προς Θεσσαλονικείς".to_string(),
+            ],
+        ),
+        (
+            BibleBook::ITimothy,
+            vec![
+                "Α΄ Προς Τιμόθεον".to_string(),
+                "1 Προς Τιμόθεον".to_string(),
+            ],
+        ),
+        (
+            BibleBook::IITimothy,
+            vec![
+                "Β΄ Προς Τιμόθεον".to_string(),
+                "2 Προς Τιμόθεον".to_string(),
+            ],
+        ),
+        (BibleBook::Titus, vec!["Προς Τίτον".to_string()]),
+        (BibleBook::Philemon, vec!["Προς Φιλήμονα".to_string()]),
+        (BibleBook::Hebrews, vec!["Προς Εβραίους".to_string()]),
+        (BibleBook::James, vec!["Ιακώβου".to_string()]),
+        (
+            BibleBook::IPeter,
+            vec!["Α΄ Πέτρου".to_string(), "1 Πέτρου".to_string()],
+        ),
+        (
+            BibleBook::IIPeter,
+            vec!["Β΄ Πέτρου".to_string(), "2 Πέτρου".to_string()],
+        ),
+        (
+            BibleBook::IJohn,
+            vec!["Α΄ Ιωάννου".to_string(), "1 Ιωάννου".to_string()],
+        ),
+        (
+            BibleBook::IIJohn,
+            vec!["Β΄ Ιωάννου".to_string(), "2 Ιωάννου".to_string()],
+        ),
+        (
+            BibleBook::IIIJohn,
+            vec!["Γ΄ Ιωάννου".to_string(), "3 Ιωάννου".to_string()],
+        ),
+        (BibleBook::Jude, vec!["Ιούδα".to_string()]),
+        (
+            BibleBook::Revelation,
+            vec!["Αποκάλυψις".to_string(), "Αποκάλυψη".to_string()],
+        ),
+    ];
+    #[cfg(feature = "deuterocanon")]
+    let long_names_vec = [
+        long_names_vec,
+        vec![
+            (BibleBook::Tobit, vec!["Τωβίτ".to_string()]),
+            (BibleBook::Judith, vec!["Ιουδίθ".to_string()]),
+            (
+                BibleBook::AdditionsToEsther,
+                vec!["Προσθήκες στην Εσθήρ".to_string()],
+            ),
+            (BibleBook::Wisdom, vec!["Σοφία Σολομώντος".to_string()]),
+            (BibleBook::Sirach, vec!["Σοφία Σειράχ".to_string()]),
+            (BibleBook::Baruch, vec!["Βαρούχ".to_string()]),
+            (
+                BibleBook::PrayerOfAzariah,
+                vec!["Προσευχή του Αζαρίου".to_string()],
+            ),
+            (BibleBook::Susanna, vec!["Σωσάννα".to_string()]),
+            (
+                BibleBook::BelAndTheDragon,
+                vec!["Βηλ και Δράκων".to_string()],
+            ),
+            (
+                BibleBook::IMaccabees,
+                vec!["Α΄ Μακκαβαίων".to_string(), "1 Μακκαβαίων".to_string()],
+            ),
+            (
+                BibleBook::IIMaccabees,
+                vec!["Β΄ Μακκαβαίων".to_string(), "2 Μακκαβαίων".to_string()],
+            ),
+        ],
+    ]
+    .concat();
+    let long_names: HashMap<BibleBook, Vec<String>> = long_names_vec.into_iter().collect();
+
+    let short_names_vec = vec![
+        (BibleBook::Genesis, vec!["Γεν".to_string()]),
+        (BibleBook::Exodus, vec!["Εξ".to_string()]),
+        (BibleBook::Leviticus, vec!["Λευ".to_string()]),
+        (BibleBook::Numbers, vec!["Αρ".to_string()]),
+        (BibleBook::Deuteronomy, vec!["Δευτ".to_string()]),
+        (BibleBook::Joshua, vec!["Ιησ".to_string()]),
+        (BibleBook::Judges, vec!["Κρ".to_string()]),
+        (BibleBook::Ruth, vec!["Ρουθ".to_string()]),
+        (
+            BibleBook::ISamuel,
+            vec!["Α΄ Σαμ".to_string(), "1 Σαμ".to_string()],
+        ),
+        (
+            BibleBook::IISamuel,
+            vec!["Β΄ Σαμ".to_string(), "2 Σαμ".to_string()],
+        ),
+        (
+            BibleBook::IKings,
+            vec!["Α΄ Βασ".to_string(), "1 Βασ".to_string()],
+        ),
+        (
+            BibleBook::IIKings,
+            vec!["Β΄ Βασ".to_string(), "2 Βασ".to_string()],
+        ),
+        (
+            BibleBook::IChronicles,
+            vec!["Α΄ Παρ".to_string(), "1 Παρ".to_string()],
+        ),
+        (
+            BibleBook::IIChronicles,
+            vec!["Β΄ Παρ".to_string(), "2 Παρ".to_string()],
+        ),
+        (BibleBook::Ezra, vec!["Εσδ".to_string()]),
+        (BibleBook::Nehemiah, vec!["Νεε".to_string()]),
+        (BibleBook::Esther, vec!["Εσθ".to_string()]),
+        (BibleBook::Job, vec!["Ιώβ".to_string()]),
+        (BibleBook::Psalm, vec!["Ψαλ".to_string()]),
+        (BibleBook::Proverbs, vec!["Παροιμ".to_string()]),
+        (BibleBook::Ecclesiastes, vec!["Εκκλ".to_string()]),
+        (BibleBook::SongofSolomon, vec!["Ασμ".to_string()]),
+        (BibleBook::Isaiah, vec!["Ησ".to_string()]),
+        (BibleBook::Jeremiah, vec!["Ιερ".to_string()]),
+        (BibleBook::Lamentations, vec!["Θρ".to_string()]),
+        (BibleBook::Ezekiel, vec!["Ιεζ".to_string()]),
+        (BibleBook::Daniel, vec!["Δαν".to_string()]),
+        (BibleBook::Hosea, vec!["Ωσ".to_string()]),
+        (BibleBook::Joel, vec!["Ιωήλ".to_string()]),
+        (BibleBook::Amos, vec!["Αμ".to_string()]),
+        (BibleBook::Obadiah, vec!["Αβδ".to_string()]),
+        (BibleBook::Jonah, vec!["Ιων".to_string()]),
+        (BibleBook::Micah, vec!["Μιχ".to_string()]),
+        (BibleBook::Nahum, vec!["Ναούμ".to_string()]),
+        (BibleBook::Habakkuk, vec!["Αββ".to_string()]),
+        (BibleBook::Zephaniah, vec!["Σοφ".to_string()]),
+        (BibleBook::Haggai, vec!["Αγγ".to_string()]),
+        (BibleBook::Zechariah, vec!["Ζαχ".to_string()]),
+        (BibleBook::Malachi, vec!["Μαλ".to_string()]),
+        (BibleBook::Matthew, vec!["Μτ".to_string()]),
+        (BibleBook::Mark, vec!["Μκ".to_string()]),
+        (BibleBook::Luke, vec!["Λκ".to_string()]),
+        (BibleBook::John, vec!["Ιω".to_string()]),
+        (BibleBook::Acts, vec!["Πρξ".to_string()]),
+        (BibleBook::Romans, vec!["Ρωμ".to_string()]),
+        (
+            BibleBook::ICorinthians,
+            vec!["Α΄ Κορ".to_string(), "1 Κορ".to_string()],
+        ),
+        (
+            BibleBook::IICorinthians,
+            vec!["Β΄ Κορ".to_string(), "2 Κορ".to_string()],
+        ),
+        (BibleBook::Galatians, vec!["Γαλ".to_string()]),
+        (BibleBook::Ephesians, vec!["Εφ".to_string()]),
+        (BibleBook::Philippians, vec!["Φιλ".to_string()]),
+        (BibleBook::Colossians, vec!["Κολ".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["Α΄ Θεσ".to_string(), "1 Θεσ".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["Β΄ Θεσ".to_string(), "2 Θεσ".to_string()],
+        ),
+        (
+            BibleBook::ITimothy,
+            vec!["Α΄ Τιμ".to_string(), "1 Τιμ".to_string()],
+        ),
+        (
+            BibleBook::IITimothy,
+            vec!["Β΄ Τιμ".to_string(), "2 Τιμ".to_string()],
+        ),
+        (BibleBook::Titus, vec!["Τιτ".to_string()]),
+        (BibleBook::Philemon, vec!["Φλμ".to_string()]),
+        (BibleBook::Hebrews, vec!["Εβρ".to_string()]),
+        (BibleBook::James, vec!["Ιακ".to_string()]),
+        (
+            BibleBook::IPeter,
+            vec!["Α΄ Πέτ".to_string(), "1 Πέτ".to_string()],
+        ),
+        (
+            BibleBook::IIPeter,
+            vec!["Β΄ Πέτ".to_string(), "2 Πέτ".to_string()],
+        ),
+        (
+            BibleBook::IJohn,
+            vec!["Α΄ Ιω".to_string(), "1 Ιω".to_string()],
+        ),
+        (
+            BibleBook::IIJohn,
+            vec!["Β΄ Ιω".to_string(), "2 Ιω".to_string()],
+        ),
+        (
+            BibleBook::IIIJohn,
+            vec!["Γ΄ Ιω".to_string(), "3 Ιω".to_string()],
+        ),
+        (BibleBook::Jude, vec!["Ιούδ".to_string()]),
+        (BibleBook::Revelation, vec!["Αποκ".to_string()]),
+    ];
+    #[cfg(feature = "deuterocanon")]
+    let short_names_vec = [
+        short_names_vec,
+        vec![
+            (BibleBook::Tobit, vec!["Τωβ".to_string()]),
+            (BibleBook::Judith, vec!["Ιδθ".to_string()]),
+            (BibleBook::AdditionsToEsther, vec!["Προσθ Εσθ".to_string()]),
+            (BibleBook::Wisdom, vec!["Σοφ Σολ".to_string()]),
+            (BibleBook::Sirach, vec!["Σειρ".to_string()]),
+            (BibleBook::Baruch, vec!["Βαρ".to_string()]),
+            (BibleBook::PrayerOfAzariah, vec!["Προσ Αζ".to_string()]),
+            (BibleBook::Susanna, vec!["Σωσ".to_string()]),
+            (BibleBook::BelAndTheDragon, vec!["Βηλ".to_string()]),
+            (
+                BibleBook::IMaccabees,
+                vec!["Α΄ Μακκ".to_string(), "1 Μακκ".to_string()],
+            ),
+            (
+                BibleBook::IIMaccabees,
+                vec!["Β΄ Μακκ".to_string(), "2 Μακκ".to_string()],
+            ),
+        ],
+    ]
+    .concat();
+    let short_names: HashMap<BibleBook, Vec<String>> = short_names_vec.into_iter().collect();
+
+    ReferenceLanguage {
+        long_language_name: "Greek".to_string(),
+        language_code: "el".to_string(),
+        long_names,
+        short_names,
+        chapter_vers_delimiters: vec![":".to_string(), ",".to_string()],
+        space_separation: true,
+        range_delimiter: "-".to_string(),
+        multiple_representations_delimiters: vec![";".to_string()],
+        directionality: TextDirection::Ltr,
+    }
+}
+
+/// Returns the Arabic [ReferenceLanguage] with the book names of the Van Dyck translation.
+/// Arabic is written from right to left, see [ReferenceLanguage::directionality]. The generated references are nevertheless stored in logical order (book name first, then chapter and verse),
+/// so the bidirectional algorithm of the rendering layer displays them correctly as long as the numbers are kept in Western Arabic digits.
+fn get_arabic_reference_language() -> ReferenceLanguage {
+    let long_names_vec = vec![
+        (
+            BibleBook::Genesis,
+            vec!["تكوين".to_string(), "التكوين".to_string()],
+        ),
+        (
+            BibleBook::Exodus,
+            vec!["خروج".to_string(), "الخروج".to_string()],
+        ),
+        (
+            BibleBook::Leviticus,
+            vec!["لاويين".to_string(), "اللاويين".to_string()],
+        ),
+        (
+            BibleBook::Numbers,
+            vec!["عدد".to_string(), "العدد".to_string()],
+        ),
+        (
+            BibleBook::Deuteronomy,
+            vec!["تثنية".to_string(), "التثنية".to_string()],
+        ),
+        (BibleBook::Joshua, vec!["يشوع".to_string()]),
+        (
+            BibleBook::Judges,
+            vec!["قضاة".to_string(), "القضاة".to_string()],
+        ),
+        (BibleBook::Ruth, vec!["راعوث".to_string()]),
+        (
+            BibleBook::ISamuel,
+            vec!["صموئيل الأول".to_string(), "1 صموئيل".to_string()],
+        ),
+        (
+            BibleBook::IISamuel,
+            vec!["صموئيل الثاني".to_string(), "2 صموئيل".to_string()],
+        ),
+        (
+            BibleBook::IKings,
+            vec![
+                "ملوك الأول".to_string(),
+                "الملوك الأول".to_string(),
+                "1 ملوك".to_string(),
+            ],
+        ),
+        (
+            BibleBook::IIKings,
+            vec![
+                "ملوك الثاني".to_string(),
+                "الملوك الثاني".to_string(),
+                "2 ملوك".to_string(),
+            ],
+        ),
+        (
+            BibleBook::IChronicles,
+            vec!["أخبار الأيام الأول".to_string(), "1 أخبار".to_string()],
+        ),
+        (
+            BibleBook::IIChronicles,
+            vec!["أخبار الأيام الثاني".to_string(), "2 أخبار".to_string()],
+        ),
+        (BibleBook::Ezra, vec!["عزرا".to_string()]),
+        (BibleBook::Nehemiah, vec!["نحميا".to_string()]),
+        (BibleBook::Esther, vec!["أستير".to_string()]),
+        (BibleBook::Job, vec!["أيوب".to_string()]),
+        (
+            BibleBook::Psalm,
+            vec!["مزامير".to_string(), "المزامير".to_string()],
+        ),
+        (
+            BibleBook::Proverbs,
+            vec!["أمثال".to_string(), "الأمثال".to_string()],
+        ),
+        (
+            BibleBook::Ecclesiastes,
+            vec!["جامعة".to_string(), "الجامعة".to_string()],
+        ),
+        (BibleBook::SongofSolomon, vec!["نشيد الأنشاد".to_string()]),
+        (BibleBook::Isaiah, vec!["إشعياء".to_string()]),
+        (BibleBook::Jeremiah, vec!["إرميا".to_string()]),
+        (BibleBook::Lamentations, vec!["مراثي إرميا".to_string()]),
+        (BibleBook::Ezekiel, vec!["حزقيال".to_string()]),
+        (BibleBook::Daniel, vec!["دانيال".to_string()]),
+        (BibleBook::Hosea, vec!["هوشع".to_string()]),
+        (BibleBook::Joel, vec!["يوئيل".to_string()]),
+        (BibleBook::Amos, vec!["عاموس".to_string()]),
+        (BibleBook::Obadiah, vec!["عوبديا".to_string()]),
+        (BibleBook::Jonah, vec!["يونان".to_string()]),
+        (BibleBook::Micah, vec!["ميخا".to_string()]),
+        (BibleBook::Nahum, vec!["ناحوم".to_string()]),
+        (BibleBook::Habakkuk, vec!["حبقوق".to_string()]),
+        (BibleBook::Zephaniah, vec!["صفنيا".to_string()]),
+        (BibleBook::Haggai, vec!["حجي".to_string()]),
+        (BibleBook::Zechariah, vec!["زكريا".to_string()]),
+        (BibleBook::Malachi, vec!["ملاخي".to_string()]),
+        (BibleBook::Matthew, vec!["متى".to_string()]),
+        (BibleBook::Mark, vec!["مرقس".to_string()]),
+        (BibleBook::Luke, vec!["لوقا".to_string()]),
+        (BibleBook::John, vec!["يوحنا".to_string()]),
+        (
+            BibleBook::Acts,
+            vec!["أعمال الرسل".to_string(), "أعمال".to_string()],
+        ),
+        (BibleBook::Romans, vec!["رومية".to_string()]),
+        (
+            BibleBook::ICorinthians,
+            vec!["كورنثوس الأولى".to_string(), "1 كورنثوس".to_string()],
+        ),
+        (
+            BibleBook::IICorinthians,
+            vec!["كورنثوس الثانية".to_string(), "2 كورنثوس".to_string()],
+        ),
+        (BibleBook::Galatians, vec!["غلاطية".to_string()]),
+        (BibleBook::Ephesians, vec!["أفسس".to_string()]),
+        (BibleBook::Philippians, vec!["فيلبي".to_string()]),
+        (BibleBook::Colossians, vec!["كولوسي".to_string()]),
+        (
+            BibleBook::IThessalonians,
+            vec!["تسالونيكي الأولى".to_string(), "1 تسالونيكي".to_string()],
+        ),
+        (
+            BibleBook::IIThessalonians,
+            vec!["تسالونيكي الثانية".to_string(), "2 تسالونيكي".to_string()],
+        ),
+        (
+            BibleBook::ITimothy,
+            vec!["تيموثاوس الأولى".to_string(), "1 تيموثاوس".to_string()],
+        ),
+        (
+            BibleBook::IITimothy,
+            vec!["تيموثاوس الثانية".to_string(), "2 تيموثاوس".to_string()],
+        ),
+        (BibleBook::Titus, vec!["تيطس".to_string()]),
+        (BibleBook::Philemon, vec!["فليمون".to_string()]),
+        (
+            BibleBook::Hebrews,
+            vec!["عبرانيين".to_string(), "العبرانيين".to_string()],
+        ),
+        (BibleBook::James, vec!["يعقوب".to_string()]),
+        (
+            BibleBook::IPeter,
+            vec!["بطرس الأولى".to_string(), "1 بطرس".to_string()],
+        ),
+        (
+            BibleBook::IIPeter,
+            vec!["بطرس الثانية".to_string(), "2 بطرس".to_string()],
+        ),
+        (
+            BibleBook::IJohn,
+            vec!["يوحنا الأولى".to_string(), "1 يوحنا".to_string()],
+        ),
+        (
+            BibleBook::IIJohn,
+            vec!["يوحنا الثانية".to_string(), "2 يوحنا".to_string()],
+        ),
+        (
+            BibleBook::IIIJohn,
+            vec!["يوحنا الثالثة".to_string(), "3 يوحنا".to_string()],
+        ),
+        (BibleBook::Jude, vec!["يهوذا".to_string()]),
+        (
+            BibleBook::Revelation,
+            vec!["رؤيا".to_string(), "رؤيا يوحنا".to_string()],
+        ),
+    ];
+    let long_names: HashMap<BibleBook, Vec<String>> = long_names_vec.into_iter().collect();
+
+    let short_names_vec = vec![
+        (BibleBook::Genesis, vec!["تك".to_string()]),
+        (BibleBook::Exodus, vec!["خر".to_string()]),
+        (BibleBook::Leviticus, vec!["لا".to_string()]),
+        (BibleBook::Numbers, vec!["عد".to_string()]),
+        (BibleBook::Deuteronomy, vec!["تث".to_string()]),
+        (BibleBook::Joshua, vec!["يش".to_string()]),
+        (BibleBook::Judges, vec!["قض".to_string()]),
+        (BibleBook::Ruth, vec!["را".to_string()]),
+        (BibleBook::ISamuel, vec!["1 صم".to_string()]),
+        (BibleBook::IISamuel, vec!["2 صم".to_string()]),
+        (BibleBook::IKings, vec!["1 مل".to_string()]),
+        (BibleBook::IIKings, vec!["2 مل".to_string()]),
+        (BibleBook::IChronicles, vec!["1 أخ".to_string()]),
+        (BibleBook::IIChronicles, vec!["2 أخ".to_string()]),
+        (BibleBook::Ezra, vec!["عز".to_string()]),
+        (BibleBook::Nehemiah, vec!["نح".to_string()]),
+        (BibleBook::Esther, vec!["أس".to_string()]),
+        (BibleBook::Job, vec!["أي".to_string()]),
+        (BibleBook::Psalm, vec!["مز".to_string()]),
+        (BibleBook::Proverbs, vec!["أم".to_string()]),
+        (BibleBook::Ecclesiastes, vec!["جا".to_string()]),
+        (BibleBook::SongofSolomon, vec!["نش".to_string()]),
+        (BibleBook::Isaiah, vec!["إش".to_string()]),
+        (BibleBook::Jeremiah, vec!["إر".to_string()]),
+        (BibleBook::Lamentations, vec!["مرا".to_string()]),
+        (BibleBook::Ezekiel, vec!["حز".to_string()]),
+        (BibleBook::Daniel, vec!["دا".to_string()]),
+        (BibleBook::Hosea, vec!["هو".to_string()]),
+        (BibleBook::Joel, vec!["يوء".to_string()]),
+        (BibleBook::Amos, vec!["عا".to_string()]),
+        (BibleBook::Obadiah, vec!["عو".to_string()]),
+        (BibleBook::Jonah, vec!["يون".to_string()]),
+        (BibleBook::Micah, vec!["مي".to_string()]),
+        (BibleBook::Nahum, vec!["نا".to_string()]),
+        (BibleBook::Habakkuk, vec!["حب".to_string()]),
+        (BibleBook::Zephaniah, vec!["صف".to_string()]),
+        (BibleBook::Haggai, vec!["حج".to_string()]),
+        (BibleBook::Zechariah, vec!["زك".to_string()]),
+        (BibleBook::Malachi, vec!["ملا".to_string()]),
+        (BibleBook::Matthew, vec!["مت".to_string()]),
+        (BibleBook::Mark, vec!["مر".to_string()]),
+        (BibleBook::Luke, vec!["لو".to_string()]),
+        (BibleBook::John, vec!["يو".to_string()]),
+        (BibleBook::Acts, vec!["أع".to_string()]),
+        (BibleBook::Romans, vec!["رو".to_string()]),
+        (BibleBook::ICorinthians, vec!["1 كو".to_string()]),
+        (BibleBook::IICorinthians, vec!["2 كو".to_string()]),
+        (BibleBook::Galatians, vec!["غل".to_string()]),
+        (BibleBook::Ephesians, vec!["أف".to_string()]),
+        (BibleBook::Philippians, vec!["في".to_string()]),
+        (BibleBook::Colossians, vec!["كو".to_string()]),
+        (BibleBook::IThessalonians, vec!["1 تس".to_string()]),
+        (BibleBook::IIThessalonians, vec!["2 تس".to_string()]),
+        (BibleBook::ITimothy, vec!["1 تي".to_string()]),
+        (BibleBook::IITimothy, vec!["2 تي".to_string()]),
+        (BibleBook::Titus, vec!["تي".to_string()]),
+        (BibleBook::Philemon, vec!["فل".to_string()]),
+        (BibleBook::Hebrews, vec!["عب".to_string()]),
+        (BibleBook::James, vec!["يع".to_string()]),
+        (BibleBook::IPeter, vec!["1 بط".to_string()]),
+        (BibleBook::IIPeter, vec!["2 بط".to_string()]),
+        (BibleBook::IJohn, vec!["1 يو".to_string()]),
+        (BibleBook::IIJohn, vec!["2 يو".to_string()]),
+        (BibleBook::IIIJohn, vec!["3 يو".to_string()]),
+        (BibleBook::Jude, vec!["يه".to_string()]),
+        (BibleBook::Revelation, vec!["رؤيا".to_string()]),
+    ];
+    let short_names: HashMap<BibleBook, Vec<String>> = short_names_vec.into_iter().collect();
+
+    ReferenceLanguage {
+        long_language_name: "Arabic".to_string(),
+        language_code: "ar".to_string(),
+        long_names,
+        short_names,
+        chapter_vers_delimiters: vec![":".to_string()],
+        space_separation: true,
+        range_delimiter: "-".to_string(),
+        multiple_representations_delimiters: vec![";".to_string(), "؛".to_string()],
+        directionality: TextDirection::Rtl,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bible::{
+        BibleBookRange, BibleBookReference, BibleChapterRange, BibleChapterReference,
+        BibleVerseRange, BibleVerseReference,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_range_across_books_contains_range_delimiter() {
+        // Ranges spanning several books used to be rendered without the range delimiter, e.g. "Genesis 50Exodus 1"
+        let range = BibleReferenceRepresentation::Range(
+            BibleRange::new(
+                BibleReference::BibleChapter(
+                    BibleChapterReference::new(BibleBook::Genesis, 50).unwrap(),
+                ),
+                BibleReference::BibleChapter(
+                    BibleChapterReference::new(BibleBook::Exodus, 1).unwrap(),
+                ),
+            )
+            .unwrap(),
+        );
+        for shortened_string in [false, true] {
+            assert_eq!(
+                get_reference_representation_in_language(
+                    &range,
+                    "en",
+                    BookReferenceType::Long,
+                    shortened_string
+                )
+                .unwrap(),
+                "Genesis 50-Exodus 1".to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_references_to_human_language() {
+        // Test John 3:16 in multiple languages
+        let reference1: BibleReference =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        assert_eq!(
+            get_reference_in_language(&reference1, "en", BookReferenceType::Long).unwrap(),
+            "John 3:16".to_string(),
+        );
+        assert_eq!(
+            get_reference_in_language(&reference1, "de", BookReferenceType::Long).unwrap(),
             "Johannes 3,16".to_string()
         );
         assert_eq!(
@@ -3097,4 +5257,762 @@ mod tests {
             "Mt 1,1-2,12".to_string()
         );
     }
+
+    #[test]
+    fn test_find_book_by_name() {
+        assert_eq!(find_book_by_name("1. Mose"), Some(BibleBook::Genesis));
+        assert_eq!(find_book_by_name("  创世记 "), Some(BibleBook::Genesis));
+        assert_eq!(find_book_by_name("БЫТИЕ"), Some(BibleBook::Genesis));
+        assert_eq!(find_book_by_name("1 cor"), Some(BibleBook::ICorinthians));
+        assert_eq!(find_book_by_name(""), None);
+    }
+
+    #[test]
+    fn test_portuguese_reference_language() {
+        let reference = crate::parse("João 3:16").unwrap();
+        assert_eq!(
+            reference,
+            BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()
+            ))
+        );
+        assert_eq!(crate::translate("John 3:16", "pt").unwrap(), "João 3:16");
+        assert_eq!(crate::translate("Gn 1,1", "pt").unwrap(), "Gn 1:1");
+
+        // Job and John can be distinguished without diacritics
+        assert_eq!(crate::translate("Job 3:16", "pt").unwrap(), "Jó 3:16");
+        assert_eq!(
+            crate::parse("jo 3:16").unwrap(),
+            crate::parse("Job 3:16").unwrap()
+        );
+        assert_eq!(
+            get_reference_in_language(
+                &BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()
+                ),
+                "pt",
+                BookReferenceType::Short
+            )
+            .unwrap(),
+            "Jn 3:16"
+        );
+    }
+
+    #[test]
+    fn test_korean_reference_language() {
+        let john_3_16 = BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+            BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+        ));
+        assert_eq!(crate::parse("요한복음 3:16").unwrap(), john_3_16);
+        assert_eq!(crate::parse("요한복음3：16").unwrap(), john_3_16);
+        assert_eq!(crate::translate("John 3:16", "ko").unwrap(), "요한복음3:16");
+        assert_eq!(crate::translate("Rev 22:21", "ko").unwrap(), "계22:21");
+    }
+
+    #[test]
+    fn test_italian_reference_language() {
+        assert_eq!(crate::translate("Genesis 1:1", "it").unwrap(), "Genesi 1,1");
+        assert_eq!(crate::translate("Rev 22:21", "it").unwrap(), "Ap 22,21");
+        assert_eq!(
+            crate::parse("Giovanni 3,16").unwrap(),
+            crate::parse("John 3:16").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dutch_reference_language() {
+        assert_eq!(
+            crate::translate("Johannes 3:16", "nl").unwrap(),
+            "Johannes 3:16"
+        );
+        assert_eq!(
+            crate::translate("Revelation 1:1", "nl").unwrap(),
+            "Openbaring 1:1"
+        );
+        assert_eq!(
+            crate::parse("Openbaring 1:1").unwrap(),
+            crate::parse("Revelation 1:1").unwrap()
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_language_from_toml() {
+        let language_file = r#"
+            long_language_name = "Test"
+            language_code = "test"
+            chapter_vers_delimiters = [":"]
+            space_separation = true
+            range_delimiter = "-"
+            multiple_representations_delimiters = [";"]
+
+            [long_names]
+            Revelation = ["Revelation"]
+
+            [short_names]
+            Revelation = ["Re"]
+        "#;
+        let language = ReferenceLanguage::from_toml(language_file).unwrap();
+        assert_eq!(
+            language.short_names[&BibleBook::Revelation],
+            vec!["Re".to_string()]
+        );
+        assert_eq!(language.directionality, TextDirection::Ltr);
+
+        let rtl_language_file = language_file.replace(
+            "space_separation = true",
+            "space_separation = true\n            directionality = \"Rtl\"",
+        );
+        let language = ReferenceLanguage::from_toml(&rtl_language_file).unwrap();
+        assert_eq!(language.directionality, TextDirection::Rtl);
+
+        // An unknown Bible book can't be read
+        let language_file = language_file.replace("Revelation =", "Revelations =");
+        assert!(ReferenceLanguage::from_toml(&language_file).is_err());
+        assert!(ReferenceLanguage::from_toml("language_code = 5").is_err());
+    }
+
+    #[test]
+    fn test_language_validation() {
+        for language in REFERENCE_LANGUAGES.read().unwrap().iter() {
+            assert!(
+                language.validate().is_ok(),
+                "{} is incomplete",
+                language.language_code
+            );
+        }
+
+        let mut language = get_english_reference_language();
+        language.language_code = "en_incomplete".to_string();
+        language.short_names.insert(BibleBook::Mark, vec![]);
+        language.long_names.remove(&BibleBook::Jude);
+        let error = language.validate().err().unwrap();
+        assert_eq!(
+            error.missing_part,
+            MissingLanguagePart::BookName(BibleBook::Mark, BookReferenceType::Short)
+        );
+
+        assert!(register_language(language).is_err());
+        assert!(get_language_by_code("en_incomplete").is_none());
+
+        // Languages without delimiters are incomplete
+        let mut language = get_english_reference_language();
+        language.language_code = "en_incomplete".to_string();
+        language.chapter_vers_delimiters = vec![];
+        assert_eq!(
+            language.validate().err().unwrap().missing_part,
+            MissingLanguagePart::ChapterVerseDelimiter
+        );
+        language.chapter_vers_delimiters = vec![":".to_string(), "".to_string()];
+        assert_eq!(
+            language.validate().err().unwrap().missing_part,
+            MissingLanguagePart::ChapterVerseDelimiter
+        );
+        language.chapter_vers_delimiters = vec![":".to_string()];
+        language.range_delimiter = "".to_string();
+        assert_eq!(
+            language.validate().err().unwrap().missing_part,
+            MissingLanguagePart::RangeDelimiter
+        );
+        assert!(register_language(language).is_err());
+        assert!(get_language_by_code("en_incomplete").is_none());
+
+        let builder = ReferenceLanguage::builder().code("en_incomplete");
+        let builder = BibleBook::all()
+            .into_iter()
+            .fold(builder, |builder, book| builder.book(book, "Book", &["Bk"]));
+        assert!(builder.clone().build().is_ok());
+        assert_eq!(
+            builder.delimiters(&[]).build().err().unwrap().missing_part,
+            MissingLanguagePart::ChapterVerseDelimiter
+        );
+    }
+
+    #[test]
+    fn test_try_create_reference() {
+        let mut language = get_english_reference_language();
+        language.long_names.remove(&BibleBook::Jude);
+
+        let jude =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Jude, 1).unwrap());
+        let error = language
+            .try_create_reference(&jude, BookReferenceType::Long)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.missing_part,
+            MissingLanguagePart::BookName(BibleBook::Jude, BookReferenceType::Long)
+        );
+        assert_eq!(
+            language
+                .try_create_reference(&jude, BookReferenceType::Short)
+                .unwrap(),
+            "Jude 1:1-25"
+        );
+
+        let range = BibleRange::BookRange(
+            BibleBookRange::new(
+                BibleBookReference::new(BibleBook::John),
+                BibleBookReference::new(BibleBook::Jude),
+            )
+            .unwrap(),
+        );
+        assert!(
+            language
+                .try_create_bible_range(&range, BookReferenceType::Long, true)
+                .is_err()
+        );
+
+        // A language without chapter/verse delimiters can't create verse references
+        let mut language = get_english_reference_language();
+        language.chapter_vers_delimiters = vec![];
+        let john_3_16 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        let error = language
+            .try_create_reference(&john_3_16, BookReferenceType::Long)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.missing_part,
+            MissingLanguagePart::ChapterVerseDelimiter
+        );
+        assert!(
+            language
+                .try_create_reference_with_style(&john_3_16, &ReferenceStyle::short())
+                .is_err()
+        );
+        let verse_range = BibleRange::new(
+            john_3_16.clone(),
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 4, 2).unwrap()),
+        )
+        .unwrap();
+        assert!(
+            language
+                .try_create_bible_range(&verse_range, BookReferenceType::Long, true)
+                .is_err()
+        );
+        let john_3 =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap());
+        assert_eq!(
+            language
+                .try_create_reference(&john_3, BookReferenceType::Short)
+                .unwrap(),
+            "John 3"
+        );
+        assert_eq!(
+            language
+                .try_create_reference_with_style(&john_3_16, &ReferenceStyle::period_delimited())
+                .unwrap(),
+            "John 3.16"
+        );
+    }
+
+    #[test]
+    fn test_reference_style() {
+        let english = get_english_reference_language();
+        let german = get_german_reference_language();
+        let verse =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Psalm, 23, 1).unwrap());
+
+        // The default styles match the two-argument API
+        assert_eq!(
+            english.create_reference_with_style(&verse, &ReferenceStyle::long()),
+            english.create_reference(&verse, BookReferenceType::Long)
+        );
+        assert_eq!(ReferenceStyle::default(), ReferenceStyle::long());
+        assert_eq!(
+            ReferenceStyle::DEFAULT,
+            ReferenceStyle::from(BookReferenceType::Long)
+        );
+        assert_eq!(
+            german.create_reference_with_style(&verse, &ReferenceStyle::short()),
+            "Ps 23,1"
+        );
+
+        assert_eq!(
+            english.create_reference_with_style(&verse, &ReferenceStyle::period_delimited()),
+            "Ps 23.1"
+        );
+        assert_eq!(
+            english.create_reference_with_style(&verse, &ReferenceStyle::uppercase()),
+            "PS 23:1"
+        );
+        let padded = ReferenceStyle {
+            number_width: 3,
+            ..ReferenceStyle::long()
+        };
+        assert_eq!(
+            english.create_reference_with_style(&verse, &padded),
+            "Psalms 023:001"
+        );
+
+        // The second delimiter of German is ':', a missing delimiter falls back to the first one
+        let second_delimiter = ReferenceStyle {
+            delimiter_index: 1,
+            ..ReferenceStyle::short()
+        };
+        assert_eq!(
+            german.create_reference_with_style(&verse, &second_delimiter),
+            "Ps 23:1"
+        );
+        assert_eq!(
+            english.create_reference_with_style(&verse, &second_delimiter),
+            "Ps 23:1"
+        );
+
+        let range = BibleRange::VerseRange(
+            BibleVerseRange::new(
+                BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+                BibleVerseReference::new(BibleBook::John, 4, 2).unwrap(),
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            english.create_bible_range_with_style(
+                &range,
+                &ReferenceStyle::period_delimited(),
+                true
+            ),
+            "John 3.16-4.2"
+        );
+        assert_eq!(
+            english.create_bible_range_with_style(&range, &padded, false),
+            "John 003:016-John 004:002"
+        );
+    }
+
+    /// Creates references to every book, to chapters of every book and to their first and last verses in every language and asserts that they are parsed again into the same reference.
+    /// If `all_chapters` is false, only the first and the last chapter of every book are checked, as checking all chapters takes a long time.
+    fn assert_round_trip_of_all_languages(all_chapters: bool) {
+        use crate::bible::validate::{get_number_of_chapters, get_number_of_verses};
+        use crate::referencing::parser::parse_reference;
+
+        // Abbreviations which are used for other books in languages which are searched first, so they can not be parsed without knowing the language
+        let cross_language_collisions = [
+            ("it", BibleBook::Habakkuk, BookReferenceType::Short), // "Ab" is Obadiah in French
+            ("ja", BibleBook::SongofSolomon, BookReferenceType::Short), // "雅" is James in Simplified Chinese
+            ("uk", BibleBook::IKings, BookReferenceType::Short), // "1Цар" is 1 Samuel in Russian
+            ("uk", BibleBook::IIKings, BookReferenceType::Short), // "2Цар" is 2 Samuel in Russian
+        ];
+        for (language_code, book, book_reference_type) in cross_language_collisions {
+            let language = get_language_by_code(language_code).unwrap();
+            let book_name = language.book_name(book, book_reference_type).unwrap();
+            let parsed = parse_reference(book_name).unwrap();
+            assert_ne!(
+                parsed.language_code(),
+                language_code,
+                "{} is no collision",
+                book_name
+            );
+        }
+
+        // The lock must not be held while parsing
+        let languages = REFERENCE_LANGUAGES.read().unwrap().clone();
+        let mut failures: Vec<String> = vec![];
+        for language in languages.iter() {
+            for book in BibleBook::all() {
+                for book_reference_type in [BookReferenceType::Long, BookReferenceType::Short] {
+                    // Not every language contains the names of the deuterocanonical books
+                    if language.book_name(book, book_reference_type).is_err()
+                        || cross_language_collisions.contains(&(
+                            language.language_code.as_str(),
+                            book,
+                            book_reference_type,
+                        ))
+                    {
+                        continue;
+                    }
+                    let mut assert_round_trip = |reference: BibleReference| {
+                        let rendered = language.create_reference(&reference, book_reference_type);
+                        let parsed = parse_reference(&rendered)
+                            .map(|result| result.bible_reference().clone());
+                        // The chapter of a book with only one chapter is written as a verse range (e.g. "Jude 1:1-25")
+                        let is_verse_range = matches!(reference, BibleReference::BibleChapter(_))
+                            && has_single_chapter(book);
+                        let expected = BibleReferenceRepresentation::Single(reference);
+                        let round_trips = match &parsed {
+                            Ok(parsed) if is_verse_range => {
+                                parsed.as_verse_list() == expected.as_verse_list()
+                            }
+                            Ok(parsed) => *parsed == expected,
+                            Err(_) => false,
+                        };
+                        if !round_trips {
+                            failures.push(format!(
+                                "{} ({}): {:?}",
+                                rendered, language.language_code, parsed
+                            ));
+                        }
+                    };
+
+                    assert_round_trip(BibleReference::BibleBook(BibleBookReference::new(book)));
+                    let last_chapter = get_number_of_chapters(&book);
+                    for chapter in (1..=last_chapter)
+                        .filter(|chapter| all_chapters || *chapter == 1 || *chapter == last_chapter)
+                    {
+                        assert_round_trip(BibleReference::BibleChapter(
+                            BibleChapterReference::new(book, chapter).unwrap(),
+                        ));
+                        let last_verse = get_number_of_verses(&book, &chapter).unwrap();
+                        for verse in [1, last_verse] {
+                            assert_round_trip(BibleReference::BibleVerse(
+                                BibleVerseReference::new(book, chapter, verse).unwrap(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(
+            failures.is_empty(),
+            "{} references could not be parsed again:\n{}",
+            failures.len(),
+            failures.join("\n")
+        );
+    }
+
+    #[test]
+    fn test_round_trip_of_all_languages() {
+        assert_round_trip_of_all_languages(false);
+    }
+
+    /// Run with `cargo test -- --ignored`
+    #[test]
+    #[ignore]
+    fn test_round_trip_of_all_chapters_in_all_languages() {
+        assert_round_trip_of_all_languages(true);
+    }
+
+    #[test]
+    fn test_find_ambiguities() {
+        for language in REFERENCE_LANGUAGES.read().unwrap().iter() {
+            assert_eq!(
+                language.find_ambiguities(),
+                vec![],
+                "{}",
+                language.language_code
+            );
+        }
+
+        let mut english = get_english_reference_language();
+        // Equal names
+        english
+            .long_names
+            .get_mut(&BibleBook::Jude)
+            .unwrap()
+            .push("JOB".to_string());
+        // A name followed by a number
+        english
+            .short_names
+            .get_mut(&BibleBook::Obadiah)
+            .unwrap()
+            .push("Ps 1".to_string());
+        // A name followed by letters is no conflict
+        english
+            .short_names
+            .get_mut(&BibleBook::Nahum)
+            .unwrap()
+            .push("Psb".to_string());
+        assert_eq!(
+            english.find_ambiguities(),
+            vec![
+                (BibleBook::Job, BibleBook::Jude, "Job".to_string()),
+                (BibleBook::Psalm, BibleBook::Obadiah, "Ps".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_language_position() {
+        let position_of = |language_code: &str| {
+            REFERENCE_LANGUAGES
+                .read()
+                .unwrap()
+                .iter()
+                .position(|language| language.language_code == language_code)
+        };
+        let find_position = |language_code: &str| {
+            find_language_position(&REFERENCE_LANGUAGES.read().unwrap(), language_code)
+        };
+        for language_code in ["de", "en", "la"] {
+            assert_eq!(find_position(language_code), position_of(language_code));
+        }
+        assert_eq!(find_position("xx"), None);
+
+        // Registered languages are found, the first language with a code wins
+        let mut language = get_latin_reference_language();
+        language.language_code = "LA_POSITION".to_string();
+        register_language(language.clone()).unwrap();
+        register_language(language).unwrap();
+        assert!(find_position("la_position").is_some());
+        assert_eq!(find_position("la_position"), position_of("LA_POSITION"));
+
+        assert_eq!(get_language_by_code(" EN ").unwrap().language_code, "en");
+        assert!(get_language_by_code("xx").is_none());
+    }
+
+    #[test]
+    fn test_write_reference() {
+        use std::fmt::Write;
+
+        let german = get_german_reference_language();
+        let mut buffer = String::new();
+        let references = [
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Genesis)),
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Exodus, 3).unwrap()),
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()),
+        ];
+        for reference in references.iter() {
+            for book_reference_type in [BookReferenceType::Long, BookReferenceType::Short] {
+                buffer.clear();
+                german
+                    .write_reference(&mut buffer, reference, book_reference_type)
+                    .unwrap();
+                assert_eq!(
+                    buffer,
+                    german.create_reference(reference, book_reference_type)
+                );
+            }
+        }
+
+        // The buffer can be appended to with write!
+        buffer.clear();
+        write!(buffer, "Siehe ").unwrap();
+        german
+            .write_reference(&mut buffer, &references[2], BookReferenceType::Long)
+            .unwrap();
+        write!(buffer, " und ").unwrap();
+        german
+            .write_reference(&mut buffer, &references[1], BookReferenceType::Short)
+            .unwrap();
+        assert_eq!(buffer, "Siehe Johannes 3,16 und 2Mo 3");
+    }
+
+    #[test]
+    fn test_latin_reference_language() {
+        let reference =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        assert_eq!(
+            get_reference_in_language(&reference, "la", BookReferenceType::Long).unwrap(),
+            "Ioannes 3,16"
+        );
+        assert_eq!(
+            get_reference_in_language(&reference, "la", BookReferenceType::Short).unwrap(),
+            "Io 3,16"
+        );
+        assert_eq!(find_book_by_name("Ad Romanos"), Some(BibleBook::Romans));
+    }
+
+    #[test]
+    fn test_greek_reference_language() {
+        let reference =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        assert_eq!(
+            get_reference_in_language(&reference, "el", BookReferenceType::Long).unwrap(),
+            "Κατά Ιωάννην 3:16"
+        );
+        assert_eq!(
+            get_reference_in_language(&reference, "el", BookReferenceType::Short).unwrap(),
+            "Ιω 3:16"
+        );
+
+        for (greek_reference, expected) in [
+            ("Κατά Ιωάννην 3:16", "John 3:16"),
+            ("Κατα Ιωαννην 3:16", "John 3:16"),
+            ("Αποκάλυψις 22:21", "Revelation 22:21"),
+            ("Αποκάλυψη 22:21", "Revelation 22:21"),
+            ("Α΄ Ιωάννου 1:9", "1 John 1:9"),
+            ("1 Κορ 13:4-7", "1 Corinthians 13:4-7"),
+            ("Ρωμ 8:28", "Romans 8:28"),
+        ] {
+            let search_result =
+                crate::referencing::parser::parse_reference(greek_reference).unwrap();
+            assert_eq!(search_result.language_code(), "el");
+            assert_eq!(search_result.bible_reference().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_language_builder() {
+        let english = get_english_reference_language();
+        let mut builder = ReferenceLanguage::builder()
+            .code("en_custom")
+            .name("Custom English")
+            .range_delimiter("–")
+            .space_separation(false);
+        for book in BibleBook::all()
+            .into_iter()
+            .filter(|book| !book.is_deuterocanonical())
+        {
+            let short_names: Vec<&str> = english.short_names[&book]
+                .iter()
+                .map(|name| name.as_str())
+                .collect();
+            builder = builder.book(book, &english.long_names[&book][0], &short_names);
+        }
+        let language = builder.clone().build().unwrap();
+        assert_eq!(language.language_code, "en_custom");
+        assert_eq!(language.long_language_name, "Custom English");
+        assert_eq!(language.directionality, TextDirection::Ltr);
+        assert_eq!(
+            language.create_reference(
+                &BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()
+                ),
+                BookReferenceType::Long
+            ),
+            "John3:16"
+        );
+
+        // Further names are appended
+        let language = builder
+            .book(BibleBook::Psalm, "Psalm", &[])
+            .build()
+            .unwrap();
+        assert_eq!(
+            language.long_names[&BibleBook::Psalm],
+            vec!["Psalms".to_string(), "Psalm".to_string()]
+        );
+
+        let error = ReferenceLanguage::builder()
+            .code("incomplete")
+            .book(BibleBook::Genesis, "Genesis", &["Gen"])
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            error.missing_part,
+            MissingLanguagePart::BookName(BibleBook::Exodus, BookReferenceType::Long)
+        );
+    }
+
+    #[test]
+    fn test_text_direction() {
+        for language in REFERENCE_LANGUAGES.read().unwrap().iter() {
+            let expected_direction = match language.language_code.as_str() {
+                "ar" => TextDirection::Rtl,
+                _ => TextDirection::Ltr,
+            };
+            assert_eq!(
+                language.directionality, expected_direction,
+                "{}",
+                language.language_code
+            );
+        }
+        assert_eq!(get_text_direction(" AR "), Some(TextDirection::Rtl));
+        assert_eq!(get_text_direction("de"), Some(TextDirection::Ltr));
+        assert_eq!(get_text_direction("xx"), None);
+    }
+
+    #[test]
+    fn test_arabic_reference_language() {
+        let reference =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        // The string is in logical order: the book name comes first, followed by chapter and verse.
+        let arabic_reference =
+            get_reference_in_language(&reference, "ar", BookReferenceType::Long).unwrap();
+        assert_eq!(arabic_reference, "يوحنا 3:16");
+        assert!(arabic_reference.starts_with("يوحنا"));
+        assert!(arabic_reference.ends_with("3:16"));
+        assert_eq!(
+            get_reference_in_language(&reference, "ar", BookReferenceType::Short).unwrap(),
+            "يو 3:16"
+        );
+
+        for (arabic_reference, expected) in [
+            ("يوحنا 3:16", "John 3:16"),
+            ("تكوين 1:1", "Genesis 1:1"),
+            ("التكوين 1", "Genesis 1"),
+            ("خروج 20:1-17", "Exodus 20:1-17"),
+            ("رؤيا 22:21", "Revelation 22:21"),
+            ("يوحنا الأولى 1:9", "1 John 1:9"),
+            ("1 كو 13:4-7", "1 Corinthians 13:4-7"),
+        ] {
+            let search_result =
+                crate::referencing::parser::parse_reference(arabic_reference).unwrap();
+            assert_eq!(search_result.language_code(), "ar");
+            assert_eq!(search_result.bible_reference().to_string(), expected);
+        }
+
+        // Translate an English reference into Arabic
+        let search_result = crate::referencing::parser::parse_reference("Genesis 1:1").unwrap();
+        assert_eq!(
+            get_reference_representation_in_language(
+                search_result.bible_reference(),
+                "ar",
+                BookReferenceType::Long,
+                false
+            )
+            .unwrap(),
+            "تكوين 1:1"
+        );
+    }
+
+    #[cfg(feature = "deuterocanon")]
+    #[test]
+    fn test_deuterocanonical_names() {
+        let reference = BibleReference::BibleChapter(
+            BibleChapterReference::new(BibleBook::IMaccabees, 2).unwrap(),
+        );
+        assert_eq!(
+            get_reference_in_language(&reference, "en", BookReferenceType::Long).unwrap(),
+            "1 Maccabees 2"
+        );
+        assert_eq!(
+            get_reference_in_language(&reference, "la", BookReferenceType::Long).unwrap(),
+            "1 Machabaeorum 2"
+        );
+        assert_eq!(
+            get_reference_in_language(&reference, "el", BookReferenceType::Long).unwrap(),
+            "Α΄ Μακκαβαίων 2"
+        );
+        assert_eq!(find_book_by_name("Sirach"), Some(BibleBook::Sirach));
+        // Languages without names for the deuterocanonical books are still complete
+        assert!(get_language_by_code("fr").unwrap().validate().is_ok());
+        assert!(get_reference_in_language(&reference, "fr", BookReferenceType::Long).is_err());
+    }
+
+    #[test]
+    fn test_reference_with_delimiter_override() {
+        let john_3_16 =
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+        assert_eq!(
+            get_reference_in_language_styled(&john_3_16, "en", BookReferenceType::Long, Some("."))
+                .unwrap(),
+            "John 3.16"
+        );
+        assert_eq!(
+            get_reference_in_language_styled(
+                &john_3_16,
+                "en",
+                BookReferenceType::Short,
+                Some(" v. ")
+            )
+            .unwrap(),
+            "John 3 v. 16"
+        );
+        // The book names stay localized
+        assert_eq!(
+            get_reference_in_language_styled(&john_3_16, "de", BookReferenceType::Long, Some("."))
+                .unwrap(),
+            "Johannes 3.16"
+        );
+        // Without an override, the delimiter of the language is used
+        assert_eq!(
+            get_reference_in_language_styled(&john_3_16, "de", BookReferenceType::Long, None)
+                .unwrap(),
+            get_reference_in_language(&john_3_16, "de", BookReferenceType::Long).unwrap()
+        );
+        // Chapters and books have no delimiter
+        let john_3 =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap());
+        assert_eq!(
+            get_reference_in_language_styled(&john_3, "en", BookReferenceType::Long, Some("."))
+                .unwrap(),
+            "John 3"
+        );
+        assert!(matches!(
+            get_reference_in_language_styled(&john_3_16, "xx", BookReferenceType::Long, Some(".")),
+            Err(ReferenceCreationError::LanguageDoesNotExist(_))
+        ));
+    }
 }
@@ -4,4 +4,12 @@ pub mod language;
 
 pub mod parser;
 
-pub mod errors;
\ No newline at end of file
+pub mod errors;
+
+pub mod osis;
+
+#[cfg(feature = "serde")]
+pub mod serde_string;
+
+#[cfg(feature = "serde")]
+pub mod flat;
@@ -0,0 +1,250 @@
+//! This module contains functions for converting Bible references from and into [OSIS](https://crosswire.org/osis/) IDs like `John.3.16`, `Gen.1` or `Rev`.
+//! OSIS IDs are language-independent and used by many Bible software projects (e.g. Sword or Xiphos) to exchange Bible references.
+
+use std::error::Error;
+
+use crate::bible::{
+    BibleBook, BibleBookReference, BibleChapterReference, BibleReference, BibleVerseReference,
+};
+
+use super::errors::OsisParsingError;
+
+/// The delimiter between book, chapter and verse of an OSIS ID
+const OSIS_DELIMITER: char = '.';
+
+/// Returns the standard OSIS abbreviation of a Bible book.
+/// # Example
+/// ```
+/// use bibleref::bible::BibleBook;
+/// use bibleref::referencing::osis::get_osis_abbreviation;
+/// assert_eq!(get_osis_abbreviation(&BibleBook::Genesis), "Gen");
+/// assert_eq!(get_osis_abbreviation(&BibleBook::IJohn), "1John");
+/// ```
+pub fn get_osis_abbreviation(book: &BibleBook) -> &'static str {
+    match book {
+        BibleBook::Genesis => "Gen",
+        BibleBook::Exodus => "Exod",
+        BibleBook::Leviticus => "Lev",
+        BibleBook::Numbers => "Num",
+        BibleBook::Deuteronomy => "Deut",
+        BibleBook::Joshua => "Josh",
+        BibleBook::Judges => "Judg",
+        BibleBook::Ruth => "Ruth",
+        BibleBook::ISamuel => "1Sam",
+        BibleBook::IISamuel => "2Sam",
+        BibleBook::IKings => "1Kgs",
+        BibleBook::IIKings => "2Kgs",
+        BibleBook::IChronicles => "1Chr",
+        BibleBook::IIChronicles => "2Chr",
+        BibleBook::Ezra => "Ezra",
+        BibleBook::Nehemiah => "Neh",
+        BibleBook::Esther => "Esth",
+        BibleBook::Job => "Job",
+        BibleBook::Psalm => "Ps",
+        BibleBook::Proverbs => "Prov",
+        BibleBook::Ecclesiastes => "Eccl",
+        BibleBook::SongofSolomon => "Song",
+        BibleBook::Isaiah => "Isa",
+        BibleBook::Jeremiah => "Jer",
+        BibleBook::Lamentations => "Lam",
+        BibleBook::Ezekiel => "Ezek",
+        BibleBook::Daniel => "Dan",
+        BibleBook::Hosea => "Hos",
+        BibleBook::Joel => "Joel",
+        BibleBook::Amos => "Amos",
+        BibleBook::Obadiah => "Obad",
+        BibleBook::Jonah => "Jonah",
+        BibleBook::Micah => "Mic",
+        BibleBook::Nahum => "Nah",
+        BibleBook::Habakkuk => "Hab",
+        BibleBook::Zephaniah => "Zeph",
+        BibleBook::Haggai => "Hag",
+        BibleBook::Zechariah => "Zech",
+        BibleBook::Malachi => "Mal",
+        BibleBook::Matthew => "Matt",
+        BibleBook::Mark => "Mark",
+        BibleBook::Luke => "Luke",
+        BibleBook::John => "John",
+        BibleBook::Acts => "Acts",
+        BibleBook::Romans => "Rom",
+        BibleBook::ICorinthians => "1Cor",
+        BibleBook::IICorinthians => "2Cor",
+        BibleBook::Galatians => "Gal",
+        BibleBook::Ephesians => "Eph",
+        BibleBook::Philippians => "Phil",
+        BibleBook::Colossians => "Col",
+        BibleBook::IThessalonians => "1Thess",
+        BibleBook::IIThessalonians => "2Thess",
+        BibleBook::ITimothy => "1Tim",
+        BibleBook::IITimothy => "2Tim",
+        BibleBook::Titus => "Titus",
+        BibleBook::Philemon => "Phlm",
+        BibleBook::Hebrews => "Heb",
+        BibleBook::James => "Jas",
+        BibleBook::IPeter => "1Pet",
+        BibleBook::IIPeter => "2Pet",
+        BibleBook::IJohn => "1John",
+        BibleBook::IIJohn => "2John",
+        BibleBook::IIIJohn => "3John",
+        BibleBook::Jude => "Jude",
+        BibleBook::Revelation => "Rev",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Tobit => "Tob",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Judith => "Jdt",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::AdditionsToEsther => "AddEsth",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Wisdom => "Wis",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Sirach => "Sir",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Baruch => "Bar",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::PrayerOfAzariah => "PrAzar",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::Susanna => "Sus",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::BelAndTheDragon => "Bel",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::IMaccabees => "1Macc",
+        #[cfg(feature = "deuterocanon")]
+        BibleBook::IIMaccabees => "2Macc",
+    }
+}
+
+/// Returns the Bible book which belongs to an OSIS abbreviation. The comparison is case-insensitive.
+/// # Params
+/// - `abbreviation`: The OSIS abbreviation of the book, e.g. "Gen" or "1John"
+/// # Returns
+/// - [Some] with the [BibleBook] if the abbreviation is known
+/// - [None] if the abbreviation is unknown
+pub fn get_book_by_osis_abbreviation(abbreviation: &str) -> Option<BibleBook> {
+    BibleBook::all()
+        .into_iter()
+        .find(|book| get_osis_abbreviation(book).eq_ignore_ascii_case(abbreviation))
+}
+
+impl BibleReference {
+    /// Creates the OSIS ID of the Bible reference, e.g. `John.3.16` for a verse, `Gen.1` for a chapter or `Rev` for a book.
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
+    /// let reference = BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap());
+    /// assert_eq!(reference.to_osis(), "John.3.16");
+    /// ```
+    pub fn to_osis(&self) -> String {
+        match self {
+            BibleReference::BibleBook(book) => get_osis_abbreviation(&book.book()).to_string(),
+            BibleReference::BibleChapter(chapter) => format!(
+                "{}{}{}",
+                get_osis_abbreviation(&chapter.book()),
+                OSIS_DELIMITER,
+                chapter.chapter()
+            ),
+            BibleReference::BibleVerse(verse) => format!(
+                "{}{}{}{}{}",
+                get_osis_abbreviation(&verse.book()),
+                OSIS_DELIMITER,
+                verse.chapter(),
+                OSIS_DELIMITER,
+                verse.verse()
+            ),
+        }
+    }
+
+    /// Reads a Bible reference from an OSIS ID like `John.3.16`, `Gen.1` or `Rev`.
+    /// # Params
+    /// - `osis_id`: The OSIS ID of a book, chapter or verse
+    /// # Returns
+    /// - The [BibleReference] if the OSIS ID is valid
+    /// - An [OsisParsingError] if the OSIS ID is malformed or contains an unknown book
+    /// - A [BibleReferenceValidationError](crate::bible::errors::BibleReferenceValidationError) if the chapter or verse does not exist
+    /// # Example
+    /// ```
+    /// use bibleref::bible::{BibleBook, BibleChapterReference, BibleReference};
+    /// let reference = BibleReference::from_osis("Gen.1").unwrap();
+    /// assert_eq!(reference, BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Genesis, 1).unwrap()));
+    /// assert!(BibleReference::from_osis("John.3.99").is_err());
+    /// ```
+    pub fn from_osis(osis_id: &str) -> Result<Self, Box<dyn Error>> {
+        let osis_id = osis_id.trim();
+        let parsing_error = || OsisParsingError {
+            provided_osis_id: osis_id.to_string(),
+        };
+        let parts: Vec<&str> = osis_id.split(OSIS_DELIMITER).collect();
+        let book = get_book_by_osis_abbreviation(parts[0]).ok_or_else(parsing_error)?;
+        let numbers = parts[1..]
+            .iter()
+            .map(|part| part.parse::<u8>())
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| parsing_error())?;
+
+        match numbers.as_slice() {
+            [] => Ok(BibleReference::BibleBook(BibleBookReference::new(book))),
+            [chapter] => Ok(BibleReference::BibleChapter(BibleChapterReference::new(
+                book, *chapter,
+            )?)),
+            [chapter, verse] => Ok(BibleReference::BibleVerse(BibleVerseReference::new(
+                book, *chapter, *verse,
+            )?)),
+            _ => Err(Box::new(parsing_error())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osis_abbreviations() {
+        for book in BibleBook::all() {
+            assert_eq!(
+                get_book_by_osis_abbreviation(get_osis_abbreviation(&book)),
+                Some(book)
+            );
+        }
+        assert_eq!(get_book_by_osis_abbreviation("ps"), Some(BibleBook::Psalm));
+        assert_eq!(get_book_by_osis_abbreviation("Psalms"), None);
+    }
+
+    #[test]
+    fn test_osis_round_trip() {
+        let references = [
+            BibleReference::BibleBook(BibleBookReference::new(BibleBook::Revelation)),
+            BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Genesis, 1).unwrap(),
+            ),
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()),
+            BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::IIIJohn, 1, 14).unwrap(),
+            ),
+        ];
+        let osis_ids = ["Rev", "Gen.1", "John.3.16", "3John.1.14"];
+        for (reference, osis_id) in references.iter().zip(osis_ids) {
+            assert_eq!(reference.to_osis(), osis_id);
+            assert_eq!(&BibleReference::from_osis(osis_id).unwrap(), reference);
+        }
+    }
+
+    #[test]
+    fn test_invalid_osis_ids() {
+        for osis_id in [
+            "",
+            "Foo.1",
+            "John.",
+            "John.3.16.1",
+            "John.x",
+            "Gen.51",
+            "John.3.37",
+            "John.3.300",
+        ] {
+            assert!(
+                BibleReference::from_osis(osis_id).is_err(),
+                "{} should be invalid",
+                osis_id
+            );
+        }
+    }
+}
@@ -1,9 +1,10 @@
 //! This module contains functions for parsing real language bible references into the crate's internal structures.
 
+use std::cmp::Ordering;
 use std::error::Error;
+use std::ops::Range;
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
 
 use super::{
     errors::LanguageHasNoChapterVersDelimiterError,
@@ -13,9 +14,14 @@ use crate::{
     bible::{
         BibleBook, BibleBookReference, BibleChapterReference, BibleRange, BibleReference,
         BibleReferenceRepresentation, BibleVerseReference,
+        errors::BibleReferenceValidationError,
+        validate::{get_number_of_chapters, get_number_of_verses},
     },
     referencing::{
-        errors::{BibleBookNotFoundError, BibleRangeParsingError, ReferenceIsEmptyError},
+        errors::{
+            AmbiguousBookNameError, BibleBookNotFoundError, BibleRangeParsingError,
+            ReferenceIsEmptyError, VerseListError,
+        },
         language::get_language_by_code,
     },
 };
@@ -92,6 +98,13 @@ impl BibleReferenceRepresentationSearchResult {
 /// Parses a Bible reference string and returns a BibleReferenceRepresentationSearchResult.
 /// This function tries to parse the input as a range reference first, and if that fails,
 /// it tries to parse it as a single reference.
+/// A verse followed by "ff" (e.g. "John 3:16ff") is expanded to a range up to the last verse of the chapter, a verse followed by "f" to a range including the next verse. Chapters are expanded in the same way.
+/// If there is no following verse or chapter (e.g. "John 3:36f" or "John 3:36ff"), an error is returned.
+/// A reference followed by a range delimiter without an end (e.g. "John 3:16-") is an open range which is extended to the end of the smallest enclosing unit:
+/// a verse up to the last verse of its chapter, a chapter up to the last chapter of its book and a book up to the last book of the Bible.
+/// Spaces between the book and the chapter as well as around the range and chapter/verse delimiters are ignored, e.g. "Joshua 3 - 7" or "John 3 : 16 – 18".
+/// The space between the book and the chapter may also be missing in languages which separate them with a space, e.g. "Gen1:1" or "2Sam1:1". A leading number always belongs to the book name.
+/// A list of verses like "John 3:16,18" is no single reference and results in a [VerseListError], it can be parsed with [parse_verse_list] instead.
 ///
 /// # Arguments
 /// - `bible_reference`: A human readable Bible reference.
@@ -103,21 +116,57 @@ impl BibleReferenceRepresentationSearchResult {
 /// use bibleref::referencing::language::BookReferenceType;
 /// use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
 /// use bibleref::bible::BibleReferenceRepresentation;
-/// 
+///
 /// // Parse a single reference
 /// let result = parse_reference("1. Mose 1,3").unwrap();
 /// assert_eq!(result.language_code(), "de");
-/// 
+///
 /// // Parse a range reference
 /// let result = parse_reference("1. Mose 1,3-5").unwrap();
 /// assert_eq!(result.language_code(), "de");
+///
+/// // Parse a verse and all following verses of the chapter
+/// let result = parse_reference("John 3:16ff").unwrap();
+/// assert!(result.bible_reference().is_range());
+///
+/// // Parse an open range up to the end of the chapter
+/// let result = parse_reference("John 3:16-").unwrap();
+/// assert_eq!(result.bible_reference().to_string(), "John 3:16-36");
 /// ```
 pub fn parse_reference(
     bible_reference: &str,
 ) -> Result<BibleReferenceRepresentationSearchResult, Box<dyn Error>> {
+    // The numbers of a list like "John 3:16,18" must not be read as one verse
+    if is_verse_list(bible_reference) {
+        return Err(Box::new(VerseListError {
+            provided_reference: bible_reference.trim().to_string(),
+        }));
+    }
+
+    // References like "John 3:16ff" or "Rom 8:1f." describe a verse and the following verse(s)
+    if let Some(result) = parse_following_verses_reference(bible_reference) {
+        return result;
+    }
+
+    // References like "John 3:16-" or "Genesis 1-" describe a range up to the end of the chapter or book
+    if let Some(result) = parse_open_range_reference(bible_reference) {
+        return result;
+    }
+
     // Try to parse as a range reference first
     match parse_range_reference(bible_reference.to_string()) {
         Ok(result) => Ok(result),
+        // Both parts of the range have been found, but the range does not exist in the Bible
+        Err(err) if err.is::<BibleReferenceValidationError>() => Err(err),
+        // Both parts of the range have been found, but it is unclear which passage is meant
+        Err(err)
+            if matches!(
+                err.downcast_ref::<BibleRangeParsingError>(),
+                Some(BibleRangeParsingError::AmbiguousRangeGranularity)
+            ) =>
+        {
+            Err(err)
+        }
         Err(_) => {
             // If that fails, try to parse as a single reference
             match parse_single_reference(bible_reference.to_string()) {
@@ -135,6 +184,560 @@ pub fn parse_reference(
     }
 }
 
+/// Parses a list of verses within one chapter like "John 3:16,18,20" or "Ps 1:1-3,6" into one [BibleReferenceRepresentation] per item.
+/// The first delimiter after the chapter number separates the chapter from the verses, every following comma separates two items of the list.
+/// Therefore, languages which use a comma as chapter/verse delimiter work as well (e.g. "Joh 3,16,18,20" in German).
+/// Each item can be a single verse or a range of verses; all items refer to the chapter in front of the first delimiter.
+/// The result can be merged with [aggregate_bible_representations](crate::bible::aggregate_bible_representations).
+///
+/// # Arguments
+/// - `bible_reference`: A human readable Bible reference with a list of verses. A reference without a list (e.g. "John 3:16") results in a single item.
+/// # Returns
+/// - A result with either the [BibleReferenceRepresentation]s of all items in the order of their appearance or a [`Box<dyn Error>`] with the error of the first item which could not be parsed.
+/// # Example
+/// ```
+/// use bibleref::referencing::parser::parse_verse_list;
+/// let verses = parse_verse_list("John 3:16,18,20").unwrap();
+/// assert_eq!(verses.len(), 3);
+/// assert_eq!(verses[1].to_string(), "John 3:18");
+/// let verses = parse_verse_list("Joh 3,16-18,20").unwrap();
+/// assert_eq!(verses[0].to_string(), "John 3:16-18");
+/// ```
+pub fn parse_verse_list(
+    bible_reference: &str,
+) -> Result<Vec<BibleReferenceRepresentation>, Box<dyn Error>> {
+    Ok(parse_verse_list_items(bible_reference.trim())?
+        .into_iter()
+        .map(|(_, search_result)| search_result.bible_reference().clone())
+        .collect())
+}
+
+/// The byte ranges of references within a text together with their search results.
+type ReferencesWithSpans = Vec<(Range<usize>, BibleReferenceRepresentationSearchResult)>;
+
+/// Parses a list of verses like [parse_verse_list], but returns the byte range of each item within `bible_reference` together with its search result.
+/// The range of the first item includes the book and the chapter, the ranges of the following items only cover their verses.
+fn parse_verse_list_items(bible_reference: &str) -> Result<ReferencesWithSpans, Box<dyn Error>> {
+    let Some((verses_start, items)) = verse_list_items(bible_reference) else {
+        return Ok(vec![(
+            0..bible_reference.len(),
+            parse_reference(bible_reference)?,
+        )]);
+    };
+
+    let book_and_chapter = &bible_reference[..verses_start];
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let verses = bible_reference[item.clone()].trim();
+            if verses.is_empty() {
+                return Err(Box::new(ReferenceIsEmptyError) as Box<dyn Error>);
+            }
+            let search_result = parse_reference(&format!("{}{}", book_and_chapter, verses))?;
+            let verses_offset = item.start + bible_reference[item].find(verses).unwrap();
+            let item_start = match index {
+                0 => 0,
+                _ => verses_offset,
+            };
+            Ok((item_start..verses_offset + verses.len(), search_result))
+        })
+        .collect()
+}
+
+/// Splits a list of verses like "John 3:16,18,20" into its items at every comma after the first verse (see [parse_verse_list]).
+/// # Returns
+/// - [None] if the reference has no verses after the chapter, e.g. "John 3"
+/// - The byte position where the verses start and the byte ranges of the verses of all items otherwise, e.g. "16", "18" and "20"
+fn verse_list_items(bible_reference: &str) -> Option<(usize, Vec<Range<usize>>)> {
+    // Like in parse_single_reference, the book name ends with the first digit (except for the first character)
+    let chapter_start = bible_reference
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| c.is_numeric())
+        .map(|(index, _)| index)?;
+    let delimiter_start = bible_reference[chapter_start..]
+        .find(|c: char| !c.is_numeric())
+        .map(|index| chapter_start + index)?;
+    let verses_start = bible_reference[delimiter_start..]
+        .find(|c: char| c.is_numeric())
+        .map(|index| delimiter_start + index)?;
+
+    let mut items = vec![];
+    let mut item_start = verses_start;
+    for (index, character) in bible_reference[verses_start..].char_indices() {
+        if matches!(character, ',' | '，') {
+            items.push(item_start..verses_start + index);
+            item_start = verses_start + index + character.len_utf8();
+        }
+    }
+    items.push(item_start..bible_reference.len());
+    Some((verses_start, items))
+}
+
+/// Returns true if the reference is a list of several verses like "John 3:16,18", which can only be parsed with [parse_verse_list].
+/// A comma after a range delimiter is the chapter/verse delimiter of the end of the range in languages like German (e.g. "Joh 3,16-4,2"), so such references are no list.
+fn is_verse_list(bible_reference: &str) -> bool {
+    let bible_reference = bible_reference.trim();
+    match verse_list_items(bible_reference) {
+        Some((_, items)) => {
+            items.len() > 1 && !bible_reference[items[0].clone()].contains(RANGE_DELIMITER_VARIANTS)
+        }
+        None => false,
+    }
+}
+
+/// Searches the first Bible reference within a longer text (e.g. a paragraph of a sermon) and returns its position, e.g. for highlighting or linking it.
+/// A reference is recognized if it starts with a book name of any registered language (see [parse_leading_book]) which is followed by a chapter and/or verse,
+/// so that names like "John" or "Acts" in ordinary sentences are not mistaken for a reference. Of all possible endings, the longest valid reference is used (e.g. "John 3:16-18" instead of "John 3:16").
+/// A reference is never shortened in front of a delimiter followed by a number or in front of an "f"/"ff" suffix, so an invalid reference like "John 3:300" or "John 3:36ff" is not mistaken for a different valid one.
+///
+/// To avoid false matches in prose, the book name has to start at the beginning of a word and must not start with a lowercase letter (e.g. "is 3" is not recognized as Isaiah 3).
+/// Chapter and verse numbers have to be written with (ASCII or fullwidth) digits.
+///
+/// # Arguments
+/// - `text`: The text which is searched.
+/// # Returns
+/// - [None] if the text does not contain any Bible reference.
+/// - [`Some`] with the byte range of the reference within `text` and the parsed reference otherwise.
+/// # Example
+/// ```
+/// use bibleref::referencing::parser::find_reference_in;
+/// let text = "As it is written in Joh 3,16-18, God loved the world.";
+/// let (span, result) = find_reference_in(text).unwrap();
+/// assert_eq!(&text[span], "Joh 3,16-18");
+/// assert_eq!(result.language_code(), "de");
+/// assert_eq!(result.bible_reference().to_string(), "John 3:16-18");
+/// ```
+pub fn find_reference_in(
+    text: &str,
+) -> Option<(Range<usize>, BibleReferenceRepresentationSearchResult)> {
+    find_references_after(text, 0).and_then(|found_references| found_references.into_iter().next())
+}
+
+/// Searches the first Bible reference within `text` like [find_reference_in], but only references which start at the byte position `offset` or later are considered.
+/// A list of verses like "John 3:16,18" results in one item per verse (see [parse_verse_list]).
+pub(crate) fn find_references_after(text: &str, offset: usize) -> Option<ReferencesWithSpans> {
+    let mut previous_character = text[..offset].chars().next_back();
+    // The end of a book name which is not followed by a valid reference
+    let mut skipped_until = offset;
+    for (start, character) in text[offset..].char_indices() {
+        let start = offset + start;
+        if start >= skipped_until && is_reference_start(previous_character, character) {
+            match parse_references_at(text, start) {
+                Some((_, Some(found_references))) => return Some(found_references),
+                // A part of the book name must not be read as another book, e.g. "John 2:1" in the invalid "3 John 2:1"
+                Some((book_name_end, None)) => skipped_until = book_name_end,
+                None => {}
+            }
+        }
+        previous_character = Some(character);
+    }
+    None
+}
+
+/// Determines whether a Bible reference may start with `character`, if it follows `previous_character` in a text.
+/// A reference has to start at the beginning of a word (except for scripts which are written without spaces, like Chinese and Japanese), and it must not start with a lowercase letter.
+fn is_reference_start(previous_character: Option<char>, character: char) -> bool {
+    if !character.is_alphanumeric() || character.is_lowercase() {
+        return false;
+    }
+    match previous_character {
+        Some(previous_character) if previous_character.is_alphanumeric() => {
+            is_written_without_spaces(previous_character) || is_written_without_spaces(character)
+        }
+        _ => true,
+    }
+}
+
+/// Returns true for the Chinese characters and the Japanese kana, as these scripts are written without spaces between the words.
+fn is_written_without_spaces(character: char) -> bool {
+    matches!(
+        character,
+        '\u{3040}'..='\u{30FF}' | '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}'
+    )
+}
+
+/// Returns true if the character may be part of the chapter and verse of a Bible reference (digits, spaces, delimiters and suffixes like "ff").
+fn is_chapter_verse_character(character: char) -> bool {
+    character.is_ascii_digit()
+        || ('０'..='９').contains(&character)
+        || character.is_whitespace()
+        || CHAPTER_VERSE_COLONS.contains(&character)
+        || RANGE_DELIMITER_VARIANTS.contains(&character)
+        || matches!(
+            character,
+            ',' | '，' | '.' | 'f' | '章' | '节' | '節' | '篇'
+        )
+}
+
+/// Tries to parse a Bible reference which starts at the byte position `start` of `text`.
+/// After the book name, all characters which may belong to the chapter and verse are collected, and the longest valid reference within them is returned.
+/// A list of verses like "John 3:16,18" results in one item per verse (see [parse_verse_list]).
+/// # Returns
+/// - [None] if no book name starts at `start`
+/// - The end of the book name and the found references otherwise, which are [None] if the book name is not followed by a valid reference
+fn parse_references_at(text: &str, start: usize) -> Option<(usize, Option<ReferencesWithSpans>)> {
+    let (_, rest, _) = parse_leading_book(&text[start..])?;
+    let book_name_end = text.len() - rest.len();
+    let chapter_verse_length = rest
+        .find(|c: char| !is_chapter_verse_character(c))
+        .unwrap_or(rest.len());
+
+    for (offset, last_character) in rest[..chapter_verse_length].char_indices().rev() {
+        let end = book_name_end + offset + last_character.len_utf8();
+        let following_text = &text[end..];
+        let is_possible_end = match last_character {
+            // A number must not be cut off, neither in front of a delimiter with a further number ("John 3:300" is no "John 3") nor in front of a suffix
+            '0'..='9' | '０'..='９' => {
+                !following_text.starts_with(|c: char| c.is_numeric())
+                    && !starts_with_delimited_number(following_text)
+                    && !starts_with_following_suffix(following_text)
+            }
+            // "f" and "ff" must not be the beginning of a word
+            'f' => !following_text.starts_with(char::is_alphanumeric),
+            '章' | '节' | '節' | '篇' => true,
+            // The reference must not end with a delimiter, e.g. "John 3:16 - the verse" is not an open range
+            _ => false,
+        };
+        // Numbers which are only separated by spaces do not belong together, e.g. "John 3:1 6 times"
+        if !is_possible_end || has_numbers_separated_by_whitespace(&text[book_name_end..end]) {
+            continue;
+        }
+        let candidate = &text[start..end];
+        let found_references = match is_verse_list(candidate) {
+            true => parse_verse_list_items(candidate),
+            false => parse_reference(candidate)
+                .map(|search_result| vec![(0..candidate.len(), search_result)]),
+        };
+        if let Ok(found_references) = found_references {
+            return Some((
+                book_name_end,
+                Some(
+                    found_references
+                        .into_iter()
+                        .map(|(span, search_result)| {
+                            (start + span.start..start + span.end, search_result)
+                        })
+                        .collect(),
+                ),
+            ));
+        }
+    }
+    Some((book_name_end, None))
+}
+
+/// Returns true if the text starts with a delimiter which is directly followed by a number, e.g. ":300" or ",18".
+fn starts_with_delimited_number(text: &str) -> bool {
+    let mut characters = text.chars();
+    let is_delimiter = characters.next().is_some_and(|character| {
+        CHAPTER_VERSE_COLONS.contains(&character)
+            || RANGE_DELIMITER_VARIANTS.contains(&character)
+            || matches!(character, ',' | '，' | '.')
+    });
+    is_delimiter && characters.next().is_some_and(char::is_numeric)
+}
+
+/// Returns true if the text starts with an "f" or "ff" suffix (optionally after spaces), e.g. "ff." or " f", but not with a word like "for".
+fn starts_with_following_suffix(text: &str) -> bool {
+    let text = text.trim_start();
+    text.strip_prefix("ff")
+        .or_else(|| text.strip_prefix('f'))
+        .is_some_and(|rest| !rest.starts_with(char::is_alphanumeric))
+}
+
+/// Returns true if two numbers are only separated by whitespace, e.g. " 3:1 6".
+fn has_numbers_separated_by_whitespace(chapter_and_verse: &str) -> bool {
+    let mut previous_character: Option<char> = None;
+    let mut follows_whitespace = false;
+    for character in chapter_and_verse.chars() {
+        if character.is_whitespace() {
+            follows_whitespace = true;
+            continue;
+        }
+        if follows_whitespace
+            && character.is_numeric()
+            && previous_character.is_some_and(char::is_numeric)
+        {
+            return true;
+        }
+        previous_character = Some(character);
+        follows_whitespace = false;
+    }
+    false
+}
+
+/// Parses a Bible reference like [parse_reference], but tolerates typos in the book name.
+/// If the book name cannot be found, it is replaced by the closest book name of all registered languages (compared case- and diacritic-insensitively), as long as their Levenshtein distance is at most `max_distance`.
+///
+/// # Arguments
+/// - `bible_reference`: A human readable Bible reference.
+/// - `max_distance`: The maximum number of inserted, deleted or replaced characters to get from the provided book name to a known one.
+/// # Returns
+/// - A result with either a [BibleReferenceRepresentationSearchResult] or a [`Box<dyn Error>`] with an appropriate error message.
+///   An [AmbiguousBookNameError] is returned if several books are equally close to the provided book name.
+/// # Example
+/// ```
+/// use bibleref::referencing::parser::parse_reference_fuzzy;
+/// let result = parse_reference_fuzzy("Philipians 4:13", 2).unwrap();
+/// assert_eq!(result.bible_reference().to_string(), "Philippians 4:13");
+/// assert!(parse_reference_fuzzy("Philipians 4:13", 0).is_err());
+/// ```
+pub fn parse_reference_fuzzy(
+    bible_reference: &str,
+    max_distance: usize,
+) -> Result<BibleReferenceRepresentationSearchResult, Box<dyn Error>> {
+    let bible_reference = bible_reference.trim();
+    let error = match parse_reference(bible_reference) {
+        Ok(result) => return Ok(result),
+        Err(error) => error,
+    };
+    let Some(not_found_error) = error.downcast_ref::<BibleBookNotFoundError>() else {
+        return Err(error);
+    };
+    let Some(book_name) =
+        find_closest_book_name(&not_found_error.provided_bible_book_string, max_distance)?
+    else {
+        return Err(error);
+    };
+
+    // The book name is always at the beginning of the reference, but its spaces have been removed
+    let mut remaining_characters = not_found_error.provided_bible_book_string.chars().count();
+    let book_name_end = bible_reference
+        .char_indices()
+        .filter(|(_, c)| *c != ' ')
+        .find(|_| {
+            if remaining_characters == 0 {
+                return true;
+            }
+            remaining_characters -= 1;
+            false
+        })
+        .map(|(index, _)| index)
+        .unwrap_or(bible_reference.len());
+
+    parse_reference(&format!(
+        "{}{}",
+        book_name,
+        &bible_reference[book_name_end..]
+    ))
+}
+
+/// Finds the book name (in any registered language) with the smallest Levenshtein distance to `book_name`.
+/// # Returns
+/// - [None] if no book name is within `max_distance`.
+/// - An [AmbiguousBookNameError] if names of several books have the same smallest distance.
+fn find_closest_book_name(
+    book_name: &str,
+    max_distance: usize,
+) -> Result<Option<String>, AmbiguousBookNameError> {
+    let languages = &*REFERENCE_LANGUAGES.read().unwrap();
+    let normalized_book_name = normalize_book_name(book_name);
+
+    let mut best_distance = max_distance;
+    let mut candidates: Vec<(BibleBook, String)> = vec![];
+    for language in languages {
+        for (book, names) in language
+            .long_names
+            .iter()
+            .chain(language.short_names.iter())
+        {
+            for name in names {
+                let distance = levenshtein_distance(
+                    &normalized_book_name,
+                    &normalize_book_name(&name.replace(" ", "")),
+                );
+                if distance < best_distance {
+                    best_distance = distance;
+                    candidates.clear();
+                }
+                if distance == best_distance
+                    && !candidates.iter().any(|(candidate, _)| candidate == book)
+                {
+                    candidates.push((*book, name.clone()));
+                }
+            }
+        }
+    }
+
+    match candidates.len() {
+        0 => Ok(None),
+        1 => Ok(Some(candidates.remove(0).1)),
+        _ => {
+            let mut books: Vec<BibleBook> = candidates.into_iter().map(|(book, _)| book).collect();
+            books.sort();
+            Err(AmbiguousBookNameError {
+                provided_bible_book_string: book_name.to_string(),
+                candidates: books,
+            })
+        }
+    }
+}
+
+/// Returns the Levenshtein distance of two strings, i.e. the minimal number of inserted, deleted or replaced characters to get from `a` to `b`.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current_row = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let replace_cost = previous_row[j] + usize::from(a_char != *b_char);
+            current_row.push(
+                replace_cost
+                    .min(previous_row[j + 1] + 1)
+                    .min(current_row[j] + 1),
+            );
+        }
+        previous_row = current_row;
+    }
+    previous_row[b.len()]
+}
+
+/// Parses a verse or chapter reference with a trailing "f"/"f." (the verse or chapter and the following one) or "ff"/"ff." (the verse and all following verses of the chapter or the chapter and all following chapters of the book) suffix.
+/// The suffix may be attached directly to the number or separated by spaces.
+///
+/// # Arguments
+/// - `bible_reference`: A human readable Bible reference.
+/// # Returns
+/// - [None] if the reference does not end with such a suffix or the rest is not a verse reference.
+/// - [`Some`] with the result of the expansion into a [BibleRange] otherwise. An error is returned if there is no following verse or chapter, both for "f" and "ff".
+fn parse_following_verses_reference(
+    bible_reference: &str,
+) -> Option<Result<BibleReferenceRepresentationSearchResult, Box<dyn Error>>> {
+    let trimmed_reference = bible_reference.trim();
+    let trimmed_reference = trimmed_reference
+        .strip_suffix('.')
+        .unwrap_or(trimmed_reference);
+
+    let (verse_part, all_following) = match trimmed_reference.strip_suffix("ff") {
+        Some(verse_part) => (verse_part, true),
+        None => (trimmed_reference.strip_suffix('f')?, false),
+    };
+    // The suffix has to follow a verse number directly (or separated by spaces)
+    if !verse_part.trim_end().ends_with(|c: char| c.is_numeric()) {
+        return None;
+    }
+
+    let search_result = parse_single_reference(verse_part.to_string()).ok()?;
+    // The following verse or chapter has to exist, also if all following ones are meant
+    let range = match search_result.bible_reference() {
+        BibleReference::BibleVerse(start) => BibleVerseReference::new(
+            start.book(),
+            start.chapter(),
+            start.verse().saturating_add(1),
+        )
+        .and_then(|following| {
+            let end = match all_following {
+                true => BibleVerseReference::new(
+                    start.book(),
+                    start.chapter(),
+                    get_number_of_verses(&start.book(), &start.chapter())?,
+                )?,
+                false => following,
+            };
+            BibleRange::new(
+                BibleReference::BibleVerse(start.clone()),
+                BibleReference::BibleVerse(end),
+            )
+        }),
+        BibleReference::BibleChapter(start) => BibleChapterReference::new(
+            start.book(),
+            start.chapter().saturating_add(1),
+        )
+        .and_then(|following| {
+            let end = match all_following {
+                true => {
+                    BibleChapterReference::new(start.book(), get_number_of_chapters(&start.book()))?
+                }
+                false => following,
+            };
+            BibleRange::new(
+                BibleReference::BibleChapter(start.clone()),
+                BibleReference::BibleChapter(end),
+            )
+        }),
+        BibleReference::BibleBook(_) => return None,
+    };
+
+    Some(
+        range
+            .map(|range| {
+                BibleReferenceRepresentationSearchResult::new(
+                    BibleReferenceRepresentation::Range(range),
+                    search_result.language_code().clone(),
+                    *search_result.reference_type(),
+                )
+            })
+            .map_err(|err| Box::new(err) as Box<dyn Error>),
+    )
+}
+
+/// Parses an open range reference, i.e. a reference followed by a range delimiter without an end (e.g. "John 3:16-" or "Genesis 1-").
+/// The range is extended to the end of the smallest enclosing unit of the start:
+/// - A verse is extended to the last verse of its chapter.
+/// - A chapter is extended to the last chapter of its book.
+/// - A book is extended to the last book of the Bible.
+///
+/// # Arguments
+/// - `bible_reference`: A human readable Bible reference.
+/// # Returns
+/// - [None] if the reference does not end with a range delimiter.
+/// - [`Some`] with the open range otherwise. An error is returned if the start of the range is not a valid reference.
+fn parse_open_range_reference(
+    bible_reference: &str,
+) -> Option<Result<BibleReferenceRepresentationSearchResult, Box<dyn Error>>> {
+    let trimmed_reference = bible_reference.trim();
+    let start_part = match trimmed_reference.strip_suffix(RANGE_DELIMITER_VARIANTS) {
+        Some(start_part) => start_part,
+        None => REFERENCE_LANGUAGES
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|language| !language.range_delimiter.is_empty())
+            .find_map(|language| {
+                trimmed_reference.strip_suffix(language.range_delimiter.as_str())
+            })?,
+    };
+    // A range delimiter on its own is not an open range
+    if start_part.trim().is_empty() {
+        return None;
+    }
+
+    let search_result = match parse_single_reference(start_part.to_string()) {
+        Ok(search_result) => search_result,
+        Err(err) => return Some(Err(err)),
+    };
+    let end = match search_result.bible_reference() {
+        BibleReference::BibleVerse(start) => get_number_of_verses(&start.book(), &start.chapter())
+            .and_then(|last_verse| {
+                BibleVerseReference::new(start.book(), start.chapter(), last_verse)
+                    .map(BibleReference::BibleVerse)
+            }),
+        BibleReference::BibleChapter(start) => {
+            BibleChapterReference::new(start.book(), get_number_of_chapters(&start.book()))
+                .map(BibleReference::BibleChapter)
+        }
+        BibleReference::BibleBook(_) => {
+            let last_book = *BibleBook::all().last().unwrap();
+            Ok(BibleReference::BibleBook(BibleBookReference::new(
+                last_book,
+            )))
+        }
+    };
+
+    Some(
+        end.and_then(|end| BibleRange::new(search_result.bible_reference().clone(), end))
+            .map(|range| {
+                BibleReferenceRepresentationSearchResult::new(
+                    BibleReferenceRepresentation::Range(range),
+                    search_result.language_code().clone(),
+                    *search_result.reference_type(),
+                )
+            })
+            .map_err(|err| Box::new(err) as Box<dyn Error>),
+    )
+}
+
 impl BibleReferenceSearchResult {
     /// Creates a new BibleReferenceSearchResult.
     ///
@@ -181,6 +784,9 @@ impl BibleReferenceSearchResult {
 /// Gets a (internal) Bible reference and the language code of a given human readable reference.
 /// Returns an error if parsing fails.
 ///
+/// A single number after a book with only one chapter (Obadiah, Philemon, 2 John, 3 John and Jude) is interpreted as a verse of its first chapter, e.g. "Jude 13" as Jude 1:13.
+/// After all other books, a single number is a chapter.
+///
 /// # Arguments
 /// - `reference`: A human readable Bible reference.
 /// # Returns
@@ -210,7 +816,7 @@ pub fn parse_single_reference(
     }
 
     // We remove all spaces in the string as we don't need them
-    let binding = reference.replace(" ", "");
+    let binding = normalize_numbers(&reference).replace(" ", "");
     let reference = binding.trim();
 
     let mut reference_book_str: String = "".to_string();
@@ -259,8 +865,20 @@ pub fn parse_single_reference(
                     language,
                     book_reference_type,
                 )),
+                (0.., 0) if get_number_of_chapters(&bible_book) == 1 => {
+                    // Books with only one chapter are quoted by verse only, e.g. "Jude 13" for Jude 1:13
+                    let verse: u8 = reference_chapter_str.parse()?;
+                    match BibleVerseReference::new(bible_book, 1, verse) {
+                        Ok(verse_reference) => Ok(BibleReferenceSearchResult::new(
+                            BibleReference::BibleVerse(verse_reference),
+                            language.clone(),
+                            book_reference_type,
+                        )),
+                        Err(err) => Err(Box::new(err)),
+                    }
+                }
                 (0.., 0) => {
-                    let chapter: u8 = reference_chapter_str.parse().unwrap();
+                    let chapter: u8 = reference_chapter_str.parse()?;
                     match BibleChapterReference::new(bible_book, chapter) {
                         Ok(chapter_reference) => Ok(BibleReferenceSearchResult::new(
                             BibleReference::BibleChapter(chapter_reference),
@@ -271,8 +889,8 @@ pub fn parse_single_reference(
                     }
                 }
                 (0.., 0..) => {
-                    let chapter: u8 = reference_chapter_str.parse().unwrap();
-                    let verse: u8 = reference_verse_str.parse().unwrap();
+                    let chapter: u8 = reference_chapter_str.parse()?;
+                    let verse: u8 = reference_verse_str.parse()?;
 
                     match BibleVerseReference::new(bible_book, chapter, verse) {
                         Ok(verse_reference) => Ok(BibleReferenceSearchResult::new(
@@ -315,6 +933,8 @@ pub fn parse_single_reference(
 /// - [`BibleRangeParsingError::DelimiterNotFound`]: The delimiter between the two parts of the range reference is not found.
 /// - [`BibleRangeParsingError::InvalidFirstPart`]: The first part of the range reference is invalid.
 /// - [`BibleRangeParsingError::InvalidSecondPart`]: The second part of the range reference is invalid.
+/// - [`BibleRangeParsingError::AmbiguousRangeGranularity`]: Start and end have different granularities and the range is ambiguous.
+///   A finer end after the start is accepted (e.g. "John 3-4:2" or "Genesis-Exodus 3"), a coarser end (e.g. "John 3:16-John 4") or a finer end within the start (e.g. "John 3-3:16") is rejected.
 /// - [`LanguageHasNoChapterVersDelimiterError`]: The language has no chapter/verse delimiter.
 pub fn parse_range_reference(
     range_reference: String,
@@ -322,6 +942,18 @@ pub fn parse_range_reference(
     if range_reference.is_empty() {
         return Err(Box::new(ReferenceIsEmptyError));
     }
+    let range_reference = normalize_numbers(&range_reference);
+
+    // Without any range delimiter, there is no need to search for the first part
+    let contains_range_delimiter = range_reference.contains(RANGE_DELIMITER_VARIANTS)
+        || REFERENCE_LANGUAGES
+            .read()
+            .unwrap()
+            .iter()
+            .any(|language| range_reference.contains(language.range_delimiter.as_str()));
+    if !contains_range_delimiter {
+        return Err(Box::new(BibleRangeParsingError::DelimiterNotFound));
+    }
 
     // We remove all spaces in the string as we don't need them
     let binding = range_reference.replace(" ", "");
@@ -329,77 +961,111 @@ pub fn parse_range_reference(
 
     // Traverse the string and try to get a reference out of it
     let mut current_part: String = "".to_string();
-    let mut first_search_result_option: Option<BibleReferenceSearchResult> = None;
 
     for c in reference.chars() {
         current_part.push(c);
 
         if let Ok(reference) = parse_single_reference(current_part.clone()) {
-            // We have found a valid reference
-            first_search_result_option = Some(reference);
-            // Now get the language code and the range delimiter
-            let language =
-                get_language_by_code(first_search_result_option.clone().unwrap().language_code())
-                    .unwrap();
-            // Split the current part by the range delimiter
-            let parts: Vec<&str> = range_reference
-                .split(language.range_delimiter.as_str())
-                .collect();
-            if parts.len() < 2 {
-                return Err(Box::new(BibleRangeParsingError::DelimiterNotFound));
-            }
-            match parse_single_reference(parts[0].to_string()) {
-                Ok(reference) => {
-                    // We have found the first part of the range
-                    let first_found_reference = reference.bible_reference().clone();
-                    let chapter_vers_delimiter = match language.chapter_vers_delimiters.first() {
-                        Some(delimiter) => delimiter,
-                        None => {
-                            return Err(Box::new(LanguageHasNoChapterVersDelimiterError {
-                                language_code: language.language_code.clone(),
-                            }));
-                        }
-                    };
-                    match parse_second_range_part(
-                        &first_found_reference,
-                        chapter_vers_delimiter,
-                        parts[1].to_string(),
-                    ) {
-                        Ok(second_found_reference) => {
-                            // We have found the second part of the range
-                            let range =
-                                BibleRange::new(first_found_reference, second_found_reference)
-                                    .unwrap();
-                            return Ok(BibleReferenceRepresentationSearchResult::new(
-                                BibleReferenceRepresentation::Range(range),
-                                reference.language_code().clone(),
-                                *reference.reference_type(),
-                            ));
-                        }
-                        Err(error) => {
-                            // The second part is invalid
-                            return Err(error);
-                        }
+            // We have found a valid reference, now get the language code and the range delimiter
+            let language = get_language_by_code(reference.language_code()).unwrap();
+            // Dash variants and tildes are accepted as well and mapped to the language's range delimiter
+            let range_reference =
+                normalize_range_delimiter(&range_reference, &language.range_delimiter);
+            // The range delimiter might also be part of a book name (e.g. "Nê-hê-mi" in Vietnamese), so it is only searched after the leading book name.
+            // As a shorter book name might have been found (e.g. if the diacritics are missing), every occurrence of the delimiter is tried.
+            // If no occurrence separates a valid range, the error of the first occurrence with a recognized first part is returned.
+            let book_name_end = parse_leading_book(&range_reference)
+                .map_or(0, |(_, rest, _)| range_reference.len() - rest.len());
+            let mut first_error: Option<Box<dyn Error>> = None;
+            for (position, delimiter) in
+                range_reference[book_name_end..].match_indices(language.range_delimiter.as_str())
+            {
+                let position = book_name_end + position;
+                let first_part = &range_reference[..position];
+                let second_part = &range_reference[position + delimiter.len()..];
+                match parse_range_parts(first_part, second_part) {
+                    Ok(result) => return Ok(result),
+                    Err(Some(error)) => {
+                        first_error.get_or_insert(error);
                     }
-                }
-                Err(_) => {
-                    // The first part is invalid
-                    return Err(Box::new(BibleRangeParsingError::InvalidFirstPart));
+                    Err(None) => {}
                 }
             }
+            return Err(first_error.unwrap_or_else(|| {
+                // The first part is invalid
+                Box::new(BibleRangeParsingError::InvalidFirstPart)
+            }));
         }
     }
 
-    dbg!(&first_search_result_option);
-
     Err(Box::new(BibleRangeParsingError::InvalidFirstPart))
 }
 
+/// Parses the two parts of a range reference which have been separated at the range delimiter.
+/// # Returns
+/// - The range if both parts are valid.
+/// - An error if the first part has been recognized, but the first part does not exist in the Bible or the second part is invalid.
+/// - `Err(None)` if the first part has not been recognized.
+fn parse_range_parts(
+    first_part: &str,
+    second_part: &str,
+) -> Result<BibleReferenceRepresentationSearchResult, Option<Box<dyn Error>>> {
+    match parse_single_reference(first_part.to_string()) {
+        Ok(reference) => {
+            // We have found the first part of the range. Its language determines the chapter/verse delimiter,
+            // as the language found while traversing might only match a prefix of the book name (e.g. "Joh" for "John").
+            let first_found_reference = reference.bible_reference().clone();
+            let language = get_language_by_code(reference.language_code()).unwrap();
+            if language.chapter_vers_delimiters.is_empty() {
+                return Err(Some(Box::new(LanguageHasNoChapterVersDelimiterError {
+                    language_code: language.language_code.clone(),
+                })));
+            }
+            // We have found the second part of the range. Spaces around the delimiters are ignored (e.g. "John 3:16 - 4 : 2").
+            let second_found_reference = parse_second_range_part(
+                &first_found_reference,
+                &language.chapter_vers_delimiters,
+                second_part.split_whitespace().collect(),
+            )?;
+            if has_ambiguous_granularity(&first_found_reference, &second_found_reference) {
+                return Err(Some(Box::new(
+                    BibleRangeParsingError::AmbiguousRangeGranularity,
+                )));
+            }
+            let range = BibleRange::new(first_found_reference, second_found_reference)
+                .map_err(|error| Some(Box::new(error) as Box<dyn Error>))?;
+            Ok(BibleReferenceRepresentationSearchResult::new(
+                BibleReferenceRepresentation::Range(range),
+                reference.language_code().clone(),
+                *reference.reference_type(),
+            ))
+        }
+        // The first part has been recognized, but does not exist in the Bible (e.g. "Jude 30" in "Jude 30-31")
+        Err(error) if error.is::<BibleReferenceValidationError>() => Err(Some(error)),
+        Err(_) => Err(None),
+    }
+}
+
+/// Checks whether a range from `start` to `end` is ambiguous because of different granularities.
+/// A range is accepted if both references have the same granularity or if the end is finer than the start and lies after it, e.g. "John 3-4:2" (John 3:1-4:2) or "Genesis-Exodus 3".
+/// It is ambiguous if
+/// - the end is coarser than the start (e.g. "John 3:16-John 4"), as it is unclear whether the end is included completely, or
+/// - the end is finer than the start and lies within it (e.g. "John 3-3:16"), as the start does not mark the beginning of the passage clearly.
+fn has_ambiguous_granularity(start: &BibleReference, end: &BibleReference) -> bool {
+    match start.granularity().cmp(&end.granularity()) {
+        Ordering::Equal => false,
+        Ordering::Greater => true,
+        Ordering::Less => BibleRange::new(start.clone(), start.clone())
+            .map(|start_range| start_range.contains(end))
+            .unwrap_or(true),
+    }
+}
+
 /// Parses the second part of a range reference.
 /// The second part could be a complete reference or just a chapter or verse number (e.g. "1" or "1,3").
 /// # Arguments
 /// - `first_part`: The first part of the range reference.
-/// - `chapter_vers_delimiter`: The delimiter between the chapter and verse.
+/// - `chapter_vers_delimiters`: The delimiters between the chapter and verse of the language, each of them is accepted.
 /// - `part_string`: The second part of the range reference.
 /// # Returns
 /// - A result with either a [BibleReference] or a [`Box<dyn Error>`] with an appropriate error message.
@@ -408,7 +1074,7 @@ pub fn parse_range_reference(
 /// - [`BibleRangeParsingError::NoSecondPartProvided`]: The second part of the range reference is empty.
 fn parse_second_range_part(
     first_part: &BibleReference,
-    chapter_vers_delimiter: &str,
+    chapter_vers_delimiters: &[String],
     part_string: String,
 ) -> Result<BibleReference, Box<dyn Error>> {
     match parse_single_reference(part_string.clone()) {
@@ -417,8 +1083,18 @@ fn parse_second_range_part(
             Ok(reference_search_result.bible_reference)
         }
         Err(_) => {
-            // Try to split the part string by the chapter/verse delimiter
-            let parts: Vec<&str> = part_string.split(chapter_vers_delimiter).collect();
+            // Try to split the part string by any chapter/verse delimiter of the language (ASCII and fullwidth colons are interchangeable)
+            let parts: Vec<&str> = chapter_vers_delimiters
+                .iter()
+                .map(|delimiter| -> Vec<&str> {
+                    if delimiter.chars().all(|c| CHAPTER_VERSE_COLONS.contains(&c)) {
+                        part_string.split(CHAPTER_VERSE_COLONS).collect()
+                    } else {
+                        part_string.split(delimiter.as_str()).collect()
+                    }
+                })
+                .find(|parts| parts.len() > 1)
+                .unwrap_or_else(|| vec![part_string.as_str()]);
             match parts.len() {
                 2 => {
                     // Check that both parts are numeric
@@ -428,26 +1104,34 @@ fn parse_second_range_part(
                         return Err(Box::new(BibleRangeParsingError::InvalidSecondPart));
                     }
                     // We have found numbers which are valid
-                    let chapter: u8 = parts[0].parse().unwrap();
-                    let verse: u8 = parts[1].parse().unwrap();
+                    let (Ok(chapter), Ok(verse)) = (parts[0].parse::<u8>(), parts[1].parse::<u8>())
+                    else {
+                        return Err(Box::new(BibleRangeParsingError::InvalidSecondPart));
+                    };
                     match first_part {
                         BibleReference::BibleVerse(reference) => {
                             // We have a verse reference, so we can create a new verse reference
-                            Ok(BibleReference::BibleVerse(
-                                BibleVerseReference::new(reference.book(), chapter, verse).unwrap(),
-                            ))
+                            Ok(BibleReference::BibleVerse(BibleVerseReference::new(
+                                reference.book(),
+                                chapter,
+                                verse,
+                            )?))
                         }
                         BibleReference::BibleChapter(reference) => {
                             // We have a chapter reference, so we can create a new verse reference
-                            Ok(BibleReference::BibleVerse(
-                                BibleVerseReference::new(reference.book(), chapter, verse).unwrap(),
-                            ))
+                            Ok(BibleReference::BibleVerse(BibleVerseReference::new(
+                                reference.book(),
+                                chapter,
+                                verse,
+                            )?))
                         }
                         BibleReference::BibleBook(reference) => {
                             // We have a book reference, so we can create a new chapter reference
-                            Ok(BibleReference::BibleVerse(
-                                BibleVerseReference::new(reference.book(), chapter, verse).unwrap(),
-                            ))
+                            Ok(BibleReference::BibleVerse(BibleVerseReference::new(
+                                reference.book(),
+                                chapter,
+                                verse,
+                            )?))
                         }
                     }
                 }
@@ -460,20 +1144,18 @@ fn parse_second_range_part(
                     match first_part {
                         BibleReference::BibleVerse(reference) => {
                             // We have a verse reference, so we can create a new chapter reference
-                            Ok(BibleReference::BibleVerse(
-                                BibleVerseReference::new(
-                                    reference.book(),
-                                    reference.chapter(),
-                                    number,
-                                )
-                                .unwrap(),
-                            ))
+                            Ok(BibleReference::BibleVerse(BibleVerseReference::new(
+                                reference.book(),
+                                reference.chapter(),
+                                number,
+                            )?))
                         }
                         BibleReference::BibleChapter(reference) => {
                             // We have a chapter reference, so we can create a new chapter reference
-                            Ok(BibleReference::BibleChapter(
-                                BibleChapterReference::new(reference.book(), number).unwrap(),
-                            ))
+                            Ok(BibleReference::BibleChapter(BibleChapterReference::new(
+                                reference.book(),
+                                number,
+                            )?))
                         }
                         BibleReference::BibleBook(_) => {
                             // We have a book reference, so we can create a new chapter reference
@@ -490,17 +1172,266 @@ fn parse_second_range_part(
     }
 }
 
-fn find_book_in_any_language(book_name: &str) -> Option<(BibleBook, String, BookReferenceType)> {
+/// Parses only the book name at the beginning of `input`, e.g. for building a custom grammar on top of this crate.
+/// The longest book name of all registered languages is used. Like in [parse_reference], the comparison ignores the case and the spaces within the name (e.g. "1john" matches "1 John") and long names also match without their diacritics (e.g. "Genese" matches "Genèse").
+/// The book name has to be followed by the end of the input or by a character which is not a letter, so "Johnny" is not recognized as John.
+///
+/// # Arguments
+/// - `input`: A string which starts with a book name.
+/// # Returns
+/// - [None] if the input does not start with a book name.
+/// - [`Some`] with the [BibleBook], the remainder of `input` after the book name (starting directly after its last character) and the type of the matched name.
+/// # Example
+/// ```
+/// use bibleref::bible::BibleBook;
+/// use bibleref::referencing::language::BookReferenceType;
+/// use bibleref::referencing::parser::parse_leading_book;
+/// assert_eq!(parse_leading_book("1 John 3:16 and more"), Some((BibleBook::IJohn, " 3:16 and more", BookReferenceType::Long)));
+/// assert_eq!(parse_leading_book("Johannes 3,16"), Some((BibleBook::John, " 3,16", BookReferenceType::Long)));
+/// assert_eq!(parse_leading_book("genese 1:1"), Some((BibleBook::Genesis, " 1:1", BookReferenceType::Long)));
+/// assert_eq!(parse_leading_book("Johnny 3"), None);
+/// ```
+pub fn parse_leading_book(input: &str) -> Option<(BibleBook, &str, BookReferenceType)> {
     let languages = &*REFERENCE_LANGUAGES.read().unwrap();
 
+    let mut longest_match: Option<(BibleBook, usize, BookReferenceType)> = None;
     for language in languages {
-        let result = find_book_in_certain_language(book_name, language);
-        if result.is_some() {
-            return result;
+        for (names, reference_type) in [
+            (&language.long_names, BookReferenceType::Long),
+            (&language.short_names, BookReferenceType::Short),
+        ] {
+            // Only long names are compared without their diacritics (see find_book_in_any_language)
+            let fold_diacritics = reference_type == BookReferenceType::Long;
+            for (book, book_names) in names {
+                for name in book_names {
+                    let Some(end) = match_leading_book_name(input, name, fold_diacritics) else {
+                        continue;
+                    };
+                    if longest_match.is_none_or(|(_, longest_end, _)| end > longest_end) {
+                        longest_match = Some((*book, end, reference_type));
+                    }
+                }
+            }
         }
     }
 
-    None
+    longest_match.map(|(book, end, reference_type)| (book, &input[end..], reference_type))
+}
+
+/// Returns the byte offset in `input` directly after `name` if `input` starts with `name` (ignoring case, spaces and, if `fold_diacritics` is set, diacritics) and the name is not followed by a letter.
+/// The characters are compared one by one in their normalized form (see [normalize_book_name]), so that no string has to be allocated.
+fn match_leading_book_name(input: &str, name: &str, fold_diacritics: bool) -> Option<usize> {
+    if name.trim().is_empty() {
+        return None;
+    }
+    // Every normalized character of the input is paired with the end of the input character it stems from
+    let mut input_characters = input
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .flat_map(|(index, c)| {
+            normalize_book_name_character(c, fold_diacritics)
+                .map(move |n| (index + c.len_utf8(), n))
+        })
+        .peekable();
+    let mut end = 0;
+    for name_character in name
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(|c| normalize_book_name_character(c, fold_diacritics))
+    {
+        let (input_end, input_character) = input_characters.next()?;
+        if input_character != name_character {
+            return None;
+        }
+        end = input_end;
+    }
+    // The name must not end within an input character (e.g. a ligature)
+    if input_characters
+        .peek()
+        .is_some_and(|(input_end, _)| *input_end == end)
+    {
+        return None;
+    }
+    // Diacritics which are written as separate characters belong to the name
+    end += input[end..]
+        .chars()
+        .take_while(|c| {
+            !c.is_whitespace()
+                && normalize_book_name_character(*c, fold_diacritics)
+                    .next()
+                    .is_none()
+        })
+        .map(char::len_utf8)
+        .sum::<usize>();
+    match input[end..].starts_with(char::is_alphabetic) {
+        true => None,
+        false => Some(end),
+    }
+}
+
+/// The colons which are accepted interchangeably as chapter/verse delimiter.
+const CHAPTER_VERSE_COLONS: [char; 2] = [':', '：'];
+
+/// The characters which are accepted as range delimiter in addition to the language's own one.
+const RANGE_DELIMITER_VARIANTS: [char; 6] = ['-', '‒', '–', '—', '~', '～'];
+
+/// Replaces all range delimiter variants (hyphen, figure dash, en dash, em dash, tilde and fullwidth tilde) with the given range delimiter.
+/// # Params
+/// - `reference`: The reference string.
+/// - `range_delimiter`: The range delimiter of the language.
+/// # Returns
+/// - The reference string with a uniform range delimiter.
+fn normalize_range_delimiter(reference: &str, range_delimiter: &str) -> String {
+    let mut normalized = String::new();
+    for c in reference.chars() {
+        if RANGE_DELIMITER_VARIANTS.contains(&c) {
+            normalized.push_str(range_delimiter);
+        } else {
+            normalized.push(c);
+        }
+    }
+    normalized
+}
+
+/// Converts chapter and verse numbers into ASCII digits, so that they can be parsed:
+/// - Fullwidth digits are replaced by the corresponding ASCII digits, e.g. "约翰福音３：１６" becomes "约翰福音3：16".
+/// - Chinese numerals which are directly followed by a chapter or verse marker (章, 节, 節 or 篇) are converted into ASCII numbers, e.g. "约翰福音三章十六节" becomes "约翰福音3章16节".
+///   Other Chinese numerals are kept, as they may be part of a book name (e.g. 约翰一书).
+fn normalize_numbers(reference: &str) -> String {
+    let characters: Vec<char> = reference
+        .chars()
+        .map(|c| match c {
+            '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32).unwrap(),
+            _ => c,
+        })
+        .collect();
+
+    let mut normalized = String::new();
+    let mut index = 0;
+    while index < characters.len() {
+        let numeral_count = characters[index..]
+            .iter()
+            .take_while(|c| chinese_numeral_value(**c).is_some())
+            .count();
+        let followed_by_marker = characters
+            .get(index + numeral_count)
+            .is_some_and(|c| ['章', '节', '節', '篇'].contains(c));
+        if numeral_count > 0 && followed_by_marker {
+            normalized.push_str(
+                &parse_chinese_numeral(&characters[index..index + numeral_count]).to_string(),
+            );
+            index += numeral_count;
+        } else {
+            normalized.push(characters[index]);
+            index += 1;
+        }
+    }
+    normalized
+}
+
+/// Returns the value of a Chinese numeral character, where 十 (10) and 百 (100) are multipliers.
+fn chinese_numeral_value(c: char) -> Option<u32> {
+    match c {
+        '零' | '〇' => Some(0),
+        '一' => Some(1),
+        '二' | '两' | '兩' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        '十' => Some(10),
+        '百' => Some(100),
+        _ => None,
+    }
+}
+
+/// Parses a number written with Chinese numerals, e.g. 十六 (16), 二十三 (23) or 一百一十九 (119).
+fn parse_chinese_numeral(numerals: &[char]) -> u32 {
+    let mut total = 0;
+    let mut digit = 0;
+    for value in numerals.iter().filter_map(|c| chinese_numeral_value(*c)) {
+        match value {
+            10 | 100 => {
+                // A multiplier without a digit in front of it means one of it, e.g. 十六 is 16
+                total += digit.max(1) * value;
+                digit = 0;
+            }
+            _ => digit = value,
+        }
+    }
+    total + digit
+}
+
+fn find_book_in_any_language(book_name: &str) -> Option<(BibleBook, String, BookReferenceType)> {
+    let languages = &*REFERENCE_LANGUAGES.read().unwrap();
+    let all_reference_types = [BookReferenceType::Long, BookReferenceType::Short];
+
+    // Exact matches take precedence over case-insensitive ones and those over diacritic-insensitive ones,
+    // so that a less exact match in an earlier language does not shadow them (e.g. "Es" is Exodus in Italian, "És" is Isaiah in French).
+    // Short names are not compared without their diacritics, as they would collide with short names of other books (e.g. "Êxơ" is Esther in Vietnamese).
+    languages
+        .iter()
+        .find_map(|language| {
+            find_book_in_certain_language(
+                book_name,
+                language,
+                &all_reference_types,
+                name_matches_exactly,
+            )
+        })
+        .or_else(|| {
+            let lowercase_book_name = book_name.to_lowercase();
+            languages.iter().find_map(|language| {
+                find_book_in_certain_language(
+                    &lowercase_book_name,
+                    language,
+                    &all_reference_types,
+                    name_matches_ignoring_case,
+                )
+            })
+        })
+        .or_else(|| {
+            let normalized_book_name = normalize_book_name(book_name);
+            languages.iter().find_map(|language| {
+                find_book_in_certain_language(
+                    &normalized_book_name,
+                    language,
+                    &[BookReferenceType::Long],
+                    name_matches_normalized,
+                )
+            })
+        })
+}
+
+/// Normalizes a book name for a case- and diacritic-insensitive comparison, e.g. "Genèse" and "genese" both become "genese".
+/// The name is decomposed (NFD), the diacritics are removed (see [is_diacritic]) and the remaining characters are lowercased.
+pub(crate) fn normalize_book_name(book_name: &str) -> String {
+    book_name
+        .nfd()
+        .filter(|character| !is_diacritic(*character))
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Normalizes a single character like [normalize_book_name], but removes the diacritics only if `fold_diacritics` is set.
+/// A character may be normalized to several characters (e.g. "é" to "e" and a combining acute accent if the diacritics are kept) or to none (a combining diacritic).
+fn normalize_book_name_character(
+    character: char,
+    fold_diacritics: bool,
+) -> impl Iterator<Item = char> {
+    core::iter::once(character)
+        .nfd()
+        .filter(move |c| !(fold_diacritics && is_diacritic(*c)))
+        .flat_map(char::to_lowercase)
+}
+
+/// Returns whether a character of a decomposed (NFD) string is a diacritic which is ignored when comparing book names.
+/// These are all combining marks except the Japanese voiced sound marks, which distinguish different syllables (e.g. "エズ" for Ezra and "エス" for Esther).
+fn is_diacritic(character: char) -> bool {
+    is_combining_mark(character) && !matches!(character, '\u{3099}' | '\u{309A}')
 }
 
 /// Finds a book in any known language
@@ -509,6 +1440,8 @@ fn find_book_in_any_language(book_name: &str) -> Option<(BibleBook, String, Book
 ///
 /// * `book_name`: The name of the book to find.
 /// * `language`: The language in which the book name is written.
+/// * `reference_types`: The types of the names which are compared.
+/// * `matches`: A function which compares a name of the language with `book_name`, e.g. [name_matches_exactly] or [name_matches_normalized].
 ///
 /// # Returns
 ///
@@ -517,24 +1450,21 @@ fn find_book_in_any_language(book_name: &str) -> Option<(BibleBook, String, Book
 fn find_book_in_certain_language(
     book_name: &str,
     language: &ReferenceLanguage,
+    reference_types: &[BookReferenceType],
+    matches: fn(&str, &str) -> bool,
 ) -> Option<(BibleBook, String, BookReferenceType)> {
-    for book in language.long_names.keys() {
-        let language_long_space_removed: Vec<String> = language.long_names[book]
-            .iter()
-            .map(|str| str.replace(" ", ""))
-            .collect();
-        if language_long_space_removed.contains(&book_name.to_string()) {
+    let any_name_matches = |names: &Vec<String>| names.iter().any(|name| matches(name, book_name));
+    for (book, long_names) in &language.long_names {
+        if reference_types.contains(&BookReferenceType::Long) && any_name_matches(long_names) {
             return Some((
                 *book,
                 language.language_code.clone(),
                 BookReferenceType::Long,
             ));
         }
-        let language_short_space_removed: Vec<String> = language.short_names[book]
-            .iter()
-            .map(|str| str.replace(" ", ""))
-            .collect();
-        if language_short_space_removed.contains(&book_name.to_string()) {
+        if reference_types.contains(&BookReferenceType::Short)
+            && any_name_matches(&language.short_names[book])
+        {
             return Some((
                 *book,
                 language.language_code.clone(),
@@ -546,25 +1476,87 @@ fn find_book_in_certain_language(
     None
 }
 
+/// Returns whether a book name of a language equals `book_name` if the spaces of the name are ignored.
+fn name_matches_exactly(name: &str, book_name: &str) -> bool {
+    match name.contains(' ') {
+        true => name.chars().filter(|c| *c != ' ').eq(book_name.chars()),
+        false => name == book_name,
+    }
+}
+
+/// Returns whether a book name of a language equals the lowercased `lowercase_book_name` if the spaces and the case of the name are ignored.
+fn name_matches_ignoring_case(name: &str, lowercase_book_name: &str) -> bool {
+    name.chars()
+        .filter(|c| *c != ' ')
+        .flat_map(char::to_lowercase)
+        .eq(lowercase_book_name.chars())
+}
+
+/// Returns whether a book name of a language equals the normalized `normalized_book_name` (see [normalize_book_name]) if the spaces of the name are ignored.
+/// The name is normalized character by character, so that no string has to be allocated.
+fn name_matches_normalized(name: &str, normalized_book_name: &str) -> bool {
+    if name.is_ascii() {
+        return name
+            .bytes()
+            .filter(|c| *c != b' ')
+            .map(|c| c.to_ascii_lowercase())
+            .eq(normalized_book_name.bytes());
+    }
+    name.nfd()
+        .filter(|c| !is_diacritic(*c) && *c != ' ')
+        .flat_map(char::to_lowercase)
+        .eq(normalized_book_name.chars())
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
 
     #[test]
-    fn test_reference_finding() {
-        let reference = parse_single_reference("1. Mose 1,3".to_string()).unwrap();
+    fn test_book_name_containing_range_delimiter() {
+        // The Vietnamese name of Nehemiah ("Nê-hê-mi") contains the range delimiter
+        let reference = parse_reference("Nê-hê-mi 2:1").unwrap();
+        assert_eq!(reference.language_code(), "vi");
         assert_eq!(
-            *reference.bible_reference(),
-            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 3).unwrap())
+            reference.bible_reference(),
+            &BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Nehemiah, 2, 1).unwrap()
+            ))
         );
-        assert_eq!(reference.language_code(), "de");
-        assert_eq!(*reference.reference_type(), BookReferenceType::Long);
-
-        let reference = parse_single_reference("Joh 3".to_string()).unwrap();
 
+        let range_reference = parse_reference("Nê-hê-mi 2:1-5").unwrap();
+        assert_eq!(range_reference.language_code(), "vi");
         assert_eq!(
-            *reference.bible_reference(),
-            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap())
+            range_reference.bible_reference(),
+            &BibleReferenceRepresentation::Range(
+                BibleRange::new(
+                    BibleReference::BibleVerse(
+                        BibleVerseReference::new(BibleBook::Nehemiah, 2, 1).unwrap()
+                    ),
+                    BibleReference::BibleVerse(
+                        BibleVerseReference::new(BibleBook::Nehemiah, 2, 5).unwrap()
+                    )
+                )
+                .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_reference_finding() {
+        let reference = parse_single_reference("1. Mose 1,3".to_string()).unwrap();
+        assert_eq!(
+            *reference.bible_reference(),
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 3).unwrap())
+        );
+        assert_eq!(reference.language_code(), "de");
+        assert_eq!(*reference.reference_type(), BookReferenceType::Long);
+
+        let reference = parse_single_reference("Joh 3".to_string()).unwrap();
+
+        assert_eq!(
+            *reference.bible_reference(),
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap())
         );
         assert_eq!(reference.language_code(), "de");
         assert_eq!(*reference.reference_type(), BookReferenceType::Short);
@@ -608,4 +1600,907 @@ pub mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_following_verses_parsing() {
+        let expected = BibleReferenceRepresentation::Range(
+            BibleRange::new(
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+                ),
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, 3, 36).unwrap(),
+                ),
+            )
+            .unwrap(),
+        );
+        for reference in [
+            "John 3:16ff",
+            "John 3:16 ff",
+            "John 3:16ff.",
+            "Joh 3,16 ff.",
+        ] {
+            assert_eq!(
+                parse_reference(reference).unwrap().bible_reference(),
+                &expected
+            );
+        }
+
+        let range_reference = parse_reference("Rom 8:1f.").unwrap();
+        assert_eq!(
+            range_reference.bible_reference(),
+            &BibleReferenceRepresentation::Range(
+                BibleRange::new(
+                    BibleReference::BibleVerse(
+                        BibleVerseReference::new(BibleBook::Romans, 8, 1).unwrap()
+                    ),
+                    BibleReference::BibleVerse(
+                        BibleVerseReference::new(BibleBook::Romans, 8, 2).unwrap()
+                    )
+                )
+                .unwrap()
+            )
+        );
+
+        // There is no verse following John 3:36, neither for "f" nor for "ff"
+        for reference in [
+            "John 3:36f",
+            "John 3:36ff",
+            "Psalms 119:176f",
+            "Rev 22:21ff",
+        ] {
+            assert!(
+                parse_reference(reference)
+                    .err()
+                    .unwrap()
+                    .is::<BibleReferenceValidationError>(),
+                "{}",
+                reference
+            );
+        }
+        assert_eq!(
+            parse_reference("Psalms 119:175f")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "Psalms 119:175-176"
+        );
+
+        // Chapters are expanded in the same way
+        for (reference, expected) in [
+            ("John 3f", "John 3-4"),
+            ("John 3ff", "John 3-21"),
+            ("Rev 21f.", "Revelation 21-22"),
+        ] {
+            assert_eq!(
+                parse_reference(reference)
+                    .unwrap()
+                    .bible_reference()
+                    .to_string(),
+                expected
+            );
+        }
+        for reference in ["Rev 22f", "Rev 22ff"] {
+            assert!(
+                parse_reference(reference)
+                    .err()
+                    .unwrap()
+                    .is::<BibleReferenceValidationError>(),
+                "{}",
+                reference
+            );
+        }
+    }
+
+    #[test]
+    fn test_open_range_parsing() {
+        assert_eq!(
+            parse_reference("Genesis 1:1-")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "Genesis 1:1-31"
+        );
+        assert_eq!(
+            parse_reference("Genesis 1-")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "Genesis 1-50"
+        );
+        // Dash variants and the language's delimiter are accepted as well
+        assert_eq!(
+            parse_reference("Joh 3,16 –").unwrap().bible_reference(),
+            parse_reference("John 3:16-36").unwrap().bible_reference()
+        );
+        assert_eq!(
+            parse_reference("Jude-").unwrap().bible_reference(),
+            &BibleReferenceRepresentation::Range(
+                BibleRange::new(
+                    BibleReference::BibleBook(BibleBookReference::new(BibleBook::Jude)),
+                    BibleReference::BibleBook(BibleBookReference::new(
+                        *BibleBook::all().last().unwrap()
+                    ))
+                )
+                .unwrap()
+            )
+        );
+
+        // The start of the range has to be valid
+        assert!(
+            parse_reference("Genesis 51-")
+                .unwrap_err()
+                .is::<BibleReferenceValidationError>()
+        );
+        assert!(
+            parse_reference("Genesis 1:32-")
+                .unwrap_err()
+                .is::<BibleReferenceValidationError>()
+        );
+        assert!(parse_reference("Foo 1-").is_err());
+        assert!(parse_reference("-").is_err());
+    }
+
+    #[test]
+    fn test_find_reference_in() {
+        let find = |text: &'static str| {
+            find_reference_in(text)
+                .map(|(span, result)| (&text[span], result.bible_reference().to_string()))
+        };
+        assert_eq!(
+            find("As written in John 3:16, God loved the world."),
+            Some(("John 3:16", "John 3:16".to_string()))
+        );
+        assert_eq!(
+            find("Read 1 John 1:9 - it is short"),
+            Some(("1 John 1:9", "1 John 1:9".to_string()))
+        );
+        assert_eq!(
+            find("Read Joh 3,16-18 today"),
+            Some(("Joh 3,16-18", "John 3:16-18".to_string()))
+        );
+        assert_eq!(
+            find("See John 3:16ff. for details"),
+            Some(("John 3:16ff", "John 3:16-36".to_string()))
+        );
+        assert_eq!(
+            find("John 3:16 for God so loved the world"),
+            Some(("John 3:16", "John 3:16".to_string()))
+        );
+        assert_eq!(
+            find("请读约翰福音3:16。"),
+            Some(("约翰福音3:16", "John 3:16".to_string()))
+        );
+        assert_eq!(
+            find("Lies Genese 1:1"),
+            Some(("Genese 1:1", "Genesis 1:1".to_string()))
+        );
+        assert_eq!(find("See Exo 2:3"), None);
+        // An invalid reference is not shortened to a different valid one
+        for text in [
+            "Genesis 1:32 does not exist",
+            "See 3 John 2:1 here",
+            "See Jude 1:26 here",
+            "See John 3:300 here",
+            "See Psalm 1:0 here",
+            "See John 3:16-40 here",
+            "See John 3:36ff here",
+            "See John 3:36 f. here",
+            "See Rev 22f here",
+        ] {
+            assert_eq!(find(text), None, "{}", text);
+        }
+        assert_eq!(
+            find("John 3:16, 18 and 3:36"),
+            Some(("John 3:16", "John 3:16".to_string()))
+        );
+
+        // Names in ordinary sentences are not references
+        assert_eq!(find("John went home."), None);
+        assert_eq!(find("It is 3 miles away"), None);
+        assert_eq!(find("Johnny 3:16"), None);
+        assert_eq!(find(""), None);
+    }
+
+    #[test]
+    fn test_cross_chapter_range_parsing() {
+        let expected = BibleReferenceRepresentation::Range(
+            BibleRange::new(
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+                ),
+                BibleReference::BibleVerse(
+                    BibleVerseReference::new(BibleBook::John, 4, 2).unwrap(),
+                ),
+            )
+            .unwrap(),
+        );
+        let range_reference = parse_reference("John 3:16-4:2").unwrap();
+        assert_eq!(range_reference.language_code(), "en");
+        assert_eq!(range_reference.bible_reference(), &expected);
+        assert_eq!(
+            parse_reference("Joh 3,16-4,2").unwrap().bible_reference(),
+            &expected
+        );
+
+        // The start of the range must not be after the end
+        assert_eq!(
+            parse_reference("John 4:2-3:16")
+                .err()
+                .unwrap()
+                .downcast_ref::<BibleReferenceValidationError>()
+                .unwrap()
+                .problem,
+            crate::bible::errors::BibleReferenceProblem::StartReferenceAfterEndReference
+        );
+
+        // A range over complete chapters can be upcasted to a chapter range
+        assert_eq!(
+            parse_reference("John 3:1-4:54")
+                .unwrap()
+                .bible_reference()
+                .try_upcast(),
+            BibleReferenceRepresentation::Range(
+                BibleRange::new(
+                    BibleReference::BibleChapter(
+                        BibleChapterReference::new(BibleBook::John, 3).unwrap()
+                    ),
+                    BibleReference::BibleChapter(
+                        BibleChapterReference::new(BibleBook::John, 4).unwrap()
+                    )
+                )
+                .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_superscription_parsing() {
+        let reference = parse_reference("Psalms 3:0").unwrap();
+        assert_eq!(
+            reference.bible_reference(),
+            &BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Psalm, 3, 0).unwrap()
+            ))
+        );
+        assert!(parse_reference("Psalms 3:0-8").is_ok());
+        assert!(parse_reference("Psalms 1:0").is_err());
+        assert!(parse_reference("John 3:0").is_err());
+    }
+
+    #[test]
+    fn test_diacritic_insensitive_parsing() {
+        let reference = parse_single_reference("genese 1:1".to_string()).unwrap();
+        assert_eq!(
+            *reference.bible_reference(),
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Genesis, 1, 1).unwrap())
+        );
+        assert_eq!(reference.language_code(), "fr");
+
+        let reference = parse_single_reference("Deuteronome 6".to_string()).unwrap();
+        assert_eq!(
+            *reference.bible_reference(),
+            BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Deuteronomy, 6).unwrap()
+            )
+        );
+        assert_eq!(reference.language_code(), "fr");
+
+        let reference = parse_single_reference("Exodo 3".to_string()).unwrap();
+        assert_eq!(
+            *reference.bible_reference(),
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Exodus, 3).unwrap())
+        );
+
+        let reference = parse_single_reference("nehemias 8".to_string()).unwrap();
+        assert_eq!(
+            *reference.bible_reference(),
+            BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Nehemiah, 8).unwrap()
+            )
+        );
+        assert_eq!(reference.language_code(), "es");
+
+        let reference = parse_single_reference("levitico 19:18".to_string()).unwrap();
+        assert_eq!(
+            *reference.bible_reference(),
+            BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Leviticus, 19, 18).unwrap()
+            )
+        );
+
+        // The accented names stay the canonical output
+        assert!(
+            crate::translate("genese 1:1", "fr")
+                .unwrap()
+                .starts_with("Genèse")
+        );
+        assert!(
+            crate::translate("Exodo 3", "es")
+                .unwrap()
+                .starts_with("Éxodo")
+        );
+
+        // The Japanese voiced sound marks are no diacritics
+        assert_eq!(normalize_book_name("Genèse"), normalize_book_name("GENESE"));
+        assert_ne!(normalize_book_name("エズ"), normalize_book_name("エス"));
+    }
+
+    #[test]
+    fn test_exact_book_names_take_precedence() {
+        let book_of = |reference: &str| match parse_single_reference(reference.to_string())
+            .unwrap()
+            .bible_reference()
+        {
+            BibleReference::BibleChapter(chapter) => chapter.book(),
+            _ => unreachable!(),
+        };
+        assert_eq!(book_of("Es 1"), BibleBook::Exodus);
+        assert_eq!(book_of("És 1"), BibleBook::Isaiah);
+        assert_eq!(book_of("es 1"), BibleBook::Exodus);
+        assert_eq!(book_of("Dan 1"), BibleBook::Daniel);
+        assert_eq!(book_of("Dân 1"), BibleBook::Numbers);
+        assert_eq!(book_of("DAN 1"), BibleBook::Daniel);
+        assert_eq!(book_of("Ap 1"), BibleBook::Revelation);
+
+        // Short names are not matched without their diacritics, so "Exo" is not the Vietnamese "Êxơ" (Esther)
+        assert!(parse_reference("Exo 2:3").is_err());
+        assert!(parse_reference("Gen 1:1-Exo 2:3").is_err());
+        assert!(parse_reference("Gen-Exo").is_err());
+        assert_eq!(
+            parse_reference("Êxơ 2:3").unwrap().bible_reference(),
+            &BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Esther, 2, 3).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_folded_book_names_do_not_collide_across_languages() {
+        let languages = &*REFERENCE_LANGUAGES.read().unwrap();
+        let mut names: Vec<(&str, BibleBook, BookReferenceType, &String)> = vec![];
+        for language in languages {
+            for (book_names, reference_type) in [
+                (&language.long_names, BookReferenceType::Long),
+                (&language.short_names, BookReferenceType::Short),
+            ] {
+                for (book, book_names) in book_names {
+                    for name in book_names {
+                        names.push((&language.language_code, *book, reference_type, name));
+                    }
+                }
+            }
+        }
+
+        // A long name typed without its diacritics must not be the name of another book, otherwise it would be parsed as that book
+        for (code, book, reference_type, name) in &names {
+            if *reference_type != BookReferenceType::Long {
+                continue;
+            }
+            let folded_name = normalize_book_name(&name.replace(" ", ""));
+            for (other_code, other_book, other_reference_type, other_name) in &names {
+                let collides = match other_reference_type {
+                    BookReferenceType::Long => name_matches_normalized(other_name, &folded_name),
+                    BookReferenceType::Short => {
+                        name_matches_ignoring_case(other_name, &folded_name)
+                    }
+                };
+                assert!(
+                    book == other_book || !collides,
+                    "{name} ({code}, {book:?}) collides with {other_name} ({other_code}, {other_book:?})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_levenshtein_distance() {
+        assert_eq!(levenshtein_distance("", ""), 0);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("philipians", "philippians"), 1);
+        assert_eq!(levenshtein_distance("约翰福音", "约翰一书"), 2);
+    }
+
+    #[test]
+    fn test_fuzzy_parsing() {
+        let result = parse_reference_fuzzy("Philipians 4:13", 2).unwrap();
+        assert_eq!(
+            *result.bible_reference(),
+            BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::Philippians, 4, 13).unwrap()
+            ))
+        );
+        assert_eq!(result.language_code(), "en");
+
+        let result = parse_reference_fuzzy("Ecclesiates 3:1-8", 2).unwrap();
+        assert_eq!(result.bible_reference().to_string(), "Ecclesiastes 3:1-8");
+        let result = parse_reference_fuzzy("1 Corintians 13", 2).unwrap();
+        assert_eq!(result.bible_reference().to_string(), "1 Corinthians 13");
+
+        // Exact matches and other errors are not affected
+        assert!(parse_reference_fuzzy("John 3:16", 2).is_ok());
+        assert!(
+            parse_reference_fuzzy("Revelation 24", 2)
+                .unwrap_err()
+                .is::<BibleReferenceValidationError>()
+        );
+        // Too far away
+        assert!(
+            parse_reference_fuzzy("Philipians 4:13", 0)
+                .unwrap_err()
+                .is::<BibleBookNotFoundError>()
+        );
+        assert!(
+            parse_reference_fuzzy("Xyzzyqwerty 1", 2)
+                .unwrap_err()
+                .is::<BibleBookNotFoundError>()
+        );
+        // "Jom" is as close to "Job" as to "Joh" (John in German)
+        let error = parse_reference_fuzzy("Jom 3", 1).unwrap_err();
+        let ambiguity = error.downcast_ref::<AmbiguousBookNameError>().unwrap();
+        assert!(ambiguity.candidates.contains(&BibleBook::Job));
+        assert!(ambiguity.candidates.contains(&BibleBook::John));
+    }
+
+    #[test]
+    fn test_verse_list_parsing() {
+        let verse = |book, chapter, verse| {
+            BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+                BibleVerseReference::new(book, chapter, verse).unwrap(),
+            ))
+        };
+
+        assert_eq!(
+            parse_verse_list("John 3:16,18,20").unwrap(),
+            vec![
+                verse(BibleBook::John, 3, 16),
+                verse(BibleBook::John, 3, 18),
+                verse(BibleBook::John, 3, 20)
+            ]
+        );
+        assert_eq!(
+            parse_verse_list("Ps 1:1, 3, 6").unwrap(),
+            vec![
+                verse(BibleBook::Psalm, 1, 1),
+                verse(BibleBook::Psalm, 1, 3),
+                verse(BibleBook::Psalm, 1, 6)
+            ]
+        );
+        // In German, the first comma is the chapter/verse delimiter
+        assert_eq!(
+            parse_verse_list("Joh 3,16,18,20").unwrap(),
+            vec![
+                verse(BibleBook::John, 3, 16),
+                verse(BibleBook::John, 3, 18),
+                verse(BibleBook::John, 3, 20)
+            ]
+        );
+        let verses = parse_verse_list("1. Mose 1,1-3,5").unwrap();
+        assert_eq!(verses.len(), 2);
+        assert_eq!(verses[0].to_string(), "Genesis 1:1-3");
+        assert_eq!(verses[1], verse(BibleBook::Genesis, 1, 5));
+        assert_eq!(parse_verse_list("约翰福音3：16，18").unwrap().len(), 2);
+
+        // References without a list result in a single item
+        assert_eq!(
+            parse_verse_list("John 3:16").unwrap(),
+            vec![verse(BibleBook::John, 3, 16)]
+        );
+        assert_eq!(parse_verse_list("1 John 3").unwrap().len(), 1);
+
+        assert!(parse_verse_list("Jude 1:2,30").is_err());
+        assert!(parse_verse_list("John 3:16,,18").is_err());
+
+        // The items can be merged again
+        let aggregated = crate::bible::aggregate_bible_representations(
+            parse_verse_list("Ps 1:1,2,3,6").unwrap(),
+        );
+        assert_eq!(aggregated.len(), 2);
+        assert_eq!(aggregated[0].to_string(), "Psalms 1:1-3");
+
+        // A list is no single reference
+        for list in [
+            "Gen 1:1,3",
+            "John 3:16,18,20",
+            "Joh 3,16,18",
+            "约翰福音3：16，18",
+        ] {
+            assert!(
+                parse_reference(list).err().unwrap().is::<VerseListError>(),
+                "{}",
+                list
+            );
+        }
+        // In German, a comma after the range delimiter belongs to the end of the range
+        assert_eq!(
+            parse_reference("Joh 3,16-4,2")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "John 3:16-4:2"
+        );
+    }
+
+    #[test]
+    fn test_single_chapter_books() {
+        for (input, book, verse) in [
+            ("Obadiah 21", BibleBook::Obadiah, 21),
+            ("Philemon 3", BibleBook::Philemon, 3),
+            ("2 John 4", BibleBook::IIJohn, 4),
+            ("3 John 14", BibleBook::IIIJohn, 14),
+            ("Jude 5", BibleBook::Jude, 5),
+            ("Jude 1", BibleBook::Jude, 1),
+            ("Jud 13", BibleBook::Jude, 13),
+        ] {
+            assert_eq!(
+                *parse_single_reference(input.to_string())
+                    .unwrap()
+                    .bible_reference(),
+                BibleReference::BibleVerse(BibleVerseReference::new(book, 1, verse).unwrap()),
+                "{}",
+                input
+            );
+        }
+        // The chapter can still be given explicitly
+        assert_eq!(
+            parse_reference("Jude 1:5").unwrap().bible_reference(),
+            parse_reference("Jude 5").unwrap().bible_reference()
+        );
+        assert_eq!(
+            parse_reference("Jude 3-5")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "Jude 1:3-5"
+        );
+        assert!(
+            parse_reference("Jude")
+                .unwrap()
+                .bible_reference()
+                .is_single()
+        );
+
+        // After books with several chapters, a single number remains a chapter
+        assert_eq!(
+            *parse_single_reference("John 3".to_string())
+                .unwrap()
+                .bible_reference(),
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::John, 3).unwrap())
+        );
+        assert_eq!(
+            *parse_single_reference("Ruth 4".to_string())
+                .unwrap()
+                .bible_reference(),
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Ruth, 4).unwrap())
+        );
+
+        // The chapter of a book with one chapter is written as a verse range, so that it is not read as a verse again
+        for book in [
+            BibleBook::Obadiah,
+            BibleBook::Philemon,
+            BibleBook::IIJohn,
+            BibleBook::IIIJohn,
+            BibleBook::Jude,
+        ] {
+            let chapter = BibleChapterReference::new(book, 1).unwrap();
+            let parsed = parse_reference(&chapter.to_string()).unwrap();
+            assert_eq!(
+                parsed.bible_reference().as_verse_list(),
+                chapter.verses(),
+                "{}",
+                chapter
+            );
+        }
+        assert_eq!(
+            BibleChapterReference::new(BibleBook::Jude, 1)
+                .unwrap()
+                .to_string(),
+            "Jude 1:1-25"
+        );
+        let range = BibleRange::new(
+            BibleReference::BibleChapter(
+                BibleChapterReference::new(BibleBook::Obadiah, 1).unwrap(),
+            ),
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Jonah, 2).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(range.to_string(), "Obadiah 1:1-Jonah 2:10");
+        assert_eq!(
+            parse_reference(&range.to_string())
+                .unwrap()
+                .bible_reference()
+                .as_verse_list(),
+            BibleReferenceRepresentation::Range(range).as_verse_list()
+        );
+    }
+
+    #[test]
+    fn test_single_chapter_book_errors() {
+        use crate::bible::errors::BibleReferenceProblem;
+
+        let assert_problem = |input: &str, problem: BibleReferenceProblem| {
+            let error = parse_reference(input).unwrap_err();
+            assert_eq!(
+                error
+                    .downcast_ref::<BibleReferenceValidationError>()
+                    .unwrap()
+                    .problem,
+                problem,
+                "{}",
+                input
+            );
+        };
+        // A lone number after a single-chapter book is a verse, so a number which is too large is a missing verse
+        assert_problem("Obadiah 25", BibleReferenceProblem::VerseDoesNotExist);
+        assert_problem("Obadiah 0", BibleReferenceProblem::VerseDoesNotExist);
+        assert_problem("Obadiah 20-25", BibleReferenceProblem::VerseDoesNotExist);
+        assert_problem("Jude 30-31", BibleReferenceProblem::VerseDoesNotExist);
+        assert_problem("Obadiah 1:25", BibleReferenceProblem::VerseDoesNotExist);
+        // An explicit chapter is still checked as a chapter
+        assert_problem("Obadiah 2:1", BibleReferenceProblem::ChapterDoesNotExist);
+        assert_problem("John 22", BibleReferenceProblem::ChapterDoesNotExist);
+    }
+
+    #[test]
+    fn test_parse_leading_book() {
+        assert_eq!(
+            parse_leading_book("Song of Solomon 2:1"),
+            Some((BibleBook::SongofSolomon, " 2:1", BookReferenceType::Long))
+        );
+        assert_eq!(
+            parse_leading_book("1john3:16"),
+            Some((BibleBook::IJohn, "3:16", BookReferenceType::Long))
+        );
+        assert_eq!(
+            parse_leading_book("1. Mose 1,1"),
+            Some((BibleBook::Genesis, " 1,1", BookReferenceType::Long))
+        );
+        assert_eq!(
+            parse_leading_book("约翰福音3：16"),
+            Some((BibleBook::John, "3：16", BookReferenceType::Long))
+        );
+        assert_eq!(
+            parse_leading_book("Rev"),
+            Some((BibleBook::Revelation, "", BookReferenceType::Short))
+        );
+        // The longest name wins, e.g. "Johannes" instead of "Joh"
+        assert_eq!(parse_leading_book("Johannes").unwrap().0, BibleBook::John);
+        assert_eq!(
+            parse_leading_book("Johannes").unwrap().2,
+            BookReferenceType::Long
+        );
+        // Long names match without their diacritics like in parse_reference, short names only with them
+        assert_eq!(
+            parse_leading_book("Genese 1:1"),
+            Some((BibleBook::Genesis, " 1:1", BookReferenceType::Long))
+        );
+        assert_eq!(
+            parse_leading_book("GENÈSE 1:1"),
+            Some((BibleBook::Genesis, " 1:1", BookReferenceType::Long))
+        );
+        assert_eq!(
+            parse_leading_book("Gene\u{300}se 1:1"),
+            Some((BibleBook::Genesis, " 1:1", BookReferenceType::Long))
+        );
+        assert_eq!(
+            parse_leading_book("Êxơ 2:3"),
+            Some((BibleBook::Esther, " 2:3", BookReferenceType::Short))
+        );
+        assert_eq!(parse_leading_book("Exo 2:3"), None);
+        assert_eq!(parse_leading_book("Johnny"), None);
+        assert_eq!(parse_leading_book("3:16"), None);
+        assert_eq!(parse_leading_book(""), None);
+    }
+
+    #[test]
+    fn test_numbered_books() {
+        let numbered_books: Vec<BibleBook> = BibleBook::all()
+            .into_iter()
+            .filter(|book| !book.is_deuterocanonical())
+            .filter(|book| {
+                crate::referencing::language::ENGLISH_REFERENCE_LANGUAGE.long_names[book][0]
+                    .starts_with(|c: char| c.is_numeric())
+            })
+            .collect();
+        assert_eq!(numbered_books.len(), 17);
+
+        // The whole book name is compared, so "3 John" can never be taken for "John"
+        // (Ukrainian is left out as its abbreviations of Kings are the Russian abbreviations of Samuel)
+        for language_code in ["en", "de", "fr", "es", "zh_sim", "ru", "la"] {
+            let language = get_language_by_code(language_code).unwrap();
+            for book in &numbered_books {
+                for name in language.long_names[book]
+                    .iter()
+                    .chain(&language.short_names[book])
+                {
+                    let input = format!("{} 1:1", name);
+                    assert_eq!(
+                        *parse_single_reference(input.clone())
+                            .unwrap()
+                            .bible_reference(),
+                        BibleReference::BibleVerse(BibleVerseReference::new(*book, 1, 1).unwrap()),
+                        "{}",
+                        input
+                    );
+                }
+            }
+        }
+
+        for (input, book) in [
+            ("3 John 1", BibleBook::IIIJohn),
+            ("3John 1", BibleBook::IIIJohn),
+            ("2 John 1", BibleBook::IIJohn),
+            ("1 John 1:1", BibleBook::IJohn),
+            ("John 1:1", BibleBook::John),
+            ("2 Kings 1:1", BibleBook::IIKings),
+            ("1 Sam 1:1", BibleBook::ISamuel),
+        ] {
+            let result = parse_reference(input).unwrap();
+            let BibleReferenceRepresentation::Single(BibleReference::BibleVerse(verse)) =
+                result.bible_reference()
+            else {
+                panic!("{} is not a verse", input);
+            };
+            assert_eq!(verse.book(), book, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_fullwidth_digits_and_chinese_numerals() {
+        let john_3_16 = BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+            BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+        ));
+        for input in ["约翰福音３：１６", "约翰福音3：１６", "约翰福音三章十六节"]
+        {
+            let result = parse_reference(input).unwrap();
+            assert_eq!(result.bible_reference(), &john_3_16, "{}", input);
+            assert_eq!(result.language_code(), "zh_sim");
+        }
+        assert_eq!(
+            parse_reference("约翰福音３：１６-１８")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "John 3:16-18"
+        );
+        assert_eq!(
+            parse_reference("诗篇一百一十九篇")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "Psalms 119"
+        );
+        assert_eq!(
+            parse_reference("诗篇二十三篇")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "Psalms 23"
+        );
+        // Numerals which belong to the book name are kept
+        assert_eq!(
+            parse_reference("约翰一书四章八节")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "1 John 4:8"
+        );
+
+        assert_eq!(parse_chinese_numeral(&['十']), 10);
+        assert_eq!(parse_chinese_numeral(&['二', '十']), 20);
+        assert_eq!(parse_chinese_numeral(&['一', '百', '零', '五']), 105);
+    }
+
+    #[test]
+    fn test_book_names_with_range_delimiter() {
+        // Vietnamese book names contain the range delimiter
+        for (input, expected) in [
+            ("Nê-hê-mi 1:1", "Nehemiah 1:1"),
+            ("Lu-ca 1:1", "Luke 1:1"),
+            ("Rô-ma 8:28", "Romans 8:28"),
+            ("Nê-hê-mi 1:1-3", "Nehemiah 1:1-3"),
+            ("Lu-ca 1:1-2:3", "Luke 1:1-2:3"),
+            ("Lu-ca 1-Rô-ma 2", "Luke 1-Romans 2"),
+        ] {
+            let result = parse_reference(input).unwrap();
+            assert_eq!(result.bible_reference().to_string(), expected, "{}", input);
+            assert_eq!(result.language_code(), "vi", "{}", input);
+        }
+        // Additional parts are not ignored
+        assert!(parse_reference("John 3:16-18-20").is_err());
+    }
+
+    #[test]
+    fn test_delimiter_variants() {
+        let john_3_16 = BibleReferenceRepresentation::Single(BibleReference::BibleVerse(
+            BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+        ));
+        assert_eq!(
+            parse_reference("John 3：16").unwrap().bible_reference(),
+            &john_3_16
+        );
+
+        for input in [
+            "John 3:16-18",
+            "John 3:16‒18",
+            "John 3:16–18",
+            "John 3:16—18",
+            "John 3:16~18",
+            "John 3：16～18",
+        ] {
+            let result = parse_reference(input).unwrap();
+            assert_eq!(result.language_code(), "en", "{}", input);
+            // The output keeps the configured delimiters of the language
+            assert_eq!(
+                result.bible_reference().to_string(),
+                "John 3:16-18",
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            parse_reference("John 3:16–4：2")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "John 3:16-4:2"
+        );
+        assert_eq!(
+            parse_reference("Johannes 3,16—4,2")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "John 3:16-4:2"
+        );
+        assert_eq!(
+            parse_reference("约翰福音3:16~4:2")
+                .unwrap()
+                .bible_reference()
+                .to_string(),
+            "John 3:16-4:2"
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_range_granularity() {
+        let is_ambiguous = |input: &str| {
+            matches!(
+                parse_reference(input)
+                    .err()
+                    .unwrap()
+                    .downcast_ref::<BibleRangeParsingError>(),
+                Some(BibleRangeParsingError::AmbiguousRangeGranularity)
+            )
+        };
+
+        // A finer end within the start
+        assert!(is_ambiguous("John 3-3:16"));
+        assert!(is_ambiguous("Genesis - Genesis 3"));
+        // A coarser end
+        assert!(is_ambiguous("John 3:16-John 4"));
+        assert!(is_ambiguous("John 3:16-Acts"));
+        assert!(is_ambiguous("John 3 - Acts"));
+        assert!(is_ambiguous("Joh 3,16-Joh 4"));
+
+        // A finer end after the start is clear
+        let accepted = [
+            ("John 3-4:2", "John 3:1-4:2"),
+            ("Genesis-Exodus 3", "Genesis 1-Exodus 3"),
+            ("Genesis - Exodus 3:4", "Genesis 1:1-Exodus 3:4"),
+            ("Genesis 1-Exodus 2:3", "Genesis 1:1-Exodus 2:3"),
+            ("John 3:16-4:2", "John 3:16-4:2"),
+            ("Jude 3-5", "Jude 1:3-5"),
+        ];
+        for (input, expected) in accepted {
+            assert_eq!(
+                parse_reference(input)
+                    .unwrap()
+                    .bible_reference()
+                    .to_string(),
+                expected,
+                "'{}'",
+                input
+            );
+        }
+    }
 }
@@ -0,0 +1,180 @@
+//! This module allows to (de)serialize Bible references as compact English strings (e.g. "John 3:16-18") instead of nested structures.
+//! By default, the serde implementations of the crate serialize a [BibleVerseReference](crate::bible::BibleVerseReference) as a struct with the fields book, chapter and verse.
+//! To use the string representation instead, annotate a field of the type [BibleReference] or [BibleReferenceRepresentation] with `#[serde(with = "bibleref::referencing::serde_string")]`.
+//!
+//! The string is created with the [Display] implementation and read with [parse_reference],
+//! so every supported language is accepted while deserializing.
+//! # Note
+//! A range which contains only one reference (e.g. "John 3:16-16") is serialized as a single reference and will be deserialized as [BibleReferenceRepresentation::Single].
+//!
+//! # Example
+//! ```
+//! use bibleref::bible::{BibleBook, BibleReference, BibleVerseReference};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Bookmark {
+//!     #[serde(with = "bibleref::referencing::serde_string")]
+//!     reference: BibleReference,
+//! }
+//!
+//! let bookmark = Bookmark {
+//!     reference: BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::John, 3, 16).unwrap()),
+//! };
+//! assert_eq!(serde_json::to_string(&bookmark).unwrap(), r#"{"reference":"John 3:16"}"#);
+//!
+//! // Every supported language is accepted while deserializing
+//! let bookmark: Bookmark = serde_json::from_str(r#"{"reference":"Joh 3,16"}"#).unwrap();
+//! assert_eq!(bookmark.reference.to_string(), "John 3:16");
+//! ```
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Deserializer, Serializer, de};
+
+use crate::bible::{
+    BibleReference, BibleReferenceRepresentation, validate::get_number_of_chapters,
+};
+
+use super::parser::parse_reference;
+
+/// A type which can be (de)serialized as a string with this module.
+pub trait SerdeString: Display + Sized {
+    /// Converts a parsed [BibleReferenceRepresentation] into the type, returning [None] if it can't be represented by it.
+    fn from_representation(representation: BibleReferenceRepresentation) -> Option<Self>;
+}
+
+impl SerdeString for BibleReference {
+    fn from_representation(representation: BibleReferenceRepresentation) -> Option<Self> {
+        match as_single_chapter_book_chapter(representation) {
+            BibleReferenceRepresentation::Single(reference) => Some(reference),
+            BibleReferenceRepresentation::Range(_) => None,
+        }
+    }
+}
+
+impl SerdeString for BibleReferenceRepresentation {
+    fn from_representation(representation: BibleReferenceRepresentation) -> Option<Self> {
+        Some(as_single_chapter_book_chapter(representation))
+    }
+}
+
+/// The chapter of a book with only one chapter is written as the range of all its verses (e.g. "Jude 1:1-25"), see [Display].
+/// Such a range is converted back into the chapter, all other representations are returned unchanged.
+fn as_single_chapter_book_chapter(
+    representation: BibleReferenceRepresentation,
+) -> BibleReferenceRepresentation {
+    match representation.try_upcast() {
+        BibleReferenceRepresentation::Single(BibleReference::BibleChapter(chapter))
+            if get_number_of_chapters(&chapter.book()) == 1 =>
+        {
+            BibleReferenceRepresentation::Single(BibleReference::BibleChapter(chapter))
+        }
+        _ => representation,
+    }
+}
+
+/// Serializes a Bible reference as its English string representation.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: SerdeString,
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
+
+/// Deserializes a Bible reference from a string in any supported language.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: SerdeString,
+    D: Deserializer<'de>,
+{
+    let reference = String::deserialize(deserializer)?;
+    let search_result = parse_reference(&reference).map_err(de::Error::custom)?;
+    T::from_representation(search_result.bible_reference().clone())
+        .ok_or_else(|| de::Error::custom(format!("'{}' is no single Bible reference", reference)))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::bible::{
+        BibleBook, BibleChapterReference, BibleRange, BibleVerseRange, BibleVerseReference,
+    };
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Bookmark {
+        #[serde(with = "super")]
+        reference: BibleReference,
+        #[serde(with = "super")]
+        passage: BibleReferenceRepresentation,
+    }
+
+    #[test]
+    fn test_serde_string_round_trip() {
+        let bookmark = Bookmark {
+            reference: BibleReference::BibleVerse(
+                BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+            ),
+            passage: BibleReferenceRepresentation::Range(BibleRange::VerseRange(
+                BibleVerseRange::new(
+                    BibleVerseReference::new(BibleBook::John, 3, 16).unwrap(),
+                    BibleVerseReference::new(BibleBook::John, 3, 18).unwrap(),
+                )
+                .unwrap(),
+            )),
+        };
+        let serialized = serde_json::to_string(&bookmark).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"reference":"John 3:16","passage":"John 3:16-18"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Bookmark>(&serialized).unwrap(),
+            bookmark
+        );
+    }
+
+    #[test]
+    fn test_serde_string_single_chapter_books() {
+        // The chapter of Jude is written as a range of verses, as "Jude 1" would be read as a verse
+        let jude_1 =
+            BibleReference::BibleChapter(BibleChapterReference::new(BibleBook::Jude, 1).unwrap());
+        let bookmark = Bookmark {
+            reference: jude_1.clone(),
+            passage: BibleReferenceRepresentation::Single(jude_1),
+        };
+        let serialized = serde_json::to_string(&bookmark).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"reference":"Jude 1:1-25","passage":"Jude 1:1-25"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Bookmark>(&serialized).unwrap(),
+            bookmark
+        );
+
+        // Other ranges of whole chapters are kept
+        let bookmark: Bookmark =
+            serde_json::from_str(r#"{"reference":"Jude 1","passage":"John 3:1-36"}"#).unwrap();
+        assert_eq!(
+            bookmark.reference,
+            BibleReference::BibleVerse(BibleVerseReference::new(BibleBook::Jude, 1, 1).unwrap())
+        );
+        assert!(bookmark.passage.is_range());
+    }
+
+    #[test]
+    fn test_serde_string_errors() {
+        assert!(
+            serde_json::from_str::<Bookmark>(r#"{"reference":"John 3:16-18","passage":"John 3"}"#)
+                .is_err()
+        );
+        assert!(
+            serde_json::from_str::<Bookmark>(r#"{"reference":"John 3:99","passage":"John 3"}"#)
+                .is_err()
+        );
+    }
+}
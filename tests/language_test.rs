@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+
+use bibleref::referencing::language::{
+    get_default_language, get_language_by_code, language_exists, register_language,
+    set_default_language, supported_languages,
+};
+
+#[test]
+fn test_supported_languages() {
+    let languages = supported_languages();
+    assert!(languages.contains(&("zh_sim".to_string(), "Chinese Simplified".to_string())));
+    assert!(
+        languages
+            .iter()
+            .all(|(language_code, _)| language_exists(language_code))
+    );
+
+    // Languages which are registered during runtime are included
+    let mut language = get_language_by_code("en").unwrap();
+    language.language_code = "en_us".to_string();
+    language.long_language_name = "English (US)".to_string();
+    register_language(language).unwrap();
+    let languages = supported_languages();
+    assert_eq!(
+        languages.last().unwrap(),
+        &("en_us".to_string(), "English (US)".to_string())
+    );
+    assert!(language_exists("EN_US"));
+}
+
+#[test]
+fn test_language_exists() {
+    assert!(language_exists("en"));
+    assert!(language_exists("  Zh_Trad\t"));
+    assert!(!language_exists(""));
+    assert!(!language_exists("english"));
+    assert!(
+        bibleref::translate("John 3:16", "english")
+            .err()
+            .unwrap()
+            .is::<bibleref::referencing::errors::LanguageDoesNotExistError>()
+    );
+}
+
+/// Restores English as the default language when it is dropped, even if the test panics.
+struct DefaultLanguageGuard;
+
+impl Drop for DefaultLanguageGuard {
+    fn drop(&mut self) {
+        set_default_language("en").unwrap();
+    }
+}
+
+#[test]
+fn test_translating_into_default_language() {
+    let _guard = DefaultLanguageGuard;
+
+    // English is used until a default language is set
+    assert_eq!(get_default_language(), "en");
+    assert_eq!(
+        bibleref::translate_default("Johannes 3,16-18").unwrap(),
+        "John 3:16-18"
+    );
+
+    set_default_language(" FR ").unwrap();
+    assert_eq!(get_default_language(), "fr");
+    assert_eq!(
+        bibleref::translate_default("John 3:16-18").unwrap(),
+        bibleref::translate("John 3:16-18", "fr").unwrap()
+    );
+
+    // Unknown languages are rejected and do not change the default language
+    assert!(set_default_language("xx").is_err());
+    assert_eq!(get_default_language(), "fr");
+    assert!(bibleref::translate_default("Exodus 72").is_err());
+}
@@ -1,8 +1,8 @@
-use bibleref;
+#![cfg(feature = "std")]
 
 #[test]
 fn test_range_parsing() {
-    let ranges = vec![
+    let ranges = [
         "Joh 3-4",
         "Mt 5,2-7",
         "Klagelieder 1-2",
@@ -20,3 +20,299 @@ fn test_range_parsing() {
             Err(error) => panic!("'{}' failed to parse: {}", range, error),
         });
 }
+
+#[test]
+fn test_multiple_parsing() {
+    let references = bibleref::parse_multiple("John 3:16; Röm 8,28\nPsalms 23;").unwrap();
+    assert_eq!(references.len(), 3);
+    assert!(references.iter().all(|reference| reference.is_single()));
+
+    // Lists of verses result in one reference per verse
+    let references = bibleref::parse_multiple("Gen 1:1,3; John 3:16").unwrap();
+    assert_eq!(
+        references,
+        vec![
+            bibleref::parse("Genesis 1:1").unwrap(),
+            bibleref::parse("Genesis 1:3").unwrap(),
+            bibleref::parse("John 3:16").unwrap(),
+        ]
+    );
+
+    let error = bibleref::parse_multiple("John 3:16; Foo 1:1")
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("Foo 1:1"));
+}
+
+#[test]
+fn test_parsing_with_language() {
+    let cases = [
+        ("Johannes 3,16", "de"),
+        ("约翰福音3：16", "zh_sim"),
+        ("John 3:16-18", "en"),
+        ("Jean 3,16", "fr"),
+    ];
+    cases.iter().for_each(|(input, language_code)| {
+        let (reference, detected_language_code) = bibleref::parse_with_language(input).unwrap();
+        assert_eq!(reference, bibleref::parse(input).unwrap());
+        assert_eq!(detected_language_code, *language_code, "'{}'", input);
+    });
+    assert!(bibleref::parse_with_language("Foo 1:1").is_err());
+}
+
+#[test]
+fn test_parsing_all_chapter_verse_delimiters() {
+    // Each chapter/verse delimiter of a language is accepted, not only the first one which is used for output
+    let cases = [
+        ("Johannes", "de"),
+        ("Jean", "fr"),
+        ("От Иоанна", "ru"),
+        ("Від Івана", "uk"),
+        ("Juan", "es"),
+    ];
+    for (book_name, language_code) in cases {
+        for (reference, expected) in [
+            ("3:16", "John 3:16"),
+            ("3,16", "John 3:16"),
+            ("3:16-18", "John 3:16-18"),
+            ("3,16-18", "John 3:16-18"),
+            ("3:16-4,2", "John 3:16-4:2"),
+            ("3,16-4:2", "John 3:16-4:2"),
+        ] {
+            let input = format!("{} {}", book_name, reference);
+            let (reference, detected_language_code) =
+                bibleref::parse_with_language(&input).unwrap();
+            assert_eq!(reference.to_string(), expected, "'{}'", input);
+            assert_eq!(detected_language_code, language_code, "'{}'", input);
+        }
+    }
+}
+
+#[test]
+fn test_parsing_spaced_delimiters() {
+    let cases = [
+        ("Joshua 3 - 7", "Joshua 3-7"),
+        ("John 3:16 – 18", "John 3:16-18"),
+        ("John 3 : 16 - 4 : 2", "John 3:16-4:2"),
+        ("Johannes 3 , 16 - 18", "John 3:16-18"),
+        ("Jean 3, 16 — 4, 2", "John 3:16-4:2"),
+        ("约翰福音3：16 - 18", "John 3:16-18"),
+        ("Genesis 1 - Exodus 2", "Genesis 1-Exodus 2"),
+    ];
+    for (input, expected) in cases {
+        let reference = bibleref::parse(input).unwrap();
+        assert_eq!(reference.to_string(), expected, "'{}'", input);
+        assert_eq!(
+            reference,
+            bibleref::parse(&input.replace(' ', "")).unwrap(),
+            "'{}'",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_translating_many() {
+    let references = [
+        "Genesis 1:1",
+        "Mt 5,2-7",
+        "约翰福音3：16",
+        "Exodus 72",
+        "Jude 3-5",
+    ];
+    let translations = bibleref::translate_many(&references, "fr");
+    assert_eq!(translations.len(), references.len());
+    references
+        .iter()
+        .zip(translations.iter())
+        .for_each(
+            |(reference, translation)| match bibleref::translate(reference, "fr") {
+                Ok(expected) => assert_eq!(translation.as_ref().unwrap(), &expected),
+                Err(_) => assert!(translation.is_err(), "'{}' expected an error", reference),
+            },
+        );
+
+    let translations = bibleref::translate_many(&references, "xx");
+    assert!(translations.iter().all(|translation| {
+        translation
+            .as_ref()
+            .err()
+            .unwrap()
+            .is::<bibleref::referencing::errors::LanguageDoesNotExistError>()
+    }));
+}
+
+#[test]
+fn test_extracting_references() {
+    let text = "In John 3:16 Jesus speaks about love. Paul writes in Röm 8,28 and 1 Cor 13:4-7 about it, \
+        see also Psalms 23; Psalms 24 and John 3:17; John 3:18-20. John went home.";
+    let references: Vec<(&str, String)> = bibleref::extract_references(text)
+        .into_iter()
+        .map(|(span, reference)| (&text[span], reference.to_string()))
+        .collect();
+    assert_eq!(
+        references,
+        vec![
+            ("John 3:16", "John 3:16".to_string()),
+            ("Röm 8,28", "Romans 8:28".to_string()),
+            ("1 Cor 13:4-7", "1 Corinthians 13:4-7".to_string()),
+            // Adjacent passages are merged
+            ("Psalms 23; Psalms 24", "Psalms 23-24".to_string()),
+            ("John 3:17; John 3:18-20", "John 3:17-20".to_string()),
+        ]
+    );
+
+    assert!(bibleref::extract_references("No references here.").is_empty());
+
+    // Book names without their diacritics are recognized like in parse
+    let text = "Lies Genese 1:1 und Exodo 3";
+    let references: Vec<(&str, String)> = bibleref::extract_references(text)
+        .into_iter()
+        .map(|(span, reference)| (&text[span], reference.to_string()))
+        .collect();
+    assert_eq!(
+        references,
+        vec![
+            ("Genese 1:1", "Genesis 1:1".to_string()),
+            ("Exodo 3", "Exodus 3".to_string())
+        ]
+    );
+
+    // Lists of verses are split into their items, adjacent items are merged
+    let extract = |text: &'static str| -> Vec<(&str, String)> {
+        bibleref::extract_references(text)
+            .into_iter()
+            .map(|(span, reference)| (&text[span], reference.to_string()))
+            .collect()
+    };
+    assert_eq!(
+        extract("See Gen 1:1, 3 and more"),
+        vec![
+            ("Gen 1:1", "Genesis 1:1".to_string()),
+            ("3", "Genesis 1:3".to_string())
+        ]
+    );
+    assert_eq!(
+        extract("Ps 1:1,2,3,6"),
+        vec![
+            ("Ps 1:1,2,3", "Psalms 1:1-3".to_string()),
+            ("6", "Psalms 1:6".to_string())
+        ]
+    );
+    // Numbers which are only separated by a space do not belong together
+    assert_eq!(
+        extract("Read John 3:1 6 times"),
+        vec![("John 3:1", "John 3:1".to_string())]
+    );
+}
+
+#[test]
+fn test_normalizing() {
+    use bibleref::referencing::language::BookReferenceType;
+
+    // Ranges over a single verse, chapter or book are collapsed
+    assert_eq!(
+        bibleref::normalize("John 3:16-16", "en", BookReferenceType::Long).unwrap(),
+        "John 3:16"
+    );
+    assert_eq!(
+        bibleref::normalize("Johannes 3,16-16", "en", BookReferenceType::Long).unwrap(),
+        "John 3:16"
+    );
+    assert_eq!(
+        bibleref::normalize("Jude 1:1-25", "en", BookReferenceType::Long).unwrap(),
+        "Jude 1:1-25"
+    );
+    assert_eq!(
+        bibleref::normalize("Genesis 1-50", "en", BookReferenceType::Long).unwrap(),
+        "Genesis"
+    );
+
+    // Different spellings of the same reference are normalized to the same string
+    let inputs = ["John 3:16", "Joh 3,16", "Jn 3:16-16", "约翰福音3：16"];
+    for input in inputs {
+        assert_eq!(
+            bibleref::normalize(input, "de", BookReferenceType::Short).unwrap(),
+            bibleref::normalize(inputs[0], "de", BookReferenceType::Short).unwrap(),
+            "'{}'",
+            input
+        );
+    }
+
+    // Real ranges are kept
+    assert_eq!(
+        bibleref::normalize("Mt 5,3-12", "en", BookReferenceType::Long).unwrap(),
+        "Matthew 5:3-12"
+    );
+
+    assert!(bibleref::normalize("Exodus 72", "en", BookReferenceType::Long).is_err());
+    assert!(bibleref::normalize("John 3:16", "xx", BookReferenceType::Long).is_err());
+}
+
+#[test]
+fn test_parsing_without_space_after_book() {
+    let inputs = [
+        ("Gen1:1", "Genesis 1:1"),
+        ("Jn3:16", "John 3:16"),
+        ("Jn3:16-18", "John 3:16-18"),
+        ("2Sam1:1", "2 Samuel 1:1"),
+        ("2Sam1", "2 Samuel 1"),
+        ("1Jn1:9", "1 John 1:9"),
+        ("1Cor13:4-7", "1 Corinthians 13:4-7"),
+        ("Ps23", "Psalms 23"),
+        ("Johannes3,16", "John 3:16"),
+    ];
+    for (input, expected) in inputs {
+        assert_eq!(
+            bibleref::parse(input).unwrap().to_string(),
+            expected,
+            "'{}'",
+            input
+        );
+    }
+
+    // Invalid references are not shortened to different valid ones
+    assert!(bibleref::extract_references("See 3 John 2:1, Jude 1:26 and John 3:300.").is_empty());
+
+    // Compact references are also found within texts
+    let text = "See Gen1:1 and 2Sam1:1-3.";
+    let references = bibleref::extract_references(text);
+    assert_eq!(references.len(), 2);
+    assert_eq!(&text[references[0].0.clone()], "Gen1:1");
+    assert_eq!(references[1].1.to_string(), "2 Samuel 1:1-3");
+}
+
+#[test]
+fn test_parsing_book_name_aliases() {
+    let aliases = [
+        ("Song of Songs 1:1", "Song of Solomon 1:1"),
+        ("Canticles 2", "Song of Solomon 2"),
+        ("Revelations 21:4", "Revelation 21:4"),
+        ("Psalm 23", "Psalms 23"),
+        ("Psalm 23:1-6", "Psalms 23:1-6"),
+        ("Psa 119:105", "Psalms 119:105"),
+        ("Qoheleth 3:1", "Ecclesiastes 3:1"),
+        ("Hoheslied 2", "Song of Solomon 2"),
+        ("Psaume 23", "Psalms 23"),
+    ];
+    for (input, expected) in aliases {
+        assert_eq!(
+            bibleref::parse(input).unwrap().to_string(),
+            expected,
+            "'{}'",
+            input
+        );
+    }
+
+    // The first name stays the canonical output
+    assert_eq!(
+        bibleref::translate("Song of Songs 1:1", "en").unwrap(),
+        "Song of Solomon 1:1"
+    );
+    assert_eq!(bibleref::translate("Psa 23", "en").unwrap(), "Ps 23");
+    assert_eq!(
+        bibleref::translate("Revelations 1", "de").unwrap(),
+        "Offenbarung 1"
+    );
+    assert_eq!(bibleref::parse_with_language("Psalm 23").unwrap().1, "en");
+}
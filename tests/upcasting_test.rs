@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use bibleref::{
     bible::{
         BibleBook, BibleChapterReference, BibleRange, BibleReference, BibleReferenceRepresentation,